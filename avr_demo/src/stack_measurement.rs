@@ -1,70 +1,167 @@
 // The magic value we'll use to fill the stack area.
 const STACK_WATERMARK: u8 = 0xCE;
 
-// For the ATmega2560, RAMEND is at address 0x21FF.
-const RAMEND_ADDR: u16 = 0x21FF;
+/// Describes the unused-memory region a [`StackProfiler`] fills and later
+/// rescans for its high-water mark.
+///
+/// `start`/`end` bracket the region regardless of growth direction; which
+/// end is actually the "top" of the stack (the last byte to be touched as
+/// usage grows) depends on `grows_down`. Built from linker symbols --
+/// typically the end of `.bss`/`.data` (`_end`) paired with the top of RAM
+/// (`__stack`, `_stack_start`, or a target-specific constant like the
+/// ATmega2560's fixed `RAMEND`) -- since that's the only unused span a
+/// profiler can safely scribble over without touching live data.
+#[derive(Clone, Copy)]
+pub struct StackRegion {
+    /// Start of the unused region (the lower address).
+    pub start: *mut u8,
+    /// End of the unused region (the higher address), inclusive.
+    pub end: *mut u8,
+    /// `true` for the common case of a stack that grows toward lower
+    /// addresses (x86, ARM, AVR, RISC-V): usage eats the region from `end`
+    /// down toward `start`, so the high-water mark is the *lowest* address
+    /// still showing the watermark. `false` for the (rarer) upward-growing
+    /// case, where usage eats from `start` up toward `end`.
+    pub grows_down: bool,
+}
 
-// Linker symbol that marks the end of the .bss section.
-unsafe extern "C" {
-    static mut _end: u8;
+/// Fills `region` with the watermark pattern and scans it afterward for
+/// the high-water mark left behind once the stack has been exercised.
+///
+/// Generalizes the AVR ATmega2560-specific `fill_stack_with_watermark`/
+/// `measure_stack_usage` pair (which hardcoded `RAMEND = 0x21FF` and an
+/// implicit downward-growing stack) into something parameterized by a
+/// caller-supplied [`StackRegion`], so the same profiler works for the
+/// Cortex-M and RISC-V boards this crate also targets, not just the
+/// ATmega2560.
+pub struct StackProfiler {
+    region: StackRegion,
 }
 
-/// Fills the unused RAM with a magic value.
-pub unsafe fn fill_stack_with_watermark() {
-    let stack_start_ptr = &raw mut _end as *mut u8;
-    let stack_end_ptr = RAMEND_ADDR as *mut u8;
+impl StackProfiler {
+    /// Creates a profiler for `region`. Does not touch memory yet -- call
+    /// [`Self::fill_watermark`] next.
+    pub const fn new(region: StackRegion) -> Self {
+        Self { region }
+    }
 
-    // Even inside an `unsafe fn`, these operations now require an `unsafe` block.
-    unsafe {
-        let mut current_ptr = stack_start_ptr;
-        while current_ptr <= stack_end_ptr {
-            core::ptr::write_volatile(current_ptr, STACK_WATERMARK);
-            current_ptr = current_ptr.add(1);
+    /// Fills the entire region with the watermark pattern.
+    ///
+    /// # Safety
+    ///
+    /// `region` must describe memory that is genuinely unused at the time
+    /// of the call (no live data, no portion of the current call stack
+    /// below the caller's frame) and valid for volatile byte writes across
+    /// its whole `[start, end]` span.
+    pub unsafe fn fill_watermark(&self) {
+        let mut current = self.region.start;
+        // SAFETY: caller guarantees `[start, end]` is valid for volatile
+        // writes; the loop never advances past `end`.
+        unsafe {
+            while current <= self.region.end {
+                core::ptr::write_volatile(current, STACK_WATERMARK);
+                current = current.add(1);
+            }
         }
     }
-}
 
-/// Measures the maximum stack usage by finding the "high-water mark".
-/// This is unsafe because we are reading from a large, arbitrary memory region.
-pub unsafe fn measure_stack_usage() -> u16 {
-    let stack_start_ptr = &raw const _end as *const u8;
-    let stack_end_ptr = RAMEND_ADDR as *const u8;
+    /// Scans the region for the high-water mark left by whatever ran
+    /// between [`Self::fill_watermark`] and this call, returning the
+    /// number of bytes of the region that were touched.
+    ///
+    /// # Safety
+    ///
+    /// Same preconditions as [`Self::fill_watermark`]: `region` must be
+    /// valid for volatile byte reads across its whole `[start, end]` span.
+    pub unsafe fn measure_usage(&self) -> usize {
+        let StackRegion {
+            start, end, grows_down,
+        } = self.region;
 
-    // Validate memory region bounds before proceeding
-    if stack_start_ptr > stack_end_ptr {
-        return 0; // Invalid memory layout
-    }
-
-    unsafe {
-        let mut current_ptr = stack_start_ptr;
+        if start > end {
+            return 0; // Invalid memory layout.
+        }
 
-        // Add explicit bounds checking in the loop condition
-        while current_ptr < stack_end_ptr {
-            // Validate pointer is still within bounds before reading
-            if current_ptr > stack_end_ptr {
-                break; // Safety check - should not happen but prevents overflow
+        // SAFETY: caller guarantees `[start, end]` is valid for volatile
+        // reads; every pointer dereferenced below is within that range.
+        unsafe {
+            if grows_down {
+                // Usage eats the region from `end` downward, so the first
+                // byte (scanning from `start`) that no longer shows the
+                // watermark is the deepest point usage reached.
+                let mut current = start;
+                while current <= end {
+                    if core::ptr::read_volatile(current) != STACK_WATERMARK {
+                        return (end as usize) - (current as usize);
+                    }
+                    if current == end {
+                        break;
+                    }
+                    current = current.add(1);
+                }
+            } else {
+                // Usage eats the region from `start` upward, so scan from
+                // `end` backward for the same reason in reverse.
+                let mut current = end;
+                loop {
+                    if core::ptr::read_volatile(current) != STACK_WATERMARK {
+                        return (current as usize) - (start as usize);
+                    }
+                    if current == start {
+                        break;
+                    }
+                    current = current.sub(1);
+                }
             }
+        }
 
-            if core::ptr::read_volatile(current_ptr) != STACK_WATERMARK {
-                // We found the first byte that was overwritten. This is our high-water mark.
-                // The stack grows downwards from RAMEND, so the usage is the distance from the top.
-                return (stack_end_ptr as u16) - (current_ptr as u16);
-            }
+        0 // Should not happen if the region was used at all.
+    }
+}
 
-            // Check for potential overflow before incrementing
-            if current_ptr == stack_end_ptr {
-                break; // At boundary, prevent overflow
-            }
+// For the ATmega2560, RAMEND is at address 0x21FF.
+const RAMEND_ADDR: u16 = 0x21FF;
 
-            current_ptr = current_ptr.add(1);
-        }
+// Linker symbol that marks the end of the .bss section.
+unsafe extern "C" {
+    static mut _end: u8;
+}
 
-        // Handle edge case: check the final byte at stack_end_ptr
-        if current_ptr == stack_end_ptr && core::ptr::read_volatile(current_ptr) != STACK_WATERMARK
-        {
-            return (stack_end_ptr as u16) - (current_ptr as u16);
-        }
+/// Returns the [`StackRegion`] for this board: from the end of `.bss` up
+/// to the ATmega2560's fixed `RAMEND`, growing down.
+fn avr_stack_region() -> StackRegion {
+    StackRegion {
+        start: &raw mut _end as *mut u8,
+        end: RAMEND_ADDR as *mut u8,
+        grows_down: true,
     }
+}
 
-    0 // Should not happen if stack was used at all.
+/// Fills the unused RAM with a magic value.
+///
+/// Kept for existing callers targeting the ATmega2560; new code should
+/// build a [`StackRegion`] for its own target and use [`StackProfiler`]
+/// directly.
+///
+/// # Safety
+///
+/// Same as [`StackProfiler::fill_watermark`].
+pub unsafe fn fill_stack_with_watermark() {
+    // SAFETY: `avr_stack_region` spans from `_end` to `RAMEND`, the
+    // ATmega2560's unused-RAM range; forwarded to the caller's contract.
+    unsafe { StackProfiler::new(avr_stack_region()).fill_watermark() }
+}
+
+/// Measures the maximum stack usage by finding the "high-water mark".
+///
+/// Kept for existing callers targeting the ATmega2560; new code should
+/// build a [`StackRegion`] for its own target and use [`StackProfiler`]
+/// directly.
+///
+/// # Safety
+///
+/// Same as [`StackProfiler::measure_usage`].
+pub unsafe fn measure_stack_usage() -> u16 {
+    // SAFETY: forwarded to the caller's contract; see `fill_stack_with_watermark`.
+    (unsafe { StackProfiler::new(avr_stack_region()).measure_usage() }) as u16
 }
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reassembles out-of-order segments (e.g. indexed packets arriving over an
+//! unreliable transport) into the contiguous stream
+//! [`crate::stream_buffer::StreamBuffer`] expects, so a reader doesn't need
+//! an external staging buffer to reorder everything upstream. Modeled on
+//! smoltcp's `socket::tcp::Assembler`.
+
+use crate::stream_buffer::StreamBufferError;
+
+/// A gap of `hole_size` bytes (not yet received) followed by `data_size`
+/// bytes that have been received, relative to the end of the previous
+/// `Contig` (or position 0 -- "the next byte the consumer expects" -- for
+/// the first entry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contig {
+    pub hole_size: usize,
+    pub data_size: usize,
+}
+
+/// Tracks which byte ranges of an out-of-order stream have been received, up
+/// to `N` disjoint covered runs (`MAX_SEGMENT_COUNT`). The reader calls
+/// [`add`](Self::add) with each segment's absolute offset from position 0
+/// ("the next byte the consumer expects"); the caller then drains the
+/// gap-free prefix reported by [`peek_contiguous`](Self::peek_contiguous)
+/// into a `StreamBuffer` (e.g. via `mark_filled`) and calls
+/// [`remove_front`](Self::remove_front) to acknowledge it.
+///
+/// Internally this keeps the same alternating hole/data `Contig` list
+/// smoltcp's assembler uses, but `add` rebuilds it from a flat list of
+/// absolute covered runs rather than splicing `Contig`s in place in the
+/// overlap/partial-overlap case -- simpler to get right, at the cost of an
+/// O(N) rebuild per insert, which is fine for the small `N` this is sized
+/// for.
+pub struct Assembler<const N: usize> {
+    contigs: [Contig; N],
+    len: usize,
+}
+
+impl<const N: usize> Default for Assembler<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Assembler<N> {
+    /// Creates an empty assembler: nothing received yet, an infinite hole
+    /// ahead of position 0.
+    pub fn new() -> Self {
+        Self {
+            contigs: [Contig {
+                hole_size: 0,
+                data_size: 0,
+            }; N],
+            len: 0,
+        }
+    }
+
+    /// Absolute covered runs `[start, end)`, derived by walking the
+    /// hole/data `Contig` list.
+    fn runs(&self) -> [(usize, usize); N] {
+        let mut runs = [(0usize, 0usize); N];
+        let mut pos = 0;
+        for i in 0..self.len {
+            let start = pos + self.contigs[i].hole_size;
+            let end = start + self.contigs[i].data_size;
+            runs[i] = (start, end);
+            pos = end;
+        }
+        runs
+    }
+
+    /// Rebuilds the `Contig` list from a sorted, merged, disjoint run list.
+    fn set_runs(&mut self, runs: &[(usize, usize)]) {
+        let mut pos = 0;
+        for (i, &(start, end)) in runs.iter().enumerate() {
+            self.contigs[i] = Contig {
+                hole_size: start - pos,
+                data_size: end - start,
+            };
+            pos = end;
+        }
+        self.len = runs.len();
+    }
+
+    /// Records that `size` bytes were received starting at absolute
+    /// `offset` from position 0. A segment that's already fully covered is
+    /// a no-op; a segment that partially overlaps or touches an existing
+    /// run merges into it. Returns `TooManyHoles` if this segment doesn't
+    /// overlap anything and recording it would need more than `N` disjoint
+    /// runs.
+    pub fn add(&mut self, offset: usize, size: usize) -> Result<(), StreamBufferError> {
+        if size == 0 {
+            return Ok(());
+        }
+        let new_start = offset;
+        let new_end = offset.saturating_add(size);
+
+        let existing = self.runs();
+        let mut merged_start = new_start;
+        let mut merged_end = new_end;
+        let mut first = None;
+        let mut last = None;
+        for (i, &(s, e)) in existing.iter().enumerate().take(self.len) {
+            // Touching (not just overlapping) counts as mergeable, so
+            // adjacent runs coalesce into one instead of staying disjoint.
+            if e < merged_start || s > merged_end {
+                continue;
+            }
+            if first.is_none() {
+                first = Some(i);
+            }
+            last = Some(i);
+            merged_start = merged_start.min(s);
+            merged_end = merged_end.max(e);
+        }
+
+        let mut merged = [(0usize, 0usize); N];
+        let mut out_len = 0;
+        match (first, last) {
+            (Some(first), Some(last)) => {
+                for &run in &existing[..first] {
+                    merged[out_len] = run;
+                    out_len += 1;
+                }
+                merged[out_len] = (merged_start, merged_end);
+                out_len += 1;
+                for &run in &existing[(last + 1)..self.len] {
+                    merged[out_len] = run;
+                    out_len += 1;
+                }
+            }
+            _ => {
+                if self.len >= N {
+                    return Err(StreamBufferError::TooManyHoles);
+                }
+                let insert_at = existing[..self.len]
+                    .iter()
+                    .position(|&(s, _)| s > new_start)
+                    .unwrap_or(self.len);
+                for &run in &existing[..insert_at] {
+                    merged[out_len] = run;
+                    out_len += 1;
+                }
+                merged[out_len] = (new_start, new_end);
+                out_len += 1;
+                for &run in &existing[insert_at..self.len] {
+                    merged[out_len] = run;
+                    out_len += 1;
+                }
+            }
+        }
+
+        self.set_runs(&merged[..out_len]);
+        Ok(())
+    }
+
+    /// Returns how many bytes from the front (position 0) are contiguous
+    /// and gap-free, i.e. safe to expose to the tokenizer.
+    pub fn peek_contiguous(&self) -> usize {
+        if self.len == 0 || self.contigs[0].hole_size != 0 {
+            return 0;
+        }
+        self.contigs[0].data_size
+    }
+
+    /// Drops `size` gap-free bytes from the front, shifting every recorded
+    /// position back by `size` so position 0 again means "the next byte the
+    /// consumer expects". `size` must be `<= peek_contiguous()`.
+    pub fn remove_front(&mut self, size: usize) {
+        if size == 0 {
+            return;
+        }
+        let runs = self.runs();
+        let mut shifted = [(0usize, 0usize); N];
+        let mut out_len = 0;
+        for &(start, end) in runs.iter().take(self.len) {
+            let start = start.saturating_sub(size);
+            let end = end.saturating_sub(size);
+            if end > start {
+                shifted[out_len] = (start, end);
+                out_len += 1;
+            }
+        }
+        self.set_runs(&shifted[..out_len]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_assembler_has_nothing_contiguous() {
+        let asm: Assembler<4> = Assembler::new();
+        assert_eq!(asm.peek_contiguous(), 0);
+    }
+
+    #[test]
+    fn test_single_segment_at_front_is_contiguous() {
+        let mut asm: Assembler<4> = Assembler::new();
+        asm.add(0, 5).unwrap();
+        assert_eq!(asm.peek_contiguous(), 5);
+    }
+
+    #[test]
+    fn test_segment_after_a_gap_is_not_contiguous() {
+        let mut asm: Assembler<4> = Assembler::new();
+        asm.add(3, 5).unwrap();
+        assert_eq!(asm.peek_contiguous(), 0);
+    }
+
+    #[test]
+    fn test_filling_the_gap_makes_everything_contiguous() {
+        let mut asm: Assembler<4> = Assembler::new();
+        asm.add(3, 5).unwrap();
+        asm.add(0, 3).unwrap();
+        assert_eq!(asm.peek_contiguous(), 8);
+    }
+
+    #[test]
+    fn test_overlapping_segments_merge() {
+        let mut asm: Assembler<4> = Assembler::new();
+        asm.add(0, 4).unwrap();
+        asm.add(2, 4).unwrap(); // overlaps [2,4), extends to 6
+        assert_eq!(asm.peek_contiguous(), 6);
+    }
+
+    #[test]
+    fn test_adjacent_segments_merge_without_gap() {
+        let mut asm: Assembler<4> = Assembler::new();
+        asm.add(0, 3).unwrap();
+        asm.add(3, 3).unwrap(); // touches, no hole between them
+        assert_eq!(asm.peek_contiguous(), 6);
+    }
+
+    #[test]
+    fn test_fully_covered_segment_is_a_no_op() {
+        let mut asm: Assembler<4> = Assembler::new();
+        asm.add(0, 10).unwrap();
+        asm.add(2, 3).unwrap(); // already covered
+        assert_eq!(asm.peek_contiguous(), 10);
+    }
+
+    #[test]
+    fn test_remove_front_shifts_remaining_runs() {
+        let mut asm: Assembler<4> = Assembler::new();
+        asm.add(0, 4).unwrap();
+        asm.add(6, 4).unwrap(); // gap [4,6)
+        asm.remove_front(4);
+        // Front run is gone; remaining run now starts 2 bytes ahead.
+        assert_eq!(asm.peek_contiguous(), 0);
+        asm.add(0, 2).unwrap(); // fill the shifted gap
+        assert_eq!(asm.peek_contiguous(), 6);
+    }
+
+    #[test]
+    fn test_too_many_holes_once_capacity_exceeded() {
+        let mut asm: Assembler<2> = Assembler::new();
+        asm.add(0, 1).unwrap();
+        asm.add(2, 1).unwrap(); // capacity full: 2 disjoint runs
+        assert_eq!(asm.add(4, 1), Err(StreamBufferError::TooManyHoles));
+    }
+}
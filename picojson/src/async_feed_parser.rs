@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `AsyncFeedParser`: the async counterpart to [`PollParser`], for callers
+//! fed by an [`AsyncReader`] instead of handing over each chunk themselves.
+//! Gated behind the `async` feature, same as the rest of this crate's async
+//! support.
+//!
+//! [`PollParser`] is already sans-IO: it owns its handler and queues fully
+//! decoded, owned [`FeedEvent`]s, so nothing it returns borrows from the
+//! chunk that produced it. That's exactly what lets this type exist --
+//! awaiting a `read()` and feeding its result only has to happen *inside*
+//! [`Self::next_event`], never across a `.await` point the caller has to
+//! manage. [`Poll::NeedMoreInput`] becomes "await another `read()` and feed
+//! it", transparently, instead of something the caller drives by hand.
+//!
+//! Like [`AsyncStreamParser`](crate::AsyncStreamParser), the read buffer is
+//! a single caller-provided `&'buf mut [u8]`, reused a chunk at a time
+//! instead of buffering the whole input up front: each [`Self::next_event`]
+//! call reads into whatever of it is still unconsumed and feeds just the
+//! newly-read bytes, never revisiting bytes already fed. That rules out
+//! [`AsyncStreamParser`](crate::AsyncStreamParser)'s "fill, then parse from
+//! memory" approach, but keeps the same bound -- once the buffer is fully
+//! consumed and the document still isn't complete,
+//! [`ParseError::InputBufferFull`] is returned, the same as there.
+
+use crate::async_reader::AsyncReader;
+use crate::feed_parser::{FeedEvent, Poll, PollParser};
+use crate::parse_error::ParseError;
+use crate::ujson::{BitStackConfig, DefaultConfig};
+
+/// Async counterpart to [`PollParser`], fed by an [`AsyncReader`] instead
+/// of explicit [`PollParser::feed`] calls. See the [module docs](self) for
+/// how the read buffer is managed.
+pub struct AsyncFeedParser<'buf, R: AsyncReader, C: BitStackConfig = DefaultConfig> {
+    reader: R,
+    /// The still-unconsumed tail of the caller's read buffer. `None` only
+    /// transiently, while a `next_event` call has it split out to read into.
+    buf: Option<&'buf mut [u8]>,
+    inner: PollParser<'buf, 'buf, C>,
+    /// Set once `reader` has reported true end-of-stream and
+    /// [`PollParser::finish`] has been called.
+    reader_done: bool,
+}
+
+impl<'buf, R: AsyncReader> AsyncFeedParser<'buf, R, DefaultConfig> {
+    /// Creates a new parser reading from `reader`, using `buf` as the
+    /// read window (consumed, not reused -- see the module docs) and `scratch` for unescaping tokens split across
+    /// reads -- the same role the single buffer argument plays for
+    /// [`PollParser::new`], kept separate here since `buf` also has to
+    /// double as this adapter's read window.
+    pub fn new(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self::with_config(reader, buf, scratch)
+    }
+
+    /// Like [`Self::new`], but for a sequence of whitespace-separated
+    /// top-level JSON values (NDJSON-style) instead of exactly one -- same
+    /// multi-document mode as [`PollParser::new_ndjson`].
+    pub fn new_ndjson(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self::with_config_ndjson(reader, buf, scratch)
+    }
+}
+
+impl<'buf, R: AsyncReader, C: BitStackConfig> AsyncFeedParser<'buf, R, C> {
+    /// Like [`Self::new`], but with a custom [`BitStackConfig`].
+    pub fn with_config(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self {
+            reader,
+            buf: Some(buf),
+            inner: PollParser::new(scratch),
+            reader_done: false,
+        }
+    }
+
+    /// Like [`Self::new_ndjson`], but with a custom [`BitStackConfig`].
+    pub fn with_config_ndjson(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self {
+            reader,
+            buf: Some(buf),
+            inner: PollParser::new_ndjson(scratch),
+            reader_done: false,
+        }
+    }
+
+    /// See [`PushParser::set_max_depth`](crate::PushParser::set_max_depth).
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.inner.set_max_depth(max_depth);
+    }
+
+    /// See [`PushParser::depth`](crate::PushParser::depth).
+    pub fn depth(&self) -> usize {
+        self.inner.depth()
+    }
+
+    /// See [`PushParser::remaining_depth`](crate::PushParser::remaining_depth).
+    pub fn remaining_depth(&self) -> Option<usize> {
+        self.inner.remaining_depth()
+    }
+
+    /// See [`PushParser::in_object`](crate::PushParser::in_object).
+    pub fn in_object(&self) -> bool {
+        self.inner.in_object()
+    }
+
+    /// See [`PushParser::in_array`](crate::PushParser::in_array).
+    pub fn in_array(&self) -> bool {
+        self.inner.in_array()
+    }
+
+    /// See [`PushParser::set_reject_escaped_keys`](crate::PushParser::set_reject_escaped_keys).
+    pub fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.inner.set_reject_escaped_keys(reject);
+    }
+
+    /// See [`PushParser::set_reject_bidi_controls`](crate::PushParser::set_reject_bidi_controls).
+    pub fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.inner.set_reject_bidi_controls(reject);
+    }
+
+    /// See [`PushParser::set_surrogate_policy`](crate::PushParser::set_surrogate_policy).
+    pub fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.inner.set_surrogate_policy(policy);
+    }
+
+    /// See [`PushParser::set_lenient_syntax`](crate::PushParser::set_lenient_syntax).
+    pub fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.inner.set_lenient_syntax(enabled);
+    }
+
+    /// See [`PushParser::position`](crate::PushParser::position).
+    pub fn position(&self) -> crate::Position {
+        self.inner.position()
+    }
+
+    /// Returns the next decoded event, awaiting more input from `reader` as
+    /// needed. Keep calling this; it reads and feeds new chunks
+    /// transparently until an event is ready, at minimum yielding
+    /// [`FeedEvent::EndDocument`] once the value (or, in NDJSON mode, each
+    /// value) is complete.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called again after `reader` has reported end-of-stream and
+    /// every queued trailing event (including the final `EndDocument`) has
+    /// already been returned -- the same "called after finished" contract
+    /// [`PollParser::finish`] enforces.
+    pub async fn next_event(&mut self) -> Result<FeedEvent, ParseError> {
+        loop {
+            match self.inner.poll_event() {
+                Poll::Event(event) => return Ok(event),
+                Poll::NeedMoreInput => {
+                    if self.reader_done {
+                        panic!("next_event() called after parsing already finished");
+                    }
+                    let buf = self.buf.take().expect("buf missing between next_event calls");
+                    if buf.is_empty() {
+                        self.buf = Some(buf);
+                        return Err(ParseError::InputBufferFull);
+                    }
+                    let n = self
+                        .reader
+                        .read(buf)
+                        .await
+                        .map_err(|_| ParseError::ReaderError)?;
+                    if n == 0 {
+                        self.buf = Some(buf);
+                        self.reader_done = true;
+                        self.inner.finish()?;
+                    } else {
+                        let (filled, rest) = buf.split_at_mut(n);
+                        self.buf = Some(rest);
+                        self.inner.feed(filled)?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::future::Future;
+    use core::task::{Context, Poll as TaskPoll, RawWaker, RawWakerVTable, Waker};
+
+    struct ChunkedAsyncReader<'a> {
+        data: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl AsyncReader for ChunkedAsyncReader<'_> {
+        type Error = ();
+
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(self.data.len()).min(self.chunk_size.max(1));
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn no_op(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+
+    /// Same no-op-waker driver as [`crate::async_reader`]'s tests: every
+    /// reader here resolves on its first poll, so this never actually parks.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = core::pin::pin!(fut);
+        loop {
+            if let TaskPoll::Ready(val) = fut.as_mut().poll(&mut cx) {
+                return val;
+            }
+        }
+    }
+
+    #[test]
+    fn test_async_feed_parser_reads_in_small_chunks() {
+        let json = br#"{"a": [1, 2, 3]}"#;
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 3,
+        };
+        let mut buf = [0u8; 8];
+        let mut scratch = [0u8; 64];
+        let mut parser = AsyncFeedParser::new(reader, &mut buf, &mut scratch);
+
+        block_on(async {
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::StartDocument);
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::StartObject);
+            assert_eq!(
+                parser.next_event().await.unwrap(),
+                FeedEvent::Key("a".into())
+            );
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::StartArray);
+            for expected in ["1", "2", "3"] {
+                assert_eq!(
+                    parser.next_event().await.unwrap(),
+                    FeedEvent::Number(expected.into())
+                );
+            }
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::EndArray);
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::EndObject);
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::EndDocument);
+        });
+    }
+
+    #[test]
+    fn test_async_feed_parser_escape_split_across_reads() {
+        let json = br#"["hello\nworld"]"#;
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 4,
+        };
+        let mut buf = [0u8; 64];
+        let mut scratch = [0u8; 64];
+        let mut parser = AsyncFeedParser::new(reader, &mut buf, &mut scratch);
+
+        block_on(async {
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::StartDocument);
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::StartArray);
+            assert_eq!(
+                parser.next_event().await.unwrap(),
+                FeedEvent::String("hello\nworld".into())
+            );
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::EndArray);
+            assert_eq!(parser.next_event().await.unwrap(), FeedEvent::EndDocument);
+        });
+    }
+
+    #[test]
+    fn test_async_feed_parser_ndjson_resumes_across_values() {
+        let json = b"{\"a\":1}\n{\"b\":2}";
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 5,
+        };
+        let mut buf = [0u8; 64];
+        let mut scratch = [0u8; 64];
+        let mut parser = AsyncFeedParser::new_ndjson(reader, &mut buf, &mut scratch);
+
+        block_on(async {
+            for key in ["a", "b"] {
+                assert_eq!(parser.next_event().await.unwrap(), FeedEvent::StartDocument);
+                assert_eq!(parser.next_event().await.unwrap(), FeedEvent::StartObject);
+                assert_eq!(
+                    parser.next_event().await.unwrap(),
+                    FeedEvent::Key(key.into())
+                );
+                match parser.next_event().await.unwrap() {
+                    FeedEvent::Number(_) => {}
+                    other => panic!("expected Number, got {other:?}"),
+                }
+                assert_eq!(parser.next_event().await.unwrap(), FeedEvent::EndObject);
+                assert_eq!(parser.next_event().await.unwrap(), FeedEvent::EndDocument);
+            }
+        });
+    }
+
+    #[test]
+    fn test_async_feed_parser_input_larger_than_buffer_errors() {
+        let json = br#"{"a": [1, 2, 3]}"#;
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 3,
+        };
+        let mut buf = [0u8; 2]; // far smaller than the input
+        let mut scratch = [0u8; 64];
+        let mut parser = AsyncFeedParser::new(reader, &mut buf, &mut scratch);
+
+        block_on(async {
+            let mut result = Ok(FeedEvent::StartDocument);
+            while result.is_ok() {
+                result = parser.next_event().await;
+            }
+            assert_eq!(result, Err(ParseError::InputBufferFull));
+        });
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `AsyncReader`: the async counterpart to [`crate::stream_parser::Reader`],
+//! for no_std executors (embassy and similar) that can't block waiting on
+//! I/O. Gated behind the `async` feature so the default synchronous path
+//! (`Reader`/`StreamParser`) stays zero-cost for targets that don't need it.
+//!
+//! This defines the trait, plus an `embedded-io-async` adapter for it.
+//! `StreamParser` itself stays synchronous: its per-byte loop
+//! (`ParserCore::next_event_impl_with_flags`, shared with `SliceParser`)
+//! calls `provider.get_next_byte()` straight through and never suspends
+//! between bytes. The async parsers built on this trait work around that
+//! instead: [`crate::AsyncStreamParser`] awaits one upfront fill and then
+//! parses from memory, and [`crate::AsyncFeedParser`] feeds each read into
+//! a sans-IO push parser.
+
+/// Async counterpart to [`crate::stream_parser::Reader`]. Carries the same
+/// contract: a return value of `0` **must** mean true end-of-stream, never
+/// "would block" (the `Pending` poll already covers that case).
+pub trait AsyncReader {
+    /// The error type returned by read operations.
+    type Error;
+
+    /// Reads into `buf`, returning the number of bytes read once ready.
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+#[cfg(feature = "embedded-io-async")]
+mod embedded_io_async_impl {
+    use super::AsyncReader;
+    use embedded_io_async::{Error, ErrorKind, Read};
+
+    /// Wraps any [`embedded_io_async::Read`] so it can drive
+    /// [`crate::AsyncStreamParser`]/[`crate::AsyncFeedParser`] from the
+    /// embedded HAL ecosystem's async traits (embassy and similar), the
+    /// same way [`crate::EmbeddedIoReader`] wraps a synchronous
+    /// `embedded_io::Read` for the blocking [`crate::stream_parser::Reader`].
+    ///
+    /// Same `Interrupted`-is-a-retry behavior as
+    /// [`crate::EmbeddedIoReader`]; `embedded_io_async::ErrorKind` carries
+    /// an `Interrupted` variant for exactly this case.
+    pub struct EmbeddedIoAsyncReader<R> {
+        inner: R,
+    }
+
+    impl<R> EmbeddedIoAsyncReader<R> {
+        /// Wraps `inner`, an existing `embedded_io_async::Read` implementor.
+        pub fn new(inner: R) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<R: Read> AsyncReader for EmbeddedIoAsyncReader<R> {
+        type Error = R::Error;
+
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            loop {
+                match self.inner.read(buf).await {
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    result => return result,
+                }
+            }
+        }
+    }
+}
+#[cfg(feature = "embedded-io-async")]
+pub use embedded_io_async_impl::EmbeddedIoAsyncReader;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::future::Future;
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    struct ImmediateReader<'a> {
+        data: &'a [u8],
+    }
+
+    impl AsyncReader for ImmediateReader<'_> {
+        type Error = ();
+
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn no_op(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+
+    /// Polls `fut` to completion with a no-op waker. Every `AsyncReader` in
+    /// these tests resolves on its first poll, so this never actually
+    /// parks -- it just gives the `async fn` a `Context` to run against
+    /// without pulling in an executor crate.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = core::pin::pin!(fut);
+        loop {
+            if let Poll::Ready(val) = fut.as_mut().poll(&mut cx) {
+                return val;
+            }
+        }
+    }
+
+    #[test]
+    fn test_async_reader_read_future_resolves_to_bytes_copied() {
+        let mut reader = ImmediateReader { data: b"hi" };
+        let mut buf = [0u8; 8];
+        let n = block_on(reader.read(&mut buf));
+        assert_eq!(n, Ok(2));
+        assert_eq!(&buf[..2], b"hi");
+    }
+}
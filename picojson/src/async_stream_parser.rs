@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `AsyncStreamParser`: built on [`AsyncReader`] for no_std executors
+//! (embassy and similar) that can't block waiting on input. Gated behind
+//! the `async` feature, same as `AsyncReader` itself.
+//!
+//! This is deliberately not a streaming parser in the sense [`StreamParser`]
+//! is: [`Self::next_event`] first awaits filling the caller-provided buffer
+//! from `R` -- one `read` call at a time, stopping once the buffer is full
+//! or `R` reports true end of stream -- and only then hands the buffered
+//! bytes to an ordinary, synchronous [`SliceParser`]. The awaiting is
+//! confined to that one upfront fill; once it completes, every subsequent
+//! [`next_event`](Self::next_event) call parses purely in memory. Giving
+//! this the same mid-token resumption [`StreamParser`] gets over a blocking
+//! [`Reader`](crate::stream_parser::Reader) would mean suspending the
+//! tokenizer's own byte loop, not just the buffer-refill boundary -- the
+//! same larger, riskier change noted in [`AsyncReader`]'s module docs, left
+//! for the `WouldBlock`/`Incomplete` resumption work tracked alongside this.
+//!
+//! A practical consequence: the buffer must be large enough to hold the
+//! entire input. If `R` still has bytes to give once the buffer is full,
+//! [`next_event`](Self::next_event) returns
+//! [`ParseError::InputBufferFull`](crate::ParseError::InputBufferFull)
+//! rather than silently parsing a truncated prefix.
+
+use crate::async_reader::AsyncReader;
+use crate::parse_error::ParseError;
+use crate::shared::{Event, PullParser};
+use crate::slice_parser::SliceParser;
+use crate::ujson::{BitStackConfig, DefaultConfig};
+
+/// Async counterpart to [`SliceParser`], fed by an [`AsyncReader`] instead
+/// of an already-resident slice. See the module docs for what "async" does
+/// and doesn't cover here.
+pub struct AsyncStreamParser<'b, R: AsyncReader, C: BitStackConfig = DefaultConfig> {
+    reader: R,
+    input: Option<&'b mut [u8]>,
+    scratch: Option<&'b mut [u8]>,
+    filled: usize,
+    /// Whether the buffered input is a sequence of whitespace-separated
+    /// top-level values (NDJSON-style) instead of exactly one -- see
+    /// [`Self::new_ndjson`].
+    streaming: bool,
+    inner: Option<SliceParser<'b, 'b, C>>,
+}
+
+impl<'b, R: AsyncReader> AsyncStreamParser<'b, R, DefaultConfig> {
+    /// Creates a new parser reading from `reader` into `buffer`.
+    ///
+    /// Assumes no string escapes will be encountered; if escapes are found,
+    /// parsing fails the same way [`SliceParser::new_from_slice`] does. For
+    /// JSON with escapes, use [`Self::with_scratch`].
+    pub fn new(reader: R, buffer: &'b mut [u8]) -> Self {
+        Self::with_config(reader, buffer)
+    }
+
+    /// Like [`Self::new`], but with a separate scratch buffer for unescaping
+    /// string content, the same role [`SliceParser::with_buffer_from_slice`]'s
+    /// `scratch_buffer` plays.
+    pub fn with_scratch(reader: R, buffer: &'b mut [u8], scratch: &'b mut [u8]) -> Self {
+        Self::with_config_and_scratch(reader, buffer, scratch)
+    }
+
+    /// Like [`Self::new`], but for a sequence of whitespace-separated
+    /// top-level JSON values (NDJSON-style) instead of exactly one -- same
+    /// multi-document mode as [`SliceParser::new_ndjson`]/
+    /// [`StreamParser::new_ndjson`](crate::StreamParser::new_ndjson). Each
+    /// value still ends with a single [`Event::EndDocument`]; the call
+    /// after that resumes with the next value instead of repeating it.
+    ///
+    /// Since [`Self::next_event`] only awaits once, up front, this buffers
+    /// *every* record the reader has to give before parsing the first one --
+    /// same bound-by-buffer-capacity tradeoff as the module docs describe,
+    /// just applied to the whole feed instead of one value.
+    pub fn new_ndjson(reader: R, buffer: &'b mut [u8]) -> Self {
+        Self::with_config_ndjson(reader, buffer)
+    }
+
+    /// Like [`Self::new_ndjson`], but with a separate scratch buffer for
+    /// unescaping string content.
+    pub fn with_scratch_ndjson(reader: R, buffer: &'b mut [u8], scratch: &'b mut [u8]) -> Self {
+        Self::with_config_and_scratch_ndjson(reader, buffer, scratch)
+    }
+}
+
+impl<'b, R: AsyncReader, C: BitStackConfig> AsyncStreamParser<'b, R, C> {
+    /// Like [`Self::new`], but with a custom [`BitStackConfig`].
+    pub fn with_config(reader: R, buffer: &'b mut [u8]) -> Self {
+        Self {
+            reader,
+            input: Some(buffer),
+            scratch: None,
+            filled: 0,
+            streaming: false,
+            inner: None,
+        }
+    }
+
+    /// Like [`Self::with_scratch`], but with a custom [`BitStackConfig`].
+    pub fn with_config_and_scratch(reader: R, buffer: &'b mut [u8], scratch: &'b mut [u8]) -> Self {
+        Self {
+            reader,
+            input: Some(buffer),
+            scratch: Some(scratch),
+            filled: 0,
+            streaming: false,
+            inner: None,
+        }
+    }
+
+    /// Like [`Self::new_ndjson`], but with a custom [`BitStackConfig`].
+    pub fn with_config_ndjson(reader: R, buffer: &'b mut [u8]) -> Self {
+        Self {
+            streaming: true,
+            ..Self::with_config(reader, buffer)
+        }
+    }
+
+    /// Like [`Self::with_scratch_ndjson`], but with a custom [`BitStackConfig`].
+    pub fn with_config_and_scratch_ndjson(
+        reader: R,
+        buffer: &'b mut [u8],
+        scratch: &'b mut [u8],
+    ) -> Self {
+        Self {
+            streaming: true,
+            ..Self::with_config_and_scratch(reader, buffer, scratch)
+        }
+    }
+
+    /// Reads from `self.reader` until the buffer is full or `R` reports end
+    /// of stream, one `read` call at a time -- mirroring
+    /// [`StreamParserProvider::next_byte`](crate::stream_parser::StreamParser)'s
+    /// fill-when-empty logic, just run to completion upfront instead of
+    /// on demand mid-token.
+    async fn fill(&mut self) -> Result<(), ParseError> {
+        loop {
+            let buf = self
+                .input
+                .as_deref_mut()
+                .ok_or(ParseError::Unexpected(
+                    crate::shared::UnexpectedState::StateMismatch,
+                ))?;
+            if self.filled >= buf.len() {
+                // The buffer is full; the input only fits if `R` has
+                // nothing more to give.
+                let mut probe = [0u8; 1];
+                let more = self
+                    .reader
+                    .read(&mut probe)
+                    .await
+                    .map_err(|_| ParseError::ReaderError)?;
+                if more == 0 {
+                    return Ok(());
+                }
+                return Err(ParseError::InputBufferFull);
+            }
+            let bytes_read = self
+                .reader
+                .read(&mut buf[self.filled..])
+                .await
+                .map_err(|_| ParseError::ReaderError)?;
+            if bytes_read == 0 {
+                return Ok(());
+            }
+            self.filled += bytes_read;
+        }
+    }
+
+    /// Finishes buffering (if not already done) and returns the inner
+    /// [`SliceParser`] over the fully-read input.
+    async fn ensure_buffered(&mut self) -> Result<&mut SliceParser<'b, 'b, C>, ParseError> {
+        if self.inner.is_none() {
+            self.fill().await?;
+            let input: &'b [u8] = &*self.input.take().ok_or(ParseError::Unexpected(
+                crate::shared::UnexpectedState::StateMismatch,
+            ))?;
+            let input = &input[..self.filled];
+            let scratch: &'b mut [u8] = self.scratch.take().unwrap_or(&mut []);
+            self.inner = Some(if self.streaming {
+                SliceParser::with_config_and_buffer_from_slice_streaming(input, scratch)
+            } else {
+                SliceParser::with_config_and_buffer_from_slice(input, scratch)
+            });
+        }
+        Ok(self.inner.as_mut().expect("just populated above"))
+    }
+
+    /// Returns the next JSON event. The first call awaits reading the whole
+    /// input from the underlying [`AsyncReader`]; every call after that is
+    /// the same as [`SliceParser::next_event`].
+    pub async fn next_event(&mut self) -> Result<Event<'_, '_>, ParseError> {
+        self.ensure_buffered().await?.next_event()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::future::Future;
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    struct ChunkedAsyncReader<'a> {
+        data: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl AsyncReader for ChunkedAsyncReader<'_> {
+        type Error = ();
+
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(self.data.len()).min(self.chunk_size.max(1));
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn no_op(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+
+    /// Same no-op-waker driver as [`crate::async_reader`]'s tests: every
+    /// reader here resolves on its first poll, so this never actually parks.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = core::pin::pin!(fut);
+        loop {
+            if let Poll::Ready(val) = fut.as_mut().poll(&mut cx) {
+                return val;
+            }
+        }
+    }
+
+    #[test]
+    fn test_async_stream_parser_reads_in_small_chunks() {
+        let json = br#"{"a": [1, 2, 3]}"#;
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 3,
+        };
+        let mut buffer = [0u8; 256];
+        let mut parser = AsyncStreamParser::new(reader, &mut buffer);
+
+        block_on(async {
+            assert_eq!(parser.next_event().await.unwrap(), Event::StartObject);
+            assert_eq!(
+                parser.next_event().await.unwrap(),
+                Event::Key(crate::String::Borrowed("a"))
+            );
+            assert_eq!(parser.next_event().await.unwrap(), Event::StartArray);
+            for expected in ["1", "2", "3"] {
+                match parser.next_event().await.unwrap() {
+                    Event::Number(n) => assert_eq!(n.as_str(), expected),
+                    other => panic!("expected Number, got {other:?}"),
+                }
+            }
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndArray);
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndObject);
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndDocument);
+        });
+    }
+
+    #[test]
+    fn test_async_stream_parser_escape_needs_scratch() {
+        let json = br#"["hello\nworld"]"#;
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 4,
+        };
+        let mut buffer = [0u8; 64];
+        let mut scratch = [0u8; 64];
+        let mut parser = AsyncStreamParser::with_scratch(reader, &mut buffer, &mut scratch);
+
+        block_on(async {
+            assert_eq!(parser.next_event().await.unwrap(), Event::StartArray);
+            match parser.next_event().await.unwrap() {
+                Event::String(s) => assert_eq!(s.as_str(), "hello\nworld"),
+                other => panic!("expected String, got {other:?}"),
+            }
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndArray);
+        });
+    }
+
+    #[test]
+    fn test_async_stream_parser_ndjson_resumes_across_values() {
+        let json = b"{\"a\":1}\n{\"b\":2}";
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 5,
+        };
+        let mut buffer = [0u8; 256];
+        let mut parser = AsyncStreamParser::new_ndjson(reader, &mut buffer);
+
+        block_on(async {
+            assert_eq!(parser.next_event().await.unwrap(), Event::StartObject);
+            assert_eq!(
+                parser.next_event().await.unwrap(),
+                Event::Key(crate::String::Borrowed("a"))
+            );
+            match parser.next_event().await.unwrap() {
+                Event::Number(n) => assert_eq!(n.as_str(), "1"),
+                other => panic!("expected Number, got {other:?}"),
+            }
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndObject);
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndDocument);
+
+            assert_eq!(parser.next_event().await.unwrap(), Event::StartObject);
+            assert_eq!(
+                parser.next_event().await.unwrap(),
+                Event::Key(crate::String::Borrowed("b"))
+            );
+            match parser.next_event().await.unwrap() {
+                Event::Number(n) => assert_eq!(n.as_str(), "2"),
+                other => panic!("expected Number, got {other:?}"),
+            }
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndObject);
+            assert_eq!(parser.next_event().await.unwrap(), Event::EndDocument);
+        });
+    }
+
+    #[test]
+    fn test_async_stream_parser_input_larger_than_buffer_errors() {
+        let json = br#"{"a": [1, 2, 3]}"#;
+        let reader = ChunkedAsyncReader {
+            data: json,
+            chunk_size: 3,
+        };
+        let mut buffer = [0u8; 4]; // far smaller than the input
+        let mut parser = AsyncStreamParser::new(reader, &mut buffer);
+
+        block_on(async {
+            assert_eq!(
+                parser.next_event().await,
+                Err(ParseError::InputBufferFull)
+            );
+        });
+    }
+}
@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Base64 decoding for JSON string values that embed a binary blob as text
+//! (config/credential formats are the common case). `no_std` and
+//! allocation-free: [`decode`] writes straight into a caller-supplied
+//! output buffer instead of returning an owned `Vec<u8>`, the same shape
+//! [`crate::json_string::String`]'s other accessors use.
+
+/// Errors from decoding a base64 string into a byte buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The output buffer isn't big enough to hold the decoded bytes.
+    /// `needed` is the exact number of bytes required, computed up front
+    /// from the input's length and padding before anything is written.
+    OutputBufferTooSmall {
+        /// Exact number of bytes decoding would produce.
+        needed: usize,
+    },
+    /// Input length isn't a multiple of 4, so it can't be validly padded
+    /// base64 (every encoded group is exactly 4 characters).
+    InvalidLength,
+    /// A byte that's neither in the selected alphabet nor `=` padding.
+    InvalidCharacter {
+        /// The offending byte.
+        byte: u8,
+    },
+    /// An `=` appeared somewhere other than the end of the final group, or
+    /// the final group has more than two of them.
+    InvalidPadding,
+}
+
+/// Maps one base64 character to its 6-bit value, `None` if `byte` isn't in
+/// the selected alphabet. The standard and URL-safe alphabets agree on
+/// every character except the two at index 62/63 (`+`/`/` vs. `-`/`_`).
+fn decode_value(byte: u8, url_safe: bool) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' if !url_safe => Some(62),
+        b'/' if !url_safe => Some(63),
+        b'-' if url_safe => Some(62),
+        b'_' if url_safe => Some(63),
+        _ => None,
+    }
+}
+
+/// Validates `input`'s length and padding, and computes the exact decoded
+/// length -- done as its own pass so [`decode`] can report
+/// [`Base64Error::OutputBufferTooSmall`] before writing a single byte,
+/// rather than leaving `out` partially filled on failure.
+fn decoded_len(input: &[u8]) -> Result<usize, Base64Error> {
+    if input.is_empty() {
+        return Ok(0);
+    }
+    if input.len() % 4 != 0 {
+        return Err(Base64Error::InvalidLength);
+    }
+    let pad = match (input[input.len() - 2], input[input.len() - 1]) {
+        (b'=', b'=') => 2,
+        (_, b'=') => 1,
+        _ => 0,
+    };
+    if input[..input.len() - pad].contains(&b'=') {
+        return Err(Base64Error::InvalidPadding);
+    }
+    Ok((input.len() / 4) * 3 - pad)
+}
+
+/// Decodes `input` (standard alphabet if `url_safe` is `false`, otherwise
+/// `-`/`_` in place of `+`/`/`) into `out`, returning the filled prefix.
+///
+/// Four input characters decode to three output bytes; the final group may
+/// use `=` padding to represent one or two trailing bytes instead of three.
+/// Invalid characters, a stray `=`, or an input length that isn't a
+/// multiple of 4 are rejected rather than decoded partially.
+pub(crate) fn decode<'out>(
+    input: &str,
+    out: &'out mut [u8],
+    url_safe: bool,
+) -> Result<&'out [u8], Base64Error> {
+    let input = input.as_bytes();
+    let needed = decoded_len(input)?;
+    let Some(dest) = out.get_mut(..needed) else {
+        return Err(Base64Error::OutputBufferTooSmall { needed });
+    };
+
+    let group_count = input.len() / 4;
+    let mut out_pos = 0;
+    for (group_index, group) in input.chunks_exact(4).enumerate() {
+        // Only the final group can carry padding -- how many of its 4
+        // characters are "real" follows from how many output bytes this
+        // group contributes, which `decoded_len` already settled.
+        let real_chars = if group_index + 1 == group_count {
+            match needed - group_index * 3 {
+                1 => 2,
+                2 => 3,
+                _ => 4,
+            }
+        } else {
+            4
+        };
+
+        let mut values = [0u8; 4];
+        for (value, &byte) in values.iter_mut().zip(group.iter()).take(real_chars) {
+            *value = decode_value(byte, url_safe).ok_or(Base64Error::InvalidCharacter { byte })?;
+        }
+
+        dest[out_pos] = (values[0] << 2) | (values[1] >> 4);
+        out_pos += 1;
+        if real_chars >= 3 {
+            dest[out_pos] = ((values[1] & 0x0F) << 4) | (values[2] >> 2);
+            out_pos += 1;
+        }
+        if real_chars == 4 {
+            dest[out_pos] = ((values[2] & 0x03) << 6) | values[3];
+            out_pos += 1;
+        }
+    }
+
+    Ok(&dest[..out_pos])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_std(input: &str, out: &mut [u8]) -> Result<&[u8], Base64Error> {
+        decode(input, out, false)
+    }
+
+    #[test]
+    fn test_decode_no_padding() {
+        // "Man" -> "TWFu", the textbook example with no padding needed.
+        let mut out = [0u8; 3];
+        assert_eq!(decode_std("TWFu", &mut out).unwrap(), b"Man");
+    }
+
+    #[test]
+    fn test_decode_one_padding_char() {
+        // "Ma" -> "TWE=" (one trailing output byte represented by two
+        // real characters plus a single '=').
+        let mut out = [0u8; 2];
+        assert_eq!(decode_std("TWE=", &mut out).unwrap(), b"Ma");
+    }
+
+    #[test]
+    fn test_decode_two_padding_chars() {
+        // "M" -> "TQ==".
+        let mut out = [0u8; 1];
+        assert_eq!(decode_std("TQ==", &mut out).unwrap(), b"M");
+    }
+
+    #[test]
+    fn test_decode_empty_input() {
+        let mut out = [0u8; 0];
+        assert_eq!(decode_std("", &mut out).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_decode_multiple_groups() {
+        // "Hello, World!" -> "SGVsbG8sIFdvcmxkIQ=="
+        let mut out = [0u8; 13];
+        assert_eq!(
+            decode_std("SGVsbG8sIFdvcmxkIQ==", &mut out).unwrap(),
+            b"Hello, World!"
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_length_not_a_multiple_of_four() {
+        let mut out = [0u8; 8];
+        assert_eq!(decode_std("TWFubg", &mut out), Err(Base64Error::InvalidLength));
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_character() {
+        let mut out = [0u8; 3];
+        assert_eq!(
+            decode_std("TW!u", &mut out),
+            Err(Base64Error::InvalidCharacter { byte: b'!' })
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_stray_padding() {
+        let mut out = [0u8; 3];
+        assert_eq!(decode_std("T=Fu", &mut out), Err(Base64Error::InvalidPadding));
+    }
+
+    #[test]
+    fn test_decode_reports_exact_bytes_needed_when_output_too_small() {
+        let mut out = [0u8; 2];
+        assert_eq!(
+            decode_std("TWFu", &mut out),
+            Err(Base64Error::OutputBufferTooSmall { needed: 3 })
+        );
+    }
+
+    #[test]
+    fn test_decode_url_safe_alphabet() {
+        // The standard alphabet would encode the same bytes with `+`/`/`
+        // where this uses `-`/`_`.
+        let mut out = [0u8; 4];
+        assert_eq!(decode("-_--", &mut out, true).unwrap(), &[251, 255, 190]);
+        let mut out = [0u8; 3];
+        assert!(decode_std("+/+/", &mut out).is_ok());
+    }
+
+    #[test]
+    fn test_decode_url_safe_rejects_standard_characters() {
+        let mut out = [0u8; 3];
+        assert_eq!(
+            decode("+/+/", &mut out, true),
+            Err(Base64Error::InvalidCharacter { byte: b'+' })
+        );
+    }
+}
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`BufReader`], a [`Reader`] adapter that coalesces many small `read()`
+//! calls into fewer, larger reads from a slow inner source. The stress
+//! tests in [`crate::chunk_reader`] already drive [`crate::StreamParser`]
+//! with `ChunkReader::new(json, 1)` -- one byte at a time -- to prove the
+//! parser copes; against a real serial/network device each 1-byte `read()`
+//! is the expensive part, so this lets a caller amortize that cost across a
+//! caller-supplied backing buffer.
+//!
+//! Modeled on `std::io::BufReader`'s `pos`/`filled` refill-when-empty
+//! design, but `no_std` and allocation-free: the backing buffer is borrowed,
+//! the same way [`crate::StreamParser::new`] borrows its scratch buffer.
+
+use crate::Reader;
+
+/// A [`Reader`] that refills a borrowed buffer from `inner` in large chunks
+/// and serves the parser's (often much smaller) `read()` calls out of it.
+pub struct BufReader<'b, R: Reader> {
+    inner: R,
+    buffer: &'b mut [u8],
+    pos: usize,
+    filled: usize,
+}
+
+impl<'b, R: Reader> BufReader<'b, R> {
+    /// Wraps `inner`, using `buffer` to hold data read ahead from it.
+    /// Larger buffers amortize the cost of `inner`'s `read()` further, at
+    /// the cost of more memory.
+    pub fn new(inner: R, buffer: &'b mut [u8]) -> Self {
+        Self {
+            inner,
+            buffer,
+            pos: 0,
+            filled: 0,
+        }
+    }
+}
+
+impl<'b, R: Reader> Reader for BufReader<'b, R> {
+    type Error = R::Error;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if self.pos == self.filled {
+            self.pos = 0;
+            self.filled = self.inner.read(self.buffer)?;
+            if self.filled == 0 {
+                return Ok(0);
+            }
+        }
+        let n = buf.len().min(self.filled - self.pos);
+        buf[..n].copy_from_slice(&self.buffer[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_reader::ChunkReader;
+
+    #[test]
+    fn test_buf_reader_coalesces_byte_at_a_time_source() {
+        let data = b"hello world";
+        let inner = ChunkReader::new(data, 1);
+        let mut backing = [0u8; 16];
+        let mut reader = BufReader::new(inner, &mut backing);
+
+        let mut out = Vec::new();
+        let mut buf = [0u8; 4];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(out, data);
+    }
+
+    #[test]
+    fn test_buf_reader_serves_small_reads_without_touching_inner_again() {
+        // One inner read fills the whole backing buffer; every subsequent
+        // read() should be served from it without calling inner again.
+        let data = b"abcdef";
+        let inner = ChunkReader::full_slice(data);
+        let mut backing = [0u8; 64];
+        let mut reader = BufReader::new(inner, &mut backing);
+
+        let mut buf = [0u8; 2];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"ab");
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"cd");
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"ef");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_buf_reader_refills_once_buffer_is_drained() {
+        let data = b"0123456789";
+        let inner = ChunkReader::new(data, 4);
+        // Backing buffer smaller than the input, forcing multiple refills.
+        let mut backing = [0u8; 3];
+        let mut reader = BufReader::new(inner, &mut backing);
+
+        let mut out = Vec::new();
+        let mut buf = [0u8; 5];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(out, data);
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A `Buf`-style cursor over one or more already-received byte fragments, so
+//! [`crate::stream_buffer::StreamBuffer::fill_from_source`] can pull from
+//! scattered DMA ring slots or a split ring buffer's two halves without the
+//! caller concatenating them first. Modeled on the `bytes` crate's `Buf`.
+
+/// A cursor over a sequence of bytes that may be spread across more than
+/// one underlying fragment.
+pub trait ByteSource {
+    /// The longest contiguous run of unread bytes available right now.
+    /// Empty only once `remaining() == 0`.
+    fn chunk(&self) -> &[u8];
+
+    /// Marks the first `n` bytes of `chunk()` as consumed. `n` must be
+    /// `<= remaining()`.
+    fn advance(&mut self, n: usize);
+
+    /// Total unread bytes left across every fragment.
+    fn remaining(&self) -> usize;
+}
+
+impl ByteSource for &[u8] {
+    fn chunk(&self) -> &[u8] {
+        self
+    }
+
+    fn advance(&mut self, n: usize) {
+        *self = &self[n.min(self.len())..];
+    }
+
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Presents two [`ByteSource`]s as one, draining `a` before `b`.
+pub struct ChainedSource<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: ByteSource, B: ByteSource> ChainedSource<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A: ByteSource, B: ByteSource> ByteSource for ChainedSource<A, B> {
+    fn chunk(&self) -> &[u8] {
+        let a_chunk = self.a.chunk();
+        if !a_chunk.is_empty() {
+            a_chunk
+        } else {
+            self.b.chunk()
+        }
+    }
+
+    fn advance(&mut self, n: usize) {
+        let a_remaining = self.a.remaining();
+        if n <= a_remaining {
+            self.a.advance(n);
+        } else {
+            self.a.advance(a_remaining);
+            self.b.advance(n - a_remaining);
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.a.remaining() + self.b.remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_source_advances_and_reports_remaining() {
+        let mut src: &[u8] = b"hello";
+        assert_eq!(src.remaining(), 5);
+        assert_eq!(src.chunk(), b"hello");
+        src.advance(2);
+        assert_eq!(src.chunk(), b"llo");
+        assert_eq!(src.remaining(), 3);
+    }
+
+    #[test]
+    fn test_chained_source_crosses_fragment_boundary() {
+        let mut src = ChainedSource::new(&b"ab"[..], &b"cde"[..]);
+        assert_eq!(src.remaining(), 5);
+        assert_eq!(src.chunk(), b"ab");
+
+        src.advance(1);
+        assert_eq!(src.chunk(), b"b");
+        assert_eq!(src.remaining(), 4);
+
+        // Advancing past the first fragment's remainder spills into the second.
+        src.advance(2);
+        assert_eq!(src.chunk(), b"de");
+        assert_eq!(src.remaining(), 2);
+    }
+}
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A pluggable backend for a growable-or-fixed byte buffer, in the spirit of
+//! [`ByteSource`](crate::byte_source::ByteSource) on the read side: that
+//! trait lets [`StreamBuffer::fill_from_source`](crate::stream_buffer::StreamBuffer::fill_from_source)
+//! pull from whatever fragment layout a caller's reader happens to use,
+//! and this one is the write-side counterpart -- letting the *storage*
+//! [`StreamBuffer`](crate::stream_buffer::StreamBuffer) fills bytes into be
+//! a static array on an embedded target or a growable `Vec` on a hosted
+//! one, without forking the buffer logic itself.
+//!
+//! [`StreamBuffer`](crate::stream_buffer::StreamBuffer) doesn't consume this
+//! trait yet -- it stays hard-wired to a borrowed `&'a mut [u8]`, and
+//! rewiring every method that currently indexes `self.buffer` directly
+//! (`get_fill_slice`, `append_unescaped_byte`, `start_unescaping_with_copy`,
+//! `get_string_slice`, and the `Ring`-mode wraparound arithmetic) onto a
+//! generic bound is a large enough change to the crate's most
+//! performance-sensitive module that it belongs in its own follow-up,
+//! reviewed and benchmarked on its own rather than bundled in sight-unseen.
+//! This module is the trait and its blanket impls on their own: a
+//! self-contained foundation that doesn't touch -- or risk -- anything
+//! `StreamBuffer` currently does.
+
+/// A byte buffer a [`StreamBuffer`](crate::stream_buffer::StreamBuffer)
+/// could fill into: a contiguous, resizable-or-not span of storage.
+///
+/// Implementors that can't grow (a borrowed slice, a fixed-size array)
+/// leave [`try_grow`](Self::try_grow) at its default `false`, the same
+/// answer a fixed-capacity [`StreamBuffer`] already gives today by
+/// returning [`StreamBufferError::BufferFull`](crate::stream_buffer::StreamBufferError::BufferFull).
+pub trait ByteStorage {
+    /// The full storage as a mutable slice, for `StreamBuffer` to read and
+    /// write through exactly as it does its own `&mut [u8]` today.
+    fn as_mut_slice(&mut self) -> &mut [u8];
+
+    /// Current capacity in bytes -- `as_mut_slice().len()`, cached or
+    /// recomputed as the implementor prefers.
+    fn capacity(&self) -> usize;
+
+    /// Attempts to grow capacity by at least `additional` bytes, preserving
+    /// every byte already present. Returns `true` if capacity increased by
+    /// at least `additional` (callers should re-fetch
+    /// [`as_mut_slice`](Self::as_mut_slice) afterward, since growing may
+    /// reallocate), `false` if the backend can't grow at all or couldn't
+    /// grow by enough -- in which case nothing changed.
+    ///
+    /// The default implementation never grows, which is the correct answer
+    /// for fixed storage (`&mut [u8]`, `[u8; N]`): growth is opt-in, not
+    /// assumed.
+    fn try_grow(&mut self, additional: usize) -> bool {
+        let _ = additional;
+        false
+    }
+}
+
+impl ByteStorage for &mut [u8] {
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<const N: usize> ByteStorage for [u8; N] {
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.as_mut()
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impl {
+    use super::ByteStorage;
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    impl ByteStorage for Vec<u8> {
+        fn as_mut_slice(&mut self) -> &mut [u8] {
+            self.as_mut_slice()
+        }
+
+        fn capacity(&self) -> usize {
+            self.len()
+        }
+
+        fn try_grow(&mut self, additional: usize) -> bool {
+            self.resize(self.len() + additional, 0);
+            true
+        }
+    }
+}
+
+/// A strategy for how far to grow a [`ByteStorage`] backend once a token
+/// (a string, number, or escape run) no longer fits in it -- the decision
+/// [`ByteStorage::try_grow`]'s `additional` argument leaves to the caller.
+///
+/// Gated behind `alloc` because a fixed backend (`&mut [u8]`, `[u8; N]`)
+/// never has anywhere to grow *to*; this only matters paired with a
+/// growable one like `Vec<u8>`.
+///
+/// Not yet consulted by anything: [`StreamBuffer`](crate::stream_buffer::StreamBuffer)
+/// still holds a borrowed `&'a mut [u8]` directly rather than a
+/// `ByteStorage`, so there's no `append_unescaped_byte`/
+/// `start_unescaping_with_copy` call site that's hit capacity and needs to
+/// ask a policy what to do next -- that's the same generic-storage
+/// integration this module's top-level doc comment already defers. This is
+/// the policy's shape and arithmetic on their own, ready to be invoked once
+/// that integration lands.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowthPolicy {
+    /// Never grow, regardless of what's asked for -- the same answer a
+    /// fixed backend's [`ByteStorage::try_grow`] always gives.
+    Fixed,
+    /// Double the current capacity until it covers what's needed (keeping
+    /// it unchanged if it already does), capped at `max` total bytes. A token that would need more
+    /// than `max` bytes still ends in [`StreamBufferError::BufferFull`](crate::stream_buffer::StreamBufferError::BufferFull),
+    /// the same as a fixed backend today -- `max` is a ceiling a hosted
+    /// caller chooses, not an unconditional promise of unbounded growth.
+    DoublingUpTo(usize),
+}
+
+#[cfg(feature = "alloc")]
+impl GrowthPolicy {
+    /// The capacity to grow to so that at least `needed` bytes fit, given a
+    /// backend currently at `current` capacity. `None` if this policy can't
+    /// satisfy `needed` at all -- always for [`Self::Fixed`], or for
+    /// [`Self::DoublingUpTo`] when `needed` exceeds its `max`.
+    pub fn next_capacity(&self, current: usize, needed: usize) -> Option<usize> {
+        match self {
+            GrowthPolicy::Fixed => None,
+            GrowthPolicy::DoublingUpTo(max) => {
+                if needed > *max {
+                    return None;
+                }
+                let mut next = current.max(1);
+                while next < needed {
+                    next = next.saturating_mul(2);
+                }
+                Some(next.min(*max))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mut_slice_does_not_grow() {
+        let mut storage: &mut [u8] = &mut [0u8; 4];
+        assert_eq!(ByteStorage::capacity(&storage), 4);
+        assert!(!storage.try_grow(1));
+        assert_eq!(ByteStorage::capacity(&storage), 4);
+    }
+
+    #[test]
+    fn test_array_as_mut_slice_round_trips() {
+        let mut storage = [0u8; 8];
+        storage.as_mut_slice()[0] = 42;
+        assert_eq!(storage[0], 42);
+        assert_eq!(ByteStorage::capacity(&storage), 8);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_vec_try_grow_preserves_existing_bytes() {
+        extern crate alloc;
+        use alloc::vec;
+
+        let mut storage = vec![1u8, 2, 3];
+        assert!(storage.try_grow(5));
+        assert_eq!(ByteStorage::capacity(&storage), 8);
+        assert_eq!(&storage.as_mut_slice()[..3], &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_fixed_growth_policy_never_grows() {
+        assert_eq!(GrowthPolicy::Fixed.next_capacity(8, 9), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_doubling_growth_policy_doubles_until_enough() {
+        let policy = GrowthPolicy::DoublingUpTo(1024);
+        assert_eq!(policy.next_capacity(8, 9), Some(16));
+        assert_eq!(policy.next_capacity(8, 100), Some(128));
+        // Already enough: no need to grow at all, but the policy still
+        // reports a valid (unchanged) capacity rather than `None`.
+        assert_eq!(policy.next_capacity(64, 10), Some(64));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_doubling_growth_policy_refuses_past_its_max() {
+        let policy = GrowthPolicy::DoublingUpTo(100);
+        assert_eq!(policy.next_capacity(8, 101), None);
+        // Right at the ceiling is still satisfiable.
+        assert_eq!(policy.next_capacity(8, 100), Some(100));
+    }
+}
@@ -0,0 +1,303 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`ChunkedTransferReader`], a [`Reader`] adapter that transparently
+//! decodes an RFC 7230 `Transfer-Encoding: chunked` byte stream, handing
+//! the parser only the de-chunked payload. Many embedded HTTP clients
+//! receive JSON bodies this way, so without this a caller has to buffer
+//! and strip the chunk framing themselves before handing bytes to
+//! [`crate::StreamParser`].
+
+use crate::Reader;
+
+const HOLD_CAPACITY: usize = 64;
+
+/// Errors from decoding chunked transfer-encoding framing, in addition to
+/// whatever `R`'s own `read()` can fail with.
+#[derive(Debug, PartialEq)]
+pub enum ChunkedTransferError<E> {
+    /// The inner reader failed.
+    Inner(E),
+    /// A chunk-size line had no hex digits, or overflowed.
+    InvalidChunkSize,
+    /// A line that must end in `\r\n` didn't (including the stream ending
+    /// before the framing was complete).
+    MissingLineFeed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChunkState {
+    /// Accumulating hex digits of the next chunk's size.
+    Size,
+    /// Skipping a `;extension` after the size, up to the `\r`.
+    SizeExtension,
+    /// Just saw `\r` after a size (or its extension); expect `\n`.
+    SizeLf,
+    /// Copying out `usize` more payload bytes of the current chunk.
+    Body(usize),
+    /// Just finished a chunk's payload; expect the trailing `\r`.
+    BodyCr,
+    /// Saw `\r` after a chunk's payload; expect `\n`.
+    BodyLf,
+    /// After the zero-size chunk: skipping an (optional) trailer header
+    /// line, up to its `\r`.
+    Trailer,
+    /// Saw `\r` while in a trailer line; expect `\n`. A blank line here
+    /// (no bytes seen since the last `Trailer` line started) is the
+    /// terminating empty line, not another trailer header.
+    TrailerLf,
+    /// Chunked stream fully decoded; no more payload bytes will ever come.
+    End,
+}
+
+/// A [`Reader`] wrapping an inner [`Reader`] whose bytes are framed as
+/// HTTP/1.1 chunked transfer-encoding, yielding only the decoded payload.
+pub struct ChunkedTransferReader<R: Reader> {
+    inner: R,
+    /// Raw bytes pulled from `inner`, not yet run through the state machine.
+    hold: [u8; HOLD_CAPACITY],
+    hold_pos: usize,
+    hold_len: usize,
+    state: ChunkState,
+    pending_size: usize,
+    saw_size_digit: bool,
+    trailer_line_has_content: bool,
+}
+
+impl<R: Reader> ChunkedTransferReader<R> {
+    /// Wraps `inner`, whose bytes are expected to start at a chunk-size line.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hold: [0; HOLD_CAPACITY],
+            hold_pos: 0,
+            hold_len: 0,
+            state: ChunkState::Size,
+            pending_size: 0,
+            saw_size_digit: false,
+            trailer_line_has_content: false,
+        }
+    }
+
+    fn next_raw_byte(&mut self) -> Result<Option<u8>, ChunkedTransferError<R::Error>> {
+        if self.hold_pos >= self.hold_len {
+            let n = self
+                .inner
+                .read(&mut self.hold)
+                .map_err(ChunkedTransferError::Inner)?;
+            if n == 0 {
+                return Ok(None);
+            }
+            self.hold_len = n;
+            self.hold_pos = 0;
+        }
+        let byte = self.hold[self.hold_pos];
+        self.hold_pos += 1;
+        Ok(Some(byte))
+    }
+
+    /// Advances the state machine by one raw framing/body byte, returning a
+    /// payload byte when `byte` was chunk content rather than framing.
+    fn step(&mut self, byte: u8) -> Result<Option<u8>, ChunkedTransferError<R::Error>> {
+        match self.state {
+            ChunkState::Size => {
+                if let Some(digit) = (byte as char).to_digit(16) {
+                    self.pending_size = self
+                        .pending_size
+                        .checked_mul(16)
+                        .and_then(|v| v.checked_add(digit as usize))
+                        .ok_or(ChunkedTransferError::InvalidChunkSize)?;
+                    self.saw_size_digit = true;
+                    Ok(None)
+                } else if byte == b';' && self.saw_size_digit {
+                    self.state = ChunkState::SizeExtension;
+                    Ok(None)
+                } else if byte == b'\r' && self.saw_size_digit {
+                    self.state = ChunkState::SizeLf;
+                    Ok(None)
+                } else {
+                    Err(ChunkedTransferError::InvalidChunkSize)
+                }
+            }
+            ChunkState::SizeExtension => {
+                if byte == b'\r' {
+                    self.state = ChunkState::SizeLf;
+                }
+                Ok(None)
+            }
+            ChunkState::SizeLf => {
+                if byte != b'\n' {
+                    return Err(ChunkedTransferError::MissingLineFeed);
+                }
+                self.state = if self.pending_size == 0 {
+                    self.trailer_line_has_content = false;
+                    ChunkState::Trailer
+                } else {
+                    ChunkState::Body(self.pending_size)
+                };
+                self.pending_size = 0;
+                self.saw_size_digit = false;
+                Ok(None)
+            }
+            ChunkState::Body(remaining) => {
+                self.state = if remaining > 1 {
+                    ChunkState::Body(remaining - 1)
+                } else {
+                    ChunkState::BodyCr
+                };
+                Ok(Some(byte))
+            }
+            ChunkState::BodyCr => {
+                if byte != b'\r' {
+                    return Err(ChunkedTransferError::MissingLineFeed);
+                }
+                self.state = ChunkState::BodyLf;
+                Ok(None)
+            }
+            ChunkState::BodyLf => {
+                if byte != b'\n' {
+                    return Err(ChunkedTransferError::MissingLineFeed);
+                }
+                self.state = ChunkState::Size;
+                Ok(None)
+            }
+            ChunkState::Trailer => {
+                if byte == b'\r' {
+                    self.state = ChunkState::TrailerLf;
+                } else {
+                    self.trailer_line_has_content = true;
+                }
+                Ok(None)
+            }
+            ChunkState::TrailerLf => {
+                if byte != b'\n' {
+                    return Err(ChunkedTransferError::MissingLineFeed);
+                }
+                self.state = if self.trailer_line_has_content {
+                    self.trailer_line_has_content = false;
+                    ChunkState::Trailer
+                } else {
+                    ChunkState::End
+                };
+                Ok(None)
+            }
+            ChunkState::End => Ok(None),
+        }
+    }
+}
+
+impl<R: Reader> Reader for ChunkedTransferReader<R> {
+    type Error = ChunkedTransferError<R::Error>;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if self.state == ChunkState::End {
+            return Ok(0);
+        }
+
+        let mut written = 0;
+        while written < buf.len() {
+            let Some(byte) = self.next_raw_byte()? else {
+                if self.state == ChunkState::End {
+                    break;
+                }
+                return Err(ChunkedTransferError::MissingLineFeed);
+            };
+            if let Some(payload) = self.step(byte)? {
+                buf[written] = payload;
+                written += 1;
+            }
+            if self.state == ChunkState::End {
+                break;
+            }
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_reader::ChunkReader;
+
+    fn decode_all(data: &[u8], inner_chunk_size: usize) -> Vec<u8> {
+        let inner = ChunkReader::new(data, inner_chunk_size);
+        let mut reader = ChunkedTransferReader::new(inner);
+        let mut out = Vec::new();
+        let mut buf = [0u8; 8];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        out
+    }
+
+    #[test]
+    fn test_single_chunk_decodes_to_payload() {
+        let encoded = b"5\r\nhello\r\n0\r\n\r\n";
+        assert_eq!(decode_all(encoded, usize::MAX), b"hello");
+    }
+
+    #[test]
+    fn test_multiple_chunks_concatenate() {
+        let encoded = b"4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n";
+        assert_eq!(decode_all(encoded, usize::MAX), b"Wikipedia");
+    }
+
+    #[test]
+    fn test_chunk_size_straddles_tiny_inner_reads() {
+        let encoded = b"a\r\n0123456789\r\n0\r\n\r\n";
+        // 1-byte inner reads force the hex size, the CRLFs, and the body
+        // itself to each be assembled across many separate read() calls.
+        assert_eq!(decode_all(encoded, 1), b"0123456789");
+    }
+
+    #[test]
+    fn test_chunk_extension_is_skipped() {
+        let encoded = b"5;foo=bar\r\nhello\r\n0\r\n\r\n";
+        assert_eq!(decode_all(encoded, usize::MAX), b"hello");
+    }
+
+    #[test]
+    fn test_trailer_headers_are_skipped() {
+        let encoded = b"5\r\nhello\r\n0\r\nX-Trailer: value\r\n\r\n";
+        assert_eq!(decode_all(encoded, usize::MAX), b"hello");
+    }
+
+    #[test]
+    fn test_hex_chunk_size_is_parsed() {
+        // 0x1a == 26 bytes.
+        let payload = b"abcdefghijklmnopqrstuvwxyz";
+        let mut encoded = Vec::new();
+        encoded.extend_from_slice(b"1a\r\n");
+        encoded.extend_from_slice(payload);
+        encoded.extend_from_slice(b"\r\n0\r\n\r\n");
+        assert_eq!(decode_all(&encoded, usize::MAX), payload);
+    }
+
+    #[test]
+    fn test_malformed_chunk_size_is_an_error() {
+        let encoded = b"zz\r\nhello\r\n0\r\n\r\n";
+        let inner = ChunkReader::new(encoded, usize::MAX);
+        let mut reader = ChunkedTransferReader::new(inner);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(ChunkedTransferError::InvalidChunkSize)
+        );
+    }
+
+    #[test]
+    fn test_truncated_stream_is_an_error_not_silent_eof() {
+        // Cut off mid-chunk, no terminating CRLF or zero chunk.
+        let encoded = b"5\r\nhel";
+        let inner = ChunkReader::new(encoded, usize::MAX);
+        let mut reader = ChunkedTransferReader::new(inner);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(ChunkedTransferError::MissingLineFeed)
+        );
+    }
+}
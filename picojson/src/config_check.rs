@@ -43,6 +43,23 @@ compile_error!("Cannot enable both 'float-error' and 'float-truncate' features s
 ))]
 compile_error!("Cannot enable multiple float behavior features: choose only one of 'float-skip', 'float-error', or 'float-truncate'");
 
+#[cfg(all(feature = "float-decompose", feature = "float-skip"))]
+compile_error!("Cannot enable both 'float-decompose' and 'float-skip' features simultaneously");
+
+#[cfg(all(feature = "float-decompose", feature = "float-error"))]
+compile_error!("Cannot enable both 'float-decompose' and 'float-error' features simultaneously");
+
+#[cfg(all(feature = "float-decompose", feature = "float-truncate"))]
+compile_error!("Cannot enable both 'float-decompose' and 'float-truncate' features simultaneously");
+
+// 'float32' only narrows the precision of the 'float' feature's Float
+// variant; it isn't a standalone float-behavior choice the way
+// 'float-skip'/'float-error'/etc. are.
+#[cfg(all(feature = "float32", not(feature = "float")))]
+compile_error!(
+    "'float32' requires the 'float' feature: enable both for single-precision floats, or just 'float' for the default f64"
+);
+
 // Compile-time checks to prevent 'float' feature conflicts with float-behavior features
 #[cfg(all(feature = "float", feature = "float-skip"))]
 compile_error!("Cannot enable both 'float' and 'float-skip' features: 'float-skip' is only for when float parsing is disabled");
@@ -52,3 +69,6 @@ compile_error!("Cannot enable both 'float' and 'float-error' features: 'float-er
 
 #[cfg(all(feature = "float", feature = "float-truncate"))]
 compile_error!("Cannot enable both 'float' and 'float-truncate' features: 'float-truncate' is only for when float parsing is disabled");
+
+#[cfg(all(feature = "float", feature = "float-decompose"))]
+compile_error!("Cannot enable both 'float' and 'float-decompose' features: 'float-decompose' is only for when float parsing is disabled");
@@ -27,6 +27,16 @@ pub struct CopyOnEscape<'a, 'b> {
     scratch_start: usize,
     /// Current position in scratch buffer for this string
     scratch_pos: usize,
+    /// Set by [`begin_skip_string`](Self::begin_skip_string): while `true`,
+    /// `handle_escape`/`handle_unicode_escape` track
+    /// [`skip_decoded_len`](Self::skip_decoded_len) instead of writing to
+    /// `scratch`. See [`Self::skip_string`] for why.
+    skipping: bool,
+    /// Running count of how many decoded bytes this string would have
+    /// produced, while [`skipping`](Self::skipping) is set. Not a byte
+    /// offset into anything -- just a length a caller can use for telemetry
+    /// -- since skipped bytes are never materialized anywhere to index into.
+    skip_decoded_len: usize,
 }
 
 impl<'a, 'b> CopyOnEscape<'a, 'b> {
@@ -45,6 +55,8 @@ impl<'a, 'b> CopyOnEscape<'a, 'b> {
             using_scratch: false,
             scratch_start: 0,
             scratch_pos: 0,
+            skipping: false,
+            skip_decoded_len: 0,
         }
     }
 
@@ -59,6 +71,19 @@ impl<'a, 'b> CopyOnEscape<'a, 'b> {
         self.using_scratch = false; // Start with zero-copy optimization
         self.scratch_start = self.global_scratch_pos;
         self.scratch_pos = self.global_scratch_pos;
+        self.skipping = false;
+    }
+
+    /// Like [`begin_string`](Self::begin_string), but for a caller stepping
+    /// over a string it doesn't care about: see [`Self::skip_string`].
+    /// While this mode is active, `handle_escape`/`handle_unicode_escape`
+    /// track [`skip_decoded_len`](Self::skip_decoded_len) instead of
+    /// writing to `scratch`, so a string that would have overflowed the
+    /// scratch buffer no longer prevents skipping past it.
+    pub fn begin_skip_string(&mut self, pos: usize) {
+        self.begin_string(pos);
+        self.skipping = true;
+        self.skip_decoded_len = 0;
     }
 
     /// Copies a span from last_copied_pos to end position with bounds checking.
@@ -103,6 +128,13 @@ impl<'a, 'b> CopyOnEscape<'a, 'b> {
     /// * `pos` - Current position in input (pointing just after the escape sequence)
     /// * `unescaped_char` - The unescaped character to write to scratch buffer
     pub fn handle_escape(&mut self, pos: usize, unescaped_char: u8) -> Result<(), ParseError> {
+        if self.skipping {
+            // The literal run before the backslash, plus the one decoded byte.
+            let literal = pos.saturating_sub(2).saturating_sub(self.last_copied_pos);
+            self.skip_decoded_len = self.skip_decoded_len.saturating_add(literal + 1);
+            self.last_copied_pos = pos;
+            return Ok(());
+        }
         if !self.using_scratch {
             // First escape found - trigger copy-on-escape
             self.using_scratch = true;
@@ -143,6 +175,14 @@ impl<'a, 'b> CopyOnEscape<'a, 'b> {
         start_pos: usize,
         utf8_bytes: &[u8],
     ) -> Result<(), ParseError> {
+        if self.skipping {
+            let literal = start_pos.saturating_sub(self.last_copied_pos);
+            self.skip_decoded_len = self
+                .skip_decoded_len
+                .saturating_add(literal + utf8_bytes.len());
+            self.last_copied_pos = start_pos.saturating_add(6);
+            return Ok(());
+        }
         if !self.using_scratch {
             // First escape found - trigger copy-on-escape
             self.using_scratch = true;
@@ -205,6 +245,58 @@ impl<'a, 'b> CopyOnEscape<'a, 'b> {
             Ok(String::Borrowed(borrowed_str))
         }
     }
+
+    /// Like [`end_string`](Self::end_string), but for a caller who wants the
+    /// exact source text -- backslash escapes and `\uXXXX` sequences
+    /// included -- rather than the decoded value, e.g. to forward or
+    /// re-serialize a string payload byte-for-byte (canonical-form
+    /// preservation, signature verification over the original bytes).
+    ///
+    /// Always returns a borrow of the original input from `string_start` to
+    /// `pos`, even if escapes were seen and [`handle_escape`](Self::handle_escape)/
+    /// [`handle_unicode_escape`](Self::handle_unicode_escape) already copied
+    /// decoded bytes into scratch for this string -- that scratch data is
+    /// simply left unused, the scratch position isn't advanced past it, and
+    /// the next string's [`begin_string`](Self::begin_string) reclaims the
+    /// space. Calling this instead of `end_string` whenever the decoded form
+    /// isn't needed also means a string whose unescaped form would have
+    /// overflowed the scratch buffer no longer fails with
+    /// [`ParseError::ScratchBufferFull`] -- this path never touches scratch
+    /// at all. An `Err` still results if the raw span itself isn't valid
+    /// UTF-8 or `pos` is out of bounds for the input, same as `end_string`.
+    pub fn end_string_raw(&mut self, pos: usize) -> Result<&'a str, ParseError> {
+        let raw_bytes = self
+            .input
+            .get(self.string_start..pos)
+            .ok_or(UnexpectedState::InvalidSliceBounds)?;
+        crate::shared::from_utf8(raw_bytes)
+    }
+
+    /// Completes a string started with [`begin_skip_string`](Self::begin_skip_string):
+    /// validates that `pos` is a well-formed bound for the input (same check
+    /// `end_string` makes before reading from it), then discards the string
+    /// instead of materializing it, returning the decoded length it would
+    /// have had rather than a [`String`].
+    ///
+    /// Escape *validity* (a recognized escape character, well-formed `\u`
+    /// hex digits, correctly paired surrogates) is enforced the same way
+    /// for a skipped string as a decoded one -- that happens in the escape
+    /// processing a driver does before calling
+    /// [`handle_escape`](Self::handle_escape)/
+    /// [`handle_unicode_escape`](Self::handle_unicode_escape) at all, not
+    /// here. What skip mode removes is the *scratch capacity* requirement:
+    /// on a tiny device, stepping over a large or numerous string a caller
+    /// doesn't care about no longer needs it to fit in the scratch buffer.
+    pub fn skip_string(&mut self, pos: usize) -> Result<usize, ParseError> {
+        self.input
+            .get(self.string_start..pos)
+            .ok_or(UnexpectedState::InvalidSliceBounds)?;
+        self.skipping = false;
+        // The literal run after the last escape (or the whole string, if none).
+        let literal = pos.saturating_sub(self.last_copied_pos);
+        self.skip_decoded_len = self.skip_decoded_len.saturating_add(literal);
+        Ok(self.skip_decoded_len)
+    }
 }
 
 #[cfg(test)]
@@ -369,4 +461,114 @@ mod tests {
 
         assert!(matches!(result, String::Unescaped(s) if s == "plainA"));
     }
+
+    #[test]
+    fn test_coe2_end_string_raw_no_escapes() {
+        let input = b"hello world";
+        let mut scratch = [0u8; 100];
+        let mut processor = CopyOnEscape::new(input, &mut scratch);
+
+        processor.begin_string(0);
+        let result = processor.end_string_raw(input.len()).unwrap();
+
+        assert_eq!(result, "hello world");
+    }
+
+    #[test]
+    fn test_coe2_end_string_raw_keeps_escapes_verbatim() {
+        // Unlike end_string, end_string_raw must return "hello\nworld" with
+        // the backslash-n still literal, not the decoded newline.
+        let input = b"hello\\nworld";
+        let mut scratch = [0u8; 100];
+        let mut processor = CopyOnEscape::new(input, &mut scratch);
+
+        processor.begin_string(0);
+        processor.handle_escape(7, b'\n').unwrap();
+        let result = processor.end_string_raw(input.len()).unwrap();
+
+        assert_eq!(result, "hello\\nworld");
+    }
+
+    #[test]
+    fn test_coe2_end_string_raw_never_fails_with_buffer_full() {
+        // The scratch buffer is far too small for the decoded form, but
+        // end_string_raw doesn't touch it, so this still succeeds where
+        // end_string would return ScratchBufferFull.
+        let input = b"very long string with escape\\n";
+        let mut scratch = [0u8; 5];
+        let mut processor = CopyOnEscape::new(input, &mut scratch);
+
+        processor.begin_string(0);
+        let result = processor.end_string_raw(input.len()).unwrap();
+
+        assert_eq!(result, "very long string with escape\\n");
+    }
+
+    #[test]
+    fn test_coe2_skip_string_never_touches_scratch() {
+        // Scratch is far too small for the decoded form; skip mode must
+        // still succeed since it never writes to it.
+        let input = b"very long string with escape\\n";
+        let mut scratch = [0u8; 2];
+        let mut processor = CopyOnEscape::new(input, &mut scratch);
+
+        processor.begin_skip_string(0);
+        processor.handle_escape(30, b'\n').unwrap();
+        let decoded_len = processor.skip_string(input.len()).unwrap();
+
+        // "very long string with escape" (28 bytes) + the decoded '\n' (1 byte).
+        assert_eq!(decoded_len, 28 + 1);
+    }
+
+    #[test]
+    fn test_coe2_skip_string_tracks_unicode_escape_length() {
+        let input = b"test\\u03B1end"; // α = Greek alpha 'α' (2 bytes in UTF-8)
+        let mut scratch = [0u8; 2];
+        let mut processor = CopyOnEscape::new(input, &mut scratch);
+
+        processor.begin_skip_string(0);
+        let utf8_alpha = "α".as_bytes();
+        processor.handle_unicode_escape(4, utf8_alpha).unwrap();
+        let decoded_len = processor.skip_string(input.len()).unwrap();
+
+        // "test" (4) + alpha (2) + "end" (3).
+        assert_eq!(decoded_len, 4 + 2 + 3);
+    }
+
+    #[test]
+    fn test_coe2_skip_string_no_escapes_reports_raw_len() {
+        let input = b"plain string";
+        let mut scratch = [0u8; 100];
+        let mut processor = CopyOnEscape::new(input, &mut scratch);
+
+        processor.begin_skip_string(0);
+        let decoded_len = processor.skip_string(input.len()).unwrap();
+
+        // No escapes: the decoded form is the 12 source bytes as-is.
+        assert_eq!(decoded_len, 12);
+    }
+
+    #[test]
+    fn test_coe2_skip_string_then_normal_string_reuses_scratch_from_scratch() {
+        // Skipping never advances global_scratch_pos, so a normal string
+        // afterwards still starts from wherever scratch usage left off
+        // before the skip, not wherever the skipped string "would" have
+        // ended up.
+        let mut scratch = [0u8; 100];
+        let mut processor = CopyOnEscape::new(b"dummy", &mut scratch);
+
+        let skipped = b"skip\\tme";
+        processor.input = skipped;
+        processor.begin_skip_string(0);
+        processor.handle_escape(6, b'\t').unwrap();
+        processor.skip_string(skipped.len()).unwrap();
+
+        let kept = b"keep\\nme";
+        processor.input = kept;
+        processor.begin_string(0);
+        processor.handle_escape(6, b'\n').unwrap();
+        let result = processor.end_string(kept.len()).unwrap();
+
+        assert!(matches!(result, String::Unescaped(s) if s == "keep\nme"));
+    }
 }
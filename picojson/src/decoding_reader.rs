@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`DecodingReader`], a [`Reader`] adapter that transforms bytes from an
+//! inner [`Reader`] on the fly -- the motivating case being streaming
+//! decompression (gzip/zstd/etc.) layered under the JSON parser without
+//! buffering the whole document. `no_std` and allocation-free like the
+//! other [`Reader`] adapters in this crate: compressed bytes are pulled
+//! from `inner` into a caller-supplied window buffer, and a small
+//! [`ByteDecoder`] trait turns that window into decoded output one `read()`
+//! call at a time.
+//!
+//! The critical invariant is framing: a [`ByteDecoder`] impl must consume
+//! from its `input` only as much as belongs to the frame it's decoding, so
+//! once it reports the frame complete (no bytes consumed, nothing produced,
+//! input still non-empty), [`DecodingReader`] stops pulling from `inner`
+//! for good and leaves whatever's left in its window -- the next frame, or
+//! trailing bytes -- recoverable via [`DecodingReader::into_inner`] instead
+//! of silently discarding or overreading past the boundary.
+
+use crate::Reader;
+
+/// Transforms compressed (or otherwise encoded) bytes into decoded output,
+/// incrementally and without allocating.
+pub trait ByteDecoder {
+    /// The error type returned when `input` isn't valid for this decoder.
+    type Error;
+
+    /// Decodes as much of `input` as it can into `output`, returning
+    /// `(bytes_consumed, bytes_produced)`.
+    ///
+    /// `bytes_produced` may be `0` even with `input` left to consume (e.g.
+    /// skipping a header). `bytes_consumed` may be `0` when output is
+    /// produced from state the decoder already holds. Returning `(0, 0)`
+    /// while `input` is non-empty signals that the current frame is
+    /// complete and nothing in `input` belongs to it -- [`DecodingReader`]
+    /// treats this as the frame boundary and stops calling `decode` with
+    /// fresh bytes from `inner`. A decoder that needs more bytes than
+    /// `input` holds (e.g. a partial length-prefix) must therefore consume
+    /// them into its own state rather than return `(0, 0)`. Once `inner`
+    /// reaches end-of-stream, `decode` is called with an empty `input` so
+    /// it can flush output it is still holding.
+    fn decode(&mut self, input: &[u8], output: &mut [u8]) -> Result<(usize, usize), Self::Error>;
+}
+
+/// Either the inner [`Reader`] failed, or `D` rejected the bytes it was given.
+#[derive(Debug, PartialEq)]
+pub enum DecodingError<RE, DE> {
+    /// The inner reader failed.
+    Inner(RE),
+    /// The decoder rejected its input.
+    Decode(DE),
+}
+
+/// A [`Reader`] wrapping an inner [`Reader`] and a [`ByteDecoder`], so a
+/// [`StreamParser`](crate::StreamParser) can be stacked directly on top of
+/// a compressed (or otherwise encoded) source:
+/// `StreamParser::new(DecodingReader::new(inner, decoder, &mut window), &mut buf)`.
+pub struct DecodingReader<'b, R: Reader, D: ByteDecoder> {
+    inner: R,
+    decoder: D,
+    window: &'b mut [u8],
+    window_pos: usize,
+    window_len: usize,
+    /// Set once `decoder` has reported the frame complete; `read` then
+    /// always returns `Ok(0)` without touching `inner` again.
+    frame_done: bool,
+}
+
+impl<'b, R: Reader, D: ByteDecoder> DecodingReader<'b, R, D> {
+    /// Wraps `inner`, decoding through `decoder`, using `window` to buffer
+    /// undecoded bytes pulled from `inner`.
+    pub fn new(inner: R, decoder: D, window: &'b mut [u8]) -> Self {
+        Self {
+            inner,
+            decoder,
+            window,
+            window_pos: 0,
+            window_len: 0,
+            frame_done: false,
+        }
+    }
+
+    /// Recovers the inner reader, the decoder, and any bytes already
+    /// pulled from `inner` into the window but left unconsumed at the
+    /// frame boundary -- i.e. whatever follows the decoded frame (a
+    /// trailer, or the next frame in a multi-frame stream). A caller
+    /// continuing to read subsequent frames from the same transport must
+    /// treat this slice as coming before whatever `inner` reads next.
+    pub fn into_inner(self) -> (R, D, &'b [u8]) {
+        let Self {
+            inner,
+            decoder,
+            window,
+            window_pos,
+            window_len,
+            ..
+        } = self;
+        (inner, decoder, &window[window_pos..window_len])
+    }
+}
+
+impl<'b, R: Reader, D: ByteDecoder> Reader for DecodingReader<'b, R, D> {
+    type Error = DecodingError<R::Error, D::Error>;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if self.frame_done {
+            return Ok(0);
+        }
+        loop {
+            if self.window_pos >= self.window_len {
+                let n = self
+                    .inner
+                    .read(self.window)
+                    .map_err(DecodingError::Inner)?;
+                self.window_len = n;
+                self.window_pos = 0;
+                if n == 0 {
+                    // Let the decoder flush any output it is still holding.
+                    let (_, produced) = self
+                        .decoder
+                        .decode(&[], buf)
+                        .map_err(DecodingError::Decode)?;
+                    if produced > 0 {
+                        return Ok(produced);
+                    }
+                    self.frame_done = true;
+                    return Ok(0);
+                }
+            }
+
+            let input = &self.window[self.window_pos..self.window_len];
+            let (consumed, produced) = self
+                .decoder
+                .decode(input, buf)
+                .map_err(DecodingError::Decode)?;
+            self.window_pos += consumed;
+
+            if produced > 0 {
+                return Ok(produced);
+            }
+            if consumed == 0 {
+                // No progress on the bytes already buffered, and `decode`
+                // isn't asking for more either -- the frame is done. What's
+                // left in the window belongs to whatever comes after it,
+                // not to this decode, so it's left exactly where it is.
+                self.frame_done = true;
+                return Ok(0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_reader::ChunkReader;
+
+    /// Toy decoder for a `(count, byte)`-pair run-length encoding,
+    /// terminated by a `(0, _)` sentinel pair that it recognizes but does
+    /// not consume -- standing in for a real codec's trailer/checksum
+    /// bytes that belong to the frame but aren't part of the payload.
+    #[derive(Default)]
+    struct PairRleDecoder {
+        pending: Option<(usize, u8)>,
+        /// A run's count byte, consumed on its own when the window ended
+        /// before the byte it applies to.
+        count: Option<u8>,
+    }
+
+    impl ByteDecoder for PairRleDecoder {
+        type Error = ();
+
+        fn decode(&mut self, input: &[u8], output: &mut [u8]) -> Result<(usize, usize), ()> {
+            if let Some((remaining, byte)) = self.pending {
+                let n = remaining.min(output.len());
+                output[..n].fill(byte);
+                self.pending = if n < remaining {
+                    Some((remaining - n, byte))
+                } else {
+                    None
+                };
+                return Ok((0, n));
+            }
+            let (count, byte, consumed) = match (self.count, input) {
+                (Some(count), [byte, ..]) => (count as usize, *byte, 1),
+                (None, [count, byte, ..]) => (*count as usize, *byte, 2),
+                (None, [count]) if *count != 0 => {
+                    self.count = Some(*count);
+                    return Ok((1, 0));
+                }
+                _ => return Ok((0, 0)),
+            };
+            if count == 0 {
+                return Ok((0, 0));
+            }
+            self.count = None;
+            let n = count.min(output.len());
+            output[..n].fill(byte);
+            if n < count {
+                self.pending = Some((count - n, byte));
+            }
+            Ok((consumed, n))
+        }
+    }
+
+    fn decode_all(data: &[u8], inner_chunk_size: usize, window_size: usize) -> Vec<u8> {
+        let inner = ChunkReader::new(data, inner_chunk_size);
+        let mut window = vec![0u8; window_size];
+        let mut reader = DecodingReader::new(inner, PairRleDecoder::default(), &mut window);
+        let mut out = Vec::new();
+        let mut buf = vec![0u8; 4];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        out
+    }
+
+    #[test]
+    fn test_decodes_runs_to_true_eof() {
+        let data = [3, b'a', 2, b'b'];
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"aaabb");
+    }
+
+    #[test]
+    fn test_decodes_runs_split_across_tiny_inner_chunks_and_window() {
+        let data = [3, b'a', 2, b'b', 5, b'c'];
+        assert_eq!(decode_all(&data, 1, 3), b"aaabbccccc");
+    }
+
+    #[test]
+    fn test_run_longer_than_output_buffer_is_carried_over() {
+        // A 4-byte read buffer is smaller than the 6-byte run, forcing the
+        // decoder's own `pending` state to flush the rest on a later call.
+        let data = [6, b'x'];
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"xxxxxx");
+    }
+
+    #[test]
+    fn test_sentinel_stops_reads_without_consuming_trailer() {
+        let mut data = vec![3, b'a'];
+        data.extend_from_slice(&[0, 0]); // sentinel: frame end
+        data.extend_from_slice(b"NEXTFRAME"); // belongs to whatever follows
+
+        let inner = ChunkReader::full_slice(&data);
+        let mut window = [0u8; 32];
+        let mut reader = DecodingReader::new(inner, PairRleDecoder::default(), &mut window);
+
+        let mut buf = [0u8; 16];
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"aaa");
+        // Frame is done: further reads report end-of-stream without
+        // touching `inner` again.
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+        let (_inner, _decoder, leftover) = reader.into_inner();
+        assert_eq!(leftover, b"\0\0NEXTFRAME");
+    }
+
+    #[test]
+    fn test_inner_reader_error_propagates() {
+        struct FailingReader;
+        impl Reader for FailingReader {
+            type Error = &'static str;
+            fn read(&mut self, _buf: &mut [u8]) -> Result<usize, Self::Error> {
+                Err("boom")
+            }
+        }
+        let mut window = [0u8; 8];
+        let mut reader = DecodingReader::new(FailingReader, PairRleDecoder::default(), &mut window);
+        let mut buf = [0u8; 8];
+        assert_eq!(reader.read(&mut buf), Err(DecodingError::Inner("boom")));
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! An optional, pay-for-what-you-use hook for computing a running checksum
+//! over raw input bytes as they're consumed during parsing.
+//!
+//! Built on [`core::hash::Hasher`] rather than a bespoke trait: it's already
+//! `no_std`, already implemented by whatever hash algorithm a caller wants
+//! (XxHash64, CRC32, FNV, ...) in their own crate, and already has exactly
+//! the two operations this needs -- `write` to feed bytes in, `finish` to
+//! read the accumulated value out.
+//!
+//! Not yet wired into [`StreamBuffer`](crate::stream_buffer::StreamBuffer):
+//! hooking a `set_digest`/`finalize_digest` pair up to "every byte that
+//! transitions from filled-but-not-yet-hashed to consumed-past-`tokenize_pos`,
+//! exactly once, coordinated with compaction and `Ring`-mode wraparound"
+//! touches the same hot-path bookkeeping the `ByteStorage` follow-up (see
+//! [`byte_storage`](crate::byte_storage)) already deferred, for the same
+//! reason: verifying a change like that by hand, with no build to check it
+//! against, is a bigger risk than this piece alone is worth taking blind.
+//! [`DigestTracker`] is the part that's safe to add now -- the bookkeeping a
+//! caller (or a future `StreamBuffer` integration) would drive: which byte
+//! range has already been fed to the hasher, so nothing is hashed twice or
+//! skipped across a compaction shift.
+
+use core::hash::Hasher;
+
+/// Tracks how much of a growing byte range has already been fed to a
+/// [`Hasher`], across shifts (e.g. from
+/// [`StreamBuffer::compact_from`](crate::stream_buffer::StreamBuffer::compact_from))
+/// that move not-yet-hashed bytes to a new position.
+///
+/// This is deliberately just the bookkeeping, not a `StreamBuffer`
+/// integration -- see this module's top-level doc comment -- so a caller
+/// drives it directly, once per fill, with whatever slice and boundary its
+/// own buffer currently has.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DigestTracker {
+    hashed_up_to: usize,
+}
+
+impl DigestTracker {
+    /// A tracker that hasn't hashed anything yet.
+    pub fn new() -> Self {
+        Self { hashed_up_to: 0 }
+    }
+
+    /// Feeds `buffer[self.hashed_up_to..up_to]` to `hasher` and advances the
+    /// boundary past it. A no-op if `up_to` doesn't move the boundary
+    /// forward, e.g. called again with a position already hashed.
+    pub fn hash_up_to(&mut self, buffer: &[u8], up_to: usize, hasher: &mut impl Hasher) {
+        if up_to > self.hashed_up_to {
+            hasher.write(&buffer[self.hashed_up_to..up_to]);
+            self.hashed_up_to = up_to;
+        }
+    }
+
+    /// Rebases the already-hashed boundary after bytes before it are
+    /// dropped, e.g. by a compaction shift moving the buffer down by
+    /// `shift` -- the same kind of offset-rebasing
+    /// [`StreamContentBuilder::update_positions_after_compaction`](crate::stream_content_builder::StreamContentBuilder::update_positions_after_compaction)
+    /// already does for in-flight token positions.
+    pub fn rebase_after_compaction(&mut self, shift: usize) {
+        self.hashed_up_to = self.hashed_up_to.saturating_sub(shift);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SumHasher(u64);
+    impl Hasher for SumHasher {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0 += bytes.iter().map(|&b| b as u64).sum::<u64>();
+        }
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    fn byte_sum(bytes: &[u8]) -> u64 {
+        bytes.iter().map(|&b| b as u64).sum()
+    }
+
+    #[test]
+    fn test_hash_up_to_feeds_only_new_bytes() {
+        let buffer = b"hello world";
+        let mut tracker = DigestTracker::new();
+        let mut hasher = SumHasher(0);
+
+        tracker.hash_up_to(buffer, 5, &mut hasher);
+        assert_eq!(hasher.0, byte_sum(&buffer[..5]));
+
+        tracker.hash_up_to(buffer, 5, &mut hasher); // no new bytes
+        assert_eq!(hasher.0, byte_sum(&buffer[..5]));
+
+        tracker.hash_up_to(buffer, buffer.len(), &mut hasher);
+        assert_eq!(hasher.0, byte_sum(buffer));
+    }
+
+    #[test]
+    fn test_rebase_after_compaction_shifts_the_boundary_back() {
+        let mut tracker = DigestTracker::new();
+        tracker.hash_up_to(b"0123456789", 6, &mut SumHasher(0));
+        tracker.rebase_after_compaction(4);
+        assert_eq!(tracker.hashed_up_to, 2);
+    }
+}
@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A fixed-capacity duplicate-object-key checker for streaming consumers.
+//!
+//! JSON's grammar permits duplicate keys within a single object, but a lot
+//! of consumers want the stricter JSONChecker-style guarantee that none
+//! occur. Rather than wiring that into the tokenizer/`ParserCore` -- which
+//! would force every caller to pay for, and put a capacity bound on, a
+//! check most don't want -- this mirrors [`PathStack`](crate::PathStack):
+//! a handler opts in by feeding it the same `Event::StartObject`/
+//! `Event::StartArray`/`Event::EndObject`/`Event::EndArray`/`Event::Key`
+//! events it already receives, and reads back whether the latest key
+//! collides with one already seen at the same nesting level.
+
+/// Returned by [`DuplicateKeyStack::push_object`]/[`DuplicateKeyStack::push_array`]
+/// when the stack has no remaining capacity for another nesting level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateKeyStackOverflow;
+
+/// Returned by [`DuplicateKeyStack::check_and_record`] when `key` repeats
+/// one already seen earlier in the same object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateKey;
+
+/// Keys longer than this are let through unchecked rather than rejected or
+/// truncated -- see [`DuplicateKeyStack::check_and_record`].
+const MAX_KEY_LEN: usize = 32;
+
+#[derive(Debug, Clone, Copy)]
+struct StoredKey {
+    bytes: [u8; MAX_KEY_LEN],
+    len: u8,
+}
+
+impl StoredKey {
+    fn matches(&self, key: &str) -> bool {
+        key.len() == self.len as usize && key.as_bytes() == &self.bytes[..self.len as usize]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Frame {
+    /// An open object; `keys_start` is where this level's keys begin in
+    /// `keys`.
+    Object { keys_start: usize },
+    /// An open array. Arrays have no keys of their own, so entering one
+    /// just shadows the enclosing object's keys until it closes.
+    Array,
+}
+
+/// A const-generic, fixed-capacity duplicate-key checker.
+///
+/// `LEVELS` bounds the nesting depth it can track (the same role as
+/// [`PathStack`](crate::PathStack)'s `N`); `KEYS` bounds the total number
+/// of keys remembered across all currently-open objects at once. A key
+/// longer than 32 bytes, or one seen once `KEYS` is already full, is not
+/// remembered, so a later repeat of it is let through unchecked instead of
+/// erroring or growing (it is still checked against the keys that are
+/// remembered) -- this is a
+/// best-effort guard sized for typical embedded record shapes, not an
+/// unconditional guarantee.
+#[derive(Debug, Clone)]
+pub struct DuplicateKeyStack<const LEVELS: usize, const KEYS: usize> {
+    frames: [Option<Frame>; LEVELS],
+    frames_len: usize,
+    keys: [Option<StoredKey>; KEYS],
+    keys_len: usize,
+}
+
+impl<const LEVELS: usize, const KEYS: usize> DuplicateKeyStack<LEVELS, KEYS> {
+    /// Creates an empty stack, positioned at the document root.
+    pub fn new() -> Self {
+        Self {
+            frames: [None; LEVELS],
+            frames_len: 0,
+            keys: [None; KEYS],
+            keys_len: 0,
+        }
+    }
+
+    /// Pushes an object frame, to be called on `Event::StartObject`.
+    pub fn push_object(&mut self) -> Result<(), DuplicateKeyStackOverflow> {
+        self.push(Frame::Object {
+            keys_start: self.keys_len,
+        })
+    }
+
+    /// Pushes an array frame, to be called on `Event::StartArray`.
+    pub fn push_array(&mut self) -> Result<(), DuplicateKeyStackOverflow> {
+        self.push(Frame::Array)
+    }
+
+    fn push(&mut self, frame: Frame) -> Result<(), DuplicateKeyStackOverflow> {
+        if self.frames_len >= LEVELS {
+            return Err(DuplicateKeyStackOverflow);
+        }
+        self.frames[self.frames_len] = Some(frame);
+        self.frames_len += 1;
+        Ok(())
+    }
+
+    /// Pops the current frame, to be called on `Event::EndObject`/
+    /// `Event::EndArray`, releasing that level's recorded keys. A no-op at
+    /// the document root.
+    pub fn pop(&mut self) {
+        if self.frames_len == 0 {
+            return;
+        }
+        self.frames_len -= 1;
+        if let Some(Frame::Object { keys_start }) = self.frames[self.frames_len].take() {
+            for slot in &mut self.keys[keys_start..self.keys_len] {
+                *slot = None;
+            }
+            self.keys_len = keys_start;
+        }
+    }
+
+    /// Checks `key` against every key already recorded in the innermost
+    /// open object and records it if it's new. A no-op (always `Ok`) if the
+    /// stack is empty or the top frame is an array -- a `Key` event only
+    /// ever occurs while an object is open, so that shouldn't happen in
+    /// practice, but this stays a defensive no-op rather than trusting a
+    /// caller's event bookkeeping.
+    pub fn check_and_record(&mut self, key: &str) -> Result<(), DuplicateKey> {
+        let keys_start = match self.frames.get(self.frames_len.wrapping_sub(1)) {
+            Some(Some(Frame::Object { keys_start })) => *keys_start,
+            _ => return Ok(()),
+        };
+
+        for slot in &self.keys[keys_start..self.keys_len] {
+            if let Some(stored) = slot {
+                if stored.matches(key) {
+                    return Err(DuplicateKey);
+                }
+            }
+        }
+
+        if key.len() > MAX_KEY_LEN || self.keys_len >= KEYS {
+            // Best-effort: nowhere left to remember this one, so let it
+            // through unchecked rather than failing the parse over a
+            // capacity limit that has nothing to do with whether the
+            // document actually repeats a key.
+            return Ok(());
+        }
+        let mut bytes = [0u8; MAX_KEY_LEN];
+        bytes[..key.len()].copy_from_slice(key.as_bytes());
+        self.keys[self.keys_len] = Some(StoredKey {
+            bytes,
+            len: key.len() as u8,
+        });
+        self.keys_len += 1;
+        Ok(())
+    }
+
+    /// The current nesting depth (number of open frames).
+    pub fn depth(&self) -> usize {
+        self.frames_len
+    }
+}
+
+impl<const LEVELS: usize, const KEYS: usize> Default for DuplicateKeyStack<LEVELS, KEYS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_is_empty() {
+        let stack: DuplicateKeyStack<4, 8> = DuplicateKeyStack::new();
+        assert_eq!(stack.depth(), 0);
+    }
+
+    #[test]
+    fn test_duplicate_key_in_same_object_is_rejected() {
+        let mut stack: DuplicateKeyStack<4, 8> = DuplicateKeyStack::new();
+        stack.push_object().unwrap();
+        assert_eq!(stack.check_and_record("a"), Ok(()));
+        assert_eq!(stack.check_and_record("b"), Ok(()));
+        assert_eq!(stack.check_and_record("a"), Err(DuplicateKey));
+    }
+
+    #[test]
+    fn test_same_key_in_nested_object_is_allowed() {
+        let mut stack: DuplicateKeyStack<4, 8> = DuplicateKeyStack::new();
+        stack.push_object().unwrap(); // outer
+        assert_eq!(stack.check_and_record("value"), Ok(()));
+        stack.push_object().unwrap(); // nested
+        assert_eq!(stack.check_and_record("value"), Ok(()));
+    }
+
+    #[test]
+    fn test_pop_releases_that_levels_keys() {
+        let mut stack: DuplicateKeyStack<4, 8> = DuplicateKeyStack::new();
+        stack.push_object().unwrap();
+        assert_eq!(stack.check_and_record("a"), Ok(()));
+        stack.pop();
+        stack.push_object().unwrap();
+        // "a" belonged to the object that just closed, so it's fine again.
+        assert_eq!(stack.check_and_record("a"), Ok(()));
+    }
+
+    #[test]
+    fn test_array_elements_do_not_check_keys() {
+        let mut stack: DuplicateKeyStack<4, 8> = DuplicateKeyStack::new();
+        stack.push_object().unwrap();
+        assert_eq!(stack.check_and_record("items"), Ok(()));
+        stack.push_array().unwrap();
+        // No Key event can occur directly inside an array; this is just
+        // confirming it's a harmless no-op rather than panicking.
+        assert_eq!(stack.check_and_record("items"), Ok(()));
+    }
+
+    #[test]
+    fn test_overflow_rejected_past_capacity() {
+        let mut stack: DuplicateKeyStack<2, 8> = DuplicateKeyStack::new();
+        stack.push_object().unwrap();
+        stack.push_array().unwrap();
+        assert_eq!(stack.push_object(), Err(DuplicateKeyStackOverflow));
+    }
+
+    #[test]
+    fn test_key_beyond_capacity_is_let_through_unchecked() {
+        let mut stack: DuplicateKeyStack<4, 2> = DuplicateKeyStack::new();
+        stack.push_object().unwrap();
+        assert_eq!(stack.check_and_record("a"), Ok(()));
+        assert_eq!(stack.check_and_record("b"), Ok(()));
+        // Capacity (2 keys) is already used up; a third key is not
+        // remembered, so even a genuine repeat of it is let through.
+        assert_eq!(stack.check_and_record("c"), Ok(()));
+        assert_eq!(stack.check_and_record("c"), Ok(()));
+        // A repeat of a key that was remembered is still caught.
+        assert_eq!(stack.check_and_record("a"), Err(DuplicateKey));
+    }
+
+    #[test]
+    fn test_key_longer_than_max_len_is_let_through_unchecked() {
+        let mut stack: DuplicateKeyStack<4, 8> = DuplicateKeyStack::new();
+        stack.push_object().unwrap();
+        let long_key = "x".repeat(MAX_KEY_LEN + 1);
+        assert_eq!(stack.check_and_record(&long_key), Ok(()));
+        assert_eq!(stack.check_and_record(&long_key), Ok(()));
+    }
+}
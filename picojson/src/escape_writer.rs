@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A JSON string escaper -- the write-side counterpart to
+//! [`EscapeProcessor`](crate::escape_processor::EscapeProcessor).
+//!
+//! [`EscapeWriter`] is a pull iterator over escaped output bytes, so turning
+//! a `&[u8]`/`&str` into a JSON string body needs no allocation: feed the
+//! bytes to a writer one at a time, or `collect()` them if an `alloc` buffer
+//! is available.
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Decodes the UTF-8 sequence starting at `bytes[pos]`, returning its
+/// codepoint and byte length. `bytes` must be valid UTF-8 at `pos` -- true
+/// for any slice obtained from a `&str`, which is the only way callers
+/// construct an [`EscapeWriter`].
+fn decode_utf8_at(bytes: &[u8], pos: usize) -> (u32, usize) {
+    let b0 = bytes[pos];
+    if b0 & 0x80 == 0 {
+        (b0 as u32, 1)
+    } else if b0 & 0xE0 == 0xC0 {
+        let b1 = bytes[pos + 1];
+        (((b0 as u32 & 0x1F) << 6) | (b1 as u32 & 0x3F), 2)
+    } else if b0 & 0xF0 == 0xE0 {
+        let b1 = bytes[pos + 1];
+        let b2 = bytes[pos + 2];
+        (
+            ((b0 as u32 & 0x0F) << 12) | ((b1 as u32 & 0x3F) << 6) | (b2 as u32 & 0x3F),
+            3,
+        )
+    } else {
+        let b1 = bytes[pos + 1];
+        let b2 = bytes[pos + 2];
+        let b3 = bytes[pos + 3];
+        (
+            ((b0 as u32 & 0x07) << 18)
+                | ((b1 as u32 & 0x3F) << 12)
+                | ((b2 as u32 & 0x3F) << 6)
+                | (b3 as u32 & 0x3F),
+            4,
+        )
+    }
+}
+
+/// Escapes bytes for embedding inside a JSON string's `"..."` body.
+///
+/// Printable ASCII and any byte `0x20` or above pass through unchanged
+/// (this crate does not re-encode valid UTF-8, so multi-byte sequences are
+/// copied through as-is) -- unless [`Self::new_strict_ascii`] was used,
+/// in which case every codepoint above `0x7F` is instead re-encoded as a
+/// `\uXXXX` escape (a `\uXXXX\uXXXX` surrogate pair for codepoints above
+/// the Basic Multilingual Plane), keeping output bytes within ASCII for
+/// transports or consumers that can't round-trip raw UTF-8. `"` and `\`
+/// become `\"`/`\\`; `\n \t \r` and the backspace/form-feed controls use
+/// their short escapes; every other control character below `0x20`
+/// becomes a six-byte `\u00XX` sequence.
+///
+/// This does not emit the surrounding quotes -- write those yourself around
+/// the iterator's output.
+pub struct EscapeWriter<'a> {
+    input: &'a [u8],
+    pos: usize,
+    strict_ascii: bool,
+    pending: [u8; 12],
+    pending_len: u8,
+    pending_pos: u8,
+}
+
+impl<'a> EscapeWriter<'a> {
+    /// Create an escaper over `input`, passing non-ASCII UTF-8 through
+    /// unchanged.
+    pub fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            pos: 0,
+            strict_ascii: false,
+            pending: [0; 12],
+            pending_len: 0,
+            pending_pos: 0,
+        }
+    }
+
+    /// Like [`Self::new`], but re-encodes every non-ASCII codepoint as a
+    /// `\uXXXX` escape instead of copying its UTF-8 bytes through -- see
+    /// the struct docs. `input` must be valid UTF-8 (e.g. `str::as_bytes`);
+    /// feeding it arbitrary bytes is a logic error, same precondition
+    /// [`Self::new`] already has for multi-byte sequences.
+    pub fn new_strict_ascii(input: &'a [u8]) -> Self {
+        Self {
+            strict_ascii: true,
+            ..Self::new(input)
+        }
+    }
+
+    fn queue(&mut self, bytes: &[u8]) {
+        self.pending[..bytes.len()].copy_from_slice(bytes);
+        self.pending_len = bytes.len() as u8;
+        self.pending_pos = 0;
+    }
+
+    /// Queues a `\uXXXX` escape for `codepoint`, split into a UTF-16
+    /// surrogate pair first if it's above the Basic Multilingual Plane.
+    fn queue_unicode_escape(&mut self, codepoint: u32) {
+        if codepoint > 0xFFFF {
+            let v = codepoint - 0x10000;
+            let high = 0xD800 + (v >> 10);
+            let low = 0xDC00 + (v & 0x3FF);
+            self.queue(&[
+                b'\\',
+                b'u',
+                HEX_DIGITS[((high >> 12) & 0xF) as usize],
+                HEX_DIGITS[((high >> 8) & 0xF) as usize],
+                HEX_DIGITS[((high >> 4) & 0xF) as usize],
+                HEX_DIGITS[(high & 0xF) as usize],
+                b'\\',
+                b'u',
+                HEX_DIGITS[((low >> 12) & 0xF) as usize],
+                HEX_DIGITS[((low >> 8) & 0xF) as usize],
+                HEX_DIGITS[((low >> 4) & 0xF) as usize],
+                HEX_DIGITS[(low & 0xF) as usize],
+            ]);
+        } else {
+            self.queue(&[
+                b'\\',
+                b'u',
+                HEX_DIGITS[((codepoint >> 12) & 0xF) as usize],
+                HEX_DIGITS[((codepoint >> 8) & 0xF) as usize],
+                HEX_DIGITS[((codepoint >> 4) & 0xF) as usize],
+                HEX_DIGITS[(codepoint & 0xF) as usize],
+            ]);
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EscapeWriter<'a> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl Iterator for EscapeWriter<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pending_pos < self.pending_len {
+            let byte = self.pending[self.pending_pos as usize];
+            self.pending_pos += 1;
+            return Some(byte);
+        }
+
+        let byte = *self.input.get(self.pos)?;
+
+        if self.strict_ascii && byte & 0x80 != 0 {
+            let (codepoint, len) = decode_utf8_at(self.input, self.pos);
+            self.pos += len;
+            self.queue_unicode_escape(codepoint);
+            return self.next();
+        }
+        self.pos += 1;
+
+        match byte {
+            b'"' => self.queue(b"\\\""),
+            b'\\' => self.queue(b"\\\\"),
+            b'\n' => self.queue(b"\\n"),
+            b'\t' => self.queue(b"\\t"),
+            b'\r' => self.queue(b"\\r"),
+            0x08 => self.queue(b"\\b"),
+            0x0C => self.queue(b"\\f"),
+            0x00..=0x1F => self.queue(&[
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                HEX_DIGITS[(byte >> 4) as usize],
+                HEX_DIGITS[(byte & 0x0F) as usize],
+            ]),
+            _ => return Some(byte),
+        }
+
+        self.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Collects an `EscapeWriter`'s output into a fixed buffer and compares
+    /// it against `expected`, without requiring `alloc`.
+    fn assert_escapes_to(input: &str, expected: &str) {
+        let mut out = [0u8; 64];
+        let mut len = 0;
+        for byte in EscapeWriter::from(input) {
+            out[len] = byte;
+            len += 1;
+        }
+        assert_eq!(&out[..len], expected.as_bytes());
+    }
+
+    #[test]
+    fn test_passthrough_ascii() {
+        assert_escapes_to("hello world", "hello world");
+    }
+
+    #[test]
+    fn test_short_escapes() {
+        assert_escapes_to("\"\\\n\t\r\u{8}\u{c}", "\\\"\\\\\\n\\t\\r\\b\\f");
+    }
+
+    #[test]
+    fn test_control_char_unicode_escape() {
+        assert_escapes_to("\u{1}\u{1f}", "\\u0001\\u001F");
+    }
+
+    #[test]
+    fn test_passthrough_multibyte_utf8() {
+        assert_escapes_to("caf\u{e9}", "caf\u{e9}");
+    }
+
+    #[test]
+    fn test_mixed_content() {
+        assert_escapes_to(
+            "line1\nline2\t\"quoted\"",
+            "line1\\nline2\\t\\\"quoted\\\"",
+        );
+    }
+
+    fn assert_strict_ascii_escapes_to(input: &str, expected: &str) {
+        let mut out = [0u8; 64];
+        let mut len = 0;
+        for byte in EscapeWriter::new_strict_ascii(input.as_bytes()) {
+            out[len] = byte;
+            len += 1;
+        }
+        assert_eq!(&out[..len], expected.as_bytes());
+    }
+
+    #[test]
+    fn test_strict_ascii_escapes_bmp_codepoint() {
+        assert_strict_ascii_escapes_to("caf\u{e9}", "caf\\u00E9");
+    }
+
+    #[test]
+    fn test_strict_ascii_escapes_astral_codepoint_as_surrogate_pair() {
+        assert_strict_ascii_escapes_to("\u{1F600}", "\\uD83D\\uDE00");
+    }
+
+    #[test]
+    fn test_strict_ascii_still_uses_short_escapes() {
+        assert_strict_ascii_escapes_to("\"caf\u{e9}\"", "\\\"caf\\u00E9\\\"");
+    }
+}
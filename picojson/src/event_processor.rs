@@ -8,13 +8,14 @@
 use crate::escape_processor::{EscapeProcessor, UnicodeEscapeCollector};
 use crate::shared::{ContentKind, ContentRange, Event, ParserState, State, UnexpectedState};
 use crate::ujson::{EventToken, Tokenizer};
-use crate::{ujson, ParseError};
+use crate::{ujson, ParseError, Span};
 
 /// The core parser logic that handles the unified event processing loop.
 ///
 /// This struct contains all the shared state and logic that was previously
 /// duplicated between SliceParser and StreamParser. It uses trait abstractions
 /// to handle the differences in content building and byte providing.
+#[derive(Clone)]
 pub struct ParserCore<T: ujson::BitBucket, C: ujson::DepthCounter> {
     /// The tokenizer that processes JSON tokens
     pub tokenizer: Tokenizer<T, C>,
@@ -35,6 +36,80 @@ pub struct ParserCore<T: ujson::BitBucket, C: ujson::DepthCounter> {
     continuing_from_previous_chunk: bool,
     /// Flag to prevent infinite loop when emitting PartialContentSpanStart
     partial_span_start_emitted: bool,
+    /// Start offset of the content currently being tracked for [`Self::last_span`],
+    /// recorded when a string/key/number `Begin` token is seen.
+    span_start: usize,
+    /// Source span of the most recently returned event, covering the full
+    /// lexeme (including quotes/escapes for strings and keys). Read via
+    /// [`Self::last_span`] right after a `next_event`-style call.
+    last_span: Span,
+    /// Runtime container nesting limit set via [`Self::set_max_depth`].
+    /// `None` (the default) means depth is bounded only by the tokenizer's
+    /// compile-time bitstack width, as before.
+    max_depth: Option<usize>,
+    /// Current container nesting depth, incremented on `StartObject`/
+    /// `StartArray` and decremented on `EndObject`/`EndArray`.
+    current_depth: usize,
+    /// Set via [`Self::set_reject_escaped_keys`]: when `true`, a key
+    /// containing an escape sequence is rejected instead of being decoded
+    /// into the scratch buffer.
+    reject_escaped_keys: bool,
+    /// Set via [`Self::set_reject_bidi_controls`]: when `true`, a decoded
+    /// [`Event::String`]/[`Event::Key`] containing a bidirectional
+    /// text-flow-control codepoint is rejected.
+    reject_bidi_controls: bool,
+    /// Set via [`Self::set_surrogate_policy`], and applied to the active
+    /// [`UnicodeEscapeCollector`] at the start of every `\uXXXX` escape.
+    surrogate_policy: crate::escape_processor::SurrogatePolicy,
+    /// Set via [`Self::set_whitespace_events`]: when `true`, a run of
+    /// whitespace between tokens is surfaced as [`Event::Whitespace`]
+    /// instead of being silently skipped.
+    whitespace_events: bool,
+    /// Start offset of the whitespace run currently being accumulated,
+    /// when [`Self::whitespace_events`] is enabled. `None` when there's no
+    /// run in progress (including always, when the feature is off).
+    whitespace_run_start: Option<usize>,
+    /// Set via [`Self::set_recovery_mode`]: when `true`, a tokenizer or
+    /// content-extraction error surfaces as an [`Event::Error`] instead of
+    /// aborting the parse, and [`Self::next_event_impl_with_flags`]
+    /// resynchronizes immediately instead of returning the error from
+    /// `next_event`.
+    recovery_mode: bool,
+    /// Runtime limit on how many [`Event::Error`]s [`Self::set_recovery_mode`]
+    /// will emit for a single parse, set via
+    /// [`Self::set_max_recovery_errors`]. `None` (the default) emits one per
+    /// error [`Self::resynchronize`] finds, which is already bounded by the
+    /// input's length -- each call consumes at least one byte before
+    /// returning, so a finite document can never drive an unbounded number
+    /// of them -- but a caller feeding adversarial or near-entirely-garbage
+    /// input may still want a tighter, proportion-independent ceiling.
+    max_recovery_errors: Option<usize>,
+    /// Count of [`Event::Error`]s emitted so far this parse, checked against
+    /// [`Self::max_recovery_errors`] in [`Self::recover_from_error`].
+    recovery_error_count: usize,
+    /// A parallel bit-stack of the currently open containers' kinds (`true`
+    /// for object, `false` for array), one bit per [`Self::current_depth`]
+    /// level. Originally added to type the synthetic `Event::EndObject`/
+    /// `Event::EndArray` [`Self::next_event_impl_with_flags`] emits in
+    /// recovery mode for containers still open when input ends -- the
+    /// tokenizer's own equivalent stack isn't queryable from here, and is
+    /// discarded anyway when [`Self::resynchronize`] replaces it with a
+    /// fresh `Tokenizer`. [`Self::in_object`]/[`Self::in_array`] read its
+    /// `top()` directly, so it's now consulted outside recovery mode too.
+    container_kinds: T,
+    /// Set by [`Self::resynchronize`] when it replaces `self.tokenizer` with
+    /// a fresh one without feeding it any bytes of its own (the `,`/`}`/`]`
+    /// re-anchor cases). Cleared as soon as that tokenizer parses a real
+    /// byte. Consulted in the end-of-input branch of
+    /// [`Self::next_event_impl_with_flags`] to skip calling `finish()` on a
+    /// virgin tokenizer, which would otherwise misreport `EmptyStream`.
+    resynced: bool,
+    /// Line/column/byte-offset location of the next byte to be pulled from
+    /// the input (just past the one most recently pulled), tracked
+    /// incrementally in [`Self::advance_position`]
+    /// as bytes are consumed -- O(1) per byte, rather than rescanning the
+    /// input on demand. Read via [`Self::current_position`].
+    current_position: crate::Position,
 }
 
 impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
@@ -50,6 +125,25 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
             current_content_has_escapes: false,
             continuing_from_previous_chunk: false,
             partial_span_start_emitted: false,
+            span_start: 0,
+            last_span: Span { start: 0, end: 0 },
+            max_depth: None,
+            current_depth: 0,
+            reject_escaped_keys: false,
+            reject_bidi_controls: false,
+            surrogate_policy: crate::escape_processor::SurrogatePolicy::Strict,
+            whitespace_events: false,
+            whitespace_run_start: None,
+            recovery_mode: false,
+            max_recovery_errors: None,
+            recovery_error_count: 0,
+            container_kinds: T::default(),
+            resynced: false,
+            current_position: crate::Position {
+                byte_offset: 0,
+                line: 1,
+                column: 1,
+            },
         }
     }
 
@@ -65,9 +159,320 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
             current_content_has_escapes: false,
             continuing_from_previous_chunk: false,
             partial_span_start_emitted: false,
+            span_start: 0,
+            last_span: Span { start: 0, end: 0 },
+            max_depth: None,
+            current_depth: 0,
+            reject_escaped_keys: false,
+            reject_bidi_controls: false,
+            surrogate_policy: crate::escape_processor::SurrogatePolicy::Strict,
+            whitespace_events: false,
+            whitespace_run_start: None,
+            recovery_mode: false,
+            max_recovery_errors: None,
+            recovery_error_count: 0,
+            container_kinds: T::default(),
+            resynced: false,
+            current_position: crate::Position {
+                byte_offset: 0,
+                line: 1,
+                column: 1,
+            },
         }
     }
 
+    /// Byte-offset span of the event returned by the most recent
+    /// `next_event_impl`/`next_event_impl_with_flags` call.
+    pub fn last_span(&self) -> Span {
+        self.last_span
+    }
+
+    /// Line/column/byte-offset location of the next byte
+    /// [`Self::next_event_impl_with_flags`] will pull from the input.
+    /// Tracked incrementally as bytes are consumed, so this is O(1)
+    /// regardless of how much input has already been parsed.
+    pub fn current_position(&self) -> crate::Position {
+        self.current_position
+    }
+
+    /// Advances [`Self::current_position`] past one just-consumed raw
+    /// input byte: bumps the column, or -- on `\n` -- starts a new line.
+    /// Called once per byte pulled from the input, whether one at a time
+    /// or in bulk via [`ContentExtractor::consume_plain_content_run`], so
+    /// a byte is never counted twice even when it's later re-examined
+    /// during escape processing.
+    ///
+    /// `\r\n` isn't special-cased into a single break: the `\r` just bumps
+    /// the column like any other non-`\n` byte, and the `\n` right after it
+    /// resets to column 1 regardless -- so the position reported for the
+    /// byte that follows a CRLF pair is already exactly what treating it as
+    /// one break would produce. A lone `\r` (old classic-Mac line endings)
+    /// is the one case this doesn't special-case: it's counted as a plain
+    /// column-advancing byte rather than a line break.
+    fn advance_position(&mut self, byte: u8) {
+        self.current_position.byte_offset += 1;
+        if byte == b'\n' {
+            self.current_position.line += 1;
+            self.current_position.column = 1;
+        } else {
+            self.current_position.column += 1;
+        }
+    }
+
+    /// Sets a runtime limit on container nesting depth, checked on every
+    /// `StartObject`/`StartArray` in [`Self::next_event_impl`]. Exceeding it
+    /// returns [`ParseError::DepthLimitExceeded`] instead of running up
+    /// against the tokenizer's compile-time bitstack width.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = Some(max_depth);
+    }
+
+    /// Current container nesting depth: `0` at the document root, and
+    /// incremented/decremented alongside every `StartObject`/`StartArray`/
+    /// `EndObject`/`EndArray` this parser has produced so far. See
+    /// [`PullParser::depth`](crate::PullParser::depth).
+    pub fn depth(&self) -> usize {
+        self.current_depth
+    }
+
+    /// Nesting levels still available before the next `StartObject`/
+    /// `StartArray` would be rejected, or `None` if neither
+    /// [`Self::set_max_depth`] nor `container_kinds`' own
+    /// [`BitBucket::capacity_bits`](ujson::BitBucket::capacity_bits) impose
+    /// any real ceiling (e.g. a [`HeapBitStack`](crate::HeapBitStack)
+    /// bucket with no `set_max_depth` call). See
+    /// [`PullParser::remaining_depth`](crate::PullParser::remaining_depth).
+    pub fn remaining_depth(&self) -> Option<usize> {
+        let bucket_remaining = self
+            .container_kinds
+            .capacity_bits()
+            .map(|cap| cap.saturating_sub(self.current_depth));
+        let max_depth_remaining = self
+            .max_depth
+            .map(|max_depth| max_depth.saturating_sub(self.current_depth));
+        match (bucket_remaining, max_depth_remaining) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (Some(a), None) | (None, Some(a)) => Some(a),
+            (None, None) => None,
+        }
+    }
+
+    /// Whether the innermost currently-open container (if any) is an
+    /// object. `false` at the document root (`depth() == 0`) as well as
+    /// inside an array -- use [`Self::in_array`] to tell those two apart.
+    /// See [`PullParser::in_object`](crate::PullParser::in_object).
+    pub fn in_object(&self) -> bool {
+        self.current_depth > 0 && self.container_kinds.top()
+    }
+
+    /// Whether the innermost currently-open container (if any) is an
+    /// array. `false` at the document root as well as inside an object.
+    /// See [`PullParser::in_array`](crate::PullParser::in_array).
+    pub fn in_array(&self) -> bool {
+        self.current_depth > 0 && !self.container_kinds.top()
+    }
+
+    /// Updates the tracked container depth for a just-produced event,
+    /// rejecting a `StartObject`/`StartArray` that would exceed
+    /// [`Self::set_max_depth`].
+    fn track_depth(&mut self, event: &Event) -> Result<(), ParseError> {
+        match event {
+            Event::StartObject | Event::StartArray => {
+                // Mirrors the tokenizer's own `ParseContext::stack` (true for
+                // object, false for array) so a synthetic close can be
+                // correctly typed in the end-of-input branch of
+                // `Self::next_event_impl_with_flags` -- see the `container_kinds`
+                // field doc on why this can't just be read back off the
+                // tokenizer itself.
+                //
+                // `try_push` (not `push`): nesting deeper than `T::CAPACITY`
+                // without this check would silently shift the oldest bit out
+                // of this bucket instead of erroring, desynchronizing
+                // `container_kinds` from `current_depth`. In the ordinary
+                // (non-recovery) path `self.tokenizer`'s own same-sized `T`
+                // stack already rejects this first, as `ErrKind::MaxDepthReached`
+                // surfaced through `ParseError::TokenizerError` -- see
+                // `test_exceeding_native_bitstack_capacity_is_a_clean_error_without_set_max_depth`
+                // -- so this mainly guards the recovery-mode path, where
+                // `container_kinds` can be pushed to synthesize a typed close
+                // without a matching tokenizer push. Reusing `DepthLimitExceeded`
+                // rather than adding a new variant, since both report the same
+                // "a bucket ran out of room for another nesting level" condition.
+                if self
+                    .container_kinds
+                    .try_push(matches!(event, Event::StartObject), self.current_depth)
+                    .is_err()
+                {
+                    return Err(ParseError::DepthLimitExceeded {
+                        depth: self.current_depth + 1,
+                    });
+                }
+                self.current_depth += 1;
+                if let Some(max_depth) = self.max_depth {
+                    if self.current_depth > max_depth {
+                        return Err(ParseError::DepthLimitExceeded {
+                            depth: self.current_depth,
+                        });
+                    }
+                }
+            }
+            Event::EndObject | Event::EndArray => {
+                if self.current_depth > 0 {
+                    self.container_kinds.pop();
+                }
+                self.current_depth = self.current_depth.saturating_sub(1);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Enables (or disables) rejecting keys that contain an escape
+    /// sequence, so every [`Event::Key`] a caller sees is guaranteed to be a
+    /// zero-copy borrow of the source (`was_escaped()` would always be
+    /// `false`).
+    pub fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.reject_escaped_keys = reject;
+    }
+
+    /// Enables (or disables) rejecting a decoded [`Event::String`]/
+    /// [`Event::Key`] that contains a Unicode bidirectional text-flow-control
+    /// codepoint. See [`PullParser::set_reject_bidi_controls`](crate::PullParser::set_reject_bidi_controls).
+    pub fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.reject_bidi_controls = reject;
+    }
+
+    /// Sets how an unpaired surrogate in a `\uXXXX` escape is handled. See
+    /// [`PullParser::set_surrogate_policy`](crate::PullParser::set_surrogate_policy).
+    pub fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.surrogate_policy = policy;
+    }
+
+    /// Enables (or disables) surfacing inter-token whitespace as
+    /// [`Event::Whitespace`]. See [`PullParser::set_whitespace_events`](crate::PullParser::set_whitespace_events).
+    pub fn set_whitespace_events(&mut self, enabled: bool) {
+        self.whitespace_events = enabled;
+        self.whitespace_run_start = None;
+    }
+
+    /// Enables (or disables) error-recovery mode: once enabled, a tokenizer
+    /// error -- or an error from extracting a string/key/number's content
+    /// once its `End` is reached -- no longer aborts the parse. Instead,
+    /// [`Self::next_event_impl_with_flags`] immediately discards bytes
+    /// until it finds a `,`, a closing `}`/`]` that re-anchors at the
+    /// container the error occurred in, or the start of a new top-level
+    /// value, then returns an [`Event::Error`] carrying the offending
+    /// [`ParseError`] and the byte offset it occurred at -- parsing resumes
+    /// normally from the next call. Off by default: [`Self::new`]/
+    /// [`Self::new_chunked`] give the same "first error aborts the parse"
+    /// behavior as every prior release; this is meant for tooling that
+    /// lints or salvages partial JSON and wants every independent error
+    /// with its location instead.
+    ///
+    /// Resynchronization scans raw bytes rather than re-running the
+    /// tokenizer, so it is necessarily best-effort: it tracks string
+    /// literals (so a `{`/`}` inside a quoted value is never mistaken for
+    /// structure) and container nesting relative to where the error
+    /// occurred, but can't reconstruct the tokenizer's pre-error state
+    /// exactly, so deeply malformed input may need more than one recovery
+    /// to settle.
+    pub fn set_recovery_mode(&mut self, enabled: bool) {
+        self.recovery_mode = enabled;
+    }
+
+    /// Caps how many [`Event::Error`]s a single [`Self::set_recovery_mode`]
+    /// parse will emit before aborting instead of continuing to
+    /// resynchronize: once [`Self::recovery_error_count`] would exceed
+    /// `max`, [`Self::recover_from_error`] returns the error directly
+    /// rather than converting it to an [`Event::Error`]. No-op (the default)
+    /// for callers who just want every error in the document, which is
+    /// already the common case this crate's recovery mode is meant for.
+    pub fn set_max_recovery_errors(&mut self, max: usize) {
+        self.max_recovery_errors = Some(max);
+    }
+
+    /// Enables (or disables) the tokenizer's lenient syntax extensions: a
+    /// trailing comma before `]`/`}`, `'`-quoted strings/keys, `//`/`/* */`
+    /// comments, `_` digit separators, hex integers, and
+    /// `Infinity`/`-Infinity`/`NaN`. Off by default, matching the
+    /// tokenizer's own default -- every [`ParserCore`] is strict (RFC 8259)
+    /// until a caller opts into this.
+    pub fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.tokenizer.set_lenient_syntax(enabled);
+    }
+
+    /// Called for every byte fed to the tokenizer outside string/key
+    /// content, right after `parse_chunk` has run for it, when
+    /// [`Self::whitespace_events`] is enabled. Tracks the run of whitespace
+    /// currently being skipped between tokens, returning
+    /// `Some(extract_whitespace(...))` once it's known to be complete --
+    /// either because `byte` produced a real tokenizer event (so the run
+    /// ended right before it) or because `byte` is itself meaningful but
+    /// produces no event of its own (`:` and `,`, which the tokenizer
+    /// consumes silently).
+    ///
+    /// A byte can both end the previous token *and* start a new whitespace
+    /// run in the same call -- e.g. the space that terminates a bare
+    /// number is itself the first byte of the whitespace that follows it --
+    /// so this also opens a fresh run for `byte` when that's the case.
+    fn track_whitespace_byte<'a, P: ContentExtractor>(
+        &mut self,
+        provider: &'a mut P,
+        byte: u8,
+    ) -> Option<Result<Event<'a, 'a>, ParseError>> {
+        let is_whitespace = matches!(byte, b' ' | b'\t' | b'\n' | b'\r');
+        let pos = provider.current_position();
+        if is_whitespace && !have_events(&self.parser_state.evts) {
+            self.whitespace_run_start.get_or_insert(pos - 1);
+            return None;
+        }
+        let run_start = self.whitespace_run_start.take();
+        if is_whitespace {
+            // This byte both ends the run flushed below (if any) and is
+            // itself whitespace -- e.g. the space that terminates a bare
+            // number -- so it opens the next run.
+            self.whitespace_run_start = Some(pos - 1);
+        }
+        run_start.map(|start| {
+            let end = pos - 1;
+            self.last_span = Span { start, end };
+            provider.extract_whitespace(start, end)
+        })
+    }
+
+    /// Rejects `event` if it's an escaped [`Event::Key`] and
+    /// [`Self::set_reject_escaped_keys`] is enabled.
+    fn check_key_escape(&self, event: &Event) -> Result<(), ParseError> {
+        if self.reject_escaped_keys {
+            if let Event::Key(key) = event {
+                if key.was_escaped() {
+                    return Err(ParseError::EscapedKeyRejected);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects `event` if it's a [`Event::String`]/[`Event::Key`] containing
+    /// a bidi-control codepoint and [`Self::set_reject_bidi_controls`] is
+    /// enabled. Runs over the fully-decoded `&str`, so it sees the same
+    /// content regardless of whether it was a zero-copy borrow or assembled
+    /// in the scratch buffer.
+    fn check_bidi_controls(&self, event: &Event) -> Result<(), ParseError> {
+        if self.reject_bidi_controls {
+            let text = match event {
+                Event::String(s) | Event::Key(s) => Some(s.as_str()),
+                _ => None,
+            };
+            if let Some(text) = text {
+                if text.chars().any(crate::escape_processor::is_bidi_control) {
+                    return Err(ParseError::BidiControlInString);
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Core event processing implementation with byte accumulation for traditional parsers
     pub fn next_event_impl<'a, P, F>(
         &mut self,
@@ -96,13 +501,47 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
     {
         loop {
             while !have_events(&self.parser_state.evts) {
+                if !self.in_escape_sequence
+                    && matches!(provider.parser_state(), State::String(_) | State::Key(_))
+                {
+                    if let Some(run) = provider.consume_plain_content_run()? {
+                        if !run.is_empty() {
+                            for &b in run {
+                                self.advance_position(b);
+                            }
+                            clear_events(&mut self.parser_state.evts);
+                            let mut callback =
+                                create_tokenizer_callback(&mut self.parser_state.evts);
+                            self.tokenizer
+                                .parse_chunk(run, &mut callback)
+                                .map_err(ParseError::TokenizerError)?;
+                            // Plain string/key bytes never produce a tokenizer
+                            // event on their own, so `evts` is still empty here;
+                            // loop back around to handle the boundary byte
+                            // (quote, backslash, or control char) normally.
+                            continue;
+                        }
+                    }
+                }
+
                 if let Some(byte) = provider.get_next_byte()? {
+                    self.advance_position(byte);
                     {
                         clear_events(&mut self.parser_state.evts);
                         let mut callback = create_tokenizer_callback(&mut self.parser_state.evts);
-                        self.tokenizer
-                            .parse_chunk(&[byte], &mut callback)
-                            .map_err(ParseError::TokenizerError)?;
+                        if let Err(err) = self.tokenizer.parse_chunk(&[byte], &mut callback) {
+                            if self.recovery_mode {
+                                return self
+                                    .recover_from_error(provider, ParseError::TokenizerError(err));
+                            }
+                            return Err(ParseError::TokenizerError(err));
+                        }
+                    }
+
+                    if self.whitespace_events {
+                        if let Some(event) = self.track_whitespace_byte(provider, byte) {
+                            return event;
+                        }
                     }
 
                     // Handle byte accumulation if provided (for traditional parsers)
@@ -134,15 +573,37 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
                         // Otherwise, return EndOfData so they can handle chunk boundaries
                         return Err(ParseError::EndOfData);
                     } else {
-                        // For non-chunked parsers (SliceParser, StreamParser), finish the document
-                        {
+                        // For non-chunked parsers (SliceParser, StreamParser), finish the document.
+                        // Skip this when `self.resynced` is set: `Self::resynchronize` just
+                        // swapped in a fresh `Tokenizer` that hasn't parsed anything of its
+                        // own, and `finish()` on a virgin tokenizer reports `EmptyStream`
+                        // even though real input already preceded this point.
+                        if !self.resynced {
                             let mut finish_callback =
                                 create_tokenizer_callback(&mut self.parser_state.evts);
                             let _bytes_processed = self.tokenizer.finish(&mut finish_callback)?;
-                        } // Drop the callback to release the borrow
+                        }
 
-                        // If finish() generated events, process them. Otherwise, return EndDocument.
+                        // If finish() generated events, process them. Otherwise, return EndDocument
+                        // -- unless recovery left containers open with nothing left to close them;
+                        // synthesize the closes one at a time, same shape as a real `ObjectEnd`/
+                        // `ArrayEnd`, so `Self::track_depth` unwinds normally.
                         if !have_events(&self.parser_state.evts) {
+                            if self.recovery_mode && self.current_depth > 0 {
+                                self.parser_state.evts[0] = Some(if self.container_kinds.top() {
+                                    ujson::Event::ObjectEnd
+                                } else {
+                                    ujson::Event::ArrayEnd
+                                });
+                                continue;
+                            }
+                            if self.whitespace_events {
+                                if let Some(start) = self.whitespace_run_start.take() {
+                                    let end = provider.current_position();
+                                    self.last_span = Span { start, end };
+                                    return provider.extract_whitespace(start, end);
+                                }
+                            }
                             return Ok(Event::EndDocument);
                         }
                     }
@@ -157,33 +618,80 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
             // Try shared event processors first
             if let Some(result) = process_simple_events(&taken) {
                 match result {
-                    EventResult::Complete(event) => return Ok(event),
+                    EventResult::Complete(event) => {
+                        self.last_span =
+                            Self::simple_token_span(&taken, provider.current_position());
+                        self.track_depth(&event)?;
+                        return Ok(event);
+                    }
                     EventResult::ExtractString => {
+                        let end = provider.current_position();
                         // Check if we can emit a ContentSpan instead of delegating
-                        if let Some(content_span) = self
-                            .try_emit_content_span(ContentKind::String, provider.current_position())
+                        if let Some(content_span) =
+                            self.try_emit_content_span(ContentKind::String, end)
                         {
                             return Ok(content_span);
                         }
-                        return provider.validate_and_extract_string();
+                        self.last_span = Span {
+                            start: self.span_start,
+                            end,
+                        };
+                        return match provider.validate_and_extract_string().and_then(|event| {
+                            self.check_bidi_controls(&event)?;
+                            Ok(event)
+                        }) {
+                            Ok(event) => Ok(event),
+                            Err(err) if self.recovery_mode => {
+                                self.recover_from_error(provider, err)
+                            }
+                            Err(err) => Err(err),
+                        };
                     }
                     EventResult::ExtractKey => {
+                        let end = provider.current_position();
                         // Check if we can emit a ContentSpan instead of delegating
-                        if let Some(content_span) = self
-                            .try_emit_content_span(ContentKind::Key, provider.current_position())
+                        if let Some(content_span) =
+                            self.try_emit_content_span(ContentKind::Key, end)
                         {
                             return Ok(content_span);
                         }
-                        return provider.validate_and_extract_key();
+                        self.last_span = Span {
+                            start: self.span_start,
+                            end,
+                        };
+                        return match provider
+                            .validate_and_extract_key()
+                            .and_then(|event| {
+                                self.check_key_escape(&event)?;
+                                self.check_bidi_controls(&event)?;
+                                Ok(event)
+                            }) {
+                            Ok(event) => Ok(event),
+                            Err(err) if self.recovery_mode => {
+                                self.recover_from_error(provider, err)
+                            }
+                            Err(err) => Err(err),
+                        };
                     }
                     EventResult::ExtractNumber(from_container_end) => {
+                        let end = provider.current_position();
                         // Check if we can emit a ContentSpan instead of delegating
-                        if let Some(content_span) = self
-                            .try_emit_content_span(ContentKind::Number, provider.current_position())
+                        if let Some(content_span) =
+                            self.try_emit_content_span(ContentKind::Number, end)
                         {
                             return Ok(content_span);
                         }
-                        return provider.validate_and_extract_number(from_container_end);
+                        self.last_span = Span {
+                            start: self.span_start,
+                            end: ContentRange::end_position_excluding_delimiter(end),
+                        };
+                        return match provider.validate_and_extract_number(from_container_end) {
+                            Ok(event) => Ok(event),
+                            Err(err) if self.recovery_mode => {
+                                self.recover_from_error(provider, err)
+                            }
+                            Err(err) => Err(err),
+                        };
                     }
                     EventResult::Continue => continue,
                 }
@@ -193,8 +701,17 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
             if let Some(result) = self.track_content_spans(&taken, provider) {
                 match result {
                     EventResult::Complete(event) => return Ok(event),
-                    EventResult::ExtractString => return provider.validate_and_extract_string(),
-                    EventResult::ExtractKey => return provider.validate_and_extract_key(),
+                    EventResult::ExtractString => {
+                        let event = provider.validate_and_extract_string()?;
+                        self.check_bidi_controls(&event)?;
+                        return Ok(event);
+                    }
+                    EventResult::ExtractKey => {
+                        let event = provider.validate_and_extract_key()?;
+                        self.check_key_escape(&event)?;
+                        self.check_bidi_controls(&event)?;
+                        return Ok(event);
+                    }
                     EventResult::ExtractNumber(from_container_end) => {
                         return provider.validate_and_extract_number(from_container_end)
                     }
@@ -210,6 +727,9 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
                 }
                 ujson::Event::Begin(EventToken::UnicodeEscape) => {
                     self.in_escape_sequence = true;
+                    provider
+                        .unicode_escape_collector_mut()
+                        .set_surrogate_policy(self.surrogate_policy);
                     provider.process_unicode_escape_events(&taken)?;
                 }
                 ujson::Event::End(EventToken::UnicodeEscape) => {
@@ -253,6 +773,103 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
         }
     }
 
+    /// Drives the tokenizer through exactly one complete value -- scalar,
+    /// object, or array -- discarding it. Unlike [`PullParser::skip_value`]'s
+    /// default, event-based implementation, this never calls into any of
+    /// `ContentExtractor`'s extraction methods, so none of the costs those
+    /// pay (UTF-8 validation, unescaping, number parsing) are spent on
+    /// whatever gets skipped -- only tokenization runs.
+    /// [`Self::set_max_depth`] is still enforced, the same as it would be
+    /// for every nested `StartObject`/`StartArray` a normal `next_event`
+    /// call would otherwise return.
+    pub fn skip_value_impl<P: ContentExtractor>(&mut self, provider: &mut P) -> Result<(), ParseError> {
+        let mut depth: usize = 0;
+        loop {
+            while !have_events(&self.parser_state.evts) {
+                if let Some(byte) = provider.get_next_byte()? {
+                    self.advance_position(byte);
+                    clear_events(&mut self.parser_state.evts);
+                    let mut callback = create_tokenizer_callback(&mut self.parser_state.evts);
+                    self.tokenizer
+                        .parse_chunk(&[byte], &mut callback)
+                        .map_err(ParseError::TokenizerError)?;
+                } else {
+                    let mut finish_callback = create_tokenizer_callback(&mut self.parser_state.evts);
+                    let _bytes_processed = self.tokenizer.finish(&mut finish_callback)?;
+                    if !have_events(&self.parser_state.evts) {
+                        return Ok(());
+                    }
+                }
+            }
+
+            let Some(taken) = take_first_event(&mut self.parser_state.evts) else {
+                return Err(UnexpectedState::StateMismatch.into());
+            };
+
+            match taken {
+                ujson::Event::ObjectStart => {
+                    self.track_depth(&Event::StartObject)?;
+                    depth += 1;
+                }
+                ujson::Event::ArrayStart => {
+                    self.track_depth(&Event::StartArray)?;
+                    depth += 1;
+                }
+                ujson::Event::ObjectEnd => {
+                    self.track_depth(&Event::EndObject)?;
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                ujson::Event::ArrayEnd => {
+                    self.track_depth(&Event::EndArray)?;
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                ujson::Event::End(
+                    EventToken::True
+                    | EventToken::False
+                    | EventToken::Null
+                    | EventToken::String
+                    | EventToken::Key
+                    | EventToken::Number
+                    | EventToken::NumberAndArray
+                    | EventToken::NumberAndObject,
+                ) => {
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                _ => {
+                    // Begin tokens, escape sequence begin/end, etc. -- none
+                    // of these affect depth or signal a completed value.
+                }
+            }
+        }
+    }
+
+    /// Byte-offset span of a container/primitive token, given the position
+    /// just after it was fully consumed. These tokens have a fixed width,
+    /// so the span can be derived without any extra position tracking.
+    fn simple_token_span(token: &ujson::Event, end: usize) -> Span {
+        let width: usize = match token {
+            ujson::Event::ObjectStart
+            | ujson::Event::ObjectEnd
+            | ujson::Event::ArrayStart
+            | ujson::Event::ArrayEnd => 1,
+            ujson::Event::End(EventToken::True) | ujson::Event::End(EventToken::Null) => 4,
+            ujson::Event::End(EventToken::False) => 5,
+            _ => 0,
+        };
+        Span {
+            start: end.saturating_sub(width),
+            end,
+        }
+    }
+
     /// Try to emit a PartialContentSpanStart event when we hit chunk boundary while tracking content
     fn try_emit_partial_content_span_start(
         &mut self,
@@ -266,30 +883,41 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
         );
 
         // Only emit if we're currently tracking content and haven't already emitted a PartialContentSpanStart
-        if let Some(_kind) = self.current_content_kind {
-            if self.partial_span_start_emitted {
-                log::debug!("try_emit_partial_content_span_start: already emitted PartialContentSpanStart, skipping");
-                return None;
-            }
+        let kind = self.current_content_kind?;
 
-            // Skip PartialContentSpan logic if content already has escapes
-            // This ensures that escaped content continues using existing escape processing logic
-            if self.current_content_has_escapes {
-                log::debug!("try_emit_partial_content_span_start: content has escapes, using existing escape processing");
-                return None;
-            }
+        if self.partial_span_start_emitted {
+            log::debug!("try_emit_partial_content_span_start: already emitted PartialContentSpanStart, skipping");
+            return None;
+        }
 
-            // TEMPORARY: Disable PartialContentSpan logic for all content types for now
-            // The existing chunk boundary handling logic already works correctly
-            // TODO: PLACEHOLDER - Re-enable once Step 5 is complete and this can be properly tested
-            log::debug!(
-                "try_emit_partial_content_span_start: temporarily disabled for all content types"
-            );
+        // Skip PartialContentSpan logic if content already has escapes
+        // This ensures that escaped content continues using existing escape processing logic
+        if self.current_content_has_escapes {
+            log::debug!("try_emit_partial_content_span_start: content has escapes, using existing escape processing");
             return None;
-        } else {
-            log::debug!("try_emit_partial_content_span_start: not tracking content, no partial event needed");
-            None
         }
+
+        // Start excludes the opening quote for strings/keys; for numbers,
+        // `current_content_start` already points at the first digit itself.
+        let start = match kind {
+            ContentKind::String | ContentKind::Key => self.current_content_start + 1,
+            ContentKind::Number => self.current_content_start,
+        };
+
+        self.partial_span_start_emitted = true;
+        self.continuing_from_previous_chunk = true;
+
+        log::debug!(
+            "try_emit_partial_content_span_start: emitting PartialContentSpanStart {{ kind={:?}, start={} }}",
+            kind,
+            start
+        );
+
+        Some(Event::PartialContentSpanStart {
+            kind,
+            start,
+            has_escapes_in_this_chunk: false,
+        })
     }
 
     /// Try to emit a ContentSpan event if the current content is simple (no escapes, complete in chunk)
@@ -318,14 +946,12 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
                 "try_emit_content_span: content spans chunks, emitting PartialContentSpanEnd"
             );
 
-            // Calculate the final span from previous chunk boundary to current position
-            let _start = 0; // Start of current chunk
+            // Calculate the final span from the start of this chunk to current position
             let end = match kind {
                 ContentKind::String | ContentKind::Key => current_pos, // Current pos is at closing quote
                 ContentKind::Number => current_pos + 1,                // Include the last digit
             };
-
-            let _has_escapes = self.current_content_has_escapes;
+            let has_escapes_in_this_chunk = self.current_content_has_escapes;
 
             // Reset tracking since we're completing this content
             self.reset_content_tracking();
@@ -333,7 +959,7 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
             return Some(Event::PartialContentSpanEnd {
                 kind,
                 end,
-                has_escapes_in_this_chunk: self.current_content_has_escapes,
+                has_escapes_in_this_chunk,
             });
         }
 
@@ -344,28 +970,30 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
             return None;
         }
 
-        // Calculate the content span (excluding delimiters)
+        // Calculate the content span (excluding delimiters). Strings/keys
+        // skip the opening quote; `current_content_start` for a number
+        // already points at its first digit, so it needs no adjustment.
         let start = match kind {
-            ContentKind::String | ContentKind::Key => self.current_content_start + 1, // Skip opening quote
-            ContentKind::Number => self.current_content_start + 1, // Skip the position before first digit
+            ContentKind::String | ContentKind::Key => self.current_content_start + 1,
+            ContentKind::Number => self.current_content_start,
         };
         let end = match kind {
             ContentKind::String | ContentKind::Key => current_pos, // Current pos is at closing quote
             ContentKind::Number => current_pos + 1,                // Include the last digit
         };
-
-        // Use the actual escape detection result
         let has_escapes = self.current_content_has_escapes;
 
-        log::debug!("try_emit_content_span: would emit ContentSpan {{ kind={:?}, start={}, end={}, has_escapes={} }}", kind, start, end, has_escapes);
+        log::debug!("try_emit_content_span: emitting ContentSpan {{ kind={:?}, start={}, end={}, has_escapes={} }}", kind, start, end, has_escapes);
 
         // Reset tracking since we're handling this content
         self.reset_content_tracking();
 
-        // For Step 3: Just detect escapes but don't emit ContentSpan yet
-        // This will be enabled in Step 4 when we handle chunk boundaries properly
-        log::debug!("try_emit_content_span: detected escapes={}, but ContentSpan emission disabled for Step 3", has_escapes);
-        None
+        Some(Event::ContentSpan {
+            kind,
+            start,
+            end,
+            has_escapes,
+        })
     }
 
     /// Track ContentSpan state for Begin events and handle escape detection
@@ -392,6 +1020,8 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
                     pos,
                     self.handles_chunked_input
                 );
+                // The span includes the opening quote.
+                self.span_start = pos.saturating_sub(1);
                 if self.handles_chunked_input {
                     self.current_content_kind = Some(ContentKind::String);
                     self.current_content_start = pos;
@@ -408,6 +1038,8 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
                     pos,
                     self.handles_chunked_input
                 );
+                // The span includes the opening quote.
+                self.span_start = pos.saturating_sub(1);
                 if self.handles_chunked_input {
                     self.current_content_kind = Some(ContentKind::Key);
                     self.current_content_start = pos;
@@ -426,6 +1058,7 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
                     pos,
                     self.handles_chunked_input
                 );
+                self.span_start = ContentRange::number_start_from_current(pos);
                 if self.handles_chunked_input {
                     self.current_content_kind = Some(ContentKind::Number);
                     self.current_content_start = pos;
@@ -452,57 +1085,11 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
                 self.current_content_has_escapes = true;
                 None // Let the existing escape handling take over
             }
-            // Handle End events for content that might continue from previous chunks
-            ujson::Event::End(EventToken::String) => {
-                if self.handles_chunked_input && self.continuing_from_previous_chunk {
-                    // This is the end of content that was started in a previous chunk
-                    let pos = provider.current_position();
-                    log::debug!(
-                        "End String at pos={}, continuing_from_previous_chunk=true",
-                        pos
-                    );
-
-                    // Emit PartialContentSpanEnd event
-                    let partial_end_event = Event::PartialContentSpanEnd {
-                        kind: ContentKind::String,
-                        end: pos,
-                        has_escapes_in_this_chunk: self.current_content_has_escapes,
-                    };
-
-                    // Reset tracking state now that content is complete
-                    self.reset_content_tracking();
-
-                    Some(EventResult::Complete(partial_end_event))
-                } else {
-                    // Normal case - delegate to process_simple_events
-                    None
-                }
-            }
-            ujson::Event::End(EventToken::Key) => {
-                if self.handles_chunked_input && self.continuing_from_previous_chunk {
-                    // This is the end of content that was started in a previous chunk
-                    let pos = provider.current_position();
-                    log::debug!(
-                        "End Key at pos={}, continuing_from_previous_chunk=true",
-                        pos
-                    );
-
-                    // Emit PartialContentSpanEnd event
-                    let partial_end_event = Event::PartialContentSpanEnd {
-                        kind: ContentKind::Key,
-                        end: pos,
-                        has_escapes_in_this_chunk: self.current_content_has_escapes,
-                    };
-
-                    // Reset tracking state now that content is complete
-                    self.reset_content_tracking();
-
-                    Some(EventResult::Complete(partial_end_event))
-                } else {
-                    // Normal case - delegate to process_simple_events
-                    None
-                }
-            }
+            // End(String)/End(Key)/End(Number) are always intercepted by
+            // `process_simple_events` before this function is ever called
+            // (see `next_event_impl`'s `ExtractString`/`ExtractKey`/
+            // `ExtractNumber` arms, which call `try_emit_content_span`
+            // directly), so they never reach here.
             _ => {
                 // Delegate to provider for other Begin events
                 provider.process_begin_events(event)
@@ -523,6 +1110,122 @@ impl<T: ujson::BitBucket, C: ujson::DepthCounter> ParserCore<T, C> {
     pub fn reset_partial_span_start_flag(&mut self) {
         self.partial_span_start_emitted = false;
     }
+
+    /// Discards bytes -- after a tokenizer error in [`Self::set_recovery_mode`] --
+    /// until a structural delimiter re-anchors the parse: a `,` or a
+    /// `}`/`]` that closes back to the container depth the error occurred
+    /// in, or -- at depth 0 -- the start of what looks like a new
+    /// top-level value. Tracks nesting and (crudely) string literals as it
+    /// scans, so delimiter-like bytes inside a quoted string are never
+    /// mistaken for structure. Replaces the tokenizer with a fresh one and
+    /// clears escape/ContentSpan tracking up front, so the caller's next
+    /// `next_event_impl_with_flags` call resumes parsing cleanly -- either
+    /// from the byte this re-anchored on, or (at depth 0) from an event
+    /// already queued for a new value this already fed to the fresh
+    /// tokenizer. A `}`/`]` re-anchor queues the matching close event
+    /// instead of touching `current_depth` directly, so it still flows
+    /// through [`Self::track_depth`] like any other container close.
+    ///
+    /// Bounded by the input itself: running out of bytes without finding a
+    /// delimiter just ends the scan, leaving
+    /// [`Self::next_event_impl_with_flags`]'s end-of-input handling to
+    /// synthesize closes for any containers [`Self::current_depth`] still
+    /// counts as open, then return `EndOfData`/`EndDocument` as usual.
+    /// Converts `err` into [`Event::Error`] and resynchronizes, the shared
+    /// tail of every [`Self::set_recovery_mode`] call site -- a tokenizer
+    /// error on a single byte, or a content-extraction error once a
+    /// string/key/number's `End` has already been reached.
+    fn recover_from_error<P: ContentExtractor>(
+        &mut self,
+        provider: &mut P,
+        err: ParseError,
+    ) -> Result<Event<'static, 'static>, ParseError> {
+        if let Some(max) = self.max_recovery_errors {
+            if self.recovery_error_count >= max {
+                return Err(err);
+            }
+        }
+        self.recovery_error_count += 1;
+        let position = provider.current_position();
+        self.resynchronize(provider)?;
+        Ok(Event::Error { position, kind: err })
+    }
+
+    fn resynchronize<P: ContentExtractor>(&mut self, provider: &mut P) -> Result<(), ParseError> {
+        let target_depth = self.current_depth;
+        self.tokenizer = Tokenizer::new();
+        self.in_escape_sequence = false;
+        self.reset_content_tracking();
+        // Assume the fresh tokenizer above stays virgin until proven
+        // otherwise below -- see the field doc on why this matters.
+        self.resynced = true;
+        // The error may have happened mid string/key/number, leaving the
+        // provider's own `State` and any partially-collected `\uXXXX`
+        // escape behind it. Clear both so the fresh tokenizer above isn't
+        // paired with stale builder state once scanning resumes -- e.g. so
+        // `next_event_impl_with_flags`'s plain-content-run fast path
+        // doesn't mistake leftover `State::String`/`State::Key` for still
+        // being inside a string.
+        *provider.parser_state_mut() = State::None;
+        provider.unicode_escape_collector_mut().reset_all();
+
+        let mut skip_depth: usize = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        while let Some(byte) = provider.get_next_byte()? {
+            self.advance_position(byte);
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    in_string = false;
+                }
+                continue;
+            }
+            match byte {
+                b'"' => in_string = true,
+                b'{' | b'[' => skip_depth += 1,
+                b'}' | b']' if skip_depth == 0 => {
+                    // Closes the container the error occurred in. Queue the
+                    // matching close event rather than touching
+                    // `current_depth` directly, so the caller's next call
+                    // sees a real `Event::EndObject`/`Event::EndArray` go
+                    // through `Self::track_depth` like any other container
+                    // close, instead of the depth counter silently jumping.
+                    self.parser_state.evts[0] = Some(if byte == b'}' {
+                        ujson::Event::ObjectEnd
+                    } else {
+                        ujson::Event::ArrayEnd
+                    });
+                    self.parser_state.evts[1] = None;
+                    return Ok(());
+                }
+                b'}' | b']' => skip_depth -= 1,
+                b',' if skip_depth == 0 => return Ok(()),
+                _ if skip_depth == 0 && target_depth == 0 && !byte.is_ascii_whitespace() => {
+                    // Might be the start of a new top-level value -- try
+                    // feeding it to the fresh tokenizer rather than
+                    // discarding it outright.
+                    self.tokenizer = Tokenizer::new();
+                    clear_events(&mut self.parser_state.evts);
+                    let mut callback = create_tokenizer_callback(&mut self.parser_state.evts);
+                    if self.tokenizer.parse_chunk(&[byte], &mut callback).is_ok() {
+                        // Real content just went into this tokenizer --
+                        // `finish()` on it at end-of-input is meaningful
+                        // again.
+                        self.resynced = false;
+                        return Ok(());
+                    }
+                    // Still garbage at depth 0 -- keep scanning.
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T: ujson::BitBucket, C: ujson::DepthCounter> Default for ParserCore<T, C> {
@@ -541,6 +1244,17 @@ pub enum EscapeTiming {
 }
 
 /// Result of processing a tokenizer event
+///
+/// Note on raw/verbatim value capture: there's no `ExtractRaw` variant here.
+/// Unlike string/key/number extraction, a raw capture spans a whole value
+/// -- including, for an object or array, every nested container inside it
+/// -- so "delegate at `End`" doesn't fit this enum's one-event-at-a-time
+/// shape. Each parser instead tracks its own capture start offset and
+/// nesting depth (`RawCaptureState` in [`crate::push_parser`],
+/// `next_raw_value_span`'s scan in [`crate::slice_parser`]/
+/// [`crate::stream_parser`]) and emits [`Event::RawValue`] once the
+/// captured subtree closes, without ever routing through
+/// `next_event_impl`'s per-event loop.
 #[derive(Debug)]
 pub enum EventResult<'a, 'b> {
     /// Event processing is complete, return this event to the user
@@ -595,6 +1309,65 @@ pub trait ContentExtractor {
         finished: bool,
     ) -> Result<Event<'_, '_>, ParseError>;
 
+    /// Extract the verbatim source text spanning `[start_pos, end_pos)` as a
+    /// single [`Event::RawValue`], without decoding it. Used to capture a
+    /// complete scalar, object, or array in one step, once its span is
+    /// known (see [`ParserCore::last_span`]).
+    fn extract_raw(&mut self, start_pos: usize, end_pos: usize) -> Result<Event<'_, '_>, ParseError>;
+
+    /// Extract the verbatim source text spanning `[start_pos, end_pos)` as
+    /// an [`Event::Whitespace`], for [`ParserCore`]'s whitespace-events
+    /// mode. A whitespace run is always plain ASCII with no escapes, so
+    /// this just reuses [`Self::extract_raw`]'s span-to-text machinery.
+    fn extract_whitespace(&mut self, start_pos: usize, end_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+        match self.extract_raw(start_pos, end_pos)? {
+            Event::RawValue(text) => Ok(Event::Whitespace(text)),
+            _ => unreachable!("extract_raw only ever returns Event::RawValue"),
+        }
+    }
+
+    /// Returns the largest contiguous run of upcoming bytes, starting at
+    /// the current position, that are plain string/key content -- i.e.
+    /// nothing but `"`, `\`, or a control character ends the run. Only
+    /// called while [`Self::parser_state`] is `String`/`Key` outside an
+    /// escape sequence, so these bytes are guaranteed not to produce a
+    /// tokenizer event on their own; [`ParserCore::next_event_impl`] feeds
+    /// the whole run to the tokenizer in a single `parse_chunk` call
+    /// instead of one byte at a time, which is the dominant per-byte cost
+    /// for large string values. Implementations must also account for the
+    /// run in whatever byte-accumulation they'd otherwise do one byte at a
+    /// time (e.g. copy-on-escape scratch buffers).
+    ///
+    /// Returning `Ok(None)` -- the default -- just falls back to the
+    /// byte-at-a-time path, so this is always correct to leave
+    /// unimplemented, only slower.
+    fn consume_plain_content_run(&mut self) -> Result<Option<&[u8]>, ParseError> {
+        Ok(None)
+    }
+
+    /// Finalizes the Unicode escape collector at the closing `"` of a
+    /// string/key, handling a high surrogate that's still pending (e.g.
+    /// `"\uD801"` with nothing after it) per the active
+    /// [`SurrogatePolicy`](crate::escape_processor::SurrogatePolicy).
+    ///
+    /// The default implementation preserves every prior release's
+    /// behavior: unconditionally failing with
+    /// [`ParseError::UnpairedHighSurrogate`], regardless of policy.
+    /// Override this to call
+    /// [`UnicodeEscapeCollector::finish_string`] and append its
+    /// recovered bytes to the in-progress content instead, for a backend
+    /// whose content-accumulation strategy supports appending bytes that
+    /// didn't come from the source at this position.
+    fn finish_pending_unicode_escape(&mut self) -> Result<(), ParseError> {
+        if self
+            .unicode_escape_collector_mut()
+            .has_pending_high_surrogate()
+        {
+            return Err(ParseError::UnpairedHighSurrogate);
+        }
+        Ok(())
+    }
+
     /// Shared validation and extraction for string content
     fn validate_and_extract_string(&mut self) -> Result<Event<'_, '_>, ParseError> {
         let start_pos = match *self.parser_state() {
@@ -602,13 +1375,7 @@ pub trait ContentExtractor {
             _ => return Err(crate::shared::UnexpectedState::StateMismatch.into()),
         };
 
-        // Check for incomplete surrogate pairs before ending the string
-        if self
-            .unicode_escape_collector_mut()
-            .has_pending_high_surrogate()
-        {
-            return Err(ParseError::InvalidUnicodeCodepoint);
-        }
+        self.finish_pending_unicode_escape()?;
 
         *self.parser_state_mut() = State::None;
         self.extract_string_content(start_pos)
@@ -621,13 +1388,7 @@ pub trait ContentExtractor {
             _ => return Err(crate::shared::UnexpectedState::StateMismatch.into()),
         };
 
-        // Check for incomplete surrogate pairs before ending the key
-        if self
-            .unicode_escape_collector_mut()
-            .has_pending_high_surrogate()
-        {
-            return Err(ParseError::InvalidUnicodeCodepoint);
-        }
+        self.finish_pending_unicode_escape()?;
 
         *self.parser_state_mut() = State::None;
         self.extract_key_content(start_pos)
@@ -719,9 +1480,28 @@ pub trait ContentExtractor {
     }
 
     /// Process simple escape sequence events that have similar patterns between parsers
+    ///
+    /// Under a non-[`Strict`](crate::escape_processor::SurrogatePolicy::Strict)
+    /// policy this still discards a pending high surrogate silently rather
+    /// than flushing it as U+FFFD/WTF-8 like
+    /// [`Self::process_unicode_escape_events`] and
+    /// [`Self::finish_pending_unicode_escape`] both do for the other two
+    /// ways a high surrogate can go unpaired (interrupted by another `\u`
+    /// escape, or by the string ending). Doing the same here would need a
+    /// "flush these extra bytes into content, ending at an earlier
+    /// position" primitive that the per-backend `handle_simple_escape_char`
+    /// implementations don't have: [`SliceParser`](crate::SliceParser)'s
+    /// copy-on-escape backend assumes one `handle_simple_escape_char` call
+    /// corresponds to exactly one two-byte `\X` escape ending at the
+    /// *current* position, so reusing it to also flush a multi-byte
+    /// replacement for an unrelated, already-consumed `\uXXXX` escape would
+    /// corrupt the copied span. Under
+    /// [`Strict`](crate::escape_processor::SurrogatePolicy::Strict) this has
+    /// no externally visible effect either way: the surrogate was never
+    /// going to be accepted, and the subsequent low surrogate (if any) still
+    /// surfaces its own `UnpairedLowSurrogate`/`UnpairedHighSurrogate` error.
     fn process_simple_escape_event(&mut self, escape_token: &EventToken) -> Result<(), ParseError> {
-        // Clear any pending high surrogate state when we encounter a simple escape
-        // This ensures that interrupted surrogate pairs (like \uD801\n\uDC37) are properly rejected
+        // Clear any pending high surrogate state when we encounter a simple escape.
         self.unicode_escape_collector_mut().reset_all();
 
         // Use unified escape token processing from EscapeProcessor
@@ -939,6 +1719,10 @@ mod tests {
             unimplemented!("Mock doesn't need extraction")
         }
 
+        fn extract_raw(&mut self, _start_pos: usize, _end_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+            unimplemented!("Mock doesn't need extraction")
+        }
+
         fn parser_state(&self) -> &State {
             &self.state
         }
@@ -0,0 +1,541 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A no_std JSON serializer -- the write-side counterpart to this crate's
+//! `Event`-producing parsers.
+//!
+//! [`EventWriter`] accepts the same [`Event`] values a [`PullParser`](crate::PullParser)
+//! or [`PushParser`](crate::PushParser) produces and writes well-formed JSON
+//! bytes into a caller-supplied [`Write`] sink, inserting commas and colons
+//! by tracking container context the same way the tokenizer tracks nesting
+//! (one bit per level, pushed/popped on `Start*`/`End*`). This makes it
+//! straightforward to build a transform pipeline that filters or rewrites an
+//! event stream between a parser and an `EventWriter`.
+//!
+//! This is a serializer, not a validator: it assumes the `Event` sequence it
+//! is fed is already well-formed (as any of this crate's parsers would
+//! produce), the same way [`EscapeWriter`](crate::EscapeWriter) assumes its
+//! input is already valid UTF-8.
+//!
+//! Output defaults to compact JSON with non-ASCII passed through as raw
+//! UTF-8; [`EventWriter::set_pretty`] switches to an indented, one-item-
+//! per-line layout and [`EventWriter::set_strict_ascii`] re-encodes
+//! non-ASCII characters as `\uXXXX` escapes instead.
+
+use crate::{BitBucket, BitStackConfig, DefaultConfig, DepthCounter, EscapeWriter, Event};
+
+/// A sink that [`EventWriter`] writes serialized JSON bytes into -- the
+/// write-side counterpart to [`Reader`](crate::Reader).
+pub trait Write {
+    /// The error type returned by write operations.
+    type Error;
+
+    /// Writes `bytes` to the sink in full, or fails without a partial-write
+    /// contract (callers should treat any error as having possibly written
+    /// some prefix of `bytes`).
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// Returned by [`SliceWriter::write_bytes`] when the backing slice has no
+/// room left for the bytes being written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliceWriterFull;
+
+/// A [`Write`] sink backed by a fixed `&mut [u8]`, for callers that want to
+/// serialize into a stack buffer without any allocation.
+pub struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Creates a writer over `buf`, starting empty.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// The bytes written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl Write for SliceWriter<'_> {
+    type Error = SliceWriterFull;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        let end = self.len.checked_add(bytes.len()).ok_or(SliceWriterFull)?;
+        let dest = self.buf.get_mut(self.len..end).ok_or(SliceWriterFull)?;
+        dest.copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Errors from [`EventWriter::write_event`].
+///
+/// Only `Debug`/`PartialEq`, matching [`crate::ParseError`] itself -- once
+/// [`Self::Parse`] can carry one, this can't be any more `Clone`/`Copy`/`Eq`
+/// than that is.
+#[derive(Debug, PartialEq)]
+pub enum EventWriterError<E> {
+    /// The underlying [`Write`] sink returned an error.
+    Sink(E),
+    /// Nesting depth exceeded what this writer's `BitStackConfig` can track.
+    /// See [`crate::PullParser::set_max_depth`] for the parser-side
+    /// counterpart.
+    MaxDepthExceeded,
+    /// A parse error from whatever produced the `Event`s this writer was
+    /// handed, when it's driving an `EventWriter` directly as a
+    /// [`PushParser`](crate::PushParser) handler. Lets
+    /// [`PushParser::write`](crate::PushParser::write)'s `E: From<ParseError>`
+    /// bound be satisfied without a separate error type wrapping this one.
+    Parse(crate::ParseError),
+}
+
+impl<E> From<crate::ParseError> for EventWriterError<E> {
+    fn from(err: crate::ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// Serializes a stream of [`Event`]s into JSON bytes.
+///
+/// Generic over the same [`BitStackConfig`] the parsers use, so the nesting
+/// depth this writer can track matches whatever was used to produce the
+/// `Event`s in the first place (e.g. pick the same `C` as the `PullParser`
+/// at the other end of a transform pipeline).
+pub struct EventWriter<W, C: BitStackConfig = DefaultConfig> {
+    sink: W,
+    /// One bit per open level: `true` if that level is an object, `false` if
+    /// an array.
+    kind_stack: C::Bucket,
+    /// One bit per open level: `true` once that level has written its first
+    /// child (key or array element), so the next one needs a leading comma.
+    item_stack: C::Bucket,
+    depth: C::Counter,
+    /// Set by [`Self::write_key`], consumed by the very next value written:
+    /// a value directly following a key needs its colon but never a comma
+    /// (the key already accounted for one).
+    value_follows_key: bool,
+    /// Set via [`Self::set_pretty`]: `None` writes today's compact JSON
+    /// (no whitespace beyond what a string/number token already needs);
+    /// `Some(n)` instead breaks every key/array element onto its own line,
+    /// indented by `n` spaces per nesting level, and puts a space after
+    /// each key's colon -- the same compact-vs-pretty split serde_json's
+    /// `Formatter` trait draws, collapsed into a plain field since this
+    /// crate's other parser/writer options (e.g. [`crate::PullParser::set_whitespace_events`])
+    /// are already plain setters rather than a pluggable-trait knob.
+    pretty_indent: Option<u8>,
+    /// Set via [`Self::set_strict_ascii`]: re-encodes every non-ASCII
+    /// character written by [`Self::write_event`] as a `\uXXXX` escape
+    /// instead of passing its UTF-8 bytes through -- see
+    /// [`EscapeWriter::new_strict_ascii`].
+    strict_ascii: bool,
+}
+
+impl<W: Write, C: BitStackConfig> EventWriter<W, C> {
+    /// Creates a new `EventWriter` over `sink`, writing compact JSON with
+    /// non-ASCII UTF-8 passed through unchanged.
+    pub fn new(sink: W) -> Self {
+        Self {
+            sink,
+            kind_stack: C::Bucket::default(),
+            item_stack: C::Bucket::default(),
+            depth: C::Counter::zero(),
+            value_follows_key: false,
+            pretty_indent: None,
+            strict_ascii: false,
+        }
+    }
+
+    /// Enables (or disables) pretty-printing: `Some(spaces_per_level)`
+    /// breaks every key/array element onto its own indented line and adds
+    /// a space after each colon; `None` (the default) writes today's
+    /// compact JSON with no added whitespace.
+    pub fn set_pretty(&mut self, spaces_per_level: Option<u8>) {
+        self.pretty_indent = spaces_per_level;
+    }
+
+    /// Enables (or disables) strict-ASCII output: when enabled, every
+    /// non-ASCII character in a written [`Event::Key`]/[`Event::String`]
+    /// is re-encoded as a `\uXXXX` escape (a surrogate pair above the
+    /// Basic Multilingual Plane) instead of being copied through as raw
+    /// UTF-8 -- see [`EscapeWriter::new_strict_ascii`]. Off by default,
+    /// matching [`EscapeWriter::new`]'s passthrough.
+    pub fn set_strict_ascii(&mut self, enabled: bool) {
+        self.strict_ascii = enabled;
+    }
+
+    /// Consumes the writer, returning the sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+
+    fn in_object(&self) -> bool {
+        !self.depth.is_zero() && self.kind_stack.top()
+    }
+
+    /// Checked before any bracket byte is written for a new `Start*`, so a
+    /// depth limit is reported without emitting a dangling, unclosed bracket.
+    fn check_depth_capacity(&self) -> Result<(), EventWriterError<W::Error>> {
+        let used = self.depth.as_usize();
+        if used >= C::Bucket::CAPACITY || self.depth.increment().1 {
+            return Err(EventWriterError::MaxDepthExceeded);
+        }
+        Ok(())
+    }
+
+    /// Enters a new nesting level. Only call after [`Self::check_depth_capacity`]
+    /// has confirmed there's room.
+    fn push_level(&mut self, is_object: bool) {
+        let (new_depth, _overflow) = self.depth.increment();
+        self.kind_stack.push(is_object);
+        self.item_stack.push(false);
+        self.depth = new_depth;
+    }
+
+    /// `true` once the level about to be popped has written at least one
+    /// child -- i.e. the container being closed isn't empty. Checked before
+    /// [`Self::pop_level`] clears `item_stack`, so [`Self::write_event`]'s
+    /// `EndObject`/`EndArray` arms know whether to indent before the
+    /// closing bracket in pretty mode (an empty `{}`/`[]` never breaks
+    /// onto its own line).
+    fn closing_level_has_children(&self) -> bool {
+        !self.depth.is_zero() && self.item_stack.top()
+    }
+
+    fn pop_level(&mut self) {
+        self.kind_stack.pop();
+        self.item_stack.pop();
+        let (new_depth, _underflow) = self.depth.decrement();
+        self.depth = new_depth;
+    }
+
+    /// Writes a newline followed by `self.pretty_indent`-scaled spaces for
+    /// the current depth. No-op when pretty-printing is off.
+    fn write_indent(&mut self) -> Result<(), EventWriterError<W::Error>> {
+        const SPACES: [u8; 16] = [b' '; 16];
+        let Some(per_level) = self.pretty_indent else {
+            return Ok(());
+        };
+        self.write_raw(b"\n")?;
+        let mut remaining = self.depth.as_usize() * per_level as usize;
+        while remaining > 0 {
+            let n = remaining.min(SPACES.len());
+            self.write_raw(&SPACES[..n])?;
+            remaining -= n;
+        }
+        Ok(())
+    }
+
+    /// Writes whatever separator (`,`, or nothing) must precede a new child
+    /// at the current level, marks that level as having a child now, and
+    /// -- in pretty mode -- breaks onto a fresh indented line.
+    fn write_item_separator(&mut self) -> Result<(), EventWriterError<W::Error>> {
+        if !self.depth.is_zero() {
+            if self.item_stack.top() {
+                self.write_raw(b",")?;
+            }
+            self.item_stack.pop();
+            self.item_stack.push(true);
+            self.write_indent()?;
+        }
+        Ok(())
+    }
+
+    /// Writes whatever must precede a value (a scalar, or `Start*`): nothing,
+    /// if it directly follows a key; otherwise the same item separator a key
+    /// or bare array element would need.
+    fn write_value_prefix(&mut self) -> Result<(), EventWriterError<W::Error>> {
+        if self.value_follows_key {
+            self.value_follows_key = false;
+            return Ok(());
+        }
+        self.write_item_separator()
+    }
+
+    fn write_raw(&mut self, bytes: &[u8]) -> Result<(), EventWriterError<W::Error>> {
+        self.sink.write_bytes(bytes).map_err(EventWriterError::Sink)
+    }
+
+    fn write_escaped_str(&mut self, s: &str) -> Result<(), EventWriterError<W::Error>> {
+        self.write_raw(b"\"")?;
+        let escaped = if self.strict_ascii {
+            EscapeWriter::new_strict_ascii(s.as_bytes())
+        } else {
+            EscapeWriter::from(s)
+        };
+        for byte in escaped {
+            self.write_raw(&[byte])?;
+        }
+        self.write_raw(b"\"")
+    }
+
+    /// Writes a single [`Event`], inserting whatever comma/colon the current
+    /// container context calls for.
+    pub fn write_event(&mut self, event: Event<'_, '_>) -> Result<(), EventWriterError<W::Error>> {
+        match event {
+            Event::StartDocument | Event::EndDocument => Ok(()),
+            Event::StartObject => {
+                self.write_value_prefix()?;
+                self.check_depth_capacity()?;
+                self.write_raw(b"{")?;
+                self.push_level(true);
+                Ok(())
+            }
+            Event::StartArray => {
+                self.write_value_prefix()?;
+                self.check_depth_capacity()?;
+                self.write_raw(b"[")?;
+                self.push_level(false);
+                Ok(())
+            }
+            Event::EndObject => {
+                let had_children = self.closing_level_has_children();
+                self.pop_level();
+                if had_children {
+                    self.write_indent()?;
+                }
+                self.write_raw(b"}")
+            }
+            Event::EndArray => {
+                let had_children = self.closing_level_has_children();
+                self.pop_level();
+                if had_children {
+                    self.write_indent()?;
+                }
+                self.write_raw(b"]")
+            }
+            Event::Key(key) => {
+                debug_assert!(
+                    self.in_object(),
+                    "EventWriter::write_event got a Key outside an object"
+                );
+                self.write_item_separator()?;
+                self.write_escaped_str(key.as_str())?;
+                if self.pretty_indent.is_some() {
+                    self.write_raw(b": ")?;
+                } else {
+                    self.write_raw(b":")?;
+                }
+                self.value_follows_key = true;
+                Ok(())
+            }
+            Event::String(s) => {
+                self.write_value_prefix()?;
+                self.write_escaped_str(s.as_str())
+            }
+            Event::Number(n) => {
+                self.write_value_prefix()?;
+                self.write_raw(n.as_str().as_bytes())
+            }
+            Event::Bool(true) => {
+                self.write_value_prefix()?;
+                self.write_raw(b"true")
+            }
+            Event::Bool(false) => {
+                self.write_value_prefix()?;
+                self.write_raw(b"false")
+            }
+            Event::Null => {
+                self.write_value_prefix()?;
+                self.write_raw(b"null")
+            }
+            Event::RawValue(s) => {
+                self.write_value_prefix()?;
+                self.write_raw(s.as_str().as_bytes())
+            }
+            other => {
+                debug_assert!(
+                    false,
+                    "EventWriter only handles decoded events, got {other:?}"
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'input, 'scratch, W: Write, C: BitStackConfig>
+    crate::PushParserHandler<'input, 'scratch, EventWriterError<W::Error>> for EventWriter<W, C>
+{
+    /// Re-serializes each event as it arrives, so an `EventWriter` can sit
+    /// directly behind a [`PushParser`](crate::PushParser) for a
+    /// `parse -> serialize -> parse` round-trip pipeline instead of only a
+    /// pull parser's `next_event` loop calling [`Self::write_event`] by hand.
+    fn handle_event(&mut self, event: Event<'input, 'scratch>) -> Result<(), EventWriterError<W::Error>> {
+        self.write_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JsonNumber, SliceParser, String as JsonString};
+
+    fn written(events: std::vec::Vec<Event<'_, '_>>) -> std::string::String {
+        let mut buf = [0u8; 512];
+        let mut writer = EventWriter::<_, DefaultConfig>::new(SliceWriter::new(&mut buf));
+        for event in events {
+            writer.write_event(event).unwrap();
+        }
+        std::string::String::from_utf8(writer.into_inner().written().to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_writes_flat_object() {
+        let out = written(std::vec![
+            Event::StartObject,
+            Event::Key(JsonString::Borrowed("a")),
+            Event::Number(JsonNumber::from_slice(b"1").unwrap()),
+            Event::Key(JsonString::Borrowed("b")),
+            Event::Bool(true),
+            Event::EndObject,
+        ]);
+        assert_eq!(out, r#"{"a":1,"b":true}"#);
+    }
+
+    #[test]
+    fn test_writes_flat_array() {
+        let out = written(std::vec![
+            Event::StartArray,
+            Event::Number(JsonNumber::from_slice(b"1").unwrap()),
+            Event::Number(JsonNumber::from_slice(b"2").unwrap()),
+            Event::Null,
+            Event::EndArray,
+        ]);
+        assert_eq!(out, "[1,2,null]");
+    }
+
+    #[test]
+    fn test_writes_nested_object_in_array() {
+        let out = written(std::vec![
+            Event::StartArray,
+            Event::StartObject,
+            Event::Key(JsonString::Borrowed("x")),
+            Event::Number(JsonNumber::from_slice(b"1").unwrap()),
+            Event::EndObject,
+            Event::StartObject,
+            Event::Key(JsonString::Borrowed("x")),
+            Event::Number(JsonNumber::from_slice(b"2").unwrap()),
+            Event::EndObject,
+            Event::EndArray,
+        ]);
+        assert_eq!(out, r#"[{"x":1},{"x":2}]"#);
+    }
+
+    #[test]
+    fn test_escapes_strings_on_output() {
+        let out = written(std::vec![Event::String(JsonString::Borrowed("line1\nline2\t\"q\""))]);
+        assert_eq!(out, r#""line1\nline2\t\"q\"""#);
+    }
+
+    #[test]
+    fn test_writes_raw_value_verbatim_unquoted() {
+        // RawValue carries the exact source text of a captured container, so
+        // it's written through as-is rather than quoted/escaped like a string.
+        let out = written(std::vec![
+            Event::StartArray,
+            Event::RawValue(JsonString::Borrowed(r#"{"nested":true}"#)),
+            Event::EndArray,
+        ]);
+        assert_eq!(out, r#"[{"nested":true}]"#);
+    }
+
+    #[test]
+    fn test_round_trips_through_parser_and_writer() {
+        let json = r#"{"a":[1,2.5,"x\n"],"b":null,"c":false}"#;
+        let mut parser = SliceParser::new(json);
+        let mut buf = [0u8; 512];
+        let mut writer = EventWriter::<_, DefaultConfig>::new(SliceWriter::new(&mut buf));
+        loop {
+            match parser.next_event().unwrap() {
+                Event::EndDocument => break,
+                event => writer.write_event(event).unwrap(),
+            }
+        }
+        let out = core::str::from_utf8(writer.into_inner().written()).unwrap();
+        assert_eq!(out, json);
+    }
+
+    #[test]
+    fn test_round_trips_through_push_parser_and_writer() {
+        let json = br#"{"a":[1,2.5,"x\n"],"b":null,"c":false}"#;
+        let mut scratch = [0u8; 256];
+        let mut out_buf = [0u8; 512];
+        let writer = EventWriter::<_, DefaultConfig>::new(SliceWriter::new(&mut out_buf));
+        let mut parser = crate::PushParser::<_, DefaultConfig>::new(writer, &mut scratch);
+        parser
+            .write::<EventWriterError<SliceWriterFull>>(json)
+            .unwrap();
+        let writer = parser.finish::<EventWriterError<SliceWriterFull>>().unwrap();
+        let out = core::str::from_utf8(writer.into_inner().written()).unwrap();
+        assert_eq!(out, core::str::from_utf8(json).unwrap());
+    }
+
+    #[test]
+    fn test_slice_writer_full_reports_error() {
+        let mut buf = [0u8; 1];
+        let mut writer = EventWriter::<_, DefaultConfig>::new(SliceWriter::new(&mut buf));
+        assert_eq!(
+            writer.write_event(Event::StartObject),
+            Ok(())
+        );
+        assert_eq!(
+            writer.write_event(Event::EndObject),
+            Err(EventWriterError::Sink(SliceWriterFull))
+        );
+    }
+
+    #[test]
+    fn test_pretty_indents_nested_object_and_array() {
+        let mut buf = [0u8; 512];
+        let mut writer = EventWriter::<_, DefaultConfig>::new(SliceWriter::new(&mut buf));
+        writer.set_pretty(Some(2));
+        for event in [
+            Event::StartObject,
+            Event::Key(JsonString::Borrowed("a")),
+            Event::StartArray,
+            Event::Number(JsonNumber::from_slice(b"1").unwrap()),
+            Event::Number(JsonNumber::from_slice(b"2").unwrap()),
+            Event::EndArray,
+            Event::Key(JsonString::Borrowed("b")),
+            Event::StartObject,
+            Event::EndObject,
+            Event::EndObject,
+        ] {
+            writer.write_event(event).unwrap();
+        }
+        let out = core::str::from_utf8(writer.into_inner().written()).unwrap();
+        assert_eq!(
+            out,
+            "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": {}\n}"
+        );
+    }
+
+    #[test]
+    fn test_strict_ascii_escapes_non_ascii_string() {
+        let mut buf = [0u8; 64];
+        let mut writer = EventWriter::<_, DefaultConfig>::new(SliceWriter::new(&mut buf));
+        writer.set_strict_ascii(true);
+        writer
+            .write_event(Event::String(JsonString::Borrowed("caf\u{e9}")))
+            .unwrap();
+        let out = core::str::from_utf8(writer.into_inner().written()).unwrap();
+        assert_eq!(out, "\"caf\\u00E9\"");
+    }
+
+    #[test]
+    fn test_max_depth_exceeded_for_array_config() {
+        // DefaultConfig's bucket is a u32, so 32 levels deep is the limit.
+        let mut buf = [0u8; 256];
+        let mut writer = EventWriter::<_, DefaultConfig>::new(SliceWriter::new(&mut buf));
+        for _ in 0..32 {
+            writer.write_event(Event::StartArray).unwrap();
+        }
+        assert_eq!(
+            writer.write_event(Event::StartArray),
+            Err(EventWriterError::MaxDepthExceeded)
+        );
+    }
+}
@@ -0,0 +1,399 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! An iterator-style push parser for input delivered in arbitrary chunks
+//! (UART framing, network reads), built on top of [`PushParser`].
+//!
+//! [`PushParser`]'s handler callback is zero-copy: events borrow from the
+//! chunk just passed to `write()` or from the scratch buffer. [`FeedParser`]
+//! trades that away for a plain iterator API, copying each event's content
+//! into an owned [`FeedEvent`] as it's produced so it can outlive the
+//! `feed()` call that created it. Prefer [`PushParser`] directly when
+//! zero-copy matters more than this shape.
+//!
+//! Resumption across chunk boundaries -- including a `\uD83D` high surrogate
+//! split from its trailing `\uDE00` low surrogate by a chunk edge -- is
+//! handled by the same [`PushParser`]/`PushContentBuilder` machinery that
+//! backs `write()`; a token that spans a boundary is always emitted from the
+//! scratch buffer, never borrowed.
+
+extern crate alloc;
+use alloc::string::String as OwnedString;
+use alloc::vec::Vec;
+
+use crate::push_content_builder::PushParserHandler;
+use crate::push_parser::{PushParseError, PushParser};
+use crate::{BitStackConfig, DefaultConfig, Event, ParseError};
+
+/// An owned copy of an [`Event`], so it can be yielded from a [`FeedIter`]
+/// after the `feed()` call that produced it returns.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedEvent {
+    /// The start of a document.
+    StartDocument,
+    /// The start of an object (e.g., `{`).
+    StartObject,
+    /// The end of an object (e.g., `}`).
+    EndObject,
+    /// The start of an array (e.g., `[`).
+    StartArray,
+    /// The end of an array (e.g., `]`).
+    EndArray,
+    /// An object key.
+    Key(OwnedString),
+    /// A string value, already unescaped.
+    String(OwnedString),
+    /// The exact source text of a number. Re-parse with
+    /// [`crate::JsonNumber::from_slice`] for the decoded value.
+    Number(OwnedString),
+    /// A boolean value.
+    Bool(bool),
+    /// A null value.
+    Null,
+    /// End of the document.
+    EndDocument,
+}
+
+impl From<Event<'_, '_>> for FeedEvent {
+    fn from(event: Event<'_, '_>) -> Self {
+        match event {
+            Event::StartDocument => FeedEvent::StartDocument,
+            Event::StartObject => FeedEvent::StartObject,
+            Event::EndObject => FeedEvent::EndObject,
+            Event::StartArray => FeedEvent::StartArray,
+            Event::EndArray => FeedEvent::EndArray,
+            Event::Key(s) => FeedEvent::Key(OwnedString::from(s.as_str())),
+            Event::String(s) => FeedEvent::String(OwnedString::from(s.as_str())),
+            Event::Number(n) => FeedEvent::Number(OwnedString::from(n.as_ref())),
+            Event::Bool(b) => FeedEvent::Bool(b),
+            Event::Null => FeedEvent::Null,
+            Event::EndDocument => FeedEvent::EndDocument,
+            other => unreachable!(
+                "a PushParserHandler only ever receives decoded events, got {:?}",
+                other
+            ),
+        }
+    }
+}
+
+/// An iterator over the [`FeedEvent`]s produced by a single [`FeedParser::feed`] call.
+///
+/// The iterator ending (`None`) only means this chunk's events are
+/// exhausted, not that the document is complete: feed the next chunk, or
+/// check for [`FeedEvent::EndDocument`] to know parsing has finished.
+pub struct FeedIter {
+    events: alloc::vec::IntoIter<FeedEvent>,
+}
+
+impl Iterator for FeedIter {
+    type Item = FeedEvent;
+
+    fn next(&mut self) -> Option<FeedEvent> {
+        self.events.next()
+    }
+}
+
+/// Collects events into an owned queue instead of acting on them directly.
+struct EventQueue {
+    events: Vec<FeedEvent>,
+}
+
+impl<'input, 'scratch, E> PushParserHandler<'input, 'scratch, E> for EventQueue {
+    fn handle_event(&mut self, event: Event<'input, 'scratch>) -> Result<(), E> {
+        self.events.push(event.into());
+        Ok(())
+    }
+}
+
+/// A push parser for chunked/streamed input that yields events through a
+/// plain iterator rather than a handler callback. See the [module-level
+/// docs](self) for the zero-copy trade-off this makes.
+pub struct FeedParser<'input, 'scratch, C: BitStackConfig = DefaultConfig> {
+    inner: PushParser<'input, 'scratch, EventQueue, C>,
+}
+
+impl<'input, 'scratch, C: BitStackConfig> FeedParser<'input, 'scratch, C> {
+    /// Creates a new `FeedParser`. Use e.g. `FeedParser::<BitStackStruct<u64, u16>>::new(buffer)`
+    /// to pick a non-default `BitStackConfig` for deeper nesting.
+    pub fn new(buffer: &'scratch mut [u8]) -> Self {
+        Self {
+            inner: PushParser::new(EventQueue { events: Vec::new() }, buffer),
+        }
+    }
+
+    /// Creates a new `FeedParser` that accepts a sequence of whitespace- or
+    /// newline-separated top-level JSON values (NDJSON-style), instead of
+    /// exactly one. A fresh [`FeedEvent::StartDocument`]/[`FeedEvent::EndDocument`]
+    /// pair is yielded around each value; trailing whitespace after the last
+    /// record is tolerated as clean EOF. See [`PushParser::new_streaming`].
+    pub fn new_ndjson(buffer: &'scratch mut [u8]) -> Self {
+        Self {
+            inner: PushParser::new_streaming(EventQueue { events: Vec::new() }, buffer),
+        }
+    }
+
+    /// Sets a runtime limit on container nesting depth: once set, opening an
+    /// object/array that would exceed it returns
+    /// [`ParseError::DepthLimitExceeded`] instead of yielding further events.
+    /// See [`PushParser::set_max_depth`].
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.inner.set_max_depth(max_depth);
+    }
+
+    /// See [`PushParser::depth`].
+    pub fn depth(&self) -> usize {
+        self.inner.depth()
+    }
+
+    /// See [`PushParser::remaining_depth`].
+    pub fn remaining_depth(&self) -> Option<usize> {
+        self.inner.remaining_depth()
+    }
+
+    /// See [`PushParser::in_object`].
+    pub fn in_object(&self) -> bool {
+        self.inner.in_object()
+    }
+
+    /// See [`PushParser::in_array`].
+    pub fn in_array(&self) -> bool {
+        self.inner.in_array()
+    }
+
+    /// See [`PushParser::set_reject_escaped_keys`].
+    pub fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.inner.set_reject_escaped_keys(reject);
+    }
+
+    /// See [`PushParser::set_reject_bidi_controls`].
+    pub fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.inner.set_reject_bidi_controls(reject);
+    }
+
+    /// See [`PushParser::set_surrogate_policy`].
+    pub fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.inner.set_surrogate_policy(policy);
+    }
+
+    /// See [`PushParser::set_lenient_syntax`].
+    pub fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.inner.set_lenient_syntax(enabled);
+    }
+
+    /// See [`PushParser::position`].
+    pub fn position(&self) -> crate::Position {
+        self.inner.position()
+    }
+
+    /// Feeds a chunk of input, returning an iterator over the events it
+    /// produced. Call this again with the next chunk once the iterator is
+    /// exhausted; a token split across the boundary resumes correctly.
+    pub fn feed(&mut self, chunk: &'input [u8]) -> Result<FeedIter, ParseError> {
+        match self.inner.write::<ParseError>(chunk) {
+            Ok(()) => {
+                let events = core::mem::take(&mut self.inner.handler_mut().events);
+                Ok(FeedIter {
+                    events: events.into_iter(),
+                })
+            }
+            Err(PushParseError::Parse { code, .. }) => Err(code),
+            Err(PushParseError::Handler(e)) => Err(e),
+        }
+    }
+
+    /// Reports whether the last `feed()` call ended mid-token (a string,
+    /// key, or number cut off by the chunk boundary). See
+    /// [`PushParser::needs_more_input`] for when this is useful.
+    pub fn needs_more_input(&self) -> bool {
+        self.inner.needs_more_input()
+    }
+
+    /// Finishes parsing, returning any trailing events (at minimum,
+    /// [`FeedEvent::EndDocument`]). Returns an error if the document was
+    /// left incomplete (e.g. an unclosed container).
+    pub fn finish(self) -> Result<FeedIter, ParseError> {
+        match self.inner.finish::<ParseError>() {
+            Ok(mut handler) => {
+                let events = core::mem::take(&mut handler.events);
+                Ok(FeedIter {
+                    events: events.into_iter(),
+                })
+            }
+            Err(PushParseError::Parse { code, .. }) => Err(code),
+            Err(PushParseError::Handler(e)) => Err(e),
+        }
+    }
+}
+
+/// Result of [`PollParser::poll_event`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Poll {
+    /// A complete, decoded event.
+    Event(FeedEvent),
+    /// Every event the fed input produced so far has been returned. This is
+    /// not an error: feed more bytes with [`PollParser::feed`] (or call
+    /// [`PollParser::finish`] if there is no more input) and poll again.
+    ///
+    /// This is the non-fatal "need more data" outcome for a chunk that ends
+    /// mid-token (split at any byte, including inside a surrogate-pair
+    /// escape): `poll_event` reports it instead of treating the end of the
+    /// fed bytes as document EOF, so a caller driven by an async/interrupt
+    /// source that hands over bytes in arbitrary pieces never has to block
+    /// waiting for a complete token before making progress.
+    NeedMoreInput,
+}
+
+/// A sans-IO, poll-driven counterpart to [`FeedParser`], for callers that
+/// cannot offer a blocking [`Reader`](crate::Reader) and receive bytes in
+/// arbitrary chunks (a network socket, UART DMA) on their own schedule:
+/// push whatever bytes are on hand with [`Self::feed`], then pull events
+/// out one at a time with [`Self::poll_event`] until it reports
+/// [`Poll::NeedMoreInput`], instead of being handed an iterator per chunk.
+///
+/// Builds on the same [`PushParser`]/`PushContentBuilder` machinery as
+/// [`FeedParser`], so a value split across a `feed()` boundary -- including
+/// a `\uD83D` high surrogate split from its trailing `\uDE00` low surrogate
+/// -- resumes exactly as it would from contiguous input.
+pub struct PollParser<'input, 'scratch, C: BitStackConfig = DefaultConfig> {
+    /// `None` once [`Self::finish`] has consumed it; only the already-queued
+    /// `pending` events are left to drain at that point.
+    inner: Option<FeedParser<'input, 'scratch, C>>,
+    pending: alloc::collections::VecDeque<FeedEvent>,
+}
+
+impl<'input, 'scratch, C: BitStackConfig> PollParser<'input, 'scratch, C> {
+    /// Creates a new `PollParser`. Use e.g. `PollParser::<BitStackStruct<u64, u16>>::new(buffer)`
+    /// to pick a non-default `BitStackConfig` for deeper nesting.
+    pub fn new(buffer: &'scratch mut [u8]) -> Self {
+        Self {
+            inner: Some(FeedParser::new(buffer)),
+            pending: alloc::collections::VecDeque::new(),
+        }
+    }
+
+    /// Creates a new `PollParser` that accepts a sequence of whitespace- or
+    /// newline-separated top-level JSON values (NDJSON-style), instead of
+    /// exactly one. See [`FeedParser::new_ndjson`].
+    pub fn new_ndjson(buffer: &'scratch mut [u8]) -> Self {
+        Self {
+            inner: Some(FeedParser::new_ndjson(buffer)),
+            pending: alloc::collections::VecDeque::new(),
+        }
+    }
+
+    /// Sets a runtime limit on container nesting depth: once set, opening an
+    /// object/array that would exceed it returns
+    /// [`ParseError::DepthLimitExceeded`] instead of queuing further events.
+    /// See [`PushParser::set_max_depth`].
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.inner
+            .as_mut()
+            .expect("set_max_depth() called after finish()")
+            .set_max_depth(max_depth);
+    }
+
+    /// See [`PushParser::depth`].
+    pub fn depth(&self) -> usize {
+        self.inner
+            .as_ref()
+            .expect("depth() called after finish()")
+            .depth()
+    }
+
+    /// See [`PushParser::remaining_depth`].
+    pub fn remaining_depth(&self) -> Option<usize> {
+        self.inner
+            .as_ref()
+            .expect("remaining_depth() called after finish()")
+            .remaining_depth()
+    }
+
+    /// See [`PushParser::in_object`].
+    pub fn in_object(&self) -> bool {
+        self.inner
+            .as_ref()
+            .expect("in_object() called after finish()")
+            .in_object()
+    }
+
+    /// See [`PushParser::in_array`].
+    pub fn in_array(&self) -> bool {
+        self.inner
+            .as_ref()
+            .expect("in_array() called after finish()")
+            .in_array()
+    }
+
+    /// See [`PushParser::set_reject_escaped_keys`].
+    pub fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.inner
+            .as_mut()
+            .expect("set_reject_escaped_keys() called after finish()")
+            .set_reject_escaped_keys(reject);
+    }
+
+    /// See [`PushParser::set_reject_bidi_controls`].
+    pub fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.inner
+            .as_mut()
+            .expect("set_reject_bidi_controls() called after finish()")
+            .set_reject_bidi_controls(reject);
+    }
+
+    /// See [`PushParser::set_surrogate_policy`].
+    pub fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.inner
+            .as_mut()
+            .expect("set_surrogate_policy() called after finish()")
+            .set_surrogate_policy(policy);
+    }
+
+    /// See [`PushParser::set_lenient_syntax`].
+    pub fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.inner
+            .as_mut()
+            .expect("set_lenient_syntax() called after finish()")
+            .set_lenient_syntax(enabled);
+    }
+
+    /// See [`PushParser::position`].
+    pub fn position(&self) -> crate::Position {
+        self.inner
+            .as_ref()
+            .expect("position() called after finish()")
+            .position()
+    }
+
+    /// Queues a chunk of input for [`Self::poll_event`] to drain. Can be
+    /// called again with the next chunk once polling reports
+    /// [`Poll::NeedMoreInput`]; a token split across the boundary resumes
+    /// correctly.
+    pub fn feed(&mut self, chunk: &'input [u8]) -> Result<(), ParseError> {
+        let inner = self
+            .inner
+            .as_mut()
+            .expect("feed() called after finish()");
+        self.pending.extend(inner.feed(chunk)?);
+        Ok(())
+    }
+
+    /// Signals that no more input is coming, queuing any trailing events
+    /// (at minimum, [`FeedEvent::EndDocument`]). Returns an error if the
+    /// document was left incomplete (e.g. an unclosed container).
+    ///
+    /// Keep polling with [`Self::poll_event`] after calling this to drain
+    /// the trailing events it queued.
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        let inner = self.inner.take().expect("finish() called more than once");
+        self.pending.extend(inner.finish()?);
+        Ok(())
+    }
+
+    /// Pulls the next queued event, or reports that the input fed so far
+    /// has been fully drained.
+    pub fn poll_event(&mut self) -> Poll {
+        match self.pending.pop_front() {
+            Some(event) => Poll::Event(event),
+            None => Poll::NeedMoreInput,
+        }
+    }
+}
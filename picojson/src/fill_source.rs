@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Lets [`crate::stream_buffer::StreamBuffer`] pull bytes from something that
+//! produces them incrementally -- most notably a streaming decompressor --
+//! rather than only from a raw [`crate::stream_parser::Reader`]. Modeled on
+//! quickwit's `SkipReader`/`GzipDecoder` layering: the source owns whatever
+//! internal window/carry-over state it needs to resume mid-block, and
+//! `fill` is free to write fewer bytes than `dst` offers (or than it
+//! consumed from its own upstream) as long as it makes some progress or
+//! reports `EndOfInput`.
+//!
+//! A real inflate (RFC 1951/gzip) decoder is not implemented here -- hand
+//! -authoring a byte-correct Huffman/LZ77 decoder without a compiler or test
+//! harness to check it against isn't a reasonable thing to ship blind. This
+//! defines the extension point and the `StreamBuffer`-side plumbing so that
+//! wiring an existing inflate implementation (e.g. `miniz_oxide`) behind it
+//! is a small, self-contained follow-up.
+
+use crate::stream_buffer::{StreamBuffer, StreamBufferError};
+
+/// A byte producer that can be driven incrementally into a caller-supplied
+/// slice, resuming any partially-decoded state across calls.
+pub trait FillSource {
+    /// The error type this source can report (e.g. a corrupt compressed
+    /// stream, or the underlying reader's own error).
+    type Error;
+
+    /// Writes as many bytes as are currently available into the front of
+    /// `dst`, returning how many were written. `0` means "no progress was
+    /// possible right now" -- out of input to decode, not necessarily
+    /// end-of-stream; callers distinguish true end-of-stream via
+    /// [`is_exhausted`](Self::is_exhausted) after a `0` result.
+    fn fill(&mut self, dst: &mut [u8]) -> Result<usize, Self::Error>;
+
+    /// Whether the source has nothing further to produce, ever. Checked
+    /// after `fill` returns `0` to tell "genuinely done" apart from
+    /// "needs another call once more compressed input exists".
+    fn is_exhausted(&self) -> bool;
+}
+
+/// Errors from [`StreamBuffer::fill_from_fill_source`]: either the buffer
+/// had no room, or the source itself failed.
+#[derive(Debug, PartialEq)]
+pub enum FillSourceError<E> {
+    /// The `StreamBuffer` rejected the byte count the source reported
+    /// (more than the fill slice it was given). An already-full buffer is
+    /// not an error: it yields `Ok(0)`.
+    Buffer(StreamBufferError),
+    /// The source reported an error (e.g. a malformed compressed stream).
+    Source(E),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `FillSource` that only ever produces `chunk_size` bytes per call,
+    /// regardless of how much room `dst` offers -- simulating a decoder
+    /// that made partial progress through an internal window and needs to
+    /// be called again to continue.
+    struct StepSource<'a> {
+        remaining: &'a [u8],
+        chunk_size: usize,
+    }
+
+    impl<'a> FillSource for StepSource<'a> {
+        type Error = ();
+
+        fn fill(&mut self, dst: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = self.chunk_size.min(dst.len()).min(self.remaining.len());
+            dst[..n].copy_from_slice(&self.remaining[..n]);
+            self.remaining = &self.remaining[n..];
+            Ok(n)
+        }
+
+        fn is_exhausted(&self) -> bool {
+            self.remaining.is_empty()
+        }
+    }
+
+    #[test]
+    fn test_fill_from_fill_source_copies_one_step_at_a_time() {
+        let mut backing = [0u8; 16];
+        let mut buffer = StreamBuffer::new(&mut backing);
+        let mut src = StepSource {
+            remaining: b"hello world",
+            chunk_size: 4,
+        };
+
+        let n1 = buffer.fill_from_fill_source(&mut src).unwrap();
+        assert_eq!(n1, 4);
+        let n2 = buffer.fill_from_fill_source(&mut src).unwrap();
+        assert_eq!(n2, 4);
+        let n3 = buffer.fill_from_fill_source(&mut src).unwrap();
+        assert_eq!(n3, 3);
+        assert!(src.is_exhausted());
+        assert_eq!(buffer.remaining_bytes(), 11);
+    }
+
+    #[test]
+    fn test_fill_from_fill_source_stops_when_buffer_is_full() {
+        let mut backing = [0u8; 4];
+        let mut buffer = StreamBuffer::new(&mut backing);
+        let mut src = StepSource {
+            remaining: b"too much data",
+            chunk_size: 4,
+        };
+
+        let n1 = buffer.fill_from_fill_source(&mut src).unwrap();
+        assert_eq!(n1, 4);
+        // Buffer is now full; fill_from_fill_source reports no progress
+        // rather than erroring, matching the ByteSource-based path.
+        let n2 = buffer.fill_from_fill_source(&mut src).unwrap();
+        assert_eq!(n2, 0);
+        assert!(!src.is_exhausted());
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Float parser module, mirroring int_parser.rs: a panic-free, const-stable
+// decimal parser for targets that need `from_ascii_f32`/`from_ascii_f64`
+// evaluable in a `const` context, unlike the `float` feature's runtime
+// `core::str::FromStr` path in `json_number::parse_float`.
+
+use crate::ParseError;
+
+/// Creates a panic-free, const-stable decimal float parser for a specific
+/// float type.
+///
+/// Accumulates the integer and fractional digits into a `u64` mantissa
+/// (rejecting overflow past `u64::MAX` as [`ParseError::NumericOverflow`],
+/// same as [`crate::int_parser`]'s digit loops), counts the fractional
+/// digits seen, parses an optional `e`/`E` exponent the same way, then
+/// realizes the value as `mantissa as f_/f64 scaled by 10^(exponent -
+/// fractional_digits)` through repeated multiply/divide by `10`. `$max_exp`
+/// bounds how many times the multiply loop ever runs: past it a non-zero
+/// result has already saturated to infinity under IEEE 754, so this skips
+/// straight there instead of looping out to an exponent of, say, `1e9`.
+/// The divide loop stops as soon as the value underflows to `0.0`.
+///
+/// This is simpler -- and, for values near the edges of the type's range,
+/// measurably less precise -- than `core`'s `FromStr` impl the `float`
+/// feature's runtime parser uses (which round-trips via Eisel-Lemire with
+/// an exact big-integer fallback). Acceptable rounding error for a
+/// panic-free `const` fallback on targets that can't run the runtime path
+/// at all.
+macro_rules! define_const_float_parser {
+    ($fn_name:ident, $float_ty:ty, $max_exp:expr) => {
+        /// Parses a JSON number body into a(n)
+        #[doc = stringify!($float_ty)]
+        /// in a `const` context.
+        ///
+        /// This function is guaranteed not to panic.
+        pub const fn $fn_name(src: &[u8]) -> Result<$float_ty, ParseError> {
+            let (negative, rest) = match src {
+                [] => return Err(ParseError::InvalidNumber),
+                [b'-', rest @ ..] => (true, rest),
+                [b'+', rest @ ..] => (false, rest),
+                _ => (false, src),
+            };
+
+            if rest.is_empty() {
+                return Err(ParseError::InvalidNumber);
+            }
+
+            let mut mantissa: u64 = 0;
+            let mut frac_digits: i32 = 0;
+            let mut seen_dot = false;
+            let mut seen_digit = false;
+            let mut exponent: i32 = 0;
+            let mut digits = rest;
+
+            while let Some((&byte, next)) = digits.split_first() {
+                match byte {
+                    b'0'..=b'9' => {
+                        seen_digit = true;
+                        mantissa = match mantissa.checked_mul(10) {
+                            Some(val) => val,
+                            None => return Err(ParseError::NumericOverflow),
+                        };
+                        mantissa = match mantissa.checked_add((byte - b'0') as u64) {
+                            Some(val) => val,
+                            None => return Err(ParseError::NumericOverflow),
+                        };
+                        if seen_dot {
+                            frac_digits += 1;
+                        }
+                        digits = next;
+                    }
+                    b'.' if !seen_dot => {
+                        seen_dot = true;
+                        digits = next;
+                    }
+                    b'e' | b'E' => {
+                        if !seen_digit {
+                            return Err(ParseError::InvalidNumber);
+                        }
+                        let (exp_negative, mut exp_digits) = match next {
+                            [b'-', rest @ ..] => (true, rest),
+                            [b'+', rest @ ..] => (false, rest),
+                            _ => (false, next),
+                        };
+                        if exp_digits.is_empty() {
+                            return Err(ParseError::InvalidNumber);
+                        }
+
+                        let mut exp_value: i32 = 0;
+                        while let Some((&byte, rest)) = exp_digits.split_first() {
+                            match byte {
+                                b'0'..=b'9' => {
+                                    exp_value = match exp_value.checked_mul(10) {
+                                        Some(val) => val,
+                                        None => return Err(ParseError::NumericOverflow),
+                                    };
+                                    exp_value = match exp_value.checked_add((byte - b'0') as i32) {
+                                        Some(val) => val,
+                                        None => return Err(ParseError::NumericOverflow),
+                                    };
+                                }
+                                _ => return Err(ParseError::InvalidNumber),
+                            }
+                            exp_digits = rest;
+                        }
+
+                        exponent = if exp_negative { -exp_value } else { exp_value };
+                        digits = &[];
+                    }
+                    _ => return Err(ParseError::InvalidNumber),
+                }
+            }
+
+            if !seen_digit {
+                return Err(ParseError::InvalidNumber);
+            }
+
+            let net_exponent = exponent.saturating_sub(frac_digits);
+
+            if mantissa != 0 && net_exponent > $max_exp {
+                return Ok(if negative {
+                    -<$float_ty>::INFINITY
+                } else {
+                    <$float_ty>::INFINITY
+                });
+            }
+
+            let mut value: $float_ty = mantissa as $float_ty;
+            if mantissa != 0 && net_exponent > 0 {
+                let mut remaining = net_exponent;
+                while remaining > 0 {
+                    value *= 10.0;
+                    remaining -= 1;
+                }
+            } else {
+                let mut remaining = net_exponent;
+                while remaining < 0 && value != 0.0 {
+                    value /= 10.0;
+                    remaining += 1;
+                }
+            }
+
+            Ok(if negative { -value } else { value })
+        }
+    };
+}
+
+#[cfg(feature = "float-const")]
+define_const_float_parser!(from_ascii_f32, f32, 45);
+#[cfg(feature = "float-const")]
+define_const_float_parser!(from_ascii_f64, f64, 309);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "float-const")]
+    mod test_f64 {
+        use super::*;
+
+        #[test]
+        fn test_from_ascii_f64_simple() {
+            assert_eq!(from_ascii_f64(b"0"), Ok(0.0));
+            assert_eq!(from_ascii_f64(b"1"), Ok(1.0));
+            assert_eq!(from_ascii_f64(b"-1"), Ok(-1.0));
+            assert_eq!(from_ascii_f64(b"3.14"), Ok(3.14));
+            assert_eq!(from_ascii_f64(b"-2.5"), Ok(-2.5));
+        }
+
+        #[test]
+        fn test_from_ascii_f64_exponent() {
+            assert_eq!(from_ascii_f64(b"1e3"), Ok(1000.0));
+            assert_eq!(from_ascii_f64(b"2.5e1"), Ok(25.0));
+            assert_eq!(from_ascii_f64(b"100e-2"), Ok(1.0));
+            assert_eq!(from_ascii_f64(b"1E2"), Ok(100.0));
+            assert_eq!(from_ascii_f64(b"1e+2"), Ok(100.0));
+        }
+
+        #[test]
+        fn test_from_ascii_f64_saturates_on_extreme_exponent() {
+            assert_eq!(from_ascii_f64(b"1e400"), Ok(f64::INFINITY));
+            assert_eq!(from_ascii_f64(b"-1e400"), Ok(f64::NEG_INFINITY));
+            assert_eq!(from_ascii_f64(b"1e-400"), Ok(0.0));
+            assert_eq!(from_ascii_f64(b"-1e-400"), Ok(-0.0));
+        }
+
+        #[test]
+        fn test_from_ascii_f64_overflow() {
+            assert_eq!(
+                from_ascii_f64(b"99999999999999999999999999999999"),
+                Err(ParseError::NumericOverflow)
+            );
+        }
+
+        #[test]
+        fn test_from_ascii_f64_errors() {
+            assert_eq!(from_ascii_f64(b""), Err(ParseError::InvalidNumber));
+            assert_eq!(from_ascii_f64(b"-"), Err(ParseError::InvalidNumber));
+            assert_eq!(from_ascii_f64(b"e5"), Err(ParseError::InvalidNumber));
+            assert_eq!(from_ascii_f64(b"1e"), Err(ParseError::InvalidNumber));
+            assert_eq!(from_ascii_f64(b"1.2.3"), Err(ParseError::InvalidNumber));
+            assert_eq!(from_ascii_f64(b"1a"), Err(ParseError::InvalidNumber));
+        }
+    }
+
+    #[cfg(feature = "float-const")]
+    mod test_f32 {
+        use super::*;
+
+        #[test]
+        fn test_from_ascii_f32_simple() {
+            assert_eq!(from_ascii_f32(b"0"), Ok(0.0));
+            assert_eq!(from_ascii_f32(b"3.5"), Ok(3.5));
+            assert_eq!(from_ascii_f32(b"-3.5"), Ok(-3.5));
+        }
+
+        #[test]
+        fn test_from_ascii_f32_saturates_on_extreme_exponent() {
+            assert_eq!(from_ascii_f32(b"1e100"), Ok(f32::INFINITY));
+            assert_eq!(from_ascii_f32(b"1e-100"), Ok(0.0));
+        }
+    }
+}
@@ -83,6 +83,263 @@ define_const_parser!(from_ascii_i32, i32);
 #[cfg(feature = "int64")]
 define_const_parser!(from_ascii_i64, i64);
 
+/// Decodes a single ASCII digit against `radix` (2..=16), the same way for
+/// every radix-aware parser below: `0`-`9` map to 0-9, and for bases above
+/// 10, `a`-`f`/`A`-`F` map to 10-15. `None` covers both "not an ASCII
+/// hex/decimal character at all" and "a real digit, but one `radix`
+/// doesn't have" (e.g. `'8'` in octal), since both mean the same thing to
+/// a caller: the byte isn't a valid digit in this radix.
+const fn decode_radix_digit(byte: u8, radix: u32) -> Option<u32> {
+    let value = match byte {
+        b'0'..=b'9' => (byte - b'0') as u32,
+        b'a'..=b'f' => (byte - b'a') as u32 + 10,
+        b'A'..=b'F' => (byte - b'A') as u32 + 10,
+        _ => return None,
+    };
+    if value < radix {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Strips a leading `0x`/`0X`, `0o`/`0O`, or `0b`/`0B` prefix matching
+/// `radix`, if present. Call before the digit loop so e.g. `from_ascii_i32_radix(b"0x2A", 16)`
+/// and `from_ascii_i32_radix(b"2A", 16)` parse identically.
+const fn strip_radix_prefix(src: &[u8], radix: u32) -> &[u8] {
+    match (radix, src) {
+        (16, [b'0', b'x' | b'X', rest @ ..]) => rest,
+        (8, [b'0', b'o' | b'O', rest @ ..]) => rest,
+        (2, [b'0', b'b' | b'B', rest @ ..]) => rest,
+        _ => src,
+    }
+}
+
+/// Creates a panic-free, const-stable, radix-aware parser for a specific
+/// signed integer type, generalizing [`define_const_parser`] to bases other
+/// than 10 -- e.g. for JSON5-style `0x`/`0o`/`0b` literals.
+macro_rules! define_const_signed_radix_parser {
+    ($fn_name:ident, $int_ty:ty) => {
+        /// Like its fixed-base-10 counterpart, but parses in the given
+        /// `radix` (2..=16), accepting a leading `0x`/`0o`/`0b` prefix that
+        /// matches it.
+        #[doc = stringify!($int_ty)]
+        ///
+        /// This function is guaranteed not to panic.
+        pub const fn $fn_name(
+            src: &[u8],
+            radix: u32,
+        ) -> Result<$int_ty, ConstParseIntegerError> {
+            let (is_negative, rest) = match src {
+                [] => return Err(ConstParseIntegerError::Empty),
+                [b'+', rest @ ..] => (false, rest),
+                [b'-', rest @ ..] => (true, rest),
+                _ => (false, src),
+            };
+
+            let mut digits = strip_radix_prefix(rest, radix);
+
+            if digits.is_empty() {
+                return Err(ConstParseIntegerError::SignOnly);
+            }
+
+            let mut result: $int_ty = 0;
+            while let Some((&byte, rest)) = digits.split_first() {
+                let digit = match decode_radix_digit(byte, radix) {
+                    Some(digit) => digit as $int_ty,
+                    None => return Err(ConstParseIntegerError::InvalidDigit),
+                };
+
+                result = match result.checked_mul(radix as $int_ty) {
+                    Some(val) => val,
+                    None => return Err(ConstParseIntegerError::Overflow),
+                };
+
+                if is_negative {
+                    result = match result.checked_sub(digit) {
+                        Some(val) => val,
+                        None => return Err(ConstParseIntegerError::Overflow),
+                    }
+                } else {
+                    result = match result.checked_add(digit) {
+                        Some(val) => val,
+                        None => return Err(ConstParseIntegerError::Overflow),
+                    }
+                }
+
+                digits = rest;
+            }
+
+            Ok(result)
+        }
+    };
+}
+
+/// Creates a panic-free, const-stable, radix-aware parser for a specific
+/// unsigned integer type. Unlike the signed version, a leading `-` is
+/// always rejected as [`ConstParseIntegerError::InvalidDigit`] -- there's
+/// no negative unsigned value to represent it as.
+macro_rules! define_const_unsigned_radix_parser {
+    ($fn_name:ident, $uint_ty:ty) => {
+        /// Like its fixed-base-10 counterpart, but parses in the given
+        /// `radix` (2..=16), accepting a leading `0x`/`0o`/`0b` prefix that
+        /// matches it.
+        #[doc = stringify!($uint_ty)]
+        ///
+        /// This function is guaranteed not to panic.
+        pub const fn $fn_name(
+            src: &[u8],
+            radix: u32,
+        ) -> Result<$uint_ty, ConstParseIntegerError> {
+            let rest = match src {
+                [] => return Err(ConstParseIntegerError::Empty),
+                [b'-', ..] => return Err(ConstParseIntegerError::InvalidDigit),
+                [b'+', rest @ ..] => rest,
+                _ => src,
+            };
+
+            let mut digits = strip_radix_prefix(rest, radix);
+
+            if digits.is_empty() {
+                return Err(ConstParseIntegerError::SignOnly);
+            }
+
+            let mut result: $uint_ty = 0;
+            while let Some((&byte, rest)) = digits.split_first() {
+                let digit = match decode_radix_digit(byte, radix) {
+                    Some(digit) => digit as $uint_ty,
+                    None => return Err(ConstParseIntegerError::InvalidDigit),
+                };
+
+                result = match result.checked_mul(radix as $uint_ty) {
+                    Some(val) => val,
+                    None => return Err(ConstParseIntegerError::Overflow),
+                };
+                result = match result.checked_add(digit) {
+                    Some(val) => val,
+                    None => return Err(ConstParseIntegerError::Overflow),
+                };
+
+                digits = rest;
+            }
+
+            Ok(result)
+        }
+    };
+}
+
+// JSON5-style numeric literals (hex/octal/binary) live behind the "json5"
+// feature, layered on top of the same per-width feature flags as the
+// base-10 parsers above.
+#[cfg(all(feature = "json5", feature = "int8"))]
+define_const_signed_radix_parser!(from_ascii_i8_radix, i8);
+#[cfg(all(feature = "json5", feature = "int32"))]
+define_const_signed_radix_parser!(from_ascii_i32_radix, i32);
+#[cfg(all(feature = "json5", feature = "int64"))]
+define_const_signed_radix_parser!(from_ascii_i64_radix, i64);
+
+#[cfg(all(feature = "json5", feature = "int8"))]
+define_const_unsigned_radix_parser!(from_ascii_u8_radix, u8);
+#[cfg(all(feature = "json5", feature = "int32"))]
+define_const_unsigned_radix_parser!(from_ascii_u32_radix, u32);
+#[cfg(all(feature = "json5", feature = "int64"))]
+define_const_unsigned_radix_parser!(from_ascii_u64_radix, u64);
+
+/// Creates a panic-free, const-stable, base-10 parser for a specific
+/// unsigned integer type. Mirrors [`define_const_parser`], except a
+/// leading `-` is always rejected as [`ConstParseIntegerError::InvalidDigit`]
+/// -- there's no negative unsigned value to represent it as.
+macro_rules! define_const_unsigned_parser {
+    ($fn_name:ident, $uint_ty:ty) => {
+        /// Parses a byte slice into a(n) `
+        #[doc = stringify!($uint_ty)]
+        /// ` in a `const` context.
+        ///
+        /// This function is guaranteed not to panic.
+        pub const fn $fn_name(src: &[u8]) -> Result<$uint_ty, ConstParseIntegerError> {
+            let mut digits = match src {
+                [] => return Err(ConstParseIntegerError::Empty),
+                [b'-', ..] => return Err(ConstParseIntegerError::InvalidDigit),
+                [b'+', rest @ ..] => rest,
+                _ => src,
+            };
+
+            if digits.is_empty() {
+                return Err(ConstParseIntegerError::SignOnly);
+            }
+
+            let mut result: $uint_ty = 0;
+            while let Some((&byte, rest)) = digits.split_first() {
+                let digit = match byte {
+                    b'0'..=b'9' => (byte - b'0') as $uint_ty,
+                    _ => return Err(ConstParseIntegerError::InvalidDigit),
+                };
+
+                result = match result.checked_mul(10) {
+                    Some(val) => val,
+                    None => return Err(ConstParseIntegerError::Overflow),
+                };
+                result = match result.checked_add(digit) {
+                    Some(val) => val,
+                    None => return Err(ConstParseIntegerError::Overflow),
+                };
+
+                digits = rest;
+            }
+
+            Ok(result)
+        }
+    };
+}
+
+#[cfg(feature = "int8")]
+define_const_unsigned_parser!(from_ascii_u8, u8);
+#[cfg(feature = "int32")]
+define_const_unsigned_parser!(from_ascii_u32, u32);
+
+/// Parses a non-negative byte slice into a `u64`, in a `const` context.
+///
+/// Unlike [`from_ascii_i64`], this rejects a leading `-` outright rather
+/// than trying to represent it -- there's no negative `u64`. Used as the
+/// second attempt for an integer literal that didn't fit in `i64`, so a
+/// value like `18446744073709551615` (`u64::MAX`) is still realized
+/// exactly instead of falling through to [`NumberResult::IntegerOverflow`](crate::NumberResult::IntegerOverflow).
+#[cfg(feature = "int64")]
+pub const fn from_ascii_u64(src: &[u8]) -> Result<u64, ConstParseIntegerError> {
+    let digits = match src {
+        [] => return Err(ConstParseIntegerError::Empty),
+        [b'-', ..] => return Err(ConstParseIntegerError::InvalidDigit),
+        [b'+', rest @ ..] => rest,
+        _ => src,
+    };
+
+    if digits.is_empty() {
+        return Err(ConstParseIntegerError::SignOnly);
+    }
+
+    let mut result: u64 = 0;
+    let mut digits = digits;
+    while let Some((&byte, rest)) = digits.split_first() {
+        let digit = match byte {
+            b'0'..=b'9' => (byte - b'0') as u64,
+            _ => return Err(ConstParseIntegerError::InvalidDigit),
+        };
+
+        result = match result.checked_mul(10) {
+            Some(val) => val,
+            None => return Err(ConstParseIntegerError::Overflow),
+        };
+        result = match result.checked_add(digit) {
+            Some(val) => val,
+            None => return Err(ConstParseIntegerError::Overflow),
+        };
+
+        digits = rest;
+    }
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +486,149 @@ mod tests {
             );
         }
     }
+
+    // --- Tests for from_ascii_u64 ---
+    #[cfg(feature = "int64")]
+    mod test_u64 {
+        use super::*;
+        #[test]
+        fn test_from_ascii_u64_simple() {
+            assert_eq!(from_ascii_u64(b"0"), Ok(0));
+            assert_eq!(from_ascii_u64(b"1234567890"), Ok(1234567890));
+            assert_eq!(from_ascii_u64(b"+1234567890"), Ok(1234567890));
+        }
+
+        #[test]
+        fn test_from_ascii_u64_limits() {
+            assert_eq!(
+                from_ascii_u64(u64::MAX.to_string().as_bytes()),
+                Ok(u64::MAX)
+            );
+            assert_eq!(
+                from_ascii_u64(b"9223372036854775808"), // i64::MAX + 1
+                Ok(9223372036854775808)
+            );
+        }
+
+        #[test]
+        fn test_from_ascii_u64_rejects_negative() {
+            assert_eq!(
+                from_ascii_u64(b"-1"),
+                Err(ConstParseIntegerError::InvalidDigit)
+            );
+        }
+
+        #[test]
+        fn test_from_ascii_u64_overflow() {
+            assert_eq!(
+                from_ascii_u64(b"18446744073709551616"), // u64::MAX + 1
+                Err(ConstParseIntegerError::Overflow)
+            );
+        }
+
+        #[test]
+        fn test_from_ascii_u64_errors() {
+            assert_eq!(from_ascii_u64(b""), Err(ConstParseIntegerError::Empty));
+            assert_eq!(from_ascii_u64(b"+"), Err(ConstParseIntegerError::SignOnly));
+            assert_eq!(
+                from_ascii_u64(b"12a"),
+                Err(ConstParseIntegerError::InvalidDigit)
+            );
+        }
+    }
+
+    // --- Tests for from_ascii_u8 / from_ascii_u32 ---
+    #[cfg(feature = "int8")]
+    mod test_u8 {
+        use super::*;
+        #[test]
+        fn test_from_ascii_u8_simple() {
+            assert_eq!(from_ascii_u8(b"0"), Ok(0));
+            assert_eq!(from_ascii_u8(b"255"), Ok(u8::MAX));
+            assert_eq!(from_ascii_u8(b"+42"), Ok(42));
+        }
+
+        #[test]
+        fn test_from_ascii_u8_rejects_negative() {
+            assert_eq!(
+                from_ascii_u8(b"-1"),
+                Err(ConstParseIntegerError::InvalidDigit)
+            );
+        }
+
+        #[test]
+        fn test_from_ascii_u8_overflow() {
+            assert_eq!(from_ascii_u8(b"256"), Err(ConstParseIntegerError::Overflow));
+        }
+    }
+
+    #[cfg(feature = "int32")]
+    mod test_u32 {
+        use super::*;
+        #[test]
+        fn test_from_ascii_u32_simple() {
+            assert_eq!(from_ascii_u32(b"0"), Ok(0));
+            assert_eq!(from_ascii_u32(u32::MAX.to_string().as_bytes()), Ok(u32::MAX));
+        }
+
+        #[test]
+        fn test_from_ascii_u32_overflow() {
+            assert_eq!(
+                from_ascii_u32(b"4294967296"),
+                Err(ConstParseIntegerError::Overflow)
+            );
+        }
+    }
+
+    // --- Tests for the radix-aware parsers ---
+    #[cfg(all(feature = "json5", feature = "int32"))]
+    mod test_radix {
+        use super::*;
+
+        #[test]
+        fn test_signed_radix_hex() {
+            assert_eq!(from_ascii_i32_radix(b"2A", 16), Ok(42));
+            assert_eq!(from_ascii_i32_radix(b"0x2A", 16), Ok(42));
+            assert_eq!(from_ascii_i32_radix(b"0X2a", 16), Ok(42));
+            assert_eq!(from_ascii_i32_radix(b"-0x2A", 16), Ok(-42));
+        }
+
+        #[test]
+        fn test_signed_radix_octal_and_binary() {
+            assert_eq!(from_ascii_i32_radix(b"0o52", 8), Ok(42));
+            assert_eq!(from_ascii_i32_radix(b"52", 8), Ok(42));
+            assert_eq!(from_ascii_i32_radix(b"0b101010", 2), Ok(42));
+        }
+
+        #[test]
+        fn test_signed_radix_rejects_out_of_range_digit() {
+            // '8' isn't a valid octal digit.
+            assert_eq!(
+                from_ascii_i32_radix(b"8", 8),
+                Err(ConstParseIntegerError::InvalidDigit)
+            );
+            // 'g' isn't a valid hex digit.
+            assert_eq!(
+                from_ascii_i32_radix(b"g", 16),
+                Err(ConstParseIntegerError::InvalidDigit)
+            );
+        }
+
+        #[test]
+        fn test_unsigned_radix_hex() {
+            assert_eq!(from_ascii_u32_radix(b"0xFF", 16), Ok(255));
+            assert_eq!(
+                from_ascii_u32_radix(b"-0x1", 16),
+                Err(ConstParseIntegerError::InvalidDigit)
+            );
+        }
+
+        #[test]
+        fn test_radix_overflow() {
+            assert_eq!(
+                from_ascii_i32_radix(b"0x80000000", 16),
+                Err(ConstParseIntegerError::Overflow)
+            );
+        }
+    }
 }
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`IoReader`], a [`Reader`] adapter over `std::io::Read` (or, on `no_std`
+//! targets that still have `alloc`, `core_io::Read`, or -- for HAL stacks
+//! like the AVR UART demo -- `embedded_io::Read` via [`EmbeddedIoReader`]).
+//! [`crate::chunk_reader`]'s module docs already point users at
+//! "`impl Reader for std::fs::File`" as the expected on-ramp for real
+//! file/socket/peripheral I/O; this ships that glue instead of asking every
+//! user to hand-write it.
+//!
+//! Gated behind the `std`/`core_io`/`embedded-io` feature matching the
+//! adapter in use, so the default `no_std` build pulls in none of these
+//! traits. [`IterReader`] is the exception: it wraps a plain
+//! `core::iter::Iterator<Item = u8>`, needs no external crate, and so is
+//! always available.
+//!
+//! For an async executor instead of a blocking `Read`, see
+//! [`crate::async_reader`]'s `EmbeddedIoAsyncReader`, the
+//! `embedded_io_async::Read` counterpart to this module's
+//! [`EmbeddedIoReader`].
+
+use crate::Reader;
+
+/// Wraps any `Iterator<Item = u8>` so it can drive [`crate::StreamParser`],
+/// for byte sources that only offer one-at-a-time access (a deserialized
+/// peripheral FIFO, a generator, `str::bytes()`) rather than a `Read`-style
+/// buffer fill. Unlike [`IoReader`]/[`CoreIoReader`]/[`EmbeddedIoReader`]
+/// this needs no external crate's `Read` trait and no feature gate, since
+/// `Iterator` is always available in `core`.
+///
+/// `Reader::read`'s contract requires a `0`-byte result to mean true
+/// end-of-stream; since `Iterator::next` returning `None` already means
+/// exactly that (an `Iterator` has no "nothing right now, ask again later"
+/// state the way a non-blocking source would), every `None` here is a
+/// real, final end of stream, with no adapter-side buffering needed to
+/// reconcile the two contracts.
+pub struct IterReader<I> {
+    inner: I,
+}
+
+impl<I> IterReader<I> {
+    /// Wraps `inner`, an existing `Iterator<Item = u8>`.
+    pub fn new(inner: I) -> Self {
+        Self { inner }
+    }
+}
+
+impl<I: Iterator<Item = u8>> Reader for IterReader<I> {
+    type Error = core::convert::Infallible;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let mut n = 0;
+        for slot in buf.iter_mut() {
+            match self.inner.next() {
+                Some(byte) => {
+                    *slot = byte;
+                    n += 1;
+                }
+                None => break,
+            }
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod iter_reader_tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_reader_fills_buf_from_iterator() {
+        let mut reader = IterReader::new(b"hello".iter().copied());
+        let mut buf = [0u8; 8];
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"hello");
+    }
+
+    #[test]
+    fn test_iter_reader_fills_only_up_to_buf_len() {
+        let mut reader = IterReader::new(b"hello".iter().copied());
+        let mut buf = [0u8; 3];
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"hel");
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"lo");
+    }
+
+    #[test]
+    fn test_iter_reader_zero_read_means_end_of_stream() {
+        let mut reader = IterReader::new(core::iter::empty());
+        let mut buf = [0u8; 8];
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_impl {
+    use crate::Reader;
+    use std::io::{ErrorKind, Read};
+
+    /// Wraps any [`std::io::Read`] so it can drive [`crate::StreamParser`].
+    ///
+    /// `Read::read` is retried on [`ErrorKind::Interrupted`] rather than
+    /// surfaced as an error, matching the usual `std::io` convention that
+    /// callers of `read` (as opposed to `read_exact`) are expected to do
+    /// this themselves.
+    pub struct IoReader<R> {
+        inner: R,
+    }
+
+    impl<R> IoReader<R> {
+        /// Wraps `inner`, an existing `std::io::Read` implementor.
+        pub fn new(inner: R) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<R: Read> Reader for IoReader<R> {
+        type Error = std::io::Error;
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            loop {
+                match self.inner.read(buf) {
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    result => return result,
+                }
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+pub use std_impl::IoReader;
+
+#[cfg(feature = "core_io")]
+mod core_io_impl {
+    use crate::Reader;
+    use core_io::{Error as CoreIoError, ErrorKind, Read};
+
+    /// Wraps any [`core_io::Read`] so it can drive [`crate::StreamParser`]
+    /// on `no_std` + `alloc` targets that already depend on `core_io` for
+    /// their I/O traits (e.g. some embedded HAL stacks).
+    ///
+    /// Same `Interrupted`-is-a-retry behavior as the `std` [`super::IoReader`].
+    pub struct CoreIoReader<R> {
+        inner: R,
+    }
+
+    impl<R> CoreIoReader<R> {
+        /// Wraps `inner`, an existing `core_io::Read` implementor.
+        pub fn new(inner: R) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<R: Read> Reader for CoreIoReader<R> {
+        type Error = CoreIoError;
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            loop {
+                match self.inner.read(buf) {
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    result => return result,
+                }
+            }
+        }
+    }
+}
+#[cfg(feature = "core_io")]
+pub use core_io_impl::CoreIoReader;
+
+#[cfg(feature = "embedded-io")]
+mod embedded_io_impl {
+    use crate::Reader;
+    use embedded_io::{Error, ErrorKind, Read};
+
+    /// Wraps any [`embedded_io::Read`] so it can drive [`crate::StreamParser`]
+    /// on `no_std` targets built against the `embedded-io` HAL traits (e.g.
+    /// the AVR UART demo).
+    ///
+    /// Same `Interrupted`-is-a-retry behavior as the `std` [`super::IoReader`];
+    /// `embedded_io::ErrorKind` carries an `Interrupted` variant for exactly
+    /// this case.
+    pub struct EmbeddedIoReader<R> {
+        inner: R,
+    }
+
+    impl<R> EmbeddedIoReader<R> {
+        /// Wraps `inner`, an existing `embedded_io::Read` implementor.
+        pub fn new(inner: R) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<R: Read> Reader for EmbeddedIoReader<R> {
+        type Error = R::Error;
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            loop {
+                match self.inner.read(buf) {
+                    Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                    result => return result,
+                }
+            }
+        }
+    }
+}
+#[cfg(feature = "embedded-io")]
+pub use embedded_io_impl::EmbeddedIoReader;
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::Reader;
+
+    /// A `std::io::Read` that reports one `Interrupted` error before
+    /// yielding its bytes, to exercise the retry path.
+    struct FlakyOnceThenData<'a> {
+        data: &'a [u8],
+        interrupted_once: bool,
+    }
+
+    impl<'a> std::io::Read for FlakyOnceThenData<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if !self.interrupted_once {
+                self.interrupted_once = true;
+                return Err(std::io::Error::from(std::io::ErrorKind::Interrupted));
+            }
+            let n = buf.len().min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_io_reader_delegates_to_inner_read() {
+        let mut reader = IoReader::new(b"hello".as_slice());
+        let mut buf = [0u8; 8];
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"hello");
+    }
+
+    #[test]
+    fn test_io_reader_retries_on_interrupted() {
+        let mut reader = IoReader::new(FlakyOnceThenData {
+            data: b"world",
+            interrupted_once: false,
+        });
+        let mut buf = [0u8; 8];
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"world");
+    }
+
+    #[test]
+    fn test_io_reader_zero_read_means_end_of_stream() {
+        let mut reader = IoReader::new(b"".as_slice());
+        let mut buf = [0u8; 8];
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+}
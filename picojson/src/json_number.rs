@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use core::hash::{Hash, Hasher};
 use core::ops::Deref;
 use core::str::FromStr;
 
@@ -11,6 +12,7 @@ use crate::int_parser::from_ascii_i32;
 use crate::int_parser::from_ascii_i64;
 #[cfg(feature = "int8")]
 use crate::int_parser::from_ascii_i8;
+use crate::int_parser::from_ascii_u64;
 
 // Type alias for the configured integer type
 #[cfg(feature = "int8")]
@@ -20,25 +22,190 @@ type ConfiguredInt = i32;
 #[cfg(feature = "int64")]
 type ConfiguredInt = i64;
 
+// Type alias for the configured float type. Defaults to `f64`; the
+// `float32` feature narrows it to `f32` for targets (e.g. Cortex-M4F) that
+// only have single-precision FPU hardware and want to avoid soft-float
+// `f64` routines and halve `NumberResult::Float`'s payload.
+#[cfg(feature = "float32")]
+type ConfiguredFloat = f32;
+#[cfg(not(feature = "float32"))]
+type ConfiguredFloat = f64;
+
 /// Represents the parsed result of a JSON number.
 ///
 /// Depending on crate configuration for float and integer support,
-/// variants like `FloatDisabled`, `FloatSkipped` and `FloatTruncated` are
-/// conditionally available.
+/// variants like `FloatDisabled`, `FloatSkipped`, `FloatTruncated`, and
+/// `Decomposed` are only produced under some configurations.
 #[derive(Debug, PartialEq)]
 pub enum NumberResult {
     /// Integer that fits in the configured integer type
     Integer(ConfiguredInt),
+    /// A non-negative integer that overflowed the configured integer type
+    /// but still fits in a `u64`, e.g. `18446744073709551615` under the
+    /// `int64` feature (too big for `i64`, not for `u64`) or `200` under
+    /// `int8` (too big for `i8`, not for `u64`).
+    UnsignedInteger(u64),
     /// Integer too large for configured type (use raw string for exact representation)
     IntegerOverflow,
-    /// Float value (only available with float feature)
-    Float(f64),
+    /// Float value (only available with float feature). `f64` unless the
+    /// `float32` feature narrows it to `f32` for single-precision-only
+    /// targets.
+    Float(ConfiguredFloat),
     /// Float parsing disabled - behavior depends on configuration
     FloatDisabled,
+    /// A decimal/scientific-notation number that denotes an exact integer
+    /// (e.g. `1e3`, `2.5e1`, `100e-2`), realized without any float math in
+    /// the default no-float configuration. Only produced when every digit
+    /// implied by the exponent/fraction actually fits -- `3.14` still falls
+    /// through to [`FloatDisabled`](Self::FloatDisabled).
+    IntegerFromExponent(i64),
     /// Float encountered but skipped due to float-skip configuration
     FloatSkipped,
     /// Float truncated to integer due to float-truncate configuration
     FloatTruncated(ConfiguredInt),
+    /// A decimal/scientific-notation number decomposed into its raw digit
+    /// spans and sign, produced only with the `float-decompose` configuration
+    /// (float parsing disabled, but full precision wanted without float
+    /// math or the lossy fallbacks `float-skip`/`float-truncate` apply).
+    /// Equivalent to [`JsonNumber::raw_decimal`], but carried in the parsed
+    /// result itself rather than recomputed from [`JsonNumber::as_raw_str`]
+    /// on demand.
+    Decomposed {
+        /// Whether the number has a leading `-`.
+        negative: bool,
+        /// Byte range of the integer-part digits (before any `.`).
+        integer_part: core::ops::Range<usize>,
+        /// Byte range of the fraction-part digits (after `.`, before any
+        /// exponent). Empty if there's no fraction.
+        fraction_part: core::ops::Range<usize>,
+        /// The `e`/`E` exponent, sign folded in. `0` if there's no exponent.
+        /// Saturates to [`i64::MAX`]/[`i64::MIN`] if the exponent's own
+        /// digits don't fit in an `i64` -- see [`RawDecimal::exp10`].
+        exponent: i64,
+    },
+}
+
+impl NumberResult {
+    /// The realized numeric value, widened to `f64`, for variants that
+    /// carry one -- `None` for `IntegerOverflow`/`FloatDisabled`/
+    /// `FloatSkipped`/`Decomposed`, which don't. Used by [`JsonNumber`]'s
+    /// `Eq`/`Hash`/`Ord` impls to compare/hash numbers by value instead of
+    /// by variant.
+    ///
+    /// `f64` can't represent every `i64`/`u64` exactly once past 2^53, so
+    /// this is lossy the same way [`JsonNumber::as_f64`] already is for an
+    /// overflowing integer -- deliberately: a single `f64` comparand (rather
+    /// than mixing exact-integer and float comparisons) is what lets the
+    /// resulting `Eq`/`Hash` impls stay mutually consistent, and `NumberResult`
+    /// itself can't derive `Eq`/`Hash` directly since one of its variants is
+    /// an `f64`.
+    fn numeric_value(&self) -> Option<f64> {
+        match self {
+            NumberResult::Integer(val) => Some(*val as f64),
+            NumberResult::UnsignedInteger(val) => Some(*val as f64),
+            #[cfg(feature = "float")]
+            NumberResult::Float(val) => Some(*val as f64),
+            #[cfg(not(feature = "float"))]
+            NumberResult::IntegerFromExponent(val) => Some(*val as f64),
+            #[cfg(all(not(feature = "float"), feature = "float-truncate"))]
+            NumberResult::FloatTruncated(val) => Some(*val as f64),
+            _ => None,
+        }
+    }
+}
+
+/// A JSON number's exact decimal text, decomposed into sign, integer/fraction
+/// digit spans (byte ranges into the number's own text, as returned by
+/// [`JsonNumber::as_raw_str`]), and a signed decimal exponent -- see
+/// [`JsonNumber::raw_decimal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawDecimal {
+    /// Whether the number has a leading `-`.
+    pub negative: bool,
+    /// Byte range of the integer-part digits (before any `.`), excluding
+    /// the sign. Never empty -- JSON numbers always have at least one
+    /// integer digit.
+    pub int_digits: core::ops::Range<usize>,
+    /// Byte range of the fraction-part digits (after the `.`, before any
+    /// exponent). Empty if there's no fraction.
+    pub frac_digits: core::ops::Range<usize>,
+    /// The `e`/`E` exponent, sign folded in. `0` if there's no exponent.
+    /// JSON's grammar puts no digit-count limit on an exponent, so one can
+    /// have more digits than fit in an `i64` (e.g. `1e99999999999999999999`)
+    /// -- that can't be represented exactly here, so it's saturated to
+    /// [`i64::MAX`]/[`i64::MIN`] (sign per the exponent's own `-`/`+`/none)
+    /// as an overflow sentinel rather than silently rounding down to a
+    /// plausible-looking but wrong small exponent.
+    pub exp10: i64,
+}
+
+impl RawDecimal {
+    /// Decomposes `s`, the exact text of a JSON number already validated by
+    /// the tokenizer's grammar (optional leading `-` or, in the tokenizer's
+    /// lenient mode, `+`; no leading zeros; optional fraction/exponent).
+    fn parse(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+
+        let negative = bytes.first() == Some(&b'-');
+        if negative || bytes.first() == Some(&b'+') {
+            i += 1;
+        }
+
+        let int_start = i;
+        while matches!(bytes.get(i), Some(b'0'..=b'9')) {
+            i += 1;
+        }
+        let int_digits = int_start..i;
+
+        let frac_digits = if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            let frac_start = i;
+            while matches!(bytes.get(i), Some(b'0'..=b'9')) {
+                i += 1;
+            }
+            frac_start..i
+        } else {
+            i..i
+        };
+
+        let exp10 = if matches!(bytes.get(i), Some(b'e' | b'E')) {
+            i += 1;
+            let exp_negative = match bytes.get(i) {
+                Some(b'-') => {
+                    i += 1;
+                    true
+                }
+                Some(b'+') => {
+                    i += 1;
+                    false
+                }
+                _ => false,
+            };
+            let exp_start = i;
+            while matches!(bytes.get(i), Some(b'0'..=b'9')) {
+                i += 1;
+            }
+            match s[exp_start..i].parse::<i64>() {
+                Ok(magnitude) if exp_negative => -magnitude,
+                Ok(magnitude) => magnitude,
+                // Too many digits to fit in an `i64` -- saturate to a
+                // sentinel instead of defaulting to 0, which would silently
+                // turn e.g. `1e99999999999999999999` into `exp10 = 0`.
+                Err(_) if exp_negative => i64::MIN,
+                Err(_) => i64::MAX,
+            }
+        } else {
+            0
+        };
+
+        RawDecimal {
+            negative,
+            int_digits,
+            frac_digits,
+            exp10,
+        }
+    }
 }
 
 /// Represents a JSON number with both exact string representation and parsed value.
@@ -47,7 +214,21 @@ pub enum NumberResult {
 /// convenient access to parsed representations based on compilation features.
 ///
 /// Lifetimes: 'a is the input slice lifetime, 'b is the scratch/copy buffer lifetime
-#[derive(Debug, PartialEq)]
+///
+/// `Eq`/`Hash`/`Ord` (below) compare by canonical numeric value rather than
+/// by variant/text, so this type can key a map or sit in a sorted structure
+/// the way a JSON number naturally should -- see [`Self::numeric_sort_key`]
+/// for exactly what "canonical" means here. `PartialEq`/`PartialOrd` are
+/// hand-written to match rather than derived, since derived structural
+/// equality would make `1` and `1.0` compare unequal despite being the same
+/// number, and derived `Eq`/`Ord` aren't available at all: `NumberResult`
+/// has an `f64` variant, and `f64` implements neither trait (a reflexivity
+/// problem for `Eq`, a total-order problem for `Ord` -- both because of
+/// `NaN`). Going through `Eq`/`Hash`/`Ord` here is sound only because this
+/// crate's `parse_float` never produces a `NaN`/infinite `Float` -- an
+/// out-of-range literal becomes `IntegerOverflow` instead -- so every
+/// realized value is finite and totally ordered in practice.
+#[derive(Debug)]
 pub enum JsonNumber<'a, 'b> {
     /// A raw slice from the original input, used when no copying is needed.
     Borrowed { raw: &'a str, parsed: NumberResult },
@@ -55,6 +236,72 @@ pub enum JsonNumber<'a, 'b> {
     Copied { raw: &'b str, parsed: NumberResult },
 }
 
+impl JsonNumber<'_, '_> {
+    /// The key used to compare/hash/order two `JsonNumber`s: the realized
+    /// numeric value (see [`NumberResult::numeric_value`]) when there is
+    /// one, else the exact raw text for variants that don't carry a usable
+    /// number (`IntegerOverflow`, `FloatDisabled`, `FloatSkipped`,
+    /// `Decomposed`) -- so those still compare/hash/order consistently
+    /// (by their text) instead of being arbitrarily unequal to everything,
+    /// while still never colliding with a realized numeric value.
+    fn numeric_sort_key(&self) -> Result<f64, &str> {
+        self.parsed().numeric_value().ok_or_else(|| self.as_raw_str())
+    }
+}
+
+impl PartialEq for JsonNumber<'_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.numeric_sort_key() == other.numeric_sort_key()
+    }
+}
+
+/// Sound per the finite-float invariant documented on [`JsonNumber`]:
+/// `numeric_sort_key` never produces a `NaN`, so `PartialEq::eq` above is
+/// already reflexive.
+impl Eq for JsonNumber<'_, '_> {}
+
+impl Hash for JsonNumber<'_, '_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        match self.numeric_sort_key() {
+            Ok(val) => {
+                // Tag, so a numeric key and a raw-text key can never collide
+                // with each other even if their bytes happen to match.
+                state.write_u8(0);
+                // Normalize -0.0 to 0.0 so they hash the same, matching
+                // their `==` equality.
+                let val = if val == 0.0 { 0.0_f64 } else { val };
+                state.write_u64(val.to_bits());
+            }
+            Err(raw) => {
+                state.write_u8(1);
+                raw.hash(state);
+            }
+        }
+    }
+}
+
+impl PartialOrd for JsonNumber<'_, '_> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonNumber<'_, '_> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        use core::cmp::Ordering;
+        match (self.numeric_sort_key(), other.numeric_sort_key()) {
+            (Ok(a), Ok(b)) => a
+                .partial_cmp(&b)
+                .expect("JsonNumber's finite-float invariant guarantees a total order"),
+            // Numbers with a realized value sort before ones that only have
+            // raw text to fall back on.
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(a), Err(b)) => a.cmp(b),
+        }
+    }
+}
+
 impl JsonNumber<'_, '_> {
     /// Create a JsonNumber::Borrowed from a byte slice.
     ///
@@ -88,6 +335,27 @@ impl JsonNumber<'_, '_> {
     }
 
     /// Get the parsed NumberResult.
+    ///
+    /// This -- together with [`Self::as_i64`]/[`Self::as_u64`]/[`Self::as_f64`]
+    /// and, for numbers too large for any of those, [`Self::raw_decimal`] --
+    /// is this crate's take on a `no_std`, allocation-free typed decode of
+    /// a number token's byte span: no heap, no re-parsing by the caller,
+    /// and integer precision kept whenever the literal is actually an
+    /// integer rather than always widening to `f64`. It's computed once
+    /// up front in [`Self::from_slice`] (a single pass over the digits, falling
+    /// back from the configured integer width to `u64`/`f64` on overflow
+    /// the same way a hand-rolled mantissa accumulator would) rather than
+    /// lazily, since almost every caller asks for it.
+    ///
+    /// Doing this eagerly doesn't cost a value like `1e400` or a 30-digit
+    /// integer any precision: [`Self::as_raw_str`]/[`Self::raw_decimal`]
+    /// slice the original text directly and are unaffected by whatever
+    /// `parsed` came back as, and [`Self::parse`] hands that same text to a
+    /// caller-supplied `FromStr` (a bignum or decimal type) rather than
+    /// going through this field at all. What eager parsing costs is some
+    /// wasted arithmetic for a caller who only ever wanted the raw text --
+    /// acceptable here the same way the rest of this crate favors a single
+    /// up-front pass over deferred, field-by-field state.
     pub fn parsed(&self) -> &NumberResult {
         match self {
             JsonNumber::Borrowed { parsed, .. } => parsed,
@@ -97,23 +365,130 @@ impl JsonNumber<'_, '_> {
 
     /// Get the number as the configurable integer type if it's an integer that fits.
     pub fn as_int(&self) -> Option<ConfiguredInt> {
+        self.as_integer::<ConfiguredInt>()
+    }
+
+    /// Like [`as_int`](Self::as_int), but narrows to any integer type `T`
+    /// instead of only the crate's configured `ConfiguredInt` width -- a
+    /// caller built with `int64` who only needs a `u8` (or an `i16`, or a
+    /// non-configured width entirely) can go straight there with a checked
+    /// conversion, instead of re-parsing [`as_raw_str`](Self::as_raw_str)
+    /// by hand. Returns `None` either when the number isn't an integer (the
+    /// same cases [`as_int`](Self::as_int) returns `None` for) or when it is
+    /// one but doesn't fit in `T`.
+    pub fn as_integer<T: TryFrom<ConfiguredInt>>(&self) -> Option<T> {
         let parsed = self.parsed();
-        match parsed {
-            NumberResult::Integer(val) => Some(*val),
+        let value = match parsed {
+            NumberResult::Integer(val) => *val,
             #[cfg(all(not(feature = "float"), feature = "float-truncate"))]
-            NumberResult::FloatTruncated(val) => Some(*val),
-            _ => None,
-        }
+            NumberResult::FloatTruncated(val) => *val,
+            #[cfg(not(feature = "float"))]
+            NumberResult::IntegerFromExponent(val) => ConfiguredInt::try_from(*val).ok()?,
+            _ => return None,
+        };
+        T::try_from(value).ok()
     }
 
     /// Get the number as an f64 if float support is enabled.
-    /// For integers, converts to f64. For overflowing integers, returns None.
+    /// For integers, converts to f64. Integers too wide for the configured
+    /// integer type (`UnsignedInteger`/`IntegerOverflow`) fall back to
+    /// re-parsing [`as_raw_str`](Self::as_raw_str) as an `f64` directly --
+    /// lossy the same way any integer-to-double widening is once the value
+    /// exceeds 2^53, but still the double a caller asking for one almost
+    /// always wants, rather than `None` for a number that parsed just fine.
+    /// Callers who need the exact value should use [`as_int`](Self::as_int),
+    /// [`as_u64`](Self::as_u64)/[`as_i64`](Self::as_i64), or
+    /// [`as_raw_str`](Self::as_raw_str) instead -- those keep reporting
+    /// failure/exact text rather than silently rounding.
     #[cfg(feature = "float")]
     pub fn as_f64(&self) -> Option<f64> {
         let parsed = self.parsed();
         match parsed {
-            NumberResult::Float(val) => Some(*val),
+            NumberResult::Float(val) => Some(*val as f64),
             NumberResult::Integer(val) => Some(*val as f64),
+            NumberResult::UnsignedInteger(val) => Some(*val as f64),
+            NumberResult::IntegerOverflow => self.as_raw_str().parse::<f64>().ok(),
+            _ => None,
+        }
+    }
+
+    /// Like [`as_f64`](Self::as_f64), but narrows to `f32` -- the natural
+    /// accessor under the `float32` feature, where [`NumberResult::Float`]
+    /// already stores an `f32` and this avoids a widen-then-narrow round
+    /// trip through `f64`. Available under every `float` configuration
+    /// (not just `float32`): a default `f64`-precision build can still ask
+    /// for `f32` explicitly, the same way [`as_i64`](Self::as_i64) works
+    /// regardless of the configured integer width.
+    #[cfg(feature = "float")]
+    pub fn as_f32(&self) -> Option<f32> {
+        let parsed = self.parsed();
+        match parsed {
+            NumberResult::Float(val) => Some(*val as f32),
+            NumberResult::Integer(val) => Some(*val as f32),
+            NumberResult::UnsignedInteger(val) => Some(*val as f32),
+            NumberResult::IntegerOverflow => self.as_raw_str().parse::<f32>().ok(),
+            _ => None,
+        }
+    }
+
+    /// Like [`as_f64`](Self::as_f64), but available under every number
+    /// configuration (not just the `float` feature) and, on failure,
+    /// reports *why* instead of folding every case into `None`: the same
+    /// [`ParseError::NumericOverflow`]/[`ParseError::FloatNotAllowed`]
+    /// values [`as_i64`](Self::as_i64)/[`as_u64`](Self::as_u64) and
+    /// [`parse_float`] already return, rather than a second, parallel error
+    /// type -- a caller logging a failed conversion gets the same variant
+    /// names regardless of which accessor it came from.
+    ///
+    /// [`NumberResult::FloatDisabled`]/[`NumberResult::FloatSkipped`]/
+    /// [`NumberResult::Decomposed`] (the `not(feature = "float")`
+    /// configurations that don't realize a float at all) map to
+    /// `FloatNotAllowed`: the number parsed fine, but this build was
+    /// configured not to produce a float for it. The raw text is always
+    /// still available via [`as_raw_str`](Self::as_raw_str) on `self`, so
+    /// the error doesn't need to carry a copy of it.
+    pub fn try_as_f64(&self) -> Result<f64, ParseError> {
+        match self.parsed() {
+            NumberResult::Integer(val) => Ok(*val as f64),
+            NumberResult::UnsignedInteger(val) => Ok(*val as f64),
+            // `f64::from_str` rounds an out-of-range literal to infinity
+            // rather than failing, so check the result, not just the parse.
+            NumberResult::IntegerOverflow => match self.as_raw_str().parse::<f64>() {
+                Ok(val) if val.is_finite() => Ok(val),
+                _ => Err(ParseError::NumericOverflow),
+            },
+            NumberResult::Float(val) => Ok(*val as f64),
+            NumberResult::IntegerFromExponent(val) => Ok(*val as f64),
+            NumberResult::FloatTruncated(val) => Ok(*val as f64),
+            NumberResult::FloatDisabled | NumberResult::FloatSkipped => {
+                Err(ParseError::FloatNotAllowed)
+            }
+            NumberResult::Decomposed { .. } => Err(ParseError::FloatNotAllowed),
+        }
+    }
+
+    /// Get the number as an `f64`, guaranteeing the correctly-rounded value
+    /// for the decimal text -- the same guarantee serde_json's
+    /// `float_roundtrip` feature adds on top of its default fast-but-lossy
+    /// float parser.
+    ///
+    /// This crate has no fast-path float parser to opt out of: [`as_f64`](Self::as_f64)
+    /// already goes through `core`'s `f64: FromStr` impl, which itself
+    /// implements a correctly-rounded decimal-to-binary conversion (the
+    /// Eisel-Lemire algorithm, falling back to exact big-integer arithmetic
+    /// for the hard cases it can't resolve directly) rather than a
+    /// truncating shortcut. So `as_f64_exact` parses exactly the same way
+    /// `as_f64` does; it exists under its own feature so callers can require
+    /// the roundtrip guarantee by name instead of having to trust that
+    /// property of `as_f64` implicitly.
+    #[cfg(feature = "float-roundtrip")]
+    pub fn as_f64_exact(&self) -> Option<f64> {
+        let parsed = self.parsed();
+        match parsed {
+            NumberResult::Float(val) => Some(*val as f64),
+            NumberResult::Integer(val) => Some(*val as f64),
+            NumberResult::UnsignedInteger(val) => Some(*val as f64),
+            NumberResult::IntegerOverflow => self.as_raw_str().parse::<f64>().ok(),
             _ => None,
         }
     }
@@ -127,18 +502,129 @@ impl JsonNumber<'_, '_> {
         }
     }
 
+    /// Same as [`as_str`](Self::as_str), under the name used by
+    /// serde_json's `arbitrary_precision` feature. The tokenizer already
+    /// validated this as well-formed JSON number grammar (optional leading
+    /// `-`, no leading zeros, optional fraction/exponent), so it's safe to
+    /// hand to your own bignum or fixed-point parser even when it overflows
+    /// every accessor below.
+    pub fn as_raw_str(&self) -> &str {
+        self.as_str()
+    }
+
+    /// Structured decomposition of [`as_raw_str`](Self::as_raw_str) into
+    /// sign, integer/fraction digit spans, and a signed decimal exponent --
+    /// computed purely from the token's already-validated grammar, with no
+    /// float math and no loss of precision. For a no-FPU target that can't
+    /// use [`as_f64`](Self::as_f64) (or doesn't trust its rounding), this is
+    /// everything a fixed-point or decimal type needs to reconstruct the
+    /// exact value, for numbers of any magnitude `parsed()` can't represent
+    /// natively (`FloatDisabled`, `IntegerOverflow`, ...) as well as ones it
+    /// can -- except the exponent itself, in the degenerate case where its
+    /// own digit string doesn't fit in an `i64`; see
+    /// [`RawDecimal::exp10`](RawDecimal::exp10).
+    pub fn raw_decimal(&self) -> RawDecimal {
+        RawDecimal::parse(self.as_raw_str())
+    }
+
+    /// Parses this number as a `u64`, independent of the crate's configured
+    /// integer width (the `int8`/`int32`/`int64` feature). Returns
+    /// [`ParseError::InvalidNumber`] if the text isn't an integer (e.g. it
+    /// has a fraction or exponent), or [`ParseError::NumericOverflow`] if
+    /// it doesn't fit in a `u64` (any negative integer included).
+    ///
+    /// Together with [`Self::as_i64`]/[`Self::as_f64`]/[`Self::is_integer`],
+    /// this is the typed, allocation-free numeric access a caller coming from
+    /// `as_str()`-only parsing expects. It reports overflow as a distinct
+    /// `Result::Err` rather than folding "too big" and "not an integer at
+    /// all" into the same `None` the way an `Option`-returning accessor
+    /// would have to -- a caller who only cares that parsing failed can still
+    /// discard the error, but one who wants to log or branch on *why* doesn't
+    /// have to re-derive it from [`Self::as_raw_str`].
+    pub fn as_u64(&self) -> Result<u64, ParseError> {
+        if !self.is_integer() {
+            return Err(ParseError::InvalidNumber);
+        }
+        self.as_raw_str()
+            .parse()
+            .map_err(|_| ParseError::NumericOverflow)
+    }
+
+    /// Parses this number as an `i64`, independent of the crate's configured
+    /// integer width (the `int8`/`int32`/`int64` feature). Returns
+    /// [`ParseError::InvalidNumber`] if the text isn't an integer (e.g. it
+    /// has a fraction or exponent), or [`ParseError::NumericOverflow`] if it
+    /// doesn't fit in an `i64`.
+    pub fn as_i64(&self) -> Result<i64, ParseError> {
+        if !self.is_integer() {
+            return Err(ParseError::InvalidNumber);
+        }
+        self.as_raw_str()
+            .parse()
+            .map_err(|_| ParseError::NumericOverflow)
+    }
+
     /// Parse the number as a custom type using the exact string representation.
     /// This allows using external libraries like BigDecimal, arbitrary precision, etc.
     pub fn parse<T: FromStr>(&self) -> Result<T, T::Err> {
         T::from_str(self.as_str())
     }
 
+    /// Parses this number as an `i128`, independent of the crate's
+    /// configured integer width. Same error behavior as [`as_u64`](Self::as_u64)/
+    /// [`as_i64`](Self::as_i64): [`ParseError::InvalidNumber`] if the text
+    /// isn't an integer, [`ParseError::NumericOverflow`] if it doesn't fit.
+    pub fn as_i128(&self) -> Result<i128, ParseError> {
+        if !self.is_integer() {
+            return Err(ParseError::InvalidNumber);
+        }
+        self.as_raw_str()
+            .parse()
+            .map_err(|_| ParseError::NumericOverflow)
+    }
+
+    /// Parses this number as a `u128`, independent of the crate's
+    /// configured integer width. Same error behavior as [`as_u64`](Self::as_u64)/
+    /// [`as_i64`](Self::as_i64): [`ParseError::InvalidNumber`] if the text
+    /// isn't an integer, [`ParseError::NumericOverflow`] if it doesn't fit
+    /// (any negative integer included).
+    pub fn as_u128(&self) -> Result<u128, ParseError> {
+        if !self.is_integer() {
+            return Err(ParseError::InvalidNumber);
+        }
+        self.as_raw_str()
+            .parse()
+            .map_err(|_| ParseError::NumericOverflow)
+    }
+
+    /// Generic widened-integer accessor: parses this number's raw text as
+    /// any `T: FromStr`, so a caller on a target where even `u64`/`i128`
+    /// are the wrong width (or who wants a non-standard integer type
+    /// entirely) can pick exactly the one they need without re-deriving
+    /// [`as_raw_str`](Self::as_raw_str) parsing by hand. `None` covers both
+    /// "not an integer" and "doesn't fit `T`" -- unlike [`as_u64`](Self::as_u64)/
+    /// [`as_i64`](Self::as_i64)/[`as_i128`](Self::as_i128)/[`as_u128`](Self::as_u128),
+    /// this can't distinguish the two, since `T::Err` isn't assumed to carry
+    /// that distinction the way `ParseIntError` does.
+    ///
+    /// Parses from the same raw text [`as_raw_str`](Self::as_raw_str)
+    /// returns, so overflow is exact rather than routed through a
+    /// potentially-lossy intermediate like `f64`.
+    pub fn try_as<T: FromStr>(&self) -> Option<T> {
+        if !self.is_integer() {
+            return None;
+        }
+        self.as_raw_str().parse().ok()
+    }
+
     /// Check if this number represents an integer (no decimal point or exponent).
     pub fn is_integer(&self) -> bool {
         let parsed = self.parsed();
         matches!(
             parsed,
-            NumberResult::Integer(_) | NumberResult::IntegerOverflow
+            NumberResult::Integer(_)
+                | NumberResult::UnsignedInteger(_)
+                | NumberResult::IntegerOverflow
         )
     }
 
@@ -149,6 +635,14 @@ impl JsonNumber<'_, '_> {
     pub fn is_float(&self) -> bool {
         !self.is_integer()
     }
+
+    /// Returns true if [`as_u64`](Self::as_u64) would succeed: this is a
+    /// non-negative integer that fits in a `u64`, independent of the
+    /// crate's configured integer width. Mirrors serde_json's
+    /// `Number::is_u64`.
+    pub fn is_u64(&self) -> bool {
+        self.as_u64().is_ok()
+    }
 }
 
 impl AsRef<str> for JsonNumber<'_, '_> {
@@ -175,10 +669,18 @@ impl core::fmt::Display for JsonNumber<'_, '_> {
         };
         match parsed {
             NumberResult::Integer(val) => write!(f, "{val}"),
-            #[cfg(feature = "float")]
+            NumberResult::UnsignedInteger(val) => write!(f, "{val}"),
+            // Under `float32`, `val` is already rounded to `f32` precision,
+            // so re-serializing it would silently drop digits the raw text
+            // still has; show the exact original token instead.
+            #[cfg(feature = "float32")]
+            NumberResult::Float(_val) => f.write_str(raw),
+            #[cfg(all(feature = "float", not(feature = "float32")))]
             NumberResult::Float(val) => write!(f, "{val}"),
             #[cfg(all(not(feature = "float"), feature = "float-truncate"))]
             NumberResult::FloatTruncated(val) => write!(f, "{}", val),
+            #[cfg(not(feature = "float"))]
+            NumberResult::IntegerFromExponent(val) => write!(f, "{val}"),
             // For overflow, disabled, or skipped cases, show the exact raw string
             // This preserves full precision and is least surprising to users
             _ => f.write_str(raw),
@@ -199,6 +701,13 @@ pub fn is_integer(bytes: &[u8]) -> bool {
 
 /// Parses an integer byte slice into NumberResult using configured integer type.
 /// JSON numbers are pure ASCII, so this avoids unnecessary UTF-8 string processing.
+///
+/// A literal that overflows the configured signed width (`i8`/`i32`/`i64`,
+/// whichever is selected) gets a second attempt as a `u64` before falling
+/// back to [`NumberResult::IntegerOverflow`] -- see
+/// [`NumberResult::UnsignedInteger`]. This isn't limited to the `int64`
+/// feature: `200` overflows `i8` just as surely as `18446744073709551615`
+/// overflows `i64`, and both fit comfortably in the same `u64` fallback.
 pub const fn parse_integer(bytes: &[u8]) -> NumberResult {
     #[cfg(feature = "int8")]
     let result = from_ascii_i8(bytes);
@@ -209,12 +718,24 @@ pub const fn parse_integer(bytes: &[u8]) -> NumberResult {
 
     match result {
         Ok(val) => NumberResult::Integer(val),
-        Err(_) => NumberResult::IntegerOverflow,
+        Err(_) => match from_ascii_u64(bytes) {
+            Ok(val) => NumberResult::UnsignedInteger(val),
+            Err(_) => NumberResult::IntegerOverflow,
+        },
     }
 }
 
 /// Parses a float byte slice into NumberResult (only available with float feature).
 /// JSON numbers are pure ASCII, so this avoids unnecessary UTF-8 string processing.
+///
+/// This goes through `core`'s `f64: FromStr` impl (or, under the `float32`
+/// feature, `f32: FromStr`), which is available (and already
+/// correctly-rounded, via Eisel-Lemire with an exact big-integer fallback
+/// for the inputs it can't resolve directly) without `std` -- see
+/// [`JsonNumber::as_f64_exact`] for the longer rationale. There's no
+/// separate lossy fast path here to harden against long decimals or large
+/// exponents; `parse_float` and `as_f64_exact` parse the same bytes the
+/// same way.
 #[cfg(feature = "float")]
 pub fn parse_float(bytes: &[u8]) -> NumberResult {
     // Convert bytes to str - JSON numbers are guaranteed ASCII
@@ -222,7 +743,7 @@ pub fn parse_float(bytes: &[u8]) -> NumberResult {
         Ok(s) => s,
         Err(_) => return NumberResult::IntegerOverflow, // Invalid UTF-8 means invalid number
     };
-    match f64::from_str(s) {
+    match ConfiguredFloat::from_str(s) {
         Ok(val) if val.is_finite() => NumberResult::Float(val),
         _ => NumberResult::IntegerOverflow, // Infinity/NaN -> treat as overflow, use raw string
     }
@@ -249,32 +770,146 @@ pub fn parse_float(bytes: &[u8]) -> Result<NumberResult, ParseError> {
     }
     #[cfg(feature = "float-truncate")]
     {
-        // Scientific notation (1e3, 2.5e-1) would require float math to evaluate properly.
-        // For embedded targets avoiding float math, we error on scientific notation.
-        if s.contains(['e', 'E']) {
-            return Err(ParseError::InvalidNumber);
-        }
-
-        // Extract integer part before decimal point for simple decimals like 1.5 → 1
-        let int_part = if let Some(dot_pos) = s.find('.') {
-            s.get(..dot_pos).unwrap_or(s)
-        } else {
-            s // Should not happen since we detected it's a float, but handle gracefully
-        };
-
-        match ConfiguredInt::from_str(int_part) {
-            Ok(val) => Ok(NumberResult::FloatTruncated(val)),
-            Err(_) => Ok(NumberResult::IntegerOverflow),
+        // Scientific notation (1e3, 2.5e-1) needs no float math to evaluate:
+        // the exponent is just a power-of-ten shift on the digits, applied
+        // with pure integer arithmetic the same way a plain decimal's
+        // fraction already is (1.5 -> 1).
+        match scale_decimal_to_configured_int(s) {
+            Some(val) => Ok(NumberResult::FloatTruncated(val)),
+            None => Ok(NumberResult::IntegerOverflow),
         }
     }
+    #[cfg(feature = "float-decompose")]
+    {
+        let decimal = RawDecimal::parse(s);
+        Ok(NumberResult::Decomposed {
+            negative: decimal.negative,
+            integer_part: decimal.int_digits,
+            fraction_part: decimal.frac_digits,
+            exponent: decimal.exp10,
+        })
+    }
     #[cfg(not(any(
         feature = "float-error",
         feature = "float-skip",
-        feature = "float-truncate"
+        feature = "float-truncate",
+        feature = "float-decompose"
     )))]
     {
-        let _ = s; // Acknowledge parameter usage
-        Ok(NumberResult::FloatDisabled)
+        Ok(match try_integer_from_exponent(s) {
+            Some(val) => NumberResult::IntegerFromExponent(val),
+            None => NumberResult::FloatDisabled,
+        })
+    }
+}
+
+/// Evaluates a decimal/scientific-notation literal as `ConfiguredInt` using
+/// pure integer scaling, for the `float-truncate` configuration: `1e3` ->
+/// `1000`, `2.5e-1` -> `0`. Reuses [`RawDecimal::parse`]'s sign/digit-span
+/// decomposition, then concatenates the integer and fraction digits into one
+/// mantissa and shifts it by the net power of ten (`exponent - fraction
+/// length`) -- multiplying for a positive shift, truncating-toward-zero
+/// integer division for a negative one, which is what already gives a plain
+/// decimal like `1.5` its existing `1` result here (net shift of `-1`).
+/// Digit accumulation and each multiply are `checked_*`, so a mantissa or
+/// shift too wide for `ConfiguredInt` reports `None` (-> `IntegerOverflow`)
+/// rather than wrapping; shift counts are capped since any more than a
+/// handful of decimal digits already overflows every configured width.
+#[cfg(feature = "float-truncate")]
+fn scale_decimal_to_configured_int(s: &str) -> Option<ConfiguredInt> {
+    let decimal = RawDecimal::parse(s);
+
+    let mut magnitude: ConfiguredInt = 0;
+    for byte in s[decimal.int_digits]
+        .bytes()
+        .chain(s[decimal.frac_digits.clone()].bytes())
+    {
+        let digit = ConfiguredInt::try_from(byte - b'0').ok()?;
+        magnitude = magnitude.checked_mul(10)?.checked_add(digit)?;
+    }
+    if magnitude == 0 {
+        return Some(0);
+    }
+
+    let net_exponent = decimal.exp10.checked_sub(decimal.frac_digits.len() as i64)?;
+    const MAX_SHIFTS: i64 = 20; // beyond this every ConfiguredInt width has already overflowed
+    let magnitude = if net_exponent >= 0 {
+        if net_exponent > MAX_SHIFTS {
+            return None;
+        }
+        let mut m = magnitude;
+        for _ in 0..net_exponent {
+            m = m.checked_mul(10)?;
+        }
+        m
+    } else {
+        let mut m = magnitude;
+        for _ in 0..(-net_exponent).min(MAX_SHIFTS) {
+            m /= 10;
+            if m == 0 {
+                break;
+            }
+        }
+        m
+    };
+
+    Some(if decimal.negative { -magnitude } else { magnitude })
+}
+
+/// Realizes a decimal/scientific-notation number as an exact `i64` without
+/// any float math, for the default no-float configuration: `1e3`, `2.5e1`,
+/// and `100e-2` become `1000`, `25`, and `1`, while a number with a nonzero
+/// fractional remainder (e.g. `3.14`) or one that doesn't fit returns
+/// `None` so the caller falls back to [`NumberResult::FloatDisabled`].
+#[cfg(not(any(
+    feature = "float",
+    feature = "float-error",
+    feature = "float-skip",
+    feature = "float-truncate",
+    feature = "float-decompose"
+)))]
+fn try_integer_from_exponent(s: &str) -> Option<i64> {
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+
+    let (mantissa, exponent) = match rest.split_once(['e', 'E']) {
+        Some((mantissa, exp)) => (mantissa, exp.parse::<i64>().ok()?),
+        None => (rest, 0),
+    };
+
+    let (int_part, frac_part) = match mantissa.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, frac_part),
+        None => (mantissa, ""),
+    };
+
+    let mut digits: u64 = 0;
+    for byte in int_part.bytes().chain(frac_part.bytes()) {
+        let digit = u64::from(byte.checked_sub(b'0')?);
+        digits = digits.checked_mul(10)?.checked_add(digit)?;
+    }
+
+    let net_exponent = exponent.checked_sub(frac_part.len() as i64)?;
+    let magnitude = if net_exponent >= 0 {
+        let pow = 10u64.checked_pow(u32::try_from(net_exponent).ok()?)?;
+        digits.checked_mul(pow)?
+    } else {
+        let pow = 10u64.checked_pow(u32::try_from(-net_exponent).ok()?)?;
+        if digits % pow != 0 {
+            return None;
+        }
+        digits / pow
+    };
+
+    if negative {
+        if magnitude == i64::MAX as u64 + 1 {
+            Some(i64::MIN)
+        } else {
+            i64::try_from(magnitude).ok().map(|v| -v)
+        }
+    } else {
+        i64::try_from(magnitude).ok()
     }
 }
 
@@ -311,6 +946,27 @@ mod tests {
         assert!(!number.is_float());
     }
 
+    #[test]
+    fn test_as_integer_narrows_to_an_arbitrary_width() {
+        let number = JsonNumber::Borrowed {
+            raw: "42",
+            parsed: NumberResult::Integer(42),
+        };
+        assert_eq!(number.as_integer::<u8>(), Some(42u8));
+        assert_eq!(number.as_integer::<i16>(), Some(42i16));
+        assert_eq!(number.as_integer::<i128>(), Some(42i128));
+    }
+
+    #[test]
+    fn test_as_integer_reports_none_when_value_does_not_fit_width() {
+        let number = JsonNumber::Borrowed {
+            raw: "-1",
+            parsed: NumberResult::Integer(-1),
+        };
+        // -1 isn't representable in an unsigned type.
+        assert_eq!(number.as_integer::<u8>(), None);
+    }
+
     #[test]
     fn test_json_number_negative_integer() {
         let number = JsonNumber::Borrowed {
@@ -341,6 +997,31 @@ mod tests {
         assert!(number.is_integer());
     }
 
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_as_f64_falls_back_to_raw_string_for_integer_overflow() {
+        let large_int_str = "123456789012345678901234567890"; // Larger than u64::MAX
+        let number = JsonNumber::Borrowed {
+            raw: large_int_str,
+            parsed: NumberResult::IntegerOverflow,
+        };
+        // Exact precision is gone via as_int/as_u64/as_i64, but as_f64 still
+        // hands back a usable (lossy) double instead of None.
+        assert_eq!(number.as_int(), None);
+        assert!(number.as_u64().is_err());
+        assert_eq!(number.as_f64(), Some(123456789012345678901234567890.0));
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_as_f64_converts_unsigned_integer_exactly() {
+        let number = JsonNumber::Borrowed {
+            raw: "18446744073709551615",
+            parsed: NumberResult::UnsignedInteger(18446744073709551615),
+        };
+        assert_eq!(number.as_f64(), Some(18446744073709551615.0_f64));
+    }
+
     #[test]
     #[cfg(feature = "float")]
     fn test_json_number_float() {
@@ -356,7 +1037,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "float")]
+    #[cfg(all(feature = "float", not(feature = "float32")))]
     fn test_json_number_exponent() {
         let number = JsonNumber::Borrowed {
             raw: "1.5e10",
@@ -367,6 +1048,95 @@ mod tests {
         assert!(number.is_float());
     }
 
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_parse_float_scientific_notation_round_trips_exactly() {
+        assert_eq!(parse_float(b"1e3"), NumberResult::Float(1000.0));
+        assert_eq!(parse_float(b"2.5e-1"), NumberResult::Float(0.25));
+    }
+
+    #[test]
+    #[cfg(all(feature = "float", not(feature = "float32")))]
+    fn test_parse_float_more_than_19_significant_digits_is_exact() {
+        // More digits than fit in a u64 significand; the hard case the
+        // request's slow-path fallback exists for, already handled by
+        // `f64: FromStr`'s own big-integer fallback.
+        match parse_float(b"0.123456789012345678901234567890") {
+            NumberResult::Float(val) => {
+                assert_eq!(val.to_bits(), 0.123456789012345678901234567890f64.to_bits())
+            }
+            other => panic!("expected a float, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "float", not(feature = "float32")))]
+    fn test_parse_float_subnormal_is_not_flushed_to_zero() {
+        match parse_float(b"5e-324") {
+            NumberResult::Float(val) => assert_eq!(val.to_bits(), 5e-324f64.to_bits()),
+            other => panic!("expected a subnormal float, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "float", not(feature = "float32")))]
+    fn test_parse_float_halfway_case_needs_bignum_fallback() {
+        // This decimal sits almost exactly halfway between two `f64`
+        // values (the infamous PHP `var_export` rounding bug number), so
+        // resolving it correctly can't stop at the Eisel-Lemire fast path --
+        // `core`'s `FromStr` must fall back to exact big-integer comparison.
+        // Confirms that fallback is reachable through `parse_float`, not
+        // just exercised by `core`'s own test suite.
+        match parse_float(b"2.2250738585072011e-308") {
+            NumberResult::Float(val) => {
+                assert_eq!(val.to_bits(), 2.2250738585072011e-308f64.to_bits())
+            }
+            other => panic!("expected a float, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_parse_float_overflow_to_infinity_falls_back_to_raw_string() {
+        // `f64::from_str` would round this to `inf`; parse_float treats
+        // that like any other out-of-range number, preserving the raw
+        // digits instead of losing them to an unrepresentable float.
+        assert_eq!(parse_float(b"1e400"), NumberResult::IntegerOverflow);
+    }
+
+    #[test]
+    #[cfg(feature = "float32")]
+    fn test_parse_float_float32_parses_at_f32_precision() {
+        assert_eq!(parse_float(b"3.5"), NumberResult::Float(3.5_f32));
+    }
+
+    #[test]
+    #[cfg(feature = "float32")]
+    fn test_as_f32_and_as_f64_agree_once_widened() {
+        let number = JsonNumber::Borrowed {
+            raw: "3.5",
+            parsed: NumberResult::Float(3.5_f32),
+        };
+        assert_eq!(number.as_f32(), Some(3.5_f32));
+        assert_eq!(number.as_f64(), Some(3.5_f64));
+    }
+
+    #[test]
+    #[cfg(feature = "float32")]
+    fn test_float32_display_shows_raw_token_not_reserialized_value() {
+        // 1.23456789 has more significant digits than f32 can carry, so
+        // re-serializing the rounded f32 would silently drop precision the
+        // raw text still has -- Display must show the exact original token
+        // instead, unlike the default f64 configuration where re-serializing
+        // round-trips exactly.
+        let raw = "1.23456789";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::Float(raw.parse::<f32>().unwrap()),
+        };
+        assert_eq!(format!("{number}"), raw);
+    }
+
     #[test]
     #[cfg(not(feature = "float"))]
     fn test_json_number_float_disabled() {
@@ -386,6 +1156,139 @@ mod tests {
         assert!(number.is_float());
     }
 
+    #[test]
+    #[cfg(not(any(
+        feature = "float",
+        feature = "float-error",
+        feature = "float-skip",
+        feature = "float-truncate",
+        feature = "float-decompose"
+    )))]
+    fn test_parse_float_realizes_exact_integers_without_float_math() {
+        assert_eq!(parse_float(b"1e3"), Ok(NumberResult::IntegerFromExponent(1000)));
+        assert_eq!(parse_float(b"2.5e1"), Ok(NumberResult::IntegerFromExponent(25)));
+        assert_eq!(parse_float(b"100e-2"), Ok(NumberResult::IntegerFromExponent(1)));
+        assert_eq!(parse_float(b"-2.5e1"), Ok(NumberResult::IntegerFromExponent(-25)));
+    }
+
+    #[test]
+    #[cfg(not(any(
+        feature = "float",
+        feature = "float-error",
+        feature = "float-skip",
+        feature = "float-truncate",
+        feature = "float-decompose"
+    )))]
+    fn test_parse_float_falls_back_to_disabled_for_genuine_fractions() {
+        // 3.14 has a nonzero fractional remainder once the exponent is
+        // applied, so there's no exact integer to realize.
+        assert_eq!(parse_float(b"3.14"), Ok(NumberResult::FloatDisabled));
+    }
+
+    #[test]
+    #[cfg(not(any(
+        feature = "float",
+        feature = "float-error",
+        feature = "float-skip",
+        feature = "float-truncate",
+        feature = "float-decompose"
+    )))]
+    fn test_parse_float_falls_back_to_disabled_on_overflow() {
+        assert_eq!(parse_float(b"99999999999999999999e10"), Ok(NumberResult::FloatDisabled));
+    }
+
+    #[test]
+    #[cfg(feature = "float-truncate")]
+    fn test_parse_float_truncate_evaluates_scientific_notation_without_float_math() {
+        // Kept within i8's range so this holds under every int8/int32/int64
+        // build.
+        assert_eq!(parse_float(b"1e2"), Ok(NumberResult::FloatTruncated(100)));
+        assert_eq!(parse_float(b"2.5e-1"), Ok(NumberResult::FloatTruncated(0)));
+        assert_eq!(parse_float(b"100e-2"), Ok(NumberResult::FloatTruncated(1)));
+        assert_eq!(parse_float(b"-2.5e1"), Ok(NumberResult::FloatTruncated(-25)));
+    }
+
+    #[test]
+    #[cfg(feature = "float-truncate")]
+    fn test_parse_float_truncate_all_zero_mantissa_is_zero() {
+        assert_eq!(parse_float(b"0e999"), Ok(NumberResult::FloatTruncated(0)));
+        assert_eq!(parse_float(b"0.0e-999"), Ok(NumberResult::FloatTruncated(0)));
+    }
+
+    #[test]
+    #[cfg(feature = "float-truncate")]
+    fn test_parse_float_truncate_negative_exponent_drops_every_digit() {
+        // Dividing away more digits than the mantissa has truncates to 0,
+        // the same way a very negative exponent would with float math.
+        assert_eq!(parse_float(b"5e-10"), Ok(NumberResult::FloatTruncated(0)));
+    }
+
+    #[test]
+    #[cfg(feature = "float-truncate")]
+    fn test_parse_float_truncate_scientific_overflow_reports_integer_overflow() {
+        assert_eq!(parse_float(b"99999999999999999999e10"), Ok(NumberResult::IntegerOverflow));
+    }
+
+    #[test]
+    #[cfg(feature = "float-decompose")]
+    fn test_parse_float_decomposes_without_float_math() {
+        match parse_float(b"-123.456e-7").unwrap() {
+            NumberResult::Decomposed {
+                negative,
+                integer_part,
+                fraction_part,
+                exponent,
+            } => {
+                assert!(negative);
+                assert_eq!(&"-123.456e-7"[integer_part], "123");
+                assert_eq!(&"-123.456e-7"[fraction_part], "456");
+                assert_eq!(exponent, -7);
+            }
+            other => panic!("expected Decomposed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "float-decompose")]
+    fn test_parse_float_decomposes_arbitrarily_large_numbers() {
+        // The case this feature exists for: an overflowing literal that
+        // still decomposes exactly, with no float rounding or truncation.
+        let raw = "123456789012345678901234567890";
+        match parse_float(raw.as_bytes()).unwrap() {
+            NumberResult::Decomposed {
+                negative,
+                integer_part,
+                fraction_part,
+                exponent,
+            } => {
+                assert!(!negative);
+                assert_eq!(&raw[integer_part], raw);
+                assert_eq!(&raw[fraction_part], "");
+                assert_eq!(exponent, 0);
+            }
+            other => panic!("expected Decomposed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "float-roundtrip", not(feature = "float32")))]
+    fn test_as_f64_exact_roundtrips_bit_for_bit() {
+        // 0.1 has no exact binary representation; the point of as_f64_exact
+        // is that it still lands on the one f64 closest to the decimal value,
+        // not a neighbor a sloppier fast-path parser might pick.
+        let number = JsonNumber::Borrowed {
+            raw: "0.1",
+            parsed: NumberResult::Float(0.1),
+        };
+        assert_eq!(number.as_f64_exact().unwrap().to_bits(), 0.1f64.to_bits());
+
+        let integer = JsonNumber::Borrowed {
+            raw: "42",
+            parsed: NumberResult::Integer(42),
+        };
+        assert_eq!(integer.as_f64_exact(), Some(42.0));
+    }
+
     #[test]
     fn test_json_number_parse_custom() {
         let number = JsonNumber::Borrowed {
@@ -424,6 +1327,333 @@ mod tests {
         assert_eq!(json_number.as_int(), Some(56));
     }
 
+    #[test]
+    fn test_as_raw_str_matches_as_str() {
+        let number = JsonNumber::Borrowed {
+            raw: "12345678901234567890",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert_eq!(number.as_raw_str(), number.as_str());
+    }
+
+    #[test]
+    fn test_as_u64_and_as_i64() {
+        let number = JsonNumber::Borrowed {
+            raw: "42",
+            parsed: NumberResult::Integer(42),
+        };
+        assert_eq!(number.as_u64(), Ok(42));
+        assert_eq!(number.as_i64(), Ok(42));
+        assert!(number.is_u64());
+
+        // Too big for the configured int type, but fits comfortably in a u64/i64.
+        let big = JsonNumber::Borrowed {
+            raw: "123456789012345",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert_eq!(big.as_u64(), Ok(123456789012345));
+        assert_eq!(big.as_i64(), Ok(123456789012345));
+
+        // Too big even for a u64/i64.
+        let huge = JsonNumber::Borrowed {
+            raw: "99999999999999999999",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert_eq!(huge.as_u64(), Err(ParseError::NumericOverflow));
+        assert_eq!(huge.as_i64(), Err(ParseError::NumericOverflow));
+
+        // A negative number doesn't fit in a u64.
+        let negative = JsonNumber::Borrowed {
+            raw: "-5",
+            parsed: NumberResult::Integer(-5),
+        };
+        assert_eq!(negative.as_u64(), Err(ParseError::NumericOverflow));
+        assert_eq!(negative.as_i64(), Ok(-5));
+        assert!(!negative.is_u64());
+
+        // Not an integer at all.
+        let non_integer = JsonNumber::Borrowed {
+            raw: "3.25",
+            parsed: NumberResult::FloatDisabled,
+        };
+        assert_eq!(non_integer.as_u64(), Err(ParseError::InvalidNumber));
+        assert_eq!(non_integer.as_i64(), Err(ParseError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_try_as_f64_distinguishes_overflow_from_float_disabled() {
+        let int_val = JsonNumber::Borrowed {
+            raw: "42",
+            parsed: NumberResult::Integer(42),
+        };
+        assert_eq!(int_val.try_as_f64(), Ok(42.0));
+
+        // Too big for the configured integer type, but still a valid f64.
+        let huge = JsonNumber::Borrowed {
+            raw: "18446744073709551616",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert_eq!(huge.try_as_f64(), Ok(18446744073709551616.0));
+
+        // Parsed fine, but this build is configured to not realize floats
+        // at all -- distinct from "too big", even though both failure
+        // modes returned a bare `None` before `try_as_f64` existed.
+        let disabled = JsonNumber::Borrowed {
+            raw: "3.25",
+            parsed: NumberResult::FloatDisabled,
+        };
+        assert_eq!(disabled.try_as_f64(), Err(ParseError::FloatNotAllowed));
+    }
+
+    #[test]
+    fn test_as_i128_and_as_u128() {
+        // Too big for u64/i64, but fits comfortably in a 128-bit type --
+        // e.g. a Snowflake-adjacent ID that overflows the cliff as_u64/
+        // as_i64 would hit.
+        let big = JsonNumber::Borrowed {
+            raw: "123456789012345678901234567890",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert_eq!(big.as_u64(), Err(ParseError::NumericOverflow));
+        assert_eq!(big.as_u128(), Ok(123456789012345678901234567890));
+        assert_eq!(big.as_i128(), Ok(123456789012345678901234567890));
+
+        // Too big even for a u128/i128.
+        let huge = JsonNumber::Borrowed {
+            raw: "999999999999999999999999999999999999999",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert_eq!(huge.as_u128(), Err(ParseError::NumericOverflow));
+        assert_eq!(huge.as_i128(), Err(ParseError::NumericOverflow));
+
+        let negative = JsonNumber::Borrowed {
+            raw: "-5",
+            parsed: NumberResult::Integer(-5),
+        };
+        assert_eq!(negative.as_u128(), Err(ParseError::NumericOverflow));
+        assert_eq!(negative.as_i128(), Ok(-5));
+
+        let non_integer = JsonNumber::Borrowed {
+            raw: "3.25",
+            parsed: NumberResult::FloatDisabled,
+        };
+        assert_eq!(non_integer.as_u128(), Err(ParseError::InvalidNumber));
+        assert_eq!(non_integer.as_i128(), Err(ParseError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_try_as_picks_the_smallest_type_that_fits() {
+        let number = JsonNumber::Borrowed {
+            raw: "200",
+            parsed: NumberResult::UnsignedInteger(200),
+        };
+        assert_eq!(number.try_as::<u8>(), Some(200u8));
+        // Doesn't fit i8, unlike the u8 case above.
+        assert_eq!(number.try_as::<i8>(), None);
+        assert_eq!(number.try_as::<u64>(), Some(200u64));
+    }
+
+    #[test]
+    fn test_try_as_returns_none_for_non_integers() {
+        let number = JsonNumber::Borrowed {
+            raw: "3.25",
+            parsed: NumberResult::FloatDisabled,
+        };
+        assert_eq!(number.try_as::<u64>(), None);
+    }
+
+    #[test]
+    fn test_parse_integer_beyond_i64_max_becomes_unsigned_integer() {
+        // Overflows the signed range of every configured integer width
+        // (`int8`/`int32`/`int64`), not just `i64`; the `u64` fallback in
+        // `parse_integer` applies regardless of which is selected.
+        // i64::MAX + 1
+        assert_eq!(
+            parse_integer(b"9223372036854775808"),
+            NumberResult::UnsignedInteger(9223372036854775808)
+        );
+        assert_eq!(
+            parse_integer(u64::MAX.to_string().as_bytes()),
+            NumberResult::UnsignedInteger(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_parse_integer_beyond_u64_max_is_overflow() {
+        assert_eq!(
+            parse_integer(b"18446744073709551616"), // u64::MAX + 1
+            NumberResult::IntegerOverflow
+        );
+    }
+
+    #[test]
+    fn test_parse_integer_negative_beyond_i64_min_is_overflow() {
+        // Negative, so the u64 fallback doesn't apply either.
+        assert_eq!(
+            parse_integer(b"-9223372036854775809"), // i64::MIN - 1
+            NumberResult::IntegerOverflow
+        );
+    }
+
+    #[test]
+    fn test_unsigned_integer_is_integer_and_uses_raw_string_display() {
+        let number = JsonNumber::Borrowed {
+            raw: "18446744073709551615",
+            parsed: NumberResult::UnsignedInteger(u64::MAX),
+        };
+        assert!(number.is_integer());
+        assert_eq!(number.as_int(), None); // doesn't fit the configured integer type
+        assert_eq!(number.as_u64(), Ok(u64::MAX));
+        assert!(number.is_u64());
+        assert_eq!(format!("{number}"), "18446744073709551615");
+    }
+
+    #[test]
+    #[cfg(feature = "int8")]
+    fn test_parse_integer_small_overflow_also_uses_unsigned_fallback() {
+        // `200` doesn't fit `i8`, but the `u64` fallback doesn't care which
+        // signed width was configured -- it applies uniformly, not just for
+        // literals big enough to overflow `i64`.
+        assert_eq!(parse_integer(b"200"), NumberResult::UnsignedInteger(200));
+    }
+
+    #[test]
+    fn test_raw_decimal_plain_integer() {
+        let number = JsonNumber::Borrowed {
+            raw: "1234",
+            parsed: NumberResult::Integer(1234),
+        };
+        let decimal = number.raw_decimal();
+        assert!(!decimal.negative);
+        assert_eq!(&number.as_raw_str()[decimal.int_digits.clone()], "1234");
+        assert_eq!(&number.as_raw_str()[decimal.frac_digits.clone()], "");
+        assert_eq!(decimal.exp10, 0);
+    }
+
+    #[test]
+    fn test_raw_decimal_negative_fraction_and_exponent() {
+        let raw = "-123.456e-7";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::FloatDisabled,
+        };
+        let decimal = number.raw_decimal();
+        assert!(decimal.negative);
+        assert_eq!(&raw[decimal.int_digits.clone()], "123");
+        assert_eq!(&raw[decimal.frac_digits.clone()], "456");
+        assert_eq!(decimal.exp10, -7);
+    }
+
+    #[test]
+    fn test_raw_decimal_positive_exponent_no_fraction() {
+        let raw = "42e+10";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::FloatDisabled,
+        };
+        let decimal = number.raw_decimal();
+        assert!(!decimal.negative);
+        assert_eq!(&raw[decimal.int_digits.clone()], "42");
+        assert_eq!(&raw[decimal.frac_digits.clone()], "");
+        assert_eq!(decimal.exp10, 10);
+    }
+
+    #[test]
+    fn test_raw_decimal_leading_plus_sign() {
+        // `+42` is only reachable from the tokenizer in lenient/JSON5 mode
+        // (see `Tokenizer::set_lenient_syntax`), but once it is, it must
+        // decompose the same as an unsigned literal -- `+` isn't `-`, so
+        // `negative` stays false, and the digits afterward are still found
+        // at `int_digits`, not swallowed as part of the sign.
+        let raw = "+42";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::Integer(42),
+        };
+        let decimal = number.raw_decimal();
+        assert!(!decimal.negative);
+        assert_eq!(&raw[decimal.int_digits.clone()], "42");
+        assert_eq!(&raw[decimal.frac_digits.clone()], "");
+        assert_eq!(decimal.exp10, 0);
+    }
+
+    #[test]
+    fn test_raw_decimal_on_overflowing_integer_still_decomposes() {
+        // Arbitrarily large -- exactly the case this exists for, since
+        // no numeric accessor below can represent it natively.
+        let raw = "123456789012345678901234567890";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::IntegerOverflow,
+        };
+        let decimal = number.raw_decimal();
+        assert!(!decimal.negative);
+        assert_eq!(&raw[decimal.int_digits.clone()], raw);
+        assert_eq!(&raw[decimal.frac_digits.clone()], "");
+        assert_eq!(decimal.exp10, 0);
+    }
+
+    #[test]
+    fn test_raw_decimal_decomposes_huge_exponent_without_float_math() {
+        // `1e400` overflows every numeric accessor (even `f64`, where it
+        // rounds to infinity) regardless of build configuration, but
+        // `raw_decimal` never does float or big-integer math in the first
+        // place -- it just slices the already-tokenizer-validated text, so
+        // the magnitude of the exponent doesn't matter to it at all. This is
+        // this crate's answer to wanting arbitrary-precision numbers: the
+        // exact digits and exponent are always available this way, whatever
+        // `parsed()` itself came back as for a value this large.
+        let raw = "1e400";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::IntegerOverflow,
+        };
+        let decimal = number.raw_decimal();
+        assert!(!decimal.negative);
+        assert_eq!(&raw[decimal.int_digits.clone()], "1");
+        assert_eq!(&raw[decimal.frac_digits.clone()], "");
+        assert_eq!(decimal.exp10, 400);
+    }
+
+    #[test]
+    fn test_raw_decimal_exponent_wider_than_i64_saturates_instead_of_defaulting_to_zero() {
+        // 19+ digits doesn't fit in an `i64` (max is 19 digits but tops out
+        // around 9.2e18), yet the tokenizer's grammar imposes no digit-count
+        // cap on an exponent, so this is syntactically valid JSON. Silently
+        // falling back to `exp10 = 0` here would make this indistinguishable
+        // from a plain integer -- it must saturate to the overflow sentinel
+        // instead.
+        let raw = "1e99999999999999999999";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::IntegerOverflow,
+        };
+        let decimal = number.raw_decimal();
+        assert!(!decimal.negative);
+        assert_eq!(&raw[decimal.int_digits.clone()], "1");
+        assert_eq!(decimal.exp10, i64::MAX);
+
+        let raw = "1e-99999999999999999999";
+        let number = JsonNumber::Borrowed {
+            raw,
+            parsed: NumberResult::IntegerOverflow,
+        };
+        let decimal = number.raw_decimal();
+        assert_eq!(decimal.exp10, i64::MIN);
+    }
+
+    #[cfg(feature = "float-truncate")]
+    #[test]
+    fn test_scale_decimal_to_configured_int_reports_overflow_for_unrepresentable_exponent() {
+        // Before the fix, `1e<19+ digits>` decomposed to `exp10 = 0`, so
+        // this silently scaled to the plausible-looking-but-wrong value `1`
+        // instead of being rejected as unrepresentable.
+        assert_eq!(
+            scale_decimal_to_configured_int("1e99999999999999999999"),
+            None
+        );
+    }
+
     #[test]
     fn test_from_slice_at_eof() {
         // Test parsing number at end of data
@@ -434,4 +1664,106 @@ mod tests {
         assert_eq!(json_number.as_str(), "89"); // Should include full number
         assert_eq!(json_number.as_int(), Some(89));
     }
+
+    #[test]
+    fn test_json_number_eq_compares_by_numeric_value_not_variant() {
+        let int_one = JsonNumber::Borrowed {
+            raw: "1",
+            parsed: NumberResult::Integer(1),
+        };
+        let unsigned_one = JsonNumber::Borrowed {
+            raw: "1",
+            parsed: NumberResult::UnsignedInteger(1),
+        };
+        assert_eq!(int_one, unsigned_one);
+
+        #[cfg(feature = "float")]
+        {
+            let float_one = JsonNumber::Borrowed {
+                raw: "1.0",
+                parsed: NumberResult::Float(1.0),
+            };
+            // Same number, different text/variant -- this is exactly what
+            // the old derived structural `PartialEq` couldn't do.
+            assert_eq!(int_one, float_one);
+
+            let float_two = JsonNumber::Borrowed {
+                raw: "2.0",
+                parsed: NumberResult::Float(2.0),
+            };
+            assert_ne!(int_one, float_two);
+        }
+    }
+
+    #[test]
+    fn test_json_number_eq_falls_back_to_raw_text_without_a_realized_value() {
+        let overflow_a = JsonNumber::Borrowed {
+            raw: "99999999999999999999999999999999",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        let overflow_a_again = JsonNumber::Borrowed {
+            raw: "99999999999999999999999999999999",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        let overflow_b = JsonNumber::Borrowed {
+            raw: "88888888888888888888888888888888",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert_eq!(overflow_a, overflow_a_again);
+        assert_ne!(overflow_a, overflow_b);
+    }
+
+    #[test]
+    fn test_json_number_ord_orders_realized_values_before_raw_fallback() {
+        let small = JsonNumber::Borrowed {
+            raw: "1",
+            parsed: NumberResult::Integer(1),
+        };
+        let large = JsonNumber::Borrowed {
+            raw: "1000",
+            parsed: NumberResult::Integer(1000),
+        };
+        let overflow = JsonNumber::Borrowed {
+            raw: "99999999999999999999999999999999",
+            parsed: NumberResult::IntegerOverflow,
+        };
+        assert!(small < large);
+        // A realized value, however small, sorts before an unrealized one,
+        // however large its text looks.
+        assert!(large < overflow);
+
+        let mut values = [overflow, large, small];
+        values.sort();
+        assert_eq!(values[0].as_str(), "1");
+        assert_eq!(values[1].as_str(), "1000");
+        assert_eq!(
+            values[2].as_str(),
+            "99999999999999999999999999999999"
+        );
+    }
+
+    #[test]
+    fn test_json_number_hash_matches_eq_for_map_keys() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(JsonNumber::Borrowed {
+            raw: "1",
+            parsed: NumberResult::Integer(1),
+        });
+        // Same numeric value via a different variant must not be treated as
+        // a distinct key.
+        let inserted_again = set.insert(JsonNumber::Borrowed {
+            raw: "1",
+            parsed: NumberResult::UnsignedInteger(1),
+        });
+        assert!(!inserted_again);
+        assert_eq!(set.len(), 1);
+
+        set.insert(JsonNumber::Borrowed {
+            raw: "2",
+            parsed: NumberResult::Integer(2),
+        });
+        assert_eq!(set.len(), 2);
+    }
 }
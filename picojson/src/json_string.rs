@@ -2,6 +2,8 @@
 
 use core::ops::Deref;
 
+use crate::base64::{self, Base64Error};
+
 /// Represents a JSON string.
 ///
 /// 'a is the lifetime of the original input buffer.
@@ -22,6 +24,38 @@ impl String<'_, '_> {
             String::Unescaped(s) => s,
         }
     }
+
+    /// Returns `true` if this string contained an escape sequence and had to
+    /// be copied into the scratch buffer to unescape it, `false` if it was
+    /// escape-free and borrowed directly from the input. Lets a caller check
+    /// which path it's on (e.g. for metrics on how often the zero-copy fast
+    /// path is hit) without having to match on the variant itself.
+    pub fn was_escaped(&self) -> bool {
+        matches!(self, String::Unescaped(_))
+    }
+
+    /// Decodes this string's text as standard-alphabet base64
+    /// (`A`-`Z`/`a`-`z`/`0`-`9`/`+`/`/`, `=` padding) directly into `out`,
+    /// with no allocation, returning the filled prefix so the caller knows
+    /// the exact decoded length. Useful for config/credential formats that
+    /// wrap a binary blob in a JSON string.
+    ///
+    /// Rejects a malformed encoding (wrong length, stray padding, a byte
+    /// outside the alphabet) rather than decoding a truncated or garbled
+    /// result, and reports exactly how many bytes were needed if `out` is
+    /// too small to hold them.
+    pub fn decode_base64<'out>(&self, out: &'out mut [u8]) -> Result<&'out [u8], Base64Error> {
+        base64::decode(self.as_str(), out, false)
+    }
+
+    /// Like [`decode_base64`](Self::decode_base64), but for the URL-safe
+    /// alphabet (`-`/`_` in place of `+`/`/`) used by e.g. JWT segments.
+    pub fn decode_base64_url_safe<'out>(
+        &self,
+        out: &'out mut [u8],
+    ) -> Result<&'out [u8], Base64Error> {
+        base64::decode(self.as_str(), out, true)
+    }
 }
 
 impl AsRef<str> for String<'_, '_> {
@@ -69,4 +103,39 @@ mod tests {
         }
         assert_eq!(takes_str(&borrowed), 4);
     }
+
+    #[test]
+    fn test_was_escaped_reflects_variant() {
+        assert!(!String::Borrowed("test").was_escaped());
+        assert!(String::Unescaped("test").was_escaped());
+    }
+
+    #[test]
+    fn test_decode_base64_writes_into_caller_buffer() {
+        let s = String::Borrowed("SGVsbG8=");
+        let mut out = [0u8; 5];
+        assert_eq!(s.decode_base64(&mut out).unwrap(), b"Hello");
+    }
+
+    #[test]
+    fn test_decode_base64_url_safe_uses_the_url_safe_alphabet() {
+        let s = String::Borrowed("-_--");
+        let mut out = [0u8; 3];
+        assert_eq!(s.decode_base64_url_safe(&mut out).unwrap(), &[251, 255, 190]);
+        // The standard alphabet must reject the same text.
+        assert_eq!(
+            s.decode_base64(&mut out),
+            Err(Base64Error::InvalidCharacter { byte: b'-' })
+        );
+    }
+
+    #[test]
+    fn test_decode_base64_reports_invalid_character() {
+        let s = String::Borrowed("!abc");
+        let mut out = [0u8; 3];
+        assert_eq!(
+            s.decode_base64(&mut out),
+            Err(Base64Error::InvalidCharacter { byte: b'!' })
+        );
+    }
 }
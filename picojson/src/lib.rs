@@ -11,6 +11,17 @@
 //! Both parsers emit [`Event`]s representing JSON structure and values, allowing fine-grained
 //! control over parsing and memory usage.
 //!
+//! ## NDJSON / JSON Lines
+//!
+//! Every front-end parser also has a `new_ndjson`/`with_config_ndjson`-style
+//! constructor for newline-delimited JSON: after a complete top-level value,
+//! the next call resumes parsing the next one from wherever the input left
+//! off, skipping blank lines and other inter-record whitespace, rather than
+//! erroring on trailing bytes the single-document constructors would reject.
+//! See [`SliceParser::new_ndjson`], [`StreamParser::new_ndjson`],
+//! [`FeedParser::new_ndjson`], and [`PollParser::new_ndjson`] (async
+//! counterparts included).
+//!
 //! ## Quick Start
 //!
 //! ```rust
@@ -58,17 +69,47 @@ pub use ujson::ArrayBitStack;
 
 pub use ujson::ArrayBitBucket;
 pub use ujson::{BitBucket, BitStackConfig, BitStackStruct, DefaultConfig, DepthCounter};
+#[cfg(feature = "alloc")]
+pub use ujson::{HeapBitBucket, HeapBitStack};
 
 mod copy_on_escape;
 
 mod escape_processor;
+pub use escape_processor::SurrogatePolicy;
+
+mod escape_writer;
+pub use escape_writer::EscapeWriter;
 
 mod content_builder;
 
 mod parser_core;
 
+mod byte_source;
+pub use byte_source::{ByteSource, ChainedSource};
+
+mod byte_storage;
+pub use byte_storage::ByteStorage;
+#[cfg(feature = "alloc")]
+pub use byte_storage::GrowthPolicy;
+
+mod digest;
+pub use digest::DigestTracker;
+
 mod stream_buffer;
 
+// Not yet exposed at the crate root: Assembler's errors are
+// stream_buffer::StreamBufferError, which (like StreamBuffer itself) is
+// internal plumbing rather than public API. Wiring a reader-facing
+// out-of-order input path is left for a follow-up.
+mod assembler;
+
+// Same reasoning as `assembler` above: Reassembler::write_at also returns
+// stream_buffer::StreamBufferError.
+mod reassembler;
+
+// Same reasoning again: FillSourceError wraps stream_buffer::StreamBufferError.
+mod fill_source;
+
 mod stream_content_builder;
 
 mod stream_parser;
@@ -84,27 +125,112 @@ mod slice_content_builder;
 mod slice_parser;
 
 mod parse_error;
-pub use parse_error::ParseError;
+pub use parse_error::{ParseError, Position, Span};
 
 mod shared;
-pub use shared::{Event, PullParser};
+pub use shared::{Event, Flow, PullParser, RawCapture};
 
 mod event_processor;
 
 mod slice_input_buffer;
 
+mod push_content_builder;
+pub use push_content_builder::PushParserHandler;
+
+mod push_parser;
+pub use push_parser::{PushParseError, PushParser};
+
+mod feed_parser;
+pub use feed_parser::{FeedEvent, FeedIter, FeedParser, Poll, PollParser};
+
+mod poll_reader;
+pub use poll_reader::{AppendReader, PollFeedParser, PollRead, PollReader};
+
 mod json_number;
 use json_number::parse_number_from_str;
-pub use json_number::{JsonNumber, NumberResult};
+pub use json_number::{JsonNumber, NumberResult, RawDecimal};
 
 mod json_string;
 pub use json_string::String;
 
+mod base64;
+pub use base64::Base64Error;
+
 mod int_parser;
+mod float_parser;
 mod number_parser;
 
-pub use slice_parser::SliceParser;
-pub use stream_parser::{Reader, StreamParser};
+pub use slice_parser::{Checkpoint, SliceParser};
+pub use stream_parser::{ByteReader, Reader, ReaderParser, StreamParser};
 
 mod chunk_reader;
 pub use chunk_reader::ChunkReader;
+
+mod chunked_transfer_reader;
+pub use chunked_transfer_reader::{ChunkedTransferError, ChunkedTransferReader};
+
+#[cfg(feature = "async")]
+mod async_reader;
+#[cfg(feature = "async")]
+pub use async_reader::AsyncReader;
+#[cfg(all(feature = "async", feature = "embedded-io-async"))]
+pub use async_reader::EmbeddedIoAsyncReader;
+
+#[cfg(feature = "async")]
+mod async_stream_parser;
+#[cfg(feature = "async")]
+pub use async_stream_parser::AsyncStreamParser;
+
+#[cfg(feature = "async")]
+mod async_feed_parser;
+#[cfg(feature = "async")]
+pub use async_feed_parser::AsyncFeedParser;
+
+mod io_reader;
+pub use io_reader::IterReader;
+#[cfg(feature = "std")]
+pub use io_reader::IoReader;
+#[cfg(feature = "core_io")]
+pub use io_reader::CoreIoReader;
+#[cfg(feature = "embedded-io")]
+pub use io_reader::EmbeddedIoReader;
+
+mod buf_reader;
+pub use buf_reader::BufReader;
+
+mod take_reader;
+pub use take_reader::TakeReader;
+
+mod transcoding_reader;
+pub use transcoding_reader::{TranscodingError, TranscodingReader};
+
+mod decoding_reader;
+pub use decoding_reader::{ByteDecoder, DecodingError, DecodingReader};
+
+mod event_writer;
+pub use event_writer::{EventWriter, EventWriterError, SliceWriter, SliceWriterFull, Write};
+
+mod path;
+pub use path::{PathSegment, PathStack, PathStackOverflow};
+
+mod dup_key;
+pub use dup_key::{DuplicateKey, DuplicateKeyStack, DuplicateKeyStackOverflow};
+
+mod path_parser;
+pub use path_parser::{PathParser, PathParserError, PathParserOverflow, Pattern, PatternSegment};
+
+mod value;
+pub use value::{Number, TreeBuilder, Value};
+
+#[cfg(feature = "simd")]
+mod simd_scan;
+#[cfg(feature = "simd")]
+pub use simd_scan::Scanner as StructuralScanner;
+
+#[cfg(feature = "serde")]
+mod serde_de;
+#[cfg(feature = "serde")]
+pub use serde_de::{
+    from_slice, from_slice_with_buffer, from_str, from_str_with_buffer, Deserializer,
+    Error as SerdeError,
+};
@@ -6,7 +6,83 @@ use crate::stream_buffer;
 
 use crate::ujson;
 
-/// Errors that can occur during JSON parsing
+/// A location within parsed input, used for diagnostics.
+///
+/// Mirrors serde_json's `Position`: a byte offset alongside the 1-based
+/// line/column it corresponds to. `line` and `column` are `0` when the
+/// location wasn't tracked (e.g. errors surfaced outside the main parse
+/// loop). `byte_offset` is cumulative across [`PushParser::write`](crate::PushParser::write)/
+/// `parse_chunk` calls for the chunked front-ends, not reset per chunk, so a
+/// syntax error partway through a long streamed document still reports its
+/// true offset from the start of the whole input.
+///
+/// This isn't behind a cargo feature: the counters are a few `usize`/`u32`
+/// fields updated alongside bytes the tokenizer is already scanning one at a
+/// time, so there's no separate pass or allocation to opt out of, and no
+/// manifest in this tree to hang a feature flag off of in the first place.
+///
+/// There's no `FnMut(Event, Position)` callback variant alongside this.
+/// The pull-based front-ends ([`PullParser::next_event`](crate::PullParser::next_event)
+/// returns one [`Event`](crate::Event) per call) already let a caller that
+/// wants the position alongside an event have both: call
+/// [`SliceParser::position`](crate::SliceParser::position)/
+/// [`StreamParser::position`](crate::StreamParser::position) right after
+/// `next_event` returns it. The callback-driven [`PushParser`](crate::PushParser)
+/// hands its handler a [`Span`] per event instead, and exposes
+/// [`PushParser::position`](crate::PushParser::position) between `write` calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    /// Absolute byte offset from the start of input.
+    pub byte_offset: usize,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number, counting raw input bytes rather than Unicode
+    /// codepoints or grapheme clusters: each byte of a multi-byte UTF-8
+    /// sequence advances this by one, same as an ASCII byte would. Matching
+    /// [`ParserCore::advance_position`](crate::event_processor::ParserCore::advance_position)'s
+    /// own per-byte counting loop, which has no cheap way to tell a
+    /// continuation byte from a sequence start without re-decoding UTF-8 on
+    /// every call.
+    pub column: usize,
+}
+
+/// The source byte range an [`Event`](crate::Event) was produced from.
+///
+/// `start` and `end` are absolute byte offsets from the start of input,
+/// with `end` exclusive. For scalar events the range covers the full
+/// lexeme, including surrounding quotes or escapes for strings/keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    /// Absolute start byte offset (inclusive).
+    pub start: usize,
+    /// Absolute end byte offset (exclusive).
+    pub end: usize,
+}
+
+/// Errors that can occur during JSON parsing.
+///
+/// `ParseError` itself carries no location, by design: it stays cheap to
+/// construct and match on for callers who don't care where in the input a
+/// problem occurred. Location is paired on as a separate, optional step at
+/// the one place it's actually needed -- returning an error from
+/// [`next_event`](crate::PullParser::next_event) -- via
+/// [`SliceParser::next_event_located`](crate::SliceParser::next_event_located)/
+/// [`StreamParser::next_event_located`](crate::StreamParser::next_event_located),
+/// which return `(ParseError, Position)`, or via
+/// [`SliceParser::position`](crate::SliceParser::position)/
+/// [`StreamParser::position`](crate::StreamParser::position) called right
+/// after a plain `next_event` call fails. Either gives the absolute byte
+/// offset plus 1-based line/column, the same [`Position`] record
+/// [`PushParser::position`](crate::PushParser::position) exposes for the
+/// chunked parsers.
+///
+/// A `position: Position` field on every variant was considered instead --
+/// it would save the `next_event_located`/`position()` follow-up call -- but
+/// it'd cost every variant (including ones constructed far from any input
+/// cursor, like [`UnexpectedState`]) a field most callers never read, and
+/// it'd still need pairing logic at the one call site that actually knows
+/// the position, identical to what `next_event_located` already does. The
+/// pairing stays a wrapper around the plain, locationless error instead.
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
     /// An error bubbled up from the underlying tokenizer.
@@ -23,18 +99,162 @@ pub enum ParseError {
     Unexpected(UnexpectedState),
     /// End of input data.
     EndOfData,
-    /// Invalid hex digits in Unicode escape sequence.
-    InvalidUnicodeHex,
-    /// Valid hex but invalid Unicode codepoint.
-    InvalidUnicodeCodepoint,
-    /// Invalid escape sequence character.
-    InvalidEscapeSequence,
+    /// A [`StreamBuffer`](crate::stream_buffer::StreamBuffer) scan ran off
+    /// the end of currently-filled data while looking for a token boundary
+    /// (e.g. the closing `"` of a string, or an escape/control byte),
+    /// rather than the document having genuinely ended. `needed` is a lower
+    /// bound on how many more bytes must be supplied before retrying.
+    /// Unlike [`Self::EndOfData`], this is resumable: refill the buffer and
+    /// parse again. Only produced by scan-based buffer APIs that aren't yet
+    /// on any parser's hot path; [`StreamParser`](crate::StreamParser)'s
+    /// byte-at-a-time loop always fills before it can observe this, and
+    /// [`PushParser`](crate::PushParser) has its own dedicated
+    /// [`needs_more_input`](crate::PushParser::needs_more_input) signal for
+    /// the same "not done, just out of bytes right now" situation.
+    NeedMoreInput {
+        /// Lower bound on additional bytes needed before retrying.
+        needed: usize,
+    },
+    /// A `\u` escape ended before all 4 hex digits were seen, e.g. the
+    /// input ran out mid-escape.
+    ///
+    /// This is the *fatal* case: the string was closed (or the document
+    /// ended) without the remaining hex digits ever arriving. A `\u` escape
+    /// cut off by a [`PushParser::write`](crate::PushParser::write) chunk
+    /// boundary, with more bytes still to come, never reaches this error --
+    /// the tokenizer holds the escape open across the call (see
+    /// `test_unicode_escape_split_across_chunk_boundary` in
+    /// `ujson::tokenizer`) and
+    /// [`needs_more_input`](crate::PushParser::needs_more_input) reports
+    /// `true` in the meantime, exactly the "truncated-so-far but not yet
+    /// disprovable" outcome distinct from a genuinely malformed escape.
+    IncompleteUnicodeEscape {
+        /// How many of the 4 required hex digits were actually seen.
+        digits_seen: usize,
+    },
+    /// A byte inside a `\uXXXX` escape's 4-digit hex field wasn't `0-9`,
+    /// `a-f`, or `A-F`.
+    InvalidUnicodeHexDigit {
+        /// The offending byte.
+        byte: u8,
+    },
+    /// The 4 hex digits decoded to a codepoint or surrogate-pair
+    /// combination that isn't a valid Unicode scalar value.
+    InvalidUnicodeCodepoint {
+        /// The codepoint (or combined surrogate pair) that was rejected.
+        codepoint: u32,
+    },
+    /// A `\uD800`-`\uDBFF` high surrogate was followed by something other
+    /// than a `\uDC00`-`\uDFFF` low surrogate to pair it with.
+    UnpairedHighSurrogate,
+    /// A `\uDC00`-`\uDFFF` low surrogate appeared with no preceding high
+    /// surrogate for it to complete.
+    UnpairedLowSurrogate,
+    /// A `\` was followed by a byte that isn't one of the recognized
+    /// escape characters (`" \\ / b f n r t u`).
+    ///
+    /// In practice the tokenizer already rejects this case as a
+    /// [`TokenizerError`](Self::TokenizerError) before it reaches the escape
+    /// processor that would otherwise return this variant -- callers
+    /// matching on escape-specific errors should handle both.
+    UnknownEscapeChar {
+        /// The byte that followed the backslash.
+        byte: u8,
+    },
     /// Float encountered but float support is disabled and float-error is configured
     FloatNotAllowed,
     /// Error from the underlying reader (I/O error, not end-of-stream)
     ReaderError,
     /// Numeric overflow
     NumericOverflow,
+    /// A key, string, or number starting at `offset` is at least `token_len`
+    /// bytes long and doesn't fit in the `buffer_len`-byte input buffer, even
+    /// after compacting away everything already consumed. Growing the
+    /// buffer (or rejecting the input) is the caller's call to make; unlike
+    /// the generic [`ParseError::InputBufferFull`], this carries enough
+    /// detail to make that call without re-deriving it from the stream.
+    TokenTooLarge {
+        /// Byte offset (from the start of input) where the token begins.
+        offset: usize,
+        /// Bytes of the token seen so far that don't fit.
+        token_len: usize,
+        /// Capacity of the input buffer.
+        buffer_len: usize,
+    },
+    /// A container was opened that would exceed the runtime limit set via
+    /// [`PullParser::set_max_depth`](crate::PullParser::set_max_depth),
+    /// reported here instead of letting the tokenizer's bitstack overflow
+    /// into a generic [`ParseError::TokenizerError`].
+    DepthLimitExceeded {
+        /// Nesting depth (counting the container that was about to open)
+        /// that exceeded the configured limit.
+        depth: usize,
+    },
+    /// A key contained an escape sequence while
+    /// [`PullParser::set_reject_escaped_keys`](crate::PullParser::set_reject_escaped_keys)
+    /// was enabled, which requires every key to be a zero-copy borrow of
+    /// the source.
+    EscapedKeyRejected,
+    /// A [`StreamParser::next_raw_value`](crate::StreamParser::next_raw_value)
+    /// (or [`skip_value_with_span`](crate::StreamParser::skip_value_with_span))
+    /// capture spanned a container whose start was compacted out of the
+    /// input buffer before its end was reached, so the byte range recorded
+    /// at the start is no longer valid. Only `StreamParser` can hit this,
+    /// since its buffer discards consumed bytes as it fills; `SliceParser`
+    /// keeps the whole input resident and never compacts.
+    ///
+    /// This is deliberately its own variant rather than reusing
+    /// [`Self::TokenTooLarge`]: that one means the input buffer can't
+    /// hold a single token's bytes, while this means the *span* of an
+    /// already-tokenized subtree outran how long the buffer keeps old bytes
+    /// around -- different causes, so a caller branching on the error
+    /// shouldn't have to disambiguate them from one shared variant.
+    RawValueTooLarge,
+    /// A raw (unescaped) UTF-8 byte sequence in string content was
+    /// malformed: a stray continuation byte, an overlong encoding, or a
+    /// multibyte sequence truncated at the end of the string. Detected by
+    /// `Utf8Validator`'s incremental DFA, independent of the
+    /// [`Self::Utf8`] variant `core::str::from_utf8` produces over an
+    /// already-assembled slice.
+    InvalidUtf8Sequence,
+    /// A string or key contained a Unicode bidirectional text-flow-control
+    /// codepoint (`U+202A`..=`U+202E`, `U+2066`..=`U+2069`) while
+    /// [`PullParser::set_reject_bidi_controls`](crate::PullParser::set_reject_bidi_controls)
+    /// was enabled. These can reorder how surrounding text *displays*
+    /// without changing the bytes a program reads, the same spoofing
+    /// technique rustc's `text_direction_codepoint_in_literal` lint flags
+    /// in source files -- rejecting them is about what a human reviewing
+    /// the JSON sees, not document well-formedness, which is why this is
+    /// opt-in rather than always on like [`Self::InvalidUtf8Sequence`].
+    BidiControlInString,
+}
+
+impl ParseError {
+    /// Whether this is a [`Self::TokenizerError`] raised only because the
+    /// document ended while a token or container was still open (e.g. an
+    /// unterminated string, or a `{` with no matching `}`), as opposed to a
+    /// genuine syntax error. A caller accumulating bytes from a transport
+    /// can use this to decide whether to wait for more data and retry from
+    /// a fresh parser over the same bytes plus whatever arrives next,
+    /// rather than giving up on the document.
+    ///
+    /// This is deliberately a read of information [`SliceParser`] and
+    /// [`StreamParser`] already had -- not a new resumable-parsing mode:
+    /// both only call the tokenizer's `finish()` (where this distinction is
+    /// made) once their input is exhausted, at which point the whole
+    /// document, complete or not, is behind them. For parsing that actually
+    /// resumes mid-token without restarting, see [`PushParser`]/
+    /// [`FeedParser`](crate::FeedParser)/[`PollParser`](crate::PollParser),
+    /// which are built around exactly that on their `parse_chunk`/feed
+    /// loop, with their own [`PushParser::needs_more_input`] signal for it.
+    ///
+    /// [`SliceParser`]: crate::SliceParser
+    /// [`StreamParser`]: crate::StreamParser
+    /// [`PushParser`]: crate::PushParser
+    /// [`PushParser::needs_more_input`]: crate::PushParser::needs_more_input
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, ParseError::TokenizerError(e) if e.is_incomplete())
+    }
 }
 
 impl From<slice_input_buffer::Error> for ParseError {
@@ -53,12 +273,18 @@ impl From<stream_buffer::StreamBufferError> for ParseError {
         match err {
             stream_buffer::StreamBufferError::BufferFull => ParseError::ScratchBufferFull,
             stream_buffer::StreamBufferError::EndOfData => ParseError::EndOfData,
+            stream_buffer::StreamBufferError::NeedMoreInput { needed } => {
+                ParseError::NeedMoreInput { needed }
+            }
             stream_buffer::StreamBufferError::Unexpected => {
                 ParseError::Unexpected(UnexpectedState::BufferCapacityExceeded)
             }
             stream_buffer::StreamBufferError::InvalidSliceBounds => {
                 ParseError::Unexpected(UnexpectedState::InvalidSliceBounds)
             }
+            stream_buffer::StreamBufferError::TooManyHoles => {
+                ParseError::Unexpected(UnexpectedState::BufferCapacityExceeded)
+            }
         }
     }
 }
@@ -86,11 +312,30 @@ impl core::fmt::Display for ParseError {
         match self {
             ParseError::TokenizerError(e) => write!(f, "{e}"),
             ParseError::Utf8(e) => write!(f, "Invalid UTF-8: {e}"),
+            ParseError::TokenTooLarge {
+                offset,
+                token_len,
+                buffer_len,
+            } => write!(
+                f,
+                "token at offset {offset} is at least {token_len} bytes, \
+                 which doesn't fit in the {buffer_len}-byte input buffer"
+            ),
             _ => write!(f, "{self:?}"),
         }
     }
 }
 
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseError::TokenizerError(e) => Some(e),
+            ParseError::Utf8(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +393,64 @@ mod tests {
             Ok(_) => panic!("Expected UTF-8 validation to fail"),
         }
     }
+
+    #[test]
+    fn test_need_more_input_conversion_is_distinct_from_end_of_data() {
+        let resumable: ParseError = stream_buffer::StreamBufferError::NeedMoreInput { needed: 1 }.into();
+        assert_eq!(resumable, ParseError::NeedMoreInput { needed: 1 });
+
+        let terminal: ParseError = stream_buffer::StreamBufferError::EndOfData.into();
+        assert_eq!(terminal, ParseError::EndOfData);
+    }
+
+    #[test]
+    fn test_source_chaining() {
+        use crate::{PullParser, SliceParser};
+        use core::error::Error as _;
+
+        let mut parser = SliceParser::new("{invalid}");
+        let error = loop {
+            match parser.next_event() {
+                Ok(_) => continue,
+                Err(e) => break e,
+            }
+        };
+        match error {
+            ParseError::TokenizerError(_) => assert!(error.source().is_some()),
+            other => panic!("Expected TokenizerError, got {other:?}"),
+        }
+
+        let mut invalid_utf8_array = [0u8; 1];
+        invalid_utf8_array[0] = 0b10000000u8;
+        let utf8_error = core::str::from_utf8(&invalid_utf8_array).unwrap_err();
+        let error = ParseError::Utf8(utf8_error);
+        assert!(error.source().is_some());
+
+        assert!(ParseError::EndOfData.source().is_none());
+    }
+
+    #[test]
+    fn test_is_incomplete_distinguishes_truncated_input_from_a_syntax_error() {
+        use crate::{PullParser, SliceParser};
+
+        let mut truncated = SliceParser::new(r#"{"a": "b""#);
+        let error = loop {
+            match truncated.next_event() {
+                Ok(_) => continue,
+                Err(e) => break e,
+            }
+        };
+        assert!(error.is_incomplete(), "{error:?} should be incomplete");
+
+        let mut malformed = SliceParser::new("{invalid}");
+        let error = loop {
+            match malformed.next_event() {
+                Ok(_) => continue,
+                Err(e) => break e,
+            }
+        };
+        assert!(!error.is_incomplete(), "{error:?} should not be incomplete");
+
+        assert!(!ParseError::EndOfData.is_incomplete());
+    }
 }
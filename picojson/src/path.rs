@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A fixed-capacity path-tracking stack for streaming consumers.
+//!
+//! Embedded users driving [`PushParser`](crate::PushParser) often only want
+//! to know "where am I?" -- e.g. to pull out just `sensors[3].value` from a
+//! large stream without building a DOM. [`PathStack`] tracks that location
+//! as the handler observes events: push a frame on `StartObject`/
+//! `StartArray`, pop one on `EndObject`/`EndArray`, record a key's byte span
+//! when it completes, and advance the top array frame's index when a value
+//! inside it completes. Keys stay as offsets into the caller's input buffer
+//! to avoid allocation; a consumer resolves them back to text with its own
+//! copy of that buffer.
+
+/// One segment of a [`PathStack`]'s current location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment {
+    /// An object key, as a byte range `[start, end)` into the caller's
+    /// input buffer (the text between the quotes, excluding them).
+    Key(usize, usize),
+    /// The index of the current (or most recently completed) element in
+    /// an array.
+    Index(u32),
+}
+
+/// Returned by [`PathStack::push_object`]/[`PathStack::push_array`] when the
+/// stack has no remaining capacity for another nesting level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathStackOverflow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frame {
+    Object { key: Option<(usize, usize)> },
+    Array { index: u32 },
+}
+
+/// A const-generic, fixed-capacity stack of [`PathSegment`]s, tracking the
+/// current location of a streaming parse.
+///
+/// `N` is the maximum nesting depth this stack can represent; pushing past
+/// it returns [`PathStackOverflow`] instead of growing, same as
+/// [`ArrayBitBucket`](crate::ArrayBitBucket)'s `try_push`.
+#[derive(Debug, Clone)]
+pub struct PathStack<const N: usize> {
+    frames: [Option<Frame>; N],
+    len: usize,
+}
+
+impl<const N: usize> PathStack<N> {
+    /// Creates an empty stack, positioned at the document root.
+    pub fn new() -> Self {
+        Self {
+            frames: [None; N],
+            len: 0,
+        }
+    }
+
+    /// Pushes an object frame, to be called on `Event::StartObject`.
+    pub fn push_object(&mut self) -> Result<(), PathStackOverflow> {
+        self.push(Frame::Object { key: None })
+    }
+
+    /// Pushes an array frame, to be called on `Event::StartArray`.
+    pub fn push_array(&mut self) -> Result<(), PathStackOverflow> {
+        self.push(Frame::Array { index: 0 })
+    }
+
+    fn push(&mut self, frame: Frame) -> Result<(), PathStackOverflow> {
+        if self.len >= N {
+            return Err(PathStackOverflow);
+        }
+        self.frames[self.len] = Some(frame);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Pops the current frame, to be called on `Event::EndObject`/
+    /// `Event::EndArray`. A no-op at the document root.
+    pub fn pop(&mut self) {
+        if self.len > 0 {
+            self.len -= 1;
+            self.frames[self.len] = None;
+        }
+    }
+
+    /// Records the byte span of a key that just completed, to be called
+    /// once a `Event::Key` is emitted while the top frame is an object.
+    /// A no-op if the top frame is an array, or the stack is empty.
+    pub fn record_key(&mut self, start: usize, end: usize) {
+        if let Some(Some(Frame::Object { key })) = self.frames.get_mut(self.len.wrapping_sub(1)) {
+            *key = Some((start, end));
+        }
+    }
+
+    /// Advances the top array frame's index, to be called once a value
+    /// nested directly inside it completes. A no-op if the top frame is an
+    /// object, or the stack is empty.
+    pub fn advance_index(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+        if let Some(Some(Frame::Array { index })) = self.frames.get_mut(self.len - 1) {
+            *index += 1;
+        }
+    }
+
+    /// The current nesting depth (number of frames on the stack).
+    pub fn depth(&self) -> usize {
+        self.len
+    }
+
+    /// Yields the current path from the document root down to the current
+    /// location, one [`PathSegment`] per frame. An object frame with no key
+    /// recorded yet (just entered, before its first `Key` event) is
+    /// omitted.
+    pub fn path(&self) -> impl Iterator<Item = PathSegment> + '_ {
+        self.frames[..self.len].iter().filter_map(|frame| match frame {
+            Some(Frame::Object { key: Some((start, end)) }) => {
+                Some(PathSegment::Key(*start, *end))
+            }
+            Some(Frame::Array { index }) => Some(PathSegment::Index(*index)),
+            _ => None,
+        })
+    }
+
+    /// Whether the current path starts with `prefix`, from the document
+    /// root.
+    pub fn starts_with(&self, prefix: &[PathSegment]) -> bool {
+        self.path().zip(prefix.iter()).all(|(seg, want)| seg == *want)
+            && prefix.len() <= self.path().count()
+    }
+
+    /// Whether the current path ends with `suffix`, i.e. `suffix` matches
+    /// the deepest segments of the path.
+    pub fn ends_with(&self, suffix: &[PathSegment]) -> bool {
+        let path_len = self.path().count();
+        if suffix.len() > path_len {
+            return false;
+        }
+        self.path()
+            .skip(path_len - suffix.len())
+            .zip(suffix.iter())
+            .all(|(seg, want)| seg == *want)
+    }
+}
+
+impl<const N: usize> Default for PathStack<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_is_empty() {
+        let stack: PathStack<4> = PathStack::new();
+        assert_eq!(stack.depth(), 0);
+        assert_eq!(stack.path().count(), 0);
+    }
+
+    #[test]
+    fn test_object_key_path() {
+        let mut stack: PathStack<4> = PathStack::new();
+        stack.push_object().unwrap();
+        stack.record_key(2, 6);
+        assert_eq!(stack.path().collect::<Vec<_>>(), vec![PathSegment::Key(2, 6)]);
+    }
+
+    #[test]
+    fn test_array_index_path() {
+        let mut stack: PathStack<4> = PathStack::new();
+        stack.push_array().unwrap();
+        assert_eq!(stack.path().collect::<Vec<_>>(), vec![PathSegment::Index(0)]);
+        stack.advance_index();
+        assert_eq!(stack.path().collect::<Vec<_>>(), vec![PathSegment::Index(1)]);
+    }
+
+    #[test]
+    fn test_nested_path_sensors_3_value() {
+        let mut stack: PathStack<8> = PathStack::new();
+        stack.push_object().unwrap(); // {
+        stack.record_key(1, 8); // "sensors"
+        stack.push_array().unwrap(); // [
+        stack.advance_index();
+        stack.advance_index();
+        stack.advance_index(); // now at index 3
+        stack.push_object().unwrap(); // { (element 3)
+        stack.record_key(30, 35); // "value"
+
+        assert_eq!(stack.depth(), 3);
+        assert!(stack.ends_with(&[PathSegment::Key(30, 35)]));
+        assert!(stack.ends_with(&[PathSegment::Index(3), PathSegment::Key(30, 35)]));
+        assert!(stack.starts_with(&[PathSegment::Key(1, 8)]));
+    }
+
+    #[test]
+    fn test_pop_restores_parent_frame() {
+        let mut stack: PathStack<4> = PathStack::new();
+        stack.push_object().unwrap();
+        stack.record_key(0, 3);
+        stack.push_array().unwrap();
+        stack.pop();
+        assert_eq!(stack.depth(), 1);
+        assert!(stack.ends_with(&[PathSegment::Key(0, 3)]));
+    }
+
+    #[test]
+    fn test_overflow_rejected_past_capacity() {
+        let mut stack: PathStack<2> = PathStack::new();
+        stack.push_object().unwrap();
+        stack.push_array().unwrap();
+        assert_eq!(stack.push_object(), Err(PathStackOverflow));
+    }
+
+    #[test]
+    fn test_record_key_on_array_is_a_no_op() {
+        let mut stack: PathStack<4> = PathStack::new();
+        stack.push_array().unwrap();
+        stack.record_key(0, 3);
+        assert_eq!(stack.path().collect::<Vec<_>>(), vec![PathSegment::Index(0)]);
+    }
+
+    #[test]
+    fn test_advance_index_on_object_is_a_no_op() {
+        let mut stack: PathStack<4> = PathStack::new();
+        stack.push_object().unwrap();
+        stack.record_key(0, 3);
+        stack.advance_index();
+        assert_eq!(stack.path().collect::<Vec<_>>(), vec![PathSegment::Key(0, 3)]);
+    }
+
+    #[test]
+    fn test_starts_with_empty_prefix_always_matches() {
+        let mut stack: PathStack<4> = PathStack::new();
+        stack.push_array().unwrap();
+        assert!(stack.starts_with(&[]));
+        assert!(stack.ends_with(&[]));
+    }
+}
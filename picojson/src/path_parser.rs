@@ -0,0 +1,374 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A declarative path-matching extraction layer over [`PullParser`].
+//!
+//! Hand-rolling selective extraction today means tracking an ad hoc state
+//! machine (which key are we under, how deep is the current array) and
+//! re-deriving it for every new document shape. [`PathParser`] does that
+//! bookkeeping once: it maintains the current location as events are
+//! pulled from an inner [`PullParser`] and yields only the `String`/
+//! `Number`/`Bool` values whose full path matches one of a fixed set of
+//! caller-supplied patterns, e.g. `products[*].product_id` as
+//! `&[Key("products"), Wildcard, Key("product_id")]`.
+//!
+//! Like [`PathStack`](crate::PathStack), the location stack is a
+//! caller-sized array (`N` bounds nesting depth) rather than a `Vec`, so
+//! this stays zero-allocation. Unlike [`PathStack`] -- built for
+//! [`PushParser`](crate::PushParser) handlers that see a key's byte span
+//! into a buffer they already hold -- [`PathParser`] sits on top of a pull
+//! parser whose events borrow from `&mut self` on every call, so a key
+//! observed on one `next_match` iteration can't be borrowed into a later
+//! one. Recorded keys are instead copied into a small fixed-size buffer
+//! per frame (the same trade-off [`DuplicateKeyStack`](crate::DuplicateKeyStack)
+//! makes for the same reason).
+
+use crate::{Event, ParseError, PullParser};
+
+/// Keys longer than this are never matched -- see [`PathParser::next_match`].
+const MAX_KEY_LEN: usize = 32;
+
+#[derive(Debug, Clone, Copy)]
+struct StoredKey {
+    bytes: [u8; MAX_KEY_LEN],
+    len: u8,
+}
+
+impl StoredKey {
+    /// `None` if `key` is too long to store -- callers with longer keys in
+    /// their real documents should widen `MAX_KEY_LEN` by vendoring this
+    /// module, there's no feature knob for it today.
+    fn new(key: &str) -> Option<Self> {
+        if key.len() > MAX_KEY_LEN {
+            return None;
+        }
+        let mut bytes = [0u8; MAX_KEY_LEN];
+        bytes[..key.len()].copy_from_slice(key.as_bytes());
+        Some(Self {
+            bytes,
+            len: key.len() as u8,
+        })
+    }
+
+    fn matches(&self, key: &str) -> bool {
+        key.len() == self.len as usize && key.as_bytes() == &self.bytes[..self.len as usize]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Frame {
+    /// An open object; `key` is the most recently seen `Key` event's text,
+    /// `None` until the first one arrives (or if it didn't fit in
+    /// [`StoredKey`]).
+    Object { key: Option<StoredKey> },
+    /// An open array; `index` is the index of the current (or most
+    /// recently completed) element.
+    Array { index: u32 },
+}
+
+/// Returned when a document nests deeper than [`PathParser`]'s `N` allows.
+/// Matching can't continue correctly past this point (the path reported
+/// for anything inside the frame that didn't fit would be wrong), so this
+/// ends the traversal rather than attempting to recover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathParserOverflow;
+
+/// Errors [`PathParser::next_match`] can return: either the inner parser
+/// failed, or the document nested deeper than this `PathParser` can track.
+#[derive(Debug, PartialEq)]
+pub enum PathParserError {
+    /// The inner [`PullParser`] reported an error.
+    Parse(ParseError),
+    /// See [`PathParserOverflow`].
+    Overflow(PathParserOverflow),
+}
+
+impl From<ParseError> for PathParserError {
+    fn from(err: ParseError) -> Self {
+        PathParserError::Parse(err)
+    }
+}
+
+/// One segment of a registered extraction [`Pattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternSegment<'p> {
+    /// Matches only an object value recorded under this exact key.
+    Key(&'p str),
+    /// Matches only an array element at this exact index.
+    Index(u32),
+    /// Matches any key or any index at this position.
+    Wildcard,
+}
+
+/// A compiled extraction pattern: the sequence of [`PatternSegment`]s from
+/// the document root to the value being extracted. `products[*].product_id`
+/// is `&[PatternSegment::Key("products"), PatternSegment::Wildcard,
+/// PatternSegment::Key("product_id")]`.
+pub type Pattern<'p> = &'p [PatternSegment<'p>];
+
+/// Wraps an inner [`PullParser`], maintaining the current location as a
+/// fixed-capacity stack of frames and yielding only the scalar events
+/// (`Key`/`String`/`Number`/`Bool`... narrowed to `String`/`Number`/`Bool`
+/// values, see [`Self::next_match`]) whose full path matches one of
+/// `patterns`.
+///
+/// `N` bounds the nesting depth this can track; exceeding it is reported
+/// as [`PathParserError::Overflow`] rather than silently mismatching
+/// everything below that depth.
+pub struct PathParser<'pp, 'p, P, const N: usize> {
+    parser: &'pp mut P,
+    patterns: &'p [Pattern<'p>],
+    frames: [Option<Frame>; N],
+    len: usize,
+}
+
+impl<'pp, 'p, P: PullParser, const N: usize> PathParser<'pp, 'p, P, N> {
+    /// Wraps `parser`, matching every pulled value's path against `patterns`.
+    pub fn new(parser: &'pp mut P, patterns: &'p [Pattern<'p>]) -> Self {
+        Self {
+            parser,
+            patterns,
+            frames: [None; N],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, frame: Frame) -> Result<(), PathParserOverflow> {
+        if self.len >= N {
+            return Err(PathParserOverflow);
+        }
+        self.frames[self.len] = Some(frame);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) {
+        if self.len > 0 {
+            self.len -= 1;
+            self.frames[self.len] = None;
+        }
+    }
+
+    fn record_key(&mut self, key: &str) {
+        if let Some(Some(Frame::Object { key: slot })) = self.frames.get_mut(self.len.wrapping_sub(1)) {
+            *slot = StoredKey::new(key);
+        }
+    }
+
+    fn advance_index(&mut self) {
+        if self.len == 0 {
+            return;
+        }
+        if let Some(Some(Frame::Array { index })) = self.frames.get_mut(self.len - 1) {
+            *index += 1;
+        }
+    }
+
+    /// Whether the current path matches `pattern` exactly: same length,
+    /// and every segment agrees (a `Wildcard` agrees with any key or
+    /// index; a stored key longer than [`MAX_KEY_LEN`] never agrees with
+    /// any `Key` segment, since its text wasn't kept).
+    fn matches(&self, pattern: Pattern<'_>) -> bool {
+        if pattern.len() != self.len {
+            return false;
+        }
+        self.frames[..self.len]
+            .iter()
+            .zip(pattern.iter())
+            .all(|(frame, want)| match (frame, want) {
+                (_, PatternSegment::Wildcard) => true,
+                (Some(Frame::Object { key: Some(stored) }), PatternSegment::Key(want)) => {
+                    stored.matches(want)
+                }
+                (Some(Frame::Array { index }), PatternSegment::Index(want)) => index == want,
+                _ => false,
+            })
+    }
+
+    /// Finds the first pattern in `patterns` the current path matches,
+    /// returning its index.
+    fn matching_pattern(&self) -> Option<usize> {
+        self.patterns.iter().position(|p| self.matches(p))
+    }
+
+    /// Pulls events from the inner parser, applying them to the location
+    /// stack, until a `String`/`Number`/`Bool` value's path matches one of
+    /// `patterns` (returning `Some(Ok((pattern_index, event)))`), the
+    /// inner parser errors or overflows this stack (`Some(Err(_))`), or the
+    /// document ends (`None`).
+    ///
+    /// `Null` values are never yielded even if their path matches: a
+    /// `null` carries no data for a caller to extract, so there's nothing
+    /// useful to hand back for it.
+    pub fn next_match(&mut self) -> Option<Result<(usize, Event<'_, '_>), PathParserError>> {
+        loop {
+            let event = match self.parser.next() {
+                None => return None,
+                Some(Err(err)) => return Some(Err(err.into())),
+                Some(Ok(event)) => event,
+            };
+
+            match &event {
+                Event::StartObject => {
+                    if let Err(overflow) = self.push(Frame::Object { key: None }) {
+                        return Some(Err(PathParserError::Overflow(overflow)));
+                    }
+                    continue;
+                }
+                Event::StartArray => {
+                    if let Err(overflow) = self.push(Frame::Array { index: 0 }) {
+                        return Some(Err(PathParserError::Overflow(overflow)));
+                    }
+                    continue;
+                }
+                Event::EndObject | Event::EndArray => {
+                    self.pop();
+                    self.advance_index();
+                    continue;
+                }
+                Event::Key(key) => {
+                    self.record_key(key.as_str());
+                    continue;
+                }
+                Event::String(_) | Event::Number(_) | Event::Bool(_) => {
+                    let matched = self.matching_pattern();
+                    self.advance_index();
+                    if let Some(pattern_index) = matched {
+                        // `event` was only borrowed for `matches()` above;
+                        // re-fetching it isn't possible (the inner parser
+                        // has already moved on), so thread the already-owned
+                        // value through instead of pulling again.
+                        return Some(Ok((pattern_index, event)));
+                    }
+                    continue;
+                }
+                Event::Null => {
+                    self.advance_index();
+                    continue;
+                }
+                _ => continue,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SliceParser;
+
+    #[test]
+    fn test_top_level_key_matches() {
+        let json = r#"{"feature_flags":{"new_dashboard":true}}"#;
+        let mut parser = SliceParser::new(json);
+        let patterns: &[Pattern] = &[&[
+            PatternSegment::Key("feature_flags"),
+            PatternSegment::Key("new_dashboard"),
+        ]];
+        let mut path_parser: PathParser<_, 4> = PathParser::new(&mut parser, patterns);
+
+        let (id, event) = path_parser.next_match().unwrap().unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(event, Event::Bool(true));
+        assert!(path_parser.next_match().is_none());
+    }
+
+    #[test]
+    fn test_wildcard_array_index_matches_every_element() {
+        let json = r#"{"products":[{"product_id":1},{"product_id":2}]}"#;
+        let mut parser = SliceParser::new(json);
+        let patterns: &[Pattern] = &[&[
+            PatternSegment::Key("products"),
+            PatternSegment::Wildcard,
+            PatternSegment::Key("product_id"),
+        ]];
+        let mut path_parser: PathParser<_, 4> = PathParser::new(&mut parser, patterns);
+
+        let (id, event) = path_parser.next_match().unwrap().unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(event, Event::Number(crate::JsonNumber::Borrowed {
+            raw: "1",
+            parsed: crate::NumberResult::Integer(1),
+        }));
+
+        let (id, event) = path_parser.next_match().unwrap().unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(event, Event::Number(crate::JsonNumber::Borrowed {
+            raw: "2",
+            parsed: crate::NumberResult::Integer(2),
+        }));
+
+        assert!(path_parser.next_match().is_none());
+    }
+
+    #[test]
+    fn test_literal_index_matches_only_that_element() {
+        let json = r#"[10,20,30]"#;
+        let mut parser = SliceParser::new(json);
+        let patterns: &[Pattern] = &[&[PatternSegment::Index(1)]];
+        let mut path_parser: PathParser<_, 4> = PathParser::new(&mut parser, patterns);
+
+        let (id, event) = path_parser.next_match().unwrap().unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(event, Event::Number(crate::JsonNumber::Borrowed {
+            raw: "20",
+            parsed: crate::NumberResult::Integer(20),
+        }));
+        assert!(path_parser.next_match().is_none());
+    }
+
+    #[test]
+    fn test_non_matching_values_are_skipped() {
+        let json = r#"{"a":1,"b":2,"c":3}"#;
+        let mut parser = SliceParser::new(json);
+        let patterns: &[Pattern] = &[&[PatternSegment::Key("b")]];
+        let mut path_parser: PathParser<_, 4> = PathParser::new(&mut parser, patterns);
+
+        let (id, event) = path_parser.next_match().unwrap().unwrap();
+        assert_eq!(id, 0);
+        assert_eq!(event, Event::Number(crate::JsonNumber::Borrowed {
+            raw: "2",
+            parsed: crate::NumberResult::Integer(2),
+        }));
+        assert!(path_parser.next_match().is_none());
+    }
+
+    #[test]
+    fn test_multiple_patterns_report_which_one_matched() {
+        let json = r#"{"a":1,"b":2}"#;
+        let mut parser = SliceParser::new(json);
+        let patterns: &[Pattern] = &[&[PatternSegment::Key("b")], &[PatternSegment::Key("a")]];
+        let mut path_parser: PathParser<_, 4> = PathParser::new(&mut parser, patterns);
+
+        // "a" is seen first in the document but matches pattern index 1.
+        let (id, _) = path_parser.next_match().unwrap().unwrap();
+        assert_eq!(id, 1);
+        let (id, _) = path_parser.next_match().unwrap().unwrap();
+        assert_eq!(id, 0);
+    }
+
+    #[test]
+    fn test_null_never_matches() {
+        let json = r#"{"a":null}"#;
+        let mut parser = SliceParser::new(json);
+        let patterns: &[Pattern] = &[&[PatternSegment::Key("a")]];
+        let mut path_parser: PathParser<_, 4> = PathParser::new(&mut parser, patterns);
+        assert!(path_parser.next_match().is_none());
+    }
+
+    #[test]
+    fn test_depth_beyond_capacity_overflows() {
+        let json = r#"{"a":{"b":{"c":1}}}"#;
+        let mut parser = SliceParser::new(json);
+        let patterns: &[Pattern] = &[&[
+            PatternSegment::Key("a"),
+            PatternSegment::Key("b"),
+            PatternSegment::Key("c"),
+        ]];
+        let mut path_parser: PathParser<_, 2> = PathParser::new(&mut parser, patterns);
+
+        match path_parser.next_match() {
+            Some(Err(PathParserError::Overflow(PathParserOverflow))) => {}
+            other => panic!("expected Overflow, got {other:?}"),
+        }
+    }
+}
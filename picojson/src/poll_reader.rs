@@ -0,0 +1,514 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `PollReader`: a non-blocking counterpart to
+//! [`Reader`](crate::stream_parser::Reader), for embedded-io-style
+//! transports (UART DMA, non-blocking sockets) that can report "no data
+//! *yet*" as an outcome distinct from true end-of-stream, instead of
+//! blocking `read` until bytes arrive.
+//!
+//! [`Reader`](crate::stream_parser::Reader)'s contract is deliberately
+//! strict -- see the note on that trait -- because overloading its `read`
+//! return value with a recoverable "nothing yet" outcome would ripple
+//! through `StreamParser`'s shared per-byte loop and every existing
+//! blocking `Reader` impl. That note already points non-blocking sources at
+//! this crate's purpose-built answer: feed bytes to a
+//! [`PushParser`](crate::PushParser)/[`FeedParser`](crate::FeedParser)/
+//! [`PollParser`](crate::PollParser) as they arrive and poll for
+//! [`Poll::NeedMoreInput`]. This module is the missing glue for that
+//! family -- a `PollReader`-driven adapter that calls `feed`/`finish` on the
+//! caller's behalf, the same role [`AsyncFeedParser`](crate::AsyncFeedParser)
+//! plays for an awaitable [`AsyncReader`](crate::AsyncReader), but for a
+//! synchronous, non-blocking source instead of one driven across `.await`
+//! points.
+//!
+//! [`AppendReader`] is a ready-made [`PollReader`] for the common case of
+//! bytes arriving via a callback rather than a transport worth writing a
+//! `PollReader` impl for -- push bytes in with [`AppendReader::append`] as
+//! they show up.
+
+extern crate alloc;
+
+use crate::feed_parser::{FeedEvent, Poll, PollParser};
+use crate::parse_error::ParseError;
+use crate::ujson::{BitStackConfig, DefaultConfig};
+
+/// Outcome of a single [`PollReader::poll_read`] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollRead {
+    /// `n` bytes were written to the start of the caller's buffer. `n == 0`
+    /// means true end-of-stream, same as
+    /// [`Reader::read`](crate::stream_parser::Reader::read)'s `0`.
+    Ready(usize),
+    /// No bytes are available right now, but the stream hasn't ended --
+    /// call again later, once more may have arrived.
+    Pending,
+}
+
+/// Non-blocking counterpart to [`Reader`](crate::stream_parser::Reader).
+/// See the [module docs](self) for why this is a separate trait rather than
+/// a new `Reader` outcome.
+pub trait PollReader {
+    /// The error type returned by read operations.
+    type Error;
+
+    /// Tries to read into `buf` without blocking, reporting
+    /// [`PollRead::Pending`] instead of waiting when nothing is available
+    /// yet. Must never report `Pending` after already reporting true
+    /// end-of-stream via `Ready(0)`.
+    fn poll_read(&mut self, buf: &mut [u8]) -> Result<PollRead, Self::Error>;
+}
+
+/// Drives a [`PollParser`] from a [`PollReader`], calling `feed`/`finish`
+/// on the caller's behalf instead of asking them to poll the transport and
+/// call those by hand. See the [module docs](self) for how this relates to
+/// [`AsyncFeedParser`](crate::AsyncFeedParser).
+pub struct PollFeedParser<'buf, R: PollReader, C: BitStackConfig = DefaultConfig> {
+    reader: R,
+    /// The still-unconsumed tail of the caller's read buffer. `None` only
+    /// transiently, while a `poll_event` call has it split out to read into.
+    buf: Option<&'buf mut [u8]>,
+    inner: PollParser<'buf, 'buf, C>,
+    /// Set once `reader` has reported true end-of-stream and
+    /// [`PollParser::finish`] has been called.
+    reader_done: bool,
+}
+
+impl<'buf, R: PollReader> PollFeedParser<'buf, R, DefaultConfig> {
+    /// Creates a new parser reading from `reader`, using `buf` as the
+    /// read window and `scratch` for unescaping tokens split across
+    /// reads -- the same roles they play for [`AsyncFeedParser::new`].
+    /// `buf` is filled front to back and never reused, so once it's used up
+    /// before the input ends, `poll_event` returns
+    /// [`ParseError::InputBufferFull`].
+    pub fn new(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self::with_config(reader, buf, scratch)
+    }
+
+    /// Like [`Self::new`], but for a sequence of whitespace-separated
+    /// top-level JSON values (NDJSON-style) instead of exactly one -- same
+    /// multi-document mode as [`PollParser::new_ndjson`].
+    pub fn new_ndjson(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self::with_config_ndjson(reader, buf, scratch)
+    }
+}
+
+impl<'buf, R: PollReader, C: BitStackConfig> PollFeedParser<'buf, R, C> {
+    /// Like [`Self::new`], but with a custom [`BitStackConfig`].
+    pub fn with_config(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self {
+            reader,
+            buf: Some(buf),
+            inner: PollParser::new(scratch),
+            reader_done: false,
+        }
+    }
+
+    /// Like [`Self::new_ndjson`], but with a custom [`BitStackConfig`].
+    pub fn with_config_ndjson(reader: R, buf: &'buf mut [u8], scratch: &'buf mut [u8]) -> Self {
+        Self {
+            reader,
+            buf: Some(buf),
+            inner: PollParser::new_ndjson(scratch),
+            reader_done: false,
+        }
+    }
+
+    /// See [`PushParser::set_max_depth`](crate::PushParser::set_max_depth).
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.inner.set_max_depth(max_depth);
+    }
+
+    /// See [`PushParser::depth`](crate::PushParser::depth).
+    pub fn depth(&self) -> usize {
+        self.inner.depth()
+    }
+
+    /// See [`PushParser::remaining_depth`](crate::PushParser::remaining_depth).
+    pub fn remaining_depth(&self) -> Option<usize> {
+        self.inner.remaining_depth()
+    }
+
+    /// See [`PushParser::in_object`](crate::PushParser::in_object).
+    pub fn in_object(&self) -> bool {
+        self.inner.in_object()
+    }
+
+    /// See [`PushParser::in_array`](crate::PushParser::in_array).
+    pub fn in_array(&self) -> bool {
+        self.inner.in_array()
+    }
+
+    /// See [`PushParser::set_reject_escaped_keys`](crate::PushParser::set_reject_escaped_keys).
+    pub fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.inner.set_reject_escaped_keys(reject);
+    }
+
+    /// See [`PushParser::set_reject_bidi_controls`](crate::PushParser::set_reject_bidi_controls).
+    pub fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.inner.set_reject_bidi_controls(reject);
+    }
+
+    /// See [`PushParser::set_surrogate_policy`](crate::PushParser::set_surrogate_policy).
+    pub fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.inner.set_surrogate_policy(policy);
+    }
+
+    /// See [`PushParser::set_lenient_syntax`](crate::PushParser::set_lenient_syntax).
+    pub fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.inner.set_lenient_syntax(enabled);
+    }
+
+    /// See [`PushParser::position`](crate::PushParser::position).
+    pub fn position(&self) -> crate::Position {
+        self.inner.position()
+    }
+
+    /// Tries to pull the next decoded event without blocking, topping up
+    /// from `reader` whenever the fed input runs dry.
+    ///
+    /// Reports [`Poll::NeedMoreInput`] -- rather than blocking -- both when
+    /// `reader` itself reports [`PollRead::Pending`] and (as usual for
+    /// [`PollParser::poll_event`]) when it's simply drained everything fed
+    /// so far. Call again once more bytes may be ready; nothing already fed
+    /// is lost or re-parsed, the same resumption guarantee [`PollParser`]
+    /// gives a chunk split at any byte.
+    pub fn poll_event(&mut self) -> Result<Poll, ParseError> {
+        loop {
+            match self.inner.poll_event() {
+                Poll::Event(event) => return Ok(Poll::Event(event)),
+                Poll::NeedMoreInput => {
+                    if self.reader_done {
+                        return Ok(Poll::NeedMoreInput);
+                    }
+                    let buf = self.buf.take().expect("buf missing between poll_event calls");
+                    if buf.is_empty() {
+                        self.buf = Some(buf);
+                        return Err(ParseError::InputBufferFull);
+                    }
+                    match self.reader.poll_read(buf) {
+                        Ok(PollRead::Ready(0)) => {
+                            self.buf = Some(buf);
+                            self.reader_done = true;
+                            self.inner.finish()?;
+                        }
+                        Ok(PollRead::Ready(n)) => {
+                            let (filled, rest) = buf.split_at_mut(n);
+                            self.buf = Some(rest);
+                            self.inner.feed(filled)?;
+                        }
+                        Ok(PollRead::Pending) => {
+                            self.buf = Some(buf);
+                            return Ok(Poll::NeedMoreInput);
+                        }
+                        Err(_e) => {
+                            self.buf = Some(buf);
+                            return Err(ParseError::ReaderError);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A [`PollReader`] backed by a fixed-capacity caller-owned byte ring,
+/// for callers that receive bytes from a callback or interrupt handler
+/// (rather than a transport they could implement [`PollReader`] against
+/// directly) and just need somewhere to hand them to the parser -- the
+/// same role [`ChunkReader`](crate::ChunkReader) plays for
+/// [`Reader`](crate::stream_parser::Reader), but non-blocking and fed by
+/// pushing instead of pulling.
+///
+/// Bytes are appended with [`Self::append`] between [`PollFeedParser::poll_event`]
+/// calls; [`Self::finish`] marks the end of the stream, after which a
+/// drained ring reports true end-of-stream (`Ready(0)`) instead of
+/// [`PollRead::Pending`].
+pub struct AppendReader<'a> {
+    ring: &'a mut [u8],
+    start: usize,
+    len: usize,
+    finished: bool,
+}
+
+impl<'a> AppendReader<'a> {
+    /// Creates a reader whose ring buffer is `ring`; `append`ed bytes beyond
+    /// its capacity are rejected until `poll_read` drains some.
+    pub fn new(ring: &'a mut [u8]) -> Self {
+        Self {
+            ring,
+            start: 0,
+            len: 0,
+            finished: false,
+        }
+    }
+
+    /// Copies as much of `data` into the ring as there's room for, returning
+    /// the number of bytes actually copied. A short return means the ring is
+    /// full -- call again with the remainder once `poll_read` has drained it.
+    pub fn append(&mut self, data: &[u8]) -> usize {
+        let capacity = self.ring.len();
+        let free = capacity.saturating_sub(self.len);
+        let n = data.len().min(free);
+        for (i, &byte) in data[..n].iter().enumerate() {
+            let idx = (self.start + self.len + i) % capacity;
+            self.ring[idx] = byte;
+        }
+        self.len += n;
+        n
+    }
+
+    /// Marks the stream as finished: once the ring is fully drained,
+    /// `poll_read` reports true end-of-stream instead of `Pending`.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+}
+
+impl PollReader for AppendReader<'_> {
+    type Error = ();
+
+    fn poll_read(&mut self, buf: &mut [u8]) -> Result<PollRead, Self::Error> {
+        if self.len == 0 {
+            return Ok(if self.finished {
+                PollRead::Ready(0)
+            } else {
+                PollRead::Pending
+            });
+        }
+
+        let capacity = self.ring.len();
+        let n = buf.len().min(self.len);
+        for (i, dest) in buf.iter_mut().take(n).enumerate() {
+            *dest = self.ring[(self.start + i) % capacity];
+        }
+        self.start = (self.start + n) % capacity;
+        self.len -= n;
+        Ok(PollRead::Ready(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A source that reports [`PollRead::Pending`] a fixed number of times
+    /// before handing over a chunk, then repeats -- simulating a transport
+    /// whose data arrives in bursts with idle gaps between them.
+    struct BurstyReader<'a> {
+        data: &'a [u8],
+        chunk_size: usize,
+        pending_until: u32,
+        polls_since_last_chunk: u32,
+    }
+
+    impl<'a> BurstyReader<'a> {
+        fn new(data: &'a [u8], chunk_size: usize, pending_until: u32) -> Self {
+            Self {
+                data,
+                chunk_size,
+                pending_until,
+                polls_since_last_chunk: 0,
+            }
+        }
+    }
+
+    impl PollReader for BurstyReader<'_> {
+        type Error = ();
+
+        fn poll_read(&mut self, buf: &mut [u8]) -> Result<PollRead, Self::Error> {
+            if self.polls_since_last_chunk < self.pending_until {
+                self.polls_since_last_chunk += 1;
+                return Ok(PollRead::Pending);
+            }
+            self.polls_since_last_chunk = 0;
+            let n = buf.len().min(self.data.len()).min(self.chunk_size.max(1));
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(PollRead::Ready(n))
+        }
+    }
+
+    #[test]
+    fn test_poll_feed_parser_resumes_across_pending_gaps() {
+        let json = br#"{"a": [1, 2, 3]}"#;
+        let reader = BurstyReader::new(json, 3, 2);
+        // `buf` is consumed, not reused, so it has to hold the whole document.
+        let mut buf = [0u8; 32];
+        let mut scratch = [0u8; 64];
+        let mut parser = PollFeedParser::new(reader, &mut buf, &mut scratch);
+
+        let mut events = alloc::vec::Vec::new();
+        loop {
+            match parser.poll_event().unwrap() {
+                Poll::Event(event) => {
+                    let done = event == FeedEvent::EndDocument;
+                    events.push(event);
+                    if done {
+                        break;
+                    }
+                }
+                Poll::NeedMoreInput => continue,
+            }
+        }
+
+        assert_eq!(
+            events,
+            alloc::vec![
+                FeedEvent::StartDocument,
+                FeedEvent::StartObject,
+                FeedEvent::Key("a".into()),
+                FeedEvent::StartArray,
+                FeedEvent::Number("1".into()),
+                FeedEvent::Number("2".into()),
+                FeedEvent::Number("3".into()),
+                FeedEvent::EndArray,
+                FeedEvent::EndObject,
+                FeedEvent::EndDocument,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_poll_feed_parser_pending_does_not_consume_input_buffer() {
+        // A reader that is permanently pending must not advance `poll_event`
+        // past `NeedMoreInput`, and must not touch the fed buffer at all.
+        struct AlwaysPending;
+        impl PollReader for AlwaysPending {
+            type Error = ();
+            fn poll_read(&mut self, _buf: &mut [u8]) -> Result<PollRead, Self::Error> {
+                Ok(PollRead::Pending)
+            }
+        }
+
+        let mut buf = [0u8; 8];
+        let mut scratch = [0u8; 64];
+        let mut parser = PollFeedParser::new(AlwaysPending, &mut buf, &mut scratch);
+
+        for _ in 0..3 {
+            assert_eq!(parser.poll_event().unwrap(), Poll::NeedMoreInput);
+        }
+    }
+
+    #[test]
+    fn test_append_reader_reports_pending_until_appended() {
+        let mut ring = [0u8; 8];
+        let mut reader = AppendReader::new(&mut ring);
+        let mut buf = [0u8; 8];
+
+        assert_eq!(reader.poll_read(&mut buf).unwrap(), PollRead::Pending);
+
+        assert_eq!(reader.append(b"hi"), 2);
+        assert_eq!(reader.poll_read(&mut buf).unwrap(), PollRead::Ready(2));
+        assert_eq!(&buf[..2], b"hi");
+    }
+
+    #[test]
+    fn test_append_reader_wraps_around_the_ring() {
+        let mut ring = [0u8; 4];
+        let mut reader = AppendReader::new(&mut ring);
+        let mut buf = [0u8; 4];
+
+        assert_eq!(reader.append(b"ab"), 2);
+        assert_eq!(reader.poll_read(&mut buf).unwrap(), PollRead::Ready(2));
+        assert_eq!(&buf[..2], b"ab");
+
+        // `start` has advanced past the end of the backing array now, so
+        // this append and the following read both wrap.
+        assert_eq!(reader.append(b"cdef"), 4);
+        assert_eq!(reader.poll_read(&mut buf).unwrap(), PollRead::Ready(4));
+        assert_eq!(&buf, b"cdef");
+    }
+
+    #[test]
+    fn test_append_reader_rejects_overflow_until_drained() {
+        let mut ring = [0u8; 4];
+        let mut reader = AppendReader::new(&mut ring);
+
+        assert_eq!(reader.append(b"abcd"), 4);
+        // The ring is full: the rest of this append is rejected, not
+        // silently dropped from the middle.
+        assert_eq!(reader.append(b"ef"), 0);
+
+        let mut buf = [0u8; 2];
+        assert_eq!(reader.poll_read(&mut buf).unwrap(), PollRead::Ready(2));
+        assert_eq!(&buf, b"ab");
+
+        // Now that two bytes have drained, there's room again.
+        assert_eq!(reader.append(b"ef"), 2);
+    }
+
+    #[test]
+    fn test_append_reader_reports_end_of_stream_once_finished_and_drained() {
+        let mut ring = [0u8; 4];
+        let mut reader = AppendReader::new(&mut ring);
+        let mut buf = [0u8; 4];
+
+        reader.append(b"hi");
+        reader.finish();
+
+        // Still draining real bytes, even though `finish` was already called.
+        assert_eq!(reader.poll_read(&mut buf).unwrap(), PollRead::Ready(2));
+        // Drained and finished: true end-of-stream, not `Pending`.
+        assert_eq!(reader.poll_read(&mut buf).unwrap(), PollRead::Ready(0));
+    }
+
+    #[test]
+    fn test_append_reader_drives_poll_feed_parser_end_to_end() {
+        let json = br#"{"a":[1,2,3]}"#;
+        let mut ring = [0u8; 8];
+        let reader = AppendReader::new(&mut ring);
+        let mut buf = [0u8; 32];
+        let mut scratch = [0u8; 64];
+        let mut parser = PollFeedParser::new(reader, &mut buf, &mut scratch);
+
+        // Feed the whole document in small bursts, interleaved with polling,
+        // then signal end-of-stream once it's all been appended.
+        let mut fed = 0;
+        let mut events = alloc::vec::Vec::new();
+        loop {
+            if fed < json.len() {
+                let reader = parser_reader_mut(&mut parser);
+                fed += reader.append(&json[fed..(fed + 3).min(json.len())]);
+                if fed >= json.len() {
+                    reader.finish();
+                }
+            }
+
+            match parser.poll_event().unwrap() {
+                Poll::Event(event) => {
+                    let done = event == FeedEvent::EndDocument;
+                    events.push(event);
+                    if done {
+                        break;
+                    }
+                }
+                Poll::NeedMoreInput => continue,
+            }
+        }
+
+        assert_eq!(
+            events,
+            alloc::vec![
+                FeedEvent::StartDocument,
+                FeedEvent::StartObject,
+                FeedEvent::Key("a".into()),
+                FeedEvent::StartArray,
+                FeedEvent::Number("1".into()),
+                FeedEvent::Number("2".into()),
+                FeedEvent::Number("3".into()),
+                FeedEvent::EndArray,
+                FeedEvent::EndObject,
+                FeedEvent::EndDocument,
+            ]
+        );
+    }
+
+    /// Test-only accessor: `PollFeedParser`'s reader isn't otherwise
+    /// reachable once handed over, but these tests need to keep appending
+    /// to it between `poll_event` calls.
+    fn parser_reader_mut<'a, 'buf>(
+        parser: &'a mut PollFeedParser<'buf, AppendReader<'buf>>,
+    ) -> &'a mut AppendReader<'buf> {
+        &mut parser.reader
+    }
+}
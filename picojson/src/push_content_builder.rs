@@ -18,6 +18,48 @@ use crate::{Event, JsonNumber, ParseError};
 pub trait PushParserHandler<'input, 'scratch, E> {
     /// Handles a single, complete JSON event.
     fn handle_event(&mut self, event: Event<'input, 'scratch>) -> Result<(), E>;
+
+    /// Called when a new value (a scalar, object, or array) is about to
+    /// begin. Return [`crate::RawCapture::CaptureRaw`] to receive that
+    /// value's verbatim source text as a single [`Event::RawValue`] instead
+    /// of its normal decoded event(s).
+    ///
+    /// The default implementation always continues with normal decoding.
+    fn on_value_start(&mut self) -> crate::RawCapture {
+        crate::RawCapture::Continue
+    }
+
+    /// Handles a single, complete JSON event, with the option to steer the
+    /// parser via the returned [`crate::Flow`]: skip the subtree a
+    /// `StartObject`/`StartArray` just opened, or stop parsing altogether.
+    ///
+    /// The default implementation forwards to [`Self::handle_event`] and
+    /// always continues; override this instead of `handle_event` to use
+    /// [`crate::Flow::SkipContainer`]/[`crate::Flow::Stop`].
+    fn handle_event_flow(&mut self, event: Event<'input, 'scratch>) -> Result<crate::Flow, E> {
+        self.handle_event(event)?;
+        Ok(crate::Flow::Continue)
+    }
+
+    /// Handles a single, complete JSON event together with the raw-input
+    /// [`crate::Span`] it was produced from, reported in raw-input byte
+    /// coordinates rather than unescaped-scratch-buffer coordinates --
+    /// spanning the opening quote to the closing one (or the whole lexeme
+    /// for a number), the same convention
+    /// [`SliceParser::next_event_with_span`](crate::SliceParser::next_event_with_span)
+    /// uses. Only meaningful for [`Event::String`]/[`Event::Key`]/
+    /// [`Event::Number`]; every other event reports [`crate::Span::default`].
+    ///
+    /// The default implementation ignores the span and forwards to
+    /// [`Self::handle_event_flow`]; override this instead to report source
+    /// locations for error messages.
+    fn handle_event_with_span(
+        &mut self,
+        event: Event<'input, 'scratch>,
+        _span: crate::Span,
+    ) -> Result<crate::Flow, E> {
+        self.handle_event_flow(event)
+    }
 }
 
 /// Content extractor for PushParser.
@@ -46,6 +88,16 @@ pub struct PushContentBuilder<'input, 'scratch> {
     in_unicode_escape: bool,
     /// Whether we're currently processing a simple escape sequence
     in_simple_escape: bool,
+    /// Current line number (1-based), for diagnostics
+    line: usize,
+    /// Current column number (1-based), for diagnostics
+    column: usize,
+    /// Raw-input [`crate::Span`] of the most recently completed
+    /// `String`/`Key`/`Number`, set by [`extract_string_content`](ContentExtractor::extract_string_content)/
+    /// [`extract_key_content`](ContentExtractor::extract_key_content)/
+    /// [`extract_number`](ContentExtractor::extract_number). Read via
+    /// [`Self::last_span`].
+    last_span: crate::Span,
 }
 
 impl<'input, 'scratch> PushContentBuilder<'input, 'scratch> {
@@ -64,9 +116,19 @@ impl<'input, 'scratch> PushContentBuilder<'input, 'scratch> {
             chunk_cursor: 0,
             in_unicode_escape: false,
             in_simple_escape: false,
+            line: 1,
+            column: 1,
+            last_span: crate::Span { start: 0, end: 0 },
         }
     }
 
+    /// Raw-input [`crate::Span`] of the most recently completed
+    /// `String`/`Key`/`Number`. See [`PushParserHandler::handle_event_with_span`]
+    /// for the exact convention and its scope.
+    pub fn last_span(&self) -> crate::Span {
+        self.last_span
+    }
+
     /// Set the current chunk of data to be processed
     pub fn set_chunk(&mut self, chunk: &'input [u8]) {
         self.current_chunk = chunk;
@@ -91,6 +153,13 @@ impl<'input, 'scratch> PushContentBuilder<'input, 'scratch> {
             .map_err(ParseError::from)
     }
 
+    /// Append a run of bytes to the unescaped buffer in one copy
+    pub fn append_unescaped_slice(&mut self, bytes: &[u8]) -> Result<(), ParseError> {
+        self.stream_buffer
+            .append_unescaped_slice(bytes)
+            .map_err(ParseError::from)
+    }
+
     /// Apply queued unescaped content reset if needed
     pub fn apply_unescaped_reset_if_queued(&mut self) {
         if self.unescaped_reset_queued {
@@ -115,7 +184,25 @@ impl<'input, 'scratch> PushContentBuilder<'input, 'scratch> {
         self.position_offset
     }
 
-
+    /// Get the current absolute position, including line/column, for diagnostics.
+    ///
+    /// `byte_offset`/`line`/`column` all advance inside [`next_byte`](Self::next_byte)
+    /// as part of consuming that byte, not the `chunk_cursor`/`peek_byte`
+    /// lookahead -- the same "advance immediately after consuming" convention
+    /// [`ParserCore::advance_position`](crate::event_processor::ParserCore::advance_position)
+    /// uses for `SliceParser`/`StreamParser`, so a `Position` means the same
+    /// thing regardless of which front-end produced it. `line`/`column` live
+    /// on `self` directly rather than on `current_chunk`, so they carry over
+    /// unchanged across a [`set_chunk`](Self::set_chunk) call the way
+    /// `position_offset` already does; [`reset_input`](Self::reset_input)
+    /// only clears `current_chunk`/`chunk_cursor` and leaves them alone.
+    pub fn position(&self) -> crate::Position {
+        crate::Position {
+            byte_offset: self.position_offset + self.chunk_cursor,
+            line: self.line,
+            column: self.column,
+        }
+    }
 }
 
 impl ContentExtractor for PushContentBuilder<'_, '_> {
@@ -152,6 +239,10 @@ impl ContentExtractor for PushContentBuilder<'_, '_> {
         // Use get_content_piece which will automatically choose scratch buffer or direct slice
         // PushParser: current_position points AT the closing quote, but get_content_piece expects
         // position AFTER the closing quote, so add 1
+        self.last_span = crate::Span {
+            start: start_pos,
+            end: self.current_position + 1,
+        };
         let content_piece =
             crate::shared::get_content_piece(self, start_pos + 1, self.current_position + 1)?;
         content_piece.into_string().map(Event::String)
@@ -164,6 +255,10 @@ impl ContentExtractor for PushContentBuilder<'_, '_> {
         }
 
         // Use get_content_piece which will automatically choose scratch buffer or direct slice
+        self.last_span = crate::Span {
+            start: start_pos,
+            end: self.current_position + 1,
+        };
         let content_piece =
             crate::shared::get_content_piece(self, start_pos + 1, self.current_position + 1)?;
         content_piece.into_string().map(Event::Key)
@@ -191,7 +286,14 @@ impl ContentExtractor for PushContentBuilder<'_, '_> {
             self.queue_unescaped_reset();
         }
 
-        // Use get_content_piece which will automatically choose scratch buffer or direct slice
+        // Use get_content_piece which will automatically choose scratch buffer or direct slice.
+        // Unlike String/Key, `start_pos` here is one byte before the first digit (see
+        // `ContentRange::number_start_from_current`), not a delimiter to skip, so the
+        // lexeme itself -- not just its content -- starts at `start_pos + 1`.
+        self.last_span = crate::Span {
+            start: start_pos + 1,
+            end: self.current_position + 1,
+        };
         let content_piece =
             crate::shared::get_content_piece(self, start_pos + 1, self.current_position + 1)?;
         let number_bytes = content_piece.as_bytes();
@@ -199,6 +301,18 @@ impl ContentExtractor for PushContentBuilder<'_, '_> {
         Ok(Event::Number(json_number))
     }
 
+    fn extract_raw(&mut self, start_pos: usize, end_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+        // PushParser captures raw values itself via `RawCapture`/`RawCaptureState`
+        // in push_parser.rs, since a value can span multiple `write()` chunks;
+        // this is only reachable through the shared `ContentExtractor` surface.
+        let bytes = self
+            .stream_buffer
+            .get_string_slice(start_pos, end_pos)
+            .map_err(ParseError::from)?;
+        let text = crate::shared::from_utf8(bytes)?;
+        Ok(Event::RawValue(crate::String::Borrowed(text)))
+    }
+
     fn begin_escape_sequence(&mut self) -> Result<(), ParseError> {
         // Implement copy-on-escape: copy the clean part before the escape to unescaped buffer
         if !self.has_unescaped_content() {
@@ -307,10 +421,10 @@ impl PushContentBuilder<'_, '_> {
                 ));
             }
 
+            // Bulk-copy the whole run in one go rather than one byte at a time;
+            // this is the hot path for long unescaped string/number tokens.
             let partial_slice = &self.current_chunk[slice_start..slice_end];
-            for &byte in partial_slice {
-                self.stream_buffer.append_unescaped_byte(byte)?;
-            }
+            self.stream_buffer.append_unescaped_slice(partial_slice)?;
         }
         Ok(())
     }
@@ -345,12 +459,21 @@ impl<'input, 'scratch> DataSource<'input, 'scratch> for PushContentBuilder<'inpu
             let byte = self.current_chunk[self.chunk_cursor];
             self.chunk_cursor += 1;
             self.current_position = self.position_offset + self.chunk_cursor - 1;
+            self.column += 1;
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 1;
+            }
             Ok(Some(byte))
         } else {
             Ok(None)
         }
     }
 
+    fn peek_byte(&mut self) -> Result<Option<u8>, ParseError> {
+        Ok(self.current_chunk.get(self.chunk_cursor).copied())
+    }
+
     fn get_borrowed_slice(
         &'input self,
         start: usize,
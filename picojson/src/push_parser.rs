@@ -6,12 +6,27 @@
 
 use crate::event_processor::{ContentExtractor, EscapeTiming, ParserCore};
 use crate::push_content_builder::{PushContentBuilder, PushParserHandler};
-use crate::shared::{ContentKind, DataSource, State};
+use crate::shared::{ContentKind, DataSource, RawCapture, State};
 use crate::stream_buffer::StreamBufferError;
-use crate::{ujson, BitStackConfig, Event, ParseError};
+use crate::{ujson, BitStackConfig, Event, Flow, ParseError};
 
 extern crate alloc;
 
+/// Tracks an in-progress verbatim capture of a container value (object or
+/// array) requested via [`RawCapture::CaptureRaw`].
+///
+/// `carry` only holds bytes once the captured value has crossed a `write()`
+/// chunk boundary; while the value stays within a single chunk, the final
+/// text is borrowed directly from the input slice.
+struct RawCaptureState {
+    /// Absolute offset of the value's first byte (the opening `{` or `[`).
+    start: usize,
+    /// Nesting depth still to close before the captured value is complete.
+    depth: usize,
+    /// Bytes carried over from earlier chunks, once the value has spanned one.
+    carry: alloc::vec::Vec<u8>,
+}
+
 /// A SAX-style JSON push parser.
 ///
 /// Generic over BitStack storage type for configurable nesting depth. Parsing
@@ -32,6 +47,31 @@ where
     handler: H,
     /// Core parser logic shared with other parsers
     core: ParserCore<C::Bucket, C::Counter>,
+    /// Active verbatim capture of a container value, if the handler requested one
+    raw_capture: Option<RawCaptureState>,
+    /// Set when the handler asked to capture a String/Number value raw (via
+    /// [`PushParserHandler::on_value_start`]) and that value turned out to
+    /// span a `write()` chunk boundary. The bytes accumulate in the same
+    /// scratch buffer a decoded value would use -- an escape-free scalar's
+    /// unescaped bytes and its raw bytes are identical -- so this only needs
+    /// to remember *that* the pending content should come out as
+    /// [`Event::RawValue`] instead of `String`/`Number` once it's complete.
+    scalar_raw_capture: bool,
+    /// Nesting depth still to close before a handler-requested
+    /// [`Flow::SkipContainer`] subtree is fully consumed.
+    skip_depth: Option<usize>,
+    /// Set once the handler has returned [`Flow::Stop`]; from then on
+    /// `write()` delivers no further events and returns immediately.
+    stopped: bool,
+    /// Whether this parser accepts a sequence of concatenated top-level
+    /// values (NDJSON-style) instead of exactly one.
+    streaming: bool,
+    /// In streaming mode, whether the next top-level value still needs its
+    /// `Event::StartDocument` emitted.
+    awaiting_document_start: bool,
+    /// In streaming mode, how many top-level values have completed so far.
+    /// Always `0` outside streaming mode.
+    document_count: usize,
 }
 
 impl<'input, 'scratch, H, C> PushParser<'input, 'scratch, H, C>
@@ -44,6 +84,212 @@ where
             extractor: PushContentBuilder::new(buffer),
             handler,
             core: ParserCore::new_chunked(),
+            raw_capture: None,
+            scalar_raw_capture: false,
+            skip_depth: None,
+            stopped: false,
+            streaming: false,
+            awaiting_document_start: false,
+            document_count: 0,
+        }
+    }
+
+    /// Creates a new `PushParser` that accepts a sequence of whitespace- or
+    /// newline-separated top-level JSON values (NDJSON-style), instead of
+    /// exactly one. A fresh `Event::StartDocument`/`Event::EndDocument` pair
+    /// is emitted around each value, [`Self::document_count`] increments as
+    /// each one completes, and the scratch buffer is reused as-is between
+    /// documents (nothing here is sized per-document, so memory stays
+    /// constant over an arbitrarily long stream). Separator detection and
+    /// the start/end-of-document reset already work across `write()` chunk
+    /// boundaries, since they ride on the same tokenizer that already
+    /// resumes mid-value across chunks.
+    ///
+    /// This accepts *any* whitespace between values (JSON Text Sequences'
+    /// separation, a superset of strict NDJSON's "exactly one `\n`"); it
+    /// doesn't offer a mode that rejects a document not followed by a
+    /// newline specifically. Distinguishing the two would need a config
+    /// type parameter threaded the same way [`BitStackConfig`] is, to stay
+    /// zero-cost when unused -- a separate, larger change from completing
+    /// the streaming mode this builds on.
+    pub fn new_streaming(handler: H, buffer: &'scratch mut [u8]) -> Self {
+        Self {
+            extractor: PushContentBuilder::new(buffer),
+            handler,
+            core: ParserCore::new_chunked(),
+            raw_capture: None,
+            scalar_raw_capture: false,
+            skip_depth: None,
+            stopped: false,
+            streaming: true,
+            awaiting_document_start: true,
+            document_count: 0,
+        }
+    }
+
+    /// Number of top-level values completed so far in streaming mode.
+    /// Always `0` outside streaming mode (a single document isn't counted
+    /// until [`Self::finish`], by which point the parser is already
+    /// consumed).
+    pub fn document_count(&self) -> usize {
+        self.document_count
+    }
+
+    /// Sets a runtime limit on container nesting depth: once set, opening an
+    /// object/array that would exceed it returns
+    /// [`ParseError::DepthLimitExceeded`] instead of a token at all. See
+    /// [`PullParser::set_max_depth`](crate::PullParser::set_max_depth) for
+    /// the full rationale; `PushParser` enforces it the same way `SliceParser`
+    /// and `StreamParser` do, since they share the same `ParserCore`.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.core.set_max_depth(max_depth);
+    }
+
+    /// See [`PullParser::depth`](crate::PullParser::depth).
+    pub fn depth(&self) -> usize {
+        self.core.depth()
+    }
+
+    /// See [`PullParser::remaining_depth`](crate::PullParser::remaining_depth).
+    pub fn remaining_depth(&self) -> Option<usize> {
+        self.core.remaining_depth()
+    }
+
+    /// See [`PullParser::in_object`](crate::PullParser::in_object).
+    pub fn in_object(&self) -> bool {
+        self.core.in_object()
+    }
+
+    /// See [`PullParser::in_array`](crate::PullParser::in_array).
+    pub fn in_array(&self) -> bool {
+        self.core.in_array()
+    }
+
+    /// See [`PullParser::set_reject_escaped_keys`](crate::PullParser::set_reject_escaped_keys).
+    pub fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.core.set_reject_escaped_keys(reject);
+    }
+
+    /// See [`PullParser::set_reject_bidi_controls`](crate::PullParser::set_reject_bidi_controls).
+    pub fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.core.set_reject_bidi_controls(reject);
+    }
+
+    /// See [`PullParser::set_surrogate_policy`](crate::PullParser::set_surrogate_policy).
+    pub fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.core.set_surrogate_policy(policy);
+    }
+
+    /// See [`PullParser::set_lenient_syntax`](crate::PullParser::set_lenient_syntax).
+    pub fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.core.set_lenient_syntax(enabled);
+    }
+
+    /// See [`PullParser::set_recovery_mode`](crate::PullParser::set_recovery_mode).
+    /// Once enabled, a resynchronized error reaches the handler as
+    /// [`Event::Error`] the same way it reaches [`SliceParser`](crate::SliceParser)/
+    /// [`StreamParser`](crate::StreamParser)'s `next_event`: `write()`/
+    /// [`Self::finish`] keep returning `Ok` for an error recovery mode
+    /// absorbed, since absorbing it is the whole point, and
+    /// [`PushParserHandler::handle_event`]/`handle_event_flow`/
+    /// `handle_event_with_span` all see it like any other event rather than
+    /// through a separate accumulator -- there's no second, alloc-free
+    /// error-collection path parallel to the event stream this parser
+    /// already delivers everything through.
+    pub fn set_recovery_mode(&mut self, enabled: bool) {
+        self.core.set_recovery_mode(enabled);
+    }
+
+    /// See [`PullParser::set_max_recovery_errors`](crate::PullParser::set_max_recovery_errors).
+    pub fn set_max_recovery_errors(&mut self, max: usize) {
+        self.core.set_max_recovery_errors(max);
+    }
+
+    /// Returns the line/column location of the current parse position, for
+    /// reporting alongside a [`PushParseError`] returned from [`Self::write`],
+    /// or just to track progress on success. See
+    /// [`SliceParser::position`](crate::SliceParser::position).
+    pub fn position(&self) -> crate::Position {
+        self.extractor.position()
+    }
+
+    /// Raw-input [`crate::Span`] of the most recently completed `String`/
+    /// `Key`/`Number`. A handler can't call this from inside
+    /// [`PushParserHandler::handle_event`]/`handle_event_flow` (it has no
+    /// reference to the parser there); override
+    /// [`PushParserHandler::handle_event_with_span`] instead to get the span
+    /// alongside each such event as it's delivered. This accessor is for
+    /// code driving `write()` from the outside, e.g. to report the span of
+    /// whatever event was dispatched last before an error on the next byte.
+    pub fn last_span(&self) -> crate::Span {
+        self.extractor.last_span()
+    }
+
+    /// In streaming mode, emits `Event::StartDocument` before the first event
+    /// of a new top-level value. No-op outside streaming mode or mid-value.
+    fn maybe_emit_start_document<E>(&mut self) -> Result<(), PushParseError<E>>
+    where
+        H: for<'a, 'b> PushParserHandler<'a, 'b, E>,
+    {
+        if self.streaming && self.awaiting_document_start {
+            self.awaiting_document_start = false;
+            self.handler
+                .handle_event(Event::StartDocument)
+                .map_err(PushParseError::Handler)?;
+        }
+        Ok(())
+    }
+
+    /// In streaming mode, once the tokenizer returns to zero nesting depth
+    /// after a complete top-level value, emits `Event::EndDocument` and
+    /// resets the tokenizer to accept another value.
+    fn maybe_finish_streaming_document<E>(&mut self) -> Result<(), PushParseError<E>>
+    where
+        H: for<'a, 'b> PushParserHandler<'a, 'b, E>,
+    {
+        if self.streaming && self.core.tokenizer.is_finished() {
+            self.core.tokenizer.reset_for_next_document();
+            self.awaiting_document_start = true;
+            self.document_count += 1;
+            self.handler
+                .handle_event(Event::EndDocument)
+                .map_err(PushParseError::Handler)?;
+        }
+        Ok(())
+    }
+
+    /// Delivers `event` to `handler` via
+    /// [`PushParserHandler::handle_event_with_span`], together with the
+    /// raw-input `span` it was produced from (pass [`crate::Span::default`]
+    /// for events other than `String`/`Key`/`Number`, per that method's
+    /// contract). `Flow::Stop` is handled here (latching `*stopped`) and
+    /// reported back as `None`, so callers only need to act on the
+    /// remaining case that's meaningful at their call site
+    /// ([`Flow::SkipContainer`] for container starts); everywhere else,
+    /// `Some(_)` just means "keep going".
+    ///
+    /// Takes `handler`/`stopped` directly, rather than `&mut self`, so
+    /// call sites that already hold a borrow of `self.extractor` (e.g. a
+    /// slice returned by [`ContentExtractor::get_borrowed_slice`]) can still
+    /// call it without conflicting with that borrow.
+    fn dispatch<E>(
+        handler: &mut H,
+        stopped: &mut bool,
+        event: Event<'input, '_>,
+        span: crate::Span,
+    ) -> Result<Option<Flow>, PushParseError<E>>
+    where
+        H: for<'a, 'b> PushParserHandler<'a, 'b, E>,
+    {
+        match handler
+            .handle_event_with_span(event, span)
+            .map_err(PushParseError::Handler)?
+        {
+            Flow::Stop => {
+                *stopped = true;
+                Ok(None)
+            }
+            flow => Ok(Some(flow)),
         }
     }
 
@@ -53,6 +299,11 @@ where
         H: for<'a, 'b> PushParserHandler<'a, 'b, E>,
         E: From<ParseError>,
     {
+        // A prior call already saw `Flow::Stop`; deliver nothing further.
+        if self.stopped {
+            return Ok(());
+        }
+
         // Apply any queued buffer resets
         self.extractor.apply_unescaped_reset_if_queued();
 
@@ -64,6 +315,8 @@ where
 
         // Use ParserCore to process all bytes in the chunk
         loop {
+            self.maybe_emit_start_document()?;
+
             match self.core.next_event_impl(
                 &mut self.extractor,
                 EscapeTiming::OnEnd, // PushParser uses OnEnd timing like StreamParser
@@ -98,9 +351,42 @@ where
                     // Handle ContentSpan by extracting content and emitting the appropriate event
                     // For simple case (no escapes), directly extract from input chunk
                     if !has_escapes {
+                        // Nested inside a container that's being captured verbatim, or one a
+                        // handler asked to skip: the bytes are already accounted for by the
+                        // parent, so don't emit anything here.
+                        if self.raw_capture.is_some() || self.skip_depth.is_some() {
+                            self.extractor.apply_unescaped_reset_if_queued();
+                            continue;
+                        }
+
+                        // `start`/`end` bound the content excluding delimiters (the quotes, for
+                        // a String/Key); widen back out to the full lexeme the same way
+                        // `PushContentBuilder::extract_string_content`/`extract_number` do.
+                        let token_span = match kind {
+                            ContentKind::String | ContentKind::Key => {
+                                crate::Span { start: start - 1, end: end + 1 }
+                            }
+                            ContentKind::Number => crate::Span { start, end },
+                        };
+
+                        if kind != ContentKind::Key
+                            && self.handler.on_value_start() == RawCapture::CaptureRaw
+                        {
+                            let content_slice = self.extractor.get_borrowed_slice(start, end)
+                                .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
+                            let content_str = core::str::from_utf8(content_slice)?;
+                            if Self::dispatch(&mut self.handler, &mut self.stopped, Event::RawValue(crate::String::Borrowed(content_str)), token_span)?
+                                .is_none()
+                            {
+                                break;
+                            }
+                            self.extractor.apply_unescaped_reset_if_queued();
+                            continue;
+                        }
+
                         let content_slice = self.extractor.get_borrowed_slice(start, end)
-                            .map_err(PushParseError::Parse)?;
-                        
+                            .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
+
                         let content_event = match kind {
                             ContentKind::String => {
                                 let content_str = core::str::from_utf8(content_slice)?;
@@ -115,10 +401,10 @@ where
                                 Event::Number(json_number)
                             }
                         };
-                        
-                        self.handler
-                            .handle_event(content_event)
-                            .map_err(PushParseError::Handler)?;
+
+                        if Self::dispatch(&mut self.handler, &mut self.stopped, content_event, token_span)?.is_none() {
+                            break;
+                        }
                     } else {
                         // For escaped content, fall back to the existing escape processing mechanism
                         // This delegates to the byte_accumulator callback pattern for now
@@ -165,7 +451,7 @@ where
                     // Append the final part from this chunk to the scratch buffer
                     // First, get and copy the final slice data
                     let final_slice = self.extractor.get_borrowed_slice(content_start, content_end)
-                        .map_err(PushParseError::Parse)?;
+                        .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
                         
                     log::debug!("PartialContentSpanEnd: final_slice = {:?}", 
                                core::str::from_utf8(final_slice).unwrap_or("[invalid utf8]"));
@@ -173,16 +459,15 @@ where
                     // Copy ALL data to local buffer to completely avoid borrowing conflicts
                     let mut final_data = alloc::vec::Vec::new();
                     final_data.extend_from_slice(final_slice);
-                    
-                    // Now append from local buffer - no more borrowing conflicts
-                    for byte in final_data {
-                        self.extractor.append_unescaped_byte(byte)
-                            .map_err(PushParseError::Parse)?;
-                    }
+
+                    // Bulk-append in one call rather than byte-by-byte
+                    self.extractor
+                        .append_unescaped_slice(&final_data)
+                        .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
                         
                     // Get the complete content from the scratch buffer and copy it
                     let complete_content = self.extractor.get_unescaped_slice()
-                        .map_err(PushParseError::Parse)?;
+                        .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
                     
                     // Copy to owned data to avoid borrowing conflicts
                     let complete_data = alloc::vec::Vec::from(complete_content);
@@ -190,46 +475,170 @@ where
                     // Queue buffer reset before creating the event
                     self.extractor.queue_unescaped_reset();
                         
-                    let content_event = match kind {
-                        ContentKind::String => {
-                            let content_str = core::str::from_utf8(&complete_data)?;
-                            Event::String(crate::String::Unescaped(content_str))
-                        }
-                        ContentKind::Key => {
-                            let content_str = core::str::from_utf8(&complete_data)?;
-                            Event::Key(crate::String::Unescaped(content_str))
-                        }
-                        ContentKind::Number => {
-                            let json_number = crate::JsonNumber::from_slice(&complete_data)?;
-                            Event::Number(json_number)
+                    let content_event = if self.scalar_raw_capture {
+                        self.scalar_raw_capture = false;
+                        let content_str = core::str::from_utf8(&complete_data)?;
+                        Event::RawValue(crate::String::Unescaped(content_str))
+                    } else {
+                        match kind {
+                            ContentKind::String => {
+                                let content_str = core::str::from_utf8(&complete_data)?;
+                                Event::String(crate::String::Unescaped(content_str))
+                            }
+                            ContentKind::Key => {
+                                let content_str = core::str::from_utf8(&complete_data)?;
+                                Event::Key(crate::String::Unescaped(content_str))
+                            }
+                            ContentKind::Number => {
+                                let json_number = crate::JsonNumber::from_slice(&complete_data)?;
+                                Event::Number(json_number)
+                            }
                         }
                     };
                     
-                    self.handler
-                        .handle_event(content_event)
-                        .map_err(PushParseError::Handler)?;
-                    
+                    // The token's start is wherever it began, possibly several `write()` calls
+                    // ago -- still sitting in `parser_state` as the absolute position `Begin`
+                    // recorded, since nothing resets it until this token is fully consumed.
+                    let token_start = match self.extractor.parser_state() {
+                        State::String(pos) | State::Key(pos) => *pos,
+                        // Like `extract_number`: `pos` is one byte before the first digit.
+                        State::Number(pos) => *pos + 1,
+                        State::None => 0,
+                    };
+                    let token_span = match kind {
+                        ContentKind::String | ContentKind::Key => {
+                            crate::Span { start: token_start, end: end + 1 }
+                        }
+                        ContentKind::Number => crate::Span { start: token_start, end },
+                    };
+
+                    // If this token is nested inside a container being captured verbatim,
+                    // or one a handler asked to skip, its bytes are already accounted for.
+                    if self.raw_capture.is_none() && self.skip_depth.is_none() {
+                        if Self::dispatch(&mut self.handler, &mut self.stopped, content_event, token_span)?.is_none() {
+                            break;
+                        }
+                    }
+
                     // Reset the extractor's parser state since content processing is complete
                     *self.extractor.parser_state_mut() = crate::shared::State::None;
                 }
+                Ok(event @ (Event::StartObject | Event::StartArray)) => {
+                    if let Some(depth) = self.skip_depth.as_mut() {
+                        *depth += 1;
+                    } else if let Some(cap) = self.raw_capture.as_mut() {
+                        cap.depth += 1;
+                    } else if self.handler.on_value_start() == RawCapture::CaptureRaw {
+                        self.raw_capture = Some(RawCaptureState {
+                            start: self.extractor.current_position(),
+                            depth: 1,
+                            carry: alloc::vec::Vec::new(),
+                        });
+                    } else {
+                        match Self::dispatch(&mut self.handler, &mut self.stopped, event, crate::Span::default())? {
+                            None => break,
+                            Some(flow) => {
+                                if flow == Flow::SkipContainer {
+                                    self.skip_depth = Some(1);
+                                }
+                                self.extractor.apply_unescaped_reset_if_queued();
+                            }
+                        }
+                    }
+                }
+                Ok(event @ (Event::EndObject | Event::EndArray)) => {
+                    if let Some(depth) = self.skip_depth.as_mut() {
+                        *depth -= 1;
+                        if *depth == 0 {
+                            self.skip_depth = None;
+                        }
+                        continue;
+                    }
+                    let mut closed = None;
+                    if let Some(cap) = self.raw_capture.as_mut() {
+                        cap.depth -= 1;
+                        if cap.depth == 0 {
+                            closed = self.raw_capture.take();
+                        }
+                    }
+                    match closed {
+                        Some(cap) => {
+                            // current_position is still on the closing bracket; include it.
+                            let end = self.extractor.current_position() + 1;
+                            let container_span = crate::Span { start: cap.start, end };
+                            if cap.carry.is_empty() {
+                                let slice = self.extractor.get_borrowed_slice(cap.start, end)
+                                    .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
+                                let text = core::str::from_utf8(slice)?;
+                                if Self::dispatch(
+                                    &mut self.handler,
+                                    &mut self.stopped,
+                                    Event::RawValue(crate::String::Borrowed(text)),
+                                    container_span,
+                                )?
+                                .is_none()
+                                {
+                                    break;
+                                }
+                            } else {
+                                let final_slice = self
+                                    .extractor
+                                    .get_borrowed_slice(self.extractor.position_offset(), end)
+                                    .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
+                                let mut bytes = cap.carry;
+                                bytes.extend_from_slice(final_slice);
+                                let text = core::str::from_utf8(&bytes)?;
+                                if Self::dispatch(&mut self.handler, &mut self.stopped, Event::RawValue(crate::String::Unescaped(text)), container_span)?
+                                    .is_none()
+                                {
+                                    break;
+                                }
+                            }
+                        }
+                        None => {
+                            if self.raw_capture.is_none() {
+                                match Self::dispatch(&mut self.handler, &mut self.stopped, event, crate::Span::default())? {
+                                    None => break,
+                                    Some(_) => self.extractor.apply_unescaped_reset_if_queued(),
+                                }
+                            }
+                        }
+                    }
+                }
                 Ok(event) => {
                     // Handle all other events normally
-                    self.handler
-                        .handle_event(event)
-                        .map_err(PushParseError::Handler)?;
-
-                    // Apply any queued buffer resets after the event has been processed
-                    // This ensures that buffer content from previous tokens doesn't leak into subsequent ones
-                    self.extractor.apply_unescaped_reset_if_queued();
+                    if self.raw_capture.is_some() || self.skip_depth.is_some() {
+                        continue;
+                    }
+                    // Reached for String/Key/Number whenever the ContentSpan fast path above
+                    // wasn't eligible (escapes, or a non-chunked front-end), since
+                    // extract_string_content/extract_key_content/extract_number already
+                    // recorded `last_span` for exactly this event as part of producing it.
+                    let span = match &event {
+                        Event::String(_) | Event::Key(_) | Event::Number(_) => {
+                            self.extractor.last_span()
+                        }
+                        _ => crate::Span::default(),
+                    };
+                    match Self::dispatch(&mut self.handler, &mut self.stopped, event, span)? {
+                        None => break,
+                        // Apply any queued buffer resets after the event has been processed.
+                        // This ensures that buffer content from previous tokens doesn't leak
+                        // into subsequent ones.
+                        Some(_) => self.extractor.apply_unescaped_reset_if_queued(),
+                    }
                 }
                 Err(ParseError::EndOfData) => {
                     // No more events available from current chunk
                     break;
                 }
                 Err(e) => {
-                    return Err(PushParseError::Parse(e));
+                    let at = self.extractor.position();
+                    return Err(PushParseError::Parse { code: e, at });
                 }
             }
+
+            self.maybe_finish_streaming_document()?;
         }
 
         // Check for chunk boundary condition - if still processing a token when chunk ends
@@ -257,6 +666,21 @@ where
             }
         }
 
+        // If a raw-value capture is still open, this chunk ended before the
+        // captured container closed: carry its share of the bytes forward so
+        // they can be stitched together once the matching close arrives.
+        if let Some(cap) = self.raw_capture.as_mut() {
+            let chunk_end = self.extractor.position_offset() + data.len();
+            let carry_start = cap.start.max(self.extractor.position_offset());
+            if carry_start < chunk_end {
+                let slice = self
+                    .extractor
+                    .get_borrowed_slice(carry_start, chunk_end)
+                    .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
+                cap.carry.extend_from_slice(slice);
+            }
+        }
+
         // Reset input slice
         self.extractor.reset_input();
 
@@ -266,6 +690,30 @@ where
         Ok(())
     }
 
+    /// Gives mutable access to the handler between `write()` calls, for
+    /// adapters (such as [`crate::FeedParser`]) that need to drain state the
+    /// handler accumulated during the last call.
+    pub(crate) fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Reports whether the last `write()` call ended mid-token (a string,
+    /// key, or number cut off by the chunk boundary) rather than at a clean
+    /// value/container boundary. Never required to drive the parser --
+    /// `write()` always resumes correctly on the next call either way --
+    /// but lets a caller feeding bytes as they arrive off a socket or UART
+    /// distinguish "still inside a token, more bytes are needed before
+    /// anything new will be emitted" from "between values" without
+    /// inspecting the events themselves. There's no `ParseError` variant for
+    /// this: it isn't an error, just as [`crate::Poll::NeedMoreInput`] isn't
+    /// one for [`crate::PollParser`].
+    pub fn needs_more_input(&self) -> bool {
+        matches!(
+            self.extractor.parser_state(),
+            State::String(_) | State::Key(_) | State::Number(_)
+        )
+    }
+
     /// Handle the start of content that spans chunk boundaries
     fn handle_partial_content_span_start<E>(
         &mut self, 
@@ -273,9 +721,22 @@ where
         absolute_start: usize, 
         has_escapes_in_this_chunk: bool
     ) -> Result<(), PushParseError<E>> {
-        log::debug!("handle_partial_content_span_start: kind={:?}, absolute_start={}, has_escapes={}", 
+        log::debug!("handle_partial_content_span_start: kind={:?}, absolute_start={}, has_escapes={}",
                    kind, absolute_start, has_escapes_in_this_chunk);
-        
+
+        // This is the first chunk to see this value, so it's the right place to ask
+        // whether the handler wants it raw -- same check as the same-chunk ContentSpan
+        // fast path, just reached from the other side of a chunk boundary. Not asked
+        // for a Key (never a standalone value) or while already nested inside a
+        // container/skip the handler decided about higher up.
+        if kind != ContentKind::Key
+            && self.raw_capture.is_none()
+            && self.skip_depth.is_none()
+            && self.handler.on_value_start() == RawCapture::CaptureRaw
+        {
+            self.scalar_raw_capture = true;
+        }
+
         // Convert absolute position to relative position within current chunk
         let position_offset = self.extractor.position_offset();
         let relative_start = absolute_start.saturating_sub(position_offset);
@@ -288,19 +749,18 @@ where
         // This can be optimized later with bulk copy methods
         
         let content_slice = self.extractor.get_borrowed_slice(relative_start, chunk_len)
-            .map_err(PushParseError::Parse)?;
+            .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
         
         log::debug!("handle_partial_content_span_start: content_slice = {:?}", 
                    core::str::from_utf8(content_slice).unwrap_or("[invalid utf8]"));
             
         // Copy ALL data to local buffer to completely avoid borrowing conflicts
         let content_data = alloc::vec::Vec::from(content_slice);
-        
-        // Now append from local buffer - no more borrowing conflicts
-        for byte in &content_data {
-            self.extractor.append_unescaped_byte(*byte)
-                .map_err(PushParseError::Parse)?;
-        }
+
+        // Bulk-append in one call rather than byte-by-byte
+        self.extractor
+            .append_unescaped_slice(&content_data)
+            .map_err(|code| PushParseError::Parse { code, at: self.extractor.position() })?;
         
         log::debug!("handle_partial_content_span_start: copied {} bytes to scratch buffer", content_data.len());
         Ok(())
@@ -313,25 +773,57 @@ where
     where
         H: for<'a, 'b> PushParserHandler<'a, 'b, E>,
     {
-        // Check that the JSON document is complete (all containers closed)
-        // Use a no-op callback since we don't expect any more events
-        let mut no_op_callback = |_event: ujson::Event, _pos: usize| {};
-        let _bytes_processed = self.core.tokenizer.finish(&mut no_op_callback)?;
+        // The handler already asked to stop: honor that unconditionally,
+        // skipping the usual "document must be fully closed" checks.
+        if self.stopped {
+            return Ok(self.handler);
+        }
+
+        // In streaming mode, a clean boundary between values (tokenizer already
+        // reset and idle, waiting for the next value or end of input) is a
+        // valid place to stop; only a value left mid-parse is an error.
+        if !self.streaming || !self.awaiting_document_start {
+            // Check that the JSON document is complete (all containers closed)
+            // Use a no-op callback since we don't expect any more events
+            let mut no_op_callback = |_event: ujson::Event, _pos: usize| {};
+            let _bytes_processed = self
+                .core
+                .tokenizer
+                .finish(&mut no_op_callback)
+                .map_err(|code| PushParseError::Parse {
+                    code: code.into(),
+                    at: self.extractor.position(),
+                })?;
+        }
+
+        // A raw-value capture left open means its container never closed.
+        if self.raw_capture.is_some() {
+            let at = self.extractor.position();
+            return Err(PushParseError::Parse {
+                code: ParseError::EndOfData,
+                at,
+            });
+        }
 
         // Handle any remaining content in the buffer
         let extractor_state = self.extractor.parser_state();
         log::debug!("finish(): extractor state = {:?}", extractor_state);
         if *extractor_state != State::None {
             log::error!("finish(): extractor still in state {:?}, returning EndOfData error", extractor_state);
-            return Err(crate::push_parser::PushParseError::Parse(
-                ParseError::EndOfData,
-            ));
+            let at = self.extractor.position();
+            return Err(crate::push_parser::PushParseError::Parse {
+                code: ParseError::EndOfData,
+                at,
+            });
         }
 
-        // Emit EndDocument event
-        self.handler
-            .handle_event(Event::EndDocument)
-            .map_err(PushParseError::Handler)?;
+        // In streaming mode each value already got its own EndDocument as it
+        // completed; the non-streaming, single-document case emits it here.
+        if !self.streaming {
+            self.handler
+                .handle_event(Event::EndDocument)
+                .map_err(PushParseError::Handler)?;
+        }
 
         Ok(self.handler)
     }
@@ -340,32 +832,52 @@ where
 /// An error that can occur during push-based parsing.
 #[derive(Debug, PartialEq)]
 pub enum PushParseError<E> {
-    /// An error occurred within the parser itself.
-    Parse(ParseError),
+    /// An error occurred within the parser itself, at the given location.
+    Parse {
+        /// The underlying parse error.
+        code: ParseError,
+        /// Where in the input the error was detected. See [`crate::Position`]
+        /// for why this stays accurate (cumulative byte offset, plus
+        /// line/column) across however many [`PushParser::write`] calls the
+        /// document was split into.
+        at: crate::Position,
+    },
     /// An error was returned by the user's handler.
     Handler(E),
 }
 
 impl<E> From<ujson::Error> for PushParseError<E> {
     fn from(e: ujson::Error) -> Self {
-        PushParseError::Parse(e.into())
+        PushParseError::Parse {
+            code: e.into(),
+            at: crate::Position::default(),
+        }
     }
 }
 
 impl<E> From<ParseError> for PushParseError<E> {
     fn from(e: ParseError) -> Self {
-        PushParseError::Parse(e)
+        PushParseError::Parse {
+            code: e,
+            at: crate::Position::default(),
+        }
     }
 }
 
 impl<E> From<StreamBufferError> for PushParseError<E> {
     fn from(e: StreamBufferError) -> Self {
-        PushParseError::Parse(e.into())
+        PushParseError::Parse {
+            code: e.into(),
+            at: crate::Position::default(),
+        }
     }
 }
 
 impl<E> From<core::str::Utf8Error> for PushParseError<E> {
     fn from(e: core::str::Utf8Error) -> Self {
-        PushParseError::Parse(ParseError::InvalidUtf8(e))
+        PushParseError::Parse {
+            code: ParseError::Utf8(e),
+            at: crate::Position::default(),
+        }
     }
 }
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reassembles segments that arrive out of order (e.g. over QUIC/UDP) into
+//! the [`crate::stream_buffer::StreamBuffer`] fill/mark_filled protocol,
+//! which otherwise assumes bytes are appended contiguously. Inspired by the
+//! s2n-quic `reassembler`. Layered on top of [`crate::assembler::Assembler`],
+//! which tracks *which* byte ranges are covered; this adds the scratch
+//! storage for the bytes themselves and the copy into `StreamBuffer`.
+
+use crate::assembler::Assembler;
+use crate::stream_buffer::{StreamBuffer, StreamBufferError};
+
+/// Stages out-of-order segments in a scratch region until they become
+/// contiguous with `consumed_watermark`, then drains them into a
+/// [`StreamBuffer`] in order.
+///
+/// `scratch[0]` always corresponds to `consumed_watermark`: once bytes are
+/// drained, both the watermark and the scratch contents shift down together,
+/// so offsets handed to [`write_at`](Self::write_at) stay absolute while
+/// everything this type tracks internally stays relative to the watermark.
+pub struct Reassembler<'a, const N: usize> {
+    scratch: &'a mut [u8],
+    assembler: Assembler<N>,
+    consumed_watermark: u64,
+}
+
+impl<'a, const N: usize> Reassembler<'a, N> {
+    /// Creates a reassembler with no data received yet, starting at stream
+    /// offset 0. `scratch` bounds how far ahead of the watermark a segment
+    /// can be staged before it's dropped as unstoreable.
+    pub fn new(scratch: &'a mut [u8]) -> Self {
+        Self {
+            scratch,
+            assembler: Assembler::new(),
+            consumed_watermark: 0,
+        }
+    }
+
+    /// Absolute offset of the next byte `dest` still needs, i.e. everything
+    /// before this has already been drained into `dest`.
+    pub fn consumed_watermark(&self) -> u64 {
+        self.consumed_watermark
+    }
+
+    /// Records that `bytes` were received starting at absolute stream offset
+    /// `offset`, then drains as much newly-contiguous data as fits into
+    /// `dest`'s current fill slice. Returns the number of bytes drained into
+    /// `dest` by this call (which can be more than `bytes.len()` once a gap
+    /// closes and previously-staged segments become contiguous too).
+    ///
+    /// A segment (or the part of one) at or before `consumed_watermark` is
+    /// clipped away as a duplicate/overlap of data `dest` already has. A
+    /// segment that starts further ahead of the watermark than `scratch` can
+    /// hold is silently dropped -- the sender is expected to retransmit, the
+    /// same as any other dropped packet on an unreliable transport.
+    pub fn write_at(
+        &mut self,
+        offset: u64,
+        bytes: &[u8],
+        dest: &mut StreamBuffer,
+    ) -> Result<usize, StreamBufferError> {
+        let seg_end = offset.saturating_add(bytes.len() as u64);
+        if seg_end <= self.consumed_watermark {
+            // Entirely behind the watermark: a pure duplicate.
+            return Ok(0);
+        }
+
+        let clipped_offset = offset.max(self.consumed_watermark);
+        let skip = usize::try_from(clipped_offset - offset).unwrap_or(usize::MAX);
+        let Some(bytes) = bytes.get(skip..) else {
+            return Ok(0);
+        };
+
+        let Ok(relative) = usize::try_from(clipped_offset - self.consumed_watermark) else {
+            return Ok(0);
+        };
+        if relative >= self.scratch.len() {
+            // Too far ahead of the watermark for this scratch region -- drop it.
+            return Ok(0);
+        }
+
+        let room = self.scratch.len() - relative;
+        let n = bytes.len().min(room);
+        self.scratch[relative..relative + n].copy_from_slice(&bytes[..n]);
+        self.assembler.add(relative, n)?;
+
+        self.drain_into(dest)
+    }
+
+    /// Copies every now-contiguous run from the front of `scratch` into
+    /// `dest`, stopping once either there's a gap or `dest`'s fill slice is
+    /// full (back-pressure: the remainder stays pending until `dest` frees
+    /// up space, e.g. via compaction, and a later `write_at` call drains it).
+    fn drain_into(&mut self, dest: &mut StreamBuffer) -> Result<usize, StreamBufferError> {
+        let mut total = 0;
+        loop {
+            let contiguous = self.assembler.peek_contiguous();
+            if contiguous == 0 {
+                break;
+            }
+            let Some(fill_slice) = dest.get_fill_slice() else {
+                break;
+            };
+            let n = contiguous.min(fill_slice.len());
+            if n == 0 {
+                break;
+            }
+            fill_slice[..n].copy_from_slice(&self.scratch[..n]);
+            dest.mark_filled(n)?;
+
+            self.assembler.remove_front(n);
+            self.scratch.copy_within(n.., 0);
+            self.consumed_watermark += n as u64;
+            total += n;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_order_segment_drains_immediately() {
+        let mut scratch = [0u8; 16];
+        let mut reassembler: Reassembler<4> = Reassembler::new(&mut scratch);
+        let mut dest_buf = [0u8; 16];
+        let mut dest = StreamBuffer::new(&mut dest_buf);
+
+        let n = reassembler.write_at(0, b"hello", &mut dest).unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(reassembler.consumed_watermark(), 5);
+        assert_eq!(dest.remaining_bytes(), 5);
+    }
+
+    #[test]
+    fn test_out_of_order_segment_stages_until_gap_fills() {
+        let mut scratch = [0u8; 16];
+        let mut reassembler: Reassembler<4> = Reassembler::new(&mut scratch);
+        let mut dest_buf = [0u8; 16];
+        let mut dest = StreamBuffer::new(&mut dest_buf);
+
+        // "world" arrives first, at offset 5 -- nothing is contiguous yet.
+        let n = reassembler.write_at(5, b"world", &mut dest).unwrap();
+        assert_eq!(n, 0);
+        assert_eq!(dest.remaining_bytes(), 0);
+
+        // "hello" fills the gap, so both segments drain together.
+        let n = reassembler.write_at(0, b"hello", &mut dest).unwrap();
+        assert_eq!(n, 10);
+        assert_eq!(reassembler.consumed_watermark(), 10);
+        assert_eq!(dest.remaining_bytes(), 10);
+    }
+
+    #[test]
+    fn test_duplicate_segment_is_clipped_away() {
+        let mut scratch = [0u8; 16];
+        let mut reassembler: Reassembler<4> = Reassembler::new(&mut scratch);
+        let mut dest_buf = [0u8; 16];
+        let mut dest = StreamBuffer::new(&mut dest_buf);
+
+        reassembler.write_at(0, b"hello", &mut dest).unwrap();
+        // Re-delivery of bytes [0, 5) plus genuinely new bytes [5, 7).
+        let n = reassembler.write_at(0, b"hello!!", &mut dest).unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(dest.remaining_bytes(), 7);
+    }
+
+    #[test]
+    fn test_segment_too_far_ahead_is_dropped() {
+        let mut scratch = [0u8; 4];
+        let mut reassembler: Reassembler<4> = Reassembler::new(&mut scratch);
+        let mut dest_buf = [0u8; 16];
+        let mut dest = StreamBuffer::new(&mut dest_buf);
+
+        let n = reassembler.write_at(10, b"late", &mut dest).unwrap();
+        assert_eq!(n, 0);
+        assert_eq!(reassembler.consumed_watermark(), 0);
+    }
+
+    #[test]
+    fn test_back_pressure_leaves_remainder_pending_until_drained() {
+        let mut scratch = [0u8; 16];
+        let mut reassembler: Reassembler<4> = Reassembler::new(&mut scratch);
+        // Only 3 bytes of room in dest -- "hello" (5 bytes) can't fit at once.
+        let mut dest_buf = [0u8; 3];
+        let mut dest = StreamBuffer::new(&mut dest_buf);
+
+        let n = reassembler.write_at(0, b"hello", &mut dest).unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(reassembler.consumed_watermark(), 3);
+        assert_eq!(dest.remaining_bytes(), 3);
+
+        // Consume dest's buffered bytes and compact, freeing room for the rest.
+        for _ in 0..3 {
+            dest.advance().unwrap();
+        }
+        dest.compact_from(3).unwrap();
+        let n = reassembler.write_at(5, b"", &mut dest).unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(reassembler.consumed_watermark(), 5);
+    }
+}
@@ -0,0 +1,389 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A `serde::Deserializer` built directly on [`SliceParser`].
+//!
+//! `SliceParser` is already a pull parser (`next_event`/`peek_event`), so
+//! this module doesn't need a separate token-at-a-time driver -- it just
+//! walks the event stream, mapping `StartObject`/`StartArray` to
+//! `MapAccess`/`SeqAccess` and the scalar events to the matching
+//! `deserialize_*` visitor call.
+//!
+//! Strings and keys get zero-copy `&'de str` deserialization when they
+//! contained no escape sequences, by slicing the original input directly
+//! with the [`Span`] [`SliceParser::next_event_with_span`] reports rather
+//! than going through the event's own (self-borrowed) string; escaped
+//! strings fall back to the already-unescaped copy in the caller-supplied
+//! scratch buffer, same as [`SliceParser::with_buffer`] everywhere else.
+
+extern crate alloc;
+
+use alloc::string::String as AllocString;
+use alloc::{format, string::ToString};
+
+use serde::de::{self, Visitor};
+use serde::forward_to_deserialize_any;
+
+use crate::{BitStackConfig, DefaultConfig, Event, ParseError, PullParser, SliceParser, Span};
+
+/// Errors produced while deserializing JSON into a `serde::Deserialize`
+/// type via [`Deserializer`].
+#[derive(Debug)]
+pub enum Error {
+    /// An error from the underlying parser.
+    Parse(ParseError),
+    /// The event stream didn't match what was expected at this point (e.g.
+    /// a scalar where an object was expected).
+    UnexpectedEvent(AllocString),
+    /// Input remained after the value was fully deserialized.
+    TrailingData,
+    /// A `serde::de::Error::custom` message from the `Deserialize` impl.
+    Custom(AllocString),
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{e}"),
+            Error::UnexpectedEvent(s) => f.write_str(s),
+            Error::TrailingData => f.write_str("trailing data after the deserialized value"),
+            Error::Custom(s) => f.write_str(s),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// A zero-copy-when-possible string: either a borrow straight out of the
+/// original input (no escapes), or an owned copy of the already-unescaped
+/// scratch-buffer content (escapes were present).
+enum StrValue<'de> {
+    Borrowed(&'de str),
+    Owned(AllocString),
+}
+
+impl<'de> StrValue<'de> {
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            StrValue::Borrowed(s) => visitor.visit_borrowed_str(s),
+            StrValue::Owned(s) => visitor.visit_string(s),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for StrValue<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        StrValue::deserialize_any(self, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// A `serde::Deserializer` over a [`SliceParser`].
+///
+/// See [`from_str`]/[`from_slice`] (no escapes) and
+/// [`from_str_with_buffer`]/[`from_slice_with_buffer`] (escapes, via a
+/// caller-supplied scratch buffer) for the usual entry points.
+pub struct Deserializer<'de, 'b, C: BitStackConfig = DefaultConfig> {
+    parser: SliceParser<'de, 'b, C>,
+    /// The same slice `parser` was constructed from, kept separately so
+    /// escape-free strings/keys can be sliced directly with a [`Span`],
+    /// independent of the event's own call-bound lifetime.
+    input: &'de [u8],
+}
+
+impl<'de, 'b, C: BitStackConfig> Deserializer<'de, 'b, C> {
+    /// Wraps an already-constructed [`SliceParser`] over `input`. `input`
+    /// must be the exact slice `parser` was built from -- passing a
+    /// different one produces garbage spans.
+    pub fn from_parser(parser: SliceParser<'de, 'b, C>, input: &'de [u8]) -> Self {
+        Self { parser, input }
+    }
+
+    /// Errors if anything other than trailing whitespace remains in the
+    /// input after a value was deserialized. Call this after
+    /// `T::deserialize(&mut deserializer)` to reject e.g. `"1 2"`.
+    pub fn end(&mut self) -> Result<(), Error> {
+        match self.parser.next_event()? {
+            Event::EndDocument => Ok(()),
+            _ => Err(Error::TrailingData),
+        }
+    }
+
+    fn content_str(&self, s: &crate::String<'_, '_>, span: Span) -> Result<StrValue<'de>, Error> {
+        if s.was_escaped() {
+            return Ok(StrValue::Owned(s.as_str().to_string()));
+        }
+        let bytes = self
+            .input
+            .get(span.start + 1..span.end.saturating_sub(1))
+            .ok_or(Error::Parse(ParseError::InputBufferFull))?;
+        let text = core::str::from_utf8(bytes).map_err(ParseError::from)?;
+        Ok(StrValue::Borrowed(text))
+    }
+}
+
+fn unexpected_event(event: &Event<'_, '_>) -> Error {
+    Error::UnexpectedEvent(format!("unexpected JSON event: {event:?}"))
+}
+
+impl<'de, 'a, 'b, C: BitStackConfig> de::Deserializer<'de> for &'a mut Deserializer<'de, 'b, C> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (event, span) = self.parser.next_event_with_span()?;
+        match event {
+            Event::Null => visitor.visit_unit(),
+            Event::Bool(b) => visitor.visit_bool(b),
+            Event::Number(n) => {
+                if n.is_integer() {
+                    if let Ok(i) = n.as_i64() {
+                        return visitor.visit_i64(i);
+                    }
+                    if let Ok(u) = n.as_u64() {
+                        return visitor.visit_u64(u);
+                    }
+                }
+                let f = n.as_f64().ok_or(Error::Parse(ParseError::InvalidNumber))?;
+                visitor.visit_f64(f)
+            }
+            Event::String(ref s) => self.content_str(s, span)?.deserialize_any(visitor),
+            Event::StartObject => visitor.visit_map(MapAccessor { de: self }),
+            Event::StartArray => visitor.visit_seq(SeqAccessor { de: self }),
+            other => Err(unexpected_event(&other)),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.parser.peek_event()? {
+            Event::Null => {
+                self.parser.next_event()?;
+                visitor.visit_none()
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.parser.peek_event()? {
+            Event::StartObject => {
+                self.parser.next_event()?;
+                let value = visitor.visit_enum(EnumAccessor {
+                    de: self,
+                    tagged: true,
+                })?;
+                match self.parser.next_event()? {
+                    Event::EndObject => Ok(value),
+                    other => Err(unexpected_event(&other)),
+                }
+            }
+            _ => visitor.visit_enum(EnumAccessor {
+                de: self,
+                tagged: false,
+            }),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqAccessor<'a, 'de, 'b, C: BitStackConfig> {
+    de: &'a mut Deserializer<'de, 'b, C>,
+}
+
+impl<'de, 'a, 'b, C: BitStackConfig> de::SeqAccess<'de> for SeqAccessor<'a, 'de, 'b, C> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        match self.de.parser.peek_event()? {
+            Event::EndArray => {
+                self.de.parser.next_event()?;
+                Ok(None)
+            }
+            _ => seed.deserialize(&mut *self.de).map(Some),
+        }
+    }
+}
+
+struct MapAccessor<'a, 'de, 'b, C: BitStackConfig> {
+    de: &'a mut Deserializer<'de, 'b, C>,
+}
+
+impl<'de, 'a, 'b, C: BitStackConfig> de::MapAccess<'de> for MapAccessor<'a, 'de, 'b, C> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        let (event, span) = self.de.parser.next_event_with_span()?;
+        match event {
+            Event::EndObject => Ok(None),
+            Event::Key(ref k) => seed.deserialize(self.de.content_str(k, span)?).map(Some),
+            other => Err(unexpected_event(&other)),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.de)
+    }
+}
+
+struct EnumAccessor<'a, 'de, 'b, C: BitStackConfig> {
+    de: &'a mut Deserializer<'de, 'b, C>,
+    /// `true` if this variant was written as `{"Variant": <content>}`,
+    /// `false` if as a bare `"Variant"` string (unit variant shorthand).
+    tagged: bool,
+}
+
+impl<'de, 'a, 'b, C: BitStackConfig> de::EnumAccess<'de> for EnumAccessor<'a, 'de, 'b, C> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let (event, span) = self.de.parser.next_event_with_span()?;
+        let name = match event {
+            Event::Key(ref k) if self.tagged => self.de.content_str(k, span)?,
+            Event::String(ref s) if !self.tagged => self.de.content_str(s, span)?,
+            other => return Err(unexpected_event(&other)),
+        };
+        Ok((seed.deserialize(name)?, self))
+    }
+}
+
+impl<'de, 'a, 'b, C: BitStackConfig> de::VariantAccess<'de> for EnumAccessor<'a, 'de, 'b, C> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        if !self.tagged {
+            return Ok(());
+        }
+        match self.de.parser.next_event()? {
+            Event::Null => Ok(()),
+            other => Err(unexpected_event(&other)),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_tuple(self.de, len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_struct(self.de, "", fields, visitor)
+    }
+}
+
+/// Deserializes `T` from `input`, assuming no string escapes (like
+/// [`SliceParser::new`]). For input that may contain escapes, use
+/// [`from_slice_with_buffer`].
+pub fn from_slice<'de, T>(input: &'de [u8]) -> Result<T, Error>
+where
+    T: serde::Deserialize<'de>,
+{
+    let mut de = Deserializer::from_parser(SliceParser::new_from_slice(input), input);
+    let value = T::deserialize(&mut de)?;
+    de.end()?;
+    Ok(value)
+}
+
+/// Like [`from_slice`], for a `&str` input.
+pub fn from_str<'de, T>(input: &'de str) -> Result<T, Error>
+where
+    T: serde::Deserialize<'de>,
+{
+    from_slice(input.as_bytes())
+}
+
+/// Deserializes `T` from `input`, unescaping strings into `scratch` as
+/// needed (like [`SliceParser::with_buffer`]).
+pub fn from_slice_with_buffer<'de, 'b, T>(input: &'de [u8], scratch: &'b mut [u8]) -> Result<T, Error>
+where
+    T: serde::Deserialize<'de>,
+{
+    let mut de = Deserializer::from_parser(SliceParser::with_buffer_from_slice(input, scratch), input);
+    let value = T::deserialize(&mut de)?;
+    de.end()?;
+    Ok(value)
+}
+
+/// Like [`from_slice_with_buffer`], for a `&str` input.
+pub fn from_str_with_buffer<'de, 'b, T>(input: &'de str, scratch: &'b mut [u8]) -> Result<T, Error>
+where
+    T: serde::Deserialize<'de>,
+{
+    from_slice_with_buffer(input.as_bytes(), scratch)
+}
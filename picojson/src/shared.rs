@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::escape_processor::SurrogatePolicy;
 use crate::ParseError;
 /// Shared components for JSON parsers
 use crate::{ujson, JsonNumber, String};
@@ -15,6 +16,9 @@ pub enum ContentKind {
 /// Events produced by JSON parsers
 #[derive(Debug, PartialEq)]
 pub enum Event<'a, 'b> {
+    /// The start of a document, emitted once per value when a parser runs in
+    /// a multi-document (NDJSON-style) streaming mode.
+    StartDocument,
     /// The start of an object (e.g., `{`).
     StartObject,
     /// The end of an object (e.g., `}`).
@@ -66,8 +70,112 @@ pub enum Event<'a, 'b> {
         has_escapes_in_this_chunk: bool,
     },
     
+    /// The verbatim JSON source text of a complete value (scalar, object, or
+    /// array), produced in place of its decoded event(s) when a handler opts
+    /// into raw capture via [`RawCapture::CaptureRaw`].
+    RawValue(String<'a, 'b>),
+
+    /// A contiguous run of whitespace between two tokens (e.g. the
+    /// indentation before a key, or the space after a `:`), emitted only
+    /// when a parser has whitespace events enabled via
+    /// [`PullParser::set_whitespace_events`]. With that opt-in off,
+    /// whitespace is skipped exactly as before and this variant never
+    /// appears. With it on, a consumer that reinserts the structural
+    /// punctuation itself (the commas, colons, quotes, and brackets implied
+    /// by the surrounding events -- the same way
+    /// [`EventWriter`](crate::EventWriter) already does) and writes every
+    /// `Whitespace` event's text back out verbatim between them
+    /// reconstructs the original document's formatting byte-for-byte.
+    Whitespace(String<'a, 'b>),
+
+    /// A piece of a string value too large to fit in
+    /// [`StreamParser`](crate::StreamParser)'s input buffer all at once.
+    ///
+    /// Not produced today: emitting this mid-token requires the tokenizer's
+    /// byte-pull loop to yield control back to the caller without consuming
+    /// a byte, which the current `ContentExtractor::next_byte`/tokenizer
+    /// interface has no way to signal. See the design note next to
+    /// `StreamContentBuilder::fill_buffer_from_reader`'s
+    /// [`ParseError::InputBufferFull`] return for where this would hook in.
+    StringChunk {
+        /// The content accumulated since the previous chunk (or the start
+        /// of the string, for the first chunk).
+        bytes: String<'a, 'b>,
+        /// `true` if this is the closing chunk, i.e. the byte after `bytes`
+        /// is the string's terminating `"`.
+        last: bool,
+    },
+
+    /// Like [`Self::StringChunk`], but for an object key too large to fit
+    /// in the input buffer at once. Not produced today; see
+    /// [`Self::StringChunk`].
+    KeyChunk {
+        /// The content accumulated since the previous chunk (or the start
+        /// of the key, for the first chunk).
+        bytes: String<'a, 'b>,
+        /// `true` if this is the closing chunk.
+        last: bool,
+    },
+
     /// End of the document.
     EndDocument,
+
+    /// A tokenizer error that parsing recovered from instead of aborting,
+    /// produced only when a parser has opted into recovery mode. Parsing
+    /// resumes after this event: the next `next_event` call discards bytes
+    /// until it finds a structural delimiter to resynchronize on and
+    /// continues from there.
+    Error {
+        /// Byte offset where the error occurred.
+        position: usize,
+        /// The tokenizer error that was recovered from.
+        kind: ParseError,
+    },
+}
+
+/// A signal a [`crate::PushParserHandler`] can return from `on_value_start`
+/// to request verbatim capture of the upcoming value's source text.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum RawCapture {
+    /// Decode and emit the value's events as usual.
+    #[default]
+    Continue,
+    /// Capture the upcoming value's source text verbatim and deliver it as a
+    /// single [`Event::RawValue`] once the value is complete.
+    ///
+    /// Honored for an object/array (captured across however many `write()`
+    /// chunks it spans, via an internal carry buffer once it crosses one)
+    /// and for a string/number with no escape sequences, whether it completes
+    /// within one `write()` call (zero-copy, via the [`Event::ContentSpan`]
+    /// fast path) or spans a chunk boundary (via the same scratch buffer a
+    /// decoded value would use -- escape-free content's raw bytes and
+    /// unescaped bytes are identical either way). A string containing an
+    /// escape sequence, if it's the whole value rather than nested inside a
+    /// captured object/array, is delivered already decoded instead: by the
+    /// time it reaches an event boundary, decoding has already consumed the
+    /// only position at which the verbatim source span could have been
+    /// recorded. Wrap it in an array if verbatim capture is required.
+    CaptureRaw,
+}
+
+/// A control-flow signal a [`crate::PushParserHandler`] can return from
+/// [`crate::PushParserHandler::handle_event_flow`] to steer the parser past
+/// its default "decode and deliver every event" behavior.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Flow {
+    /// Keep delivering events as usual.
+    #[default]
+    Continue,
+    /// Only meaningful in response to a [`Event::StartObject`]/
+    /// [`Event::StartArray`]: validate and consume the matching subtree
+    /// without delivering any further events for its contents (not even the
+    /// closing `EndObject`/`EndArray`). Ignored if returned for any other
+    /// event.
+    SkipContainer,
+    /// Stop parsing immediately. `write`/`finish` return `Ok` without
+    /// delivering any further events, even if input or an open container
+    /// remains.
+    Stop,
 }
 
 /// Specific unexpected states that can occur during parsing.
@@ -95,6 +203,7 @@ pub enum State {
 }
 
 /// Parser state and event storage
+#[derive(Clone)]
 pub struct ParserState {
     pub evts: [Option<ujson::Event>; 2],
 }
@@ -116,6 +225,12 @@ impl Default for ParserState {
 /// Trait for parsers that can be used in a pull-based manner.
 ///
 /// This trait is implemented by both `SliceParser` and `StreamParser`.
+///
+/// There's no `peek_event` here: doing that without a real rewind requires
+/// a [`Checkpoint`](crate::Checkpoint), and `StreamParser` compacts consumed
+/// bytes out of its buffer as it goes, so it has nothing to rewind to.
+/// [`SliceParser`](crate::SliceParser) offers `peek_event` as an inherent
+/// method instead, for exactly this reason -- see its doc comment.
 pub trait PullParser {
     /// Iterator-like method that returns None when parsing is complete.
     /// This method returns None when EndDocument is reached, Some(Ok(event)) for successful events,
@@ -129,6 +244,271 @@ pub trait PullParser {
     /// Returns the next JSON event or an error if parsing fails.
     /// Parsing continues until `EndDocument` is returned or an error occurs.
     fn next_event(&mut self) -> Result<Event<'_, '_>, ParseError>;
+
+    /// Sets a runtime limit on container nesting depth: once set, opening an
+    /// object/array that would exceed it returns
+    /// [`ParseError::DepthLimitExceeded`] instead of a token at all. Unlike
+    /// the tokenizer's compile-time `BitStackConfig` bucket width -- which
+    /// silently determines the maximum depth a given parser instantiation
+    /// can even represent -- this is an explicit, changeable ceiling an
+    /// application can use to defensively cap recursion when parsing
+    /// untrusted input, with an actionable error in place of the generic
+    /// [`ParseError::TokenizerError`] a bitstack overflow would otherwise
+    /// surface.
+    fn set_max_depth(&mut self, max_depth: usize);
+
+    /// Current container nesting depth: `0` at the document root (before the
+    /// first `Event::StartObject`/`Event::StartArray`, or after the
+    /// matching end event has been returned), incremented by each
+    /// `Event::StartObject`/`Event::StartArray` this parser has produced and
+    /// decremented by each `Event::EndObject`/`Event::EndArray`. Valid to
+    /// call after any [`next_event`](Self::next_event), including right
+    /// after the one that changed it.
+    ///
+    /// There's deliberately no companion method here for reading back *which
+    /// kind* of container is open at each level (e.g. "give me the container
+    /// kinds from root to here"): the per-level object-vs-array bit is
+    /// already tracked internally, but only as a plain push/pop/top
+    /// [`BitBucket`](crate::BitBucket) with no indexed or enumerating read by
+    /// design (see that trait's doc comment) -- reusable for "what's the
+    /// innermost container" but not for walking every level without
+    /// destroying the stack. A caller that wants the full path rather than
+    /// just its length already has a purpose-built tool for that:
+    /// [`PathStack`](crate::PathStack), driven by the same
+    /// `StartObject`/`StartArray`/`Key`/`EndObject`/`EndArray` events this
+    /// depth counter is, which tracks keys and array indices too -- strictly
+    /// more than bare container kind -- at a caller-chosen fixed capacity
+    /// instead of one baked into every parser instantiation whether it's
+    /// wanted or not.
+    fn depth(&self) -> usize;
+
+    /// Nesting levels still available before the next
+    /// `StartObject`/`StartArray` would fail, combining whichever of
+    /// [`Self::set_max_depth`] and the parser's compile-time
+    /// [`BitStackConfig`](crate::BitStackConfig) bucket width is more
+    /// restrictive right now. `None` if neither imposes a real ceiling --
+    /// only possible with an `alloc`-only
+    /// [`HeapBitStack`](crate::HeapBitStack) bucket and no `set_max_depth`
+    /// call, since every other bucket has a fixed compile-time width.
+    ///
+    /// A budget-conscious caller can use this to reject a document before
+    /// attempting to parse deeper into it, rather than discovering the
+    /// limit via a [`ParseError::DepthLimitExceeded`] partway through.
+    fn remaining_depth(&self) -> Option<usize>;
+
+    /// Whether the innermost currently-open container is an object --
+    /// `false` both at the document root and while inside an array. Reads
+    /// the same per-level object-vs-array `top()` bit [`Self::depth`]'s doc
+    /// comment above already mentions as available (just not for walking
+    /// every level at once): exactly enough for a streaming consumer doing
+    /// path-based filtering by *current* container kind (e.g. "skip values
+    /// inside an array, keep ones inside an object") without maintaining
+    /// its own shadow stack. A caller that also needs *which* keys or
+    /// indices got it there -- the full path, not just its current kind --
+    /// still wants [`PathStack`](crate::PathStack) instead.
+    fn in_object(&self) -> bool;
+
+    /// Whether the innermost currently-open container is an array -- the
+    /// complement of [`Self::in_object`] (both are `false` at the document
+    /// root). See [`Self::in_object`] for the rationale.
+    fn in_array(&self) -> bool;
+
+    /// Enables (or disables) a strict mode for protocols that forbid
+    /// escapes in keys: once enabled, a key containing an escape sequence
+    /// returns [`ParseError::EscapedKeyRejected`] instead of being decoded
+    /// into the scratch buffer, guaranteeing every [`Event::Key`] seen
+    /// afterward is a zero-copy borrow of the source
+    /// (`key.was_escaped()` is always `false`).
+    fn set_reject_escaped_keys(&mut self, reject: bool);
+
+    /// Enables (or disables) rejecting a decoded [`Event::String`]/
+    /// [`Event::Key`] that contains a Unicode bidirectional text-flow-control
+    /// codepoint (`U+202A`..=`U+202E`, `U+2066`..=`U+2069`) with
+    /// [`ParseError::BidiControlInString`]. Unlike an unescaped raw control
+    /// character (`U+0000`..=`U+001F`), which every parser already rejects
+    /// unconditionally per RFC 8259's grammar, these are ordinary printable
+    /// codepoints as far as the grammar is concerned -- the hazard is that
+    /// they can make the surrounding text *render* in an order that doesn't
+    /// match the bytes a program reads, the same spoofing technique rustc's
+    /// `text_direction_codepoint_in_literal` lint flags in source files. Off
+    /// by default, like every other opt-in strictness flag here.
+    fn set_reject_bidi_controls(&mut self, reject: bool);
+
+    /// Sets how a surrogate (`0xD800..=0xDFFF`) flushed without ever finding
+    /// its pair in a `\uXXXX` escape is handled: fail with
+    /// [`ParseError::UnpairedHighSurrogate`]/[`ParseError::UnpairedLowSurrogate`]
+    /// ([`SurrogatePolicy::Strict`], the default, matching every prior
+    /// release), substitute the replacement character U+FFFD
+    /// ([`SurrogatePolicy::Replace`]), or encode it as three-byte WTF-8
+    /// ([`SurrogatePolicy::Wtf8`]). See [`SurrogatePolicy`] for details.
+    ///
+    /// This alone doesn't yet change what [`next_event`](Self::next_event)
+    /// can return in the `Wtf8` case: [`Event::String`]/[`Event::Key`] wrap
+    /// a `&str`, and the moment those WTF-8 bytes land anywhere in the
+    /// string's assembled content, the final `core::str::from_utf8` check
+    /// every extraction path runs rejects them same as before -- a lone
+    /// surrogate is never valid UTF-8, WTF-8 or not. A still-pending high
+    /// surrogate at the closing `"` also still always errors regardless of
+    /// policy. Exposing the WTF-8 bytes to a caller needs a byte-level event
+    /// this crate doesn't have yet; until then, `Wtf8` only primes the
+    /// internal collector for that future consumer. `Replace` has no such
+    /// gap, since U+FFFD is always valid UTF-8.
+    fn set_surrogate_policy(&mut self, policy: SurrogatePolicy);
+
+    /// Enables (or disables) an opt-in, lossless mode where every run of
+    /// whitespace between tokens is surfaced as an [`Event::Whitespace`]
+    /// instead of being silently skipped. Off by default, matching every
+    /// prior release's behavior byte-for-byte. Meant for pretty-printers
+    /// and reindenters built on top of the streaming API that need to
+    /// reconstruct -- or selectively rewrite -- the original document's
+    /// formatting; most callers that only care about the JSON structure
+    /// should leave this off.
+    fn set_whitespace_events(&mut self, enabled: bool);
+
+    /// Enables (or disables) error-recovery mode: once enabled, an error
+    /// that would otherwise abort the parse -- a malformed token, or an
+    /// invalid escape/number discovered once a string/key/number's content
+    /// is extracted -- is instead reported as an [`Event::Error`] and
+    /// parsing resynchronizes at the next structural delimiter, so a
+    /// single call site can see every independent error in a document
+    /// instead of only the first. Off by default, matching every prior
+    /// release's "first error aborts the parse" behavior. Meant for
+    /// tooling -- linters, editors -- that wants to report all the
+    /// problems in a document at once rather than stopping at the first.
+    ///
+    /// There's no separate batch-style `parse_full_recovering(data, &mut
+    /// events, &mut errors)` entry point: with this enabled, a plain
+    /// `next_event` loop that matches out `Event::Error { position, kind }`
+    /// into its own buffer alongside every other event already does the
+    /// same job, without a second parsing path to keep behaviorally
+    /// identical to this one.
+    fn set_recovery_mode(&mut self, enabled: bool);
+
+    /// Caps how many [`Event::Error`]s a single [`Self::set_recovery_mode`]
+    /// parse will emit: once `max` is reached, the next error aborts the
+    /// parse (returned from `next_event` as a plain `Err`) instead of being
+    /// converted to another `Event::Error` and resynchronized past. Unset
+    /// by default, which already bounds the error count by the input's
+    /// length -- resynchronizing always consumes at least one byte, so a
+    /// finite document can't drive an unbounded number of them -- this is
+    /// for callers who want a tighter, size-independent ceiling on
+    /// near-entirely-garbage input.
+    fn set_max_recovery_errors(&mut self, max: usize);
+
+    /// Enables (or disables) a JSON5-like relaxed syntax: a trailing comma
+    /// before `]`/`}`, `'`-quoted strings/keys, `//`/`/* */` comments, `_`
+    /// digit-group separators in numbers, a `0x`/`0X` hex integer form, a
+    /// leading `+` sign, and the literals `Infinity`/`-Infinity`/`NaN`. Off
+    /// by default, so every parser rejects all of the above exactly as
+    /// every prior release did -- RFC 8259's grammar, not this extended
+    /// one, is what "strict" means throughout this crate's other docs and
+    /// tests.
+    ///
+    /// `Infinity`/`NaN` tokenize as an ordinary [`Event::Number`], and
+    /// [`JsonNumber::as_f64`](crate::JsonNumber::as_f64) recovers the
+    /// correct infinite/NaN `f64` for one the same way it already does for
+    /// any other literal wider than the configured integer type: by
+    /// re-parsing [`JsonNumber::as_raw_str`](crate::JsonNumber::as_raw_str).
+    /// There's no separate `JsonNumber::Float` case for them, since that
+    /// variant is documented to always be finite so `Eq`/`Ord`/`Hash` over
+    /// it stay total; `is_integer()` returning `false` plus a non-finite
+    /// `as_f64()` is how a caller tells one apart from an ordinary float.
+    fn set_lenient_syntax(&mut self, enabled: bool);
+
+    /// Consumes and discards the current value -- including, if it's a
+    /// container, everything nested inside it -- leaving the parser
+    /// positioned just after it. This is the JSON analogue of quick-xml's
+    /// `read_to_end`.
+    ///
+    /// Call this right after [`Event::StartObject`]/[`Event::StartArray`],
+    /// or in place of following up an uninteresting [`Event::Key`], to skip
+    /// a whole value without paying for an event per nested token -- useful
+    /// when only a few keys deep in a large document are actually wanted. A
+    /// single scalar value (string, number, bool, or null) completes after
+    /// just one `next_event()` call.
+    ///
+    /// This default drives [`next_event`](Self::next_event) like any other
+    /// caller would, so it still pays for UTF-8 validation, unescaping, and
+    /// number parsing on whatever it discards. [`SliceParser`](crate::SliceParser)
+    /// and [`StreamParser`](crate::StreamParser) override it with a version
+    /// that tracks nesting depth straight off the tokenizer's raw token
+    /// stream instead, skipping all of that for the part being thrown away.
+    fn skip_value(&mut self) -> Result<(), ParseError> {
+        let mut depth: usize = 0;
+        loop {
+            match self.next_event()? {
+                Event::StartObject | Event::StartArray => depth += 1,
+                Event::EndObject | Event::EndArray => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                Event::EndDocument => return Ok(()),
+                // Document/chunk framing, not a value boundary -- never
+                // completes the value being skipped on its own.
+                Event::StartDocument | Event::PartialContentSpanStart { .. } => {}
+                _ => {
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A byte offset known to sit one past a just-consumed single-byte
+/// delimiter (a closing quote, a `,`/`}`/`]`, or the first digit rolled
+/// past while detecting a number), as reported by
+/// [`NumberExtractor::current_position`](crate::number_parser::NumberExtractor::current_position)/
+/// [`DataSource`]'s position tracking.
+///
+/// [`ContentRange`]'s `saturating_sub(1)` calls all mean the same thing --
+/// "the tokenizer already consumed one byte past where this content
+/// actually ends, back up across it" -- but spelled out as bare arithmetic
+/// on a bare `usize`, nothing distinguishes that from a position that
+/// *doesn't* have a trailing delimiter to exclude (e.g. one already passed
+/// through [`Self::before_delimiter`], or a plain index from
+/// [`InputBuffer::peek`](crate::slice_input_buffer::InputBuffer::peek)).
+/// Wrapping the "just past a delimiter" ones in this newtype makes that
+/// distinction something the compiler checks instead of something a
+/// doc comment has to.
+///
+/// This deliberately doesn't extend to the whole crate's position
+/// plumbing -- [`NumberExtractor`](crate::number_parser::NumberExtractor),
+/// [`InputBuffer`](crate::slice_input_buffer::InputBuffer), [`Span`], and
+/// [`Position`] all still pass plain `usize`s, since widening this to
+/// their public signatures would ripple into every parser backend's
+/// implementation of them for a gain that only matters at the handful of
+/// call sites inside [`ContentRange`] that actually mix pre/post-delimiter
+/// positions together. [`ContentRange::unicode_escape_bounds`]'s
+/// fixed 4-/6-byte back-offsets for a known-width `\uXXXX` escape are a
+/// different calculation entirely (not "one delimiter byte") and are left
+/// as plain `usize` arithmetic rather than forced through this type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct ByteOffset(usize);
+
+impl ByteOffset {
+    /// Wraps a position the tokenizer reports immediately after consuming
+    /// a one-byte delimiter.
+    pub(crate) fn after_delimiter(current_pos: usize) -> Self {
+        Self(current_pos)
+    }
+
+    /// The position of the delimiter itself (or of whatever byte preceded
+    /// it, at the start of input): one less than `self`, saturating at `0`
+    /// rather than underflowing.
+    pub(crate) fn before_delimiter(self) -> usize {
+        self.0.saturating_sub(1)
+    }
+
+    /// Unwraps back to a plain index, for a position that turned out not
+    /// to need delimiter exclusion after all (e.g.
+    /// [`ContentRange::number_end_position`]'s `use_full_span` case).
+    pub(crate) fn to_index(self) -> usize {
+        self.0
+    }
 }
 
 /// Utility for calculating common content range boundaries in JSON parsing.
@@ -144,7 +524,7 @@ impl ContentRange {
     /// # Returns
     /// Position that includes the first digit of the number
     pub fn number_start_from_current(current_pos: usize) -> usize {
-        current_pos.saturating_sub(1) // Back up to include first digit
+        ByteOffset::after_delimiter(current_pos).before_delimiter() // Back up to include first digit
     }
 
     /// Calculate string content boundaries using content start position
@@ -160,7 +540,7 @@ impl ContentRange {
         content_start: usize,
         current_pos: usize,
     ) -> (usize, usize) {
-        let content_end = current_pos.saturating_sub(1); // Back up to exclude closing quote
+        let content_end = ByteOffset::after_delimiter(current_pos).before_delimiter(); // Back up to exclude closing quote
         if content_start > content_end {
             (content_start, content_start)
         } else {
@@ -192,7 +572,7 @@ impl ContentRange {
     /// # Returns
     /// Position excluding the final delimiter
     pub fn end_position_excluding_delimiter(current_pos: usize) -> usize {
-        current_pos.saturating_sub(1)
+        ByteOffset::after_delimiter(current_pos).before_delimiter()
     }
 
     /// Calculate number end position with delimiter handling
@@ -206,12 +586,13 @@ impl ContentRange {
     /// # Returns
     /// End position for number content
     pub fn number_end_position(current_pos: usize, use_full_span: bool) -> usize {
+        let offset = ByteOffset::after_delimiter(current_pos);
         if use_full_span {
             // At document end and standalone - use full span (no delimiter to exclude)
-            current_pos
+            offset.to_index()
         } else {
             // Normal case - exclude delimiter
-            current_pos.saturating_sub(1)
+            offset.before_delimiter()
         }
     }
 }
@@ -231,6 +612,34 @@ pub trait DataSource<'input, 'scratch> {
     /// Returns None when end of input is reached
     fn next_byte(&mut self) -> Result<Option<u8>, ParseError>;
 
+    /// Returns the next byte without consuming it, so a caller can
+    /// disambiguate a token boundary (e.g. whether a number ends at a
+    /// delimiter, or a literal was truncated at a chunk edge) before
+    /// deciding whether to consume it with [`Self::next_byte`] or
+    /// [`Self::discard`]. Returns `None` at end of input, same as
+    /// `next_byte`.
+    ///
+    /// For a refill-buffer-backed source, this may itself need to trigger
+    /// a refill when called right at a chunk boundary -- the same way
+    /// `next_byte` does -- since there may be no byte to look at yet even
+    /// though more input is still coming.
+    fn peek_byte(&mut self) -> Result<Option<u8>, ParseError>;
+
+    /// Drops the byte a prior [`Self::peek_byte`] call returned, advancing
+    /// past it without decoding it again. Calling this without a preceding
+    /// `peek_byte` (or after the peeked byte has already been consumed via
+    /// `next_byte`) has no effect.
+    ///
+    /// The default implementation just re-reads and discards via
+    /// `next_byte`, which is correct for any source since `peek_byte` never
+    /// advances; override only if a source keeps its own one-byte lookahead
+    /// cache that it needs to clear rather than re-deriving. (Named `discard`
+    /// rather than `discard_byte` to match [`Self::peek_byte`]'s own
+    /// `serde_json::de::Read::discard` counterpart.)
+    fn discard(&mut self) {
+        let _ = self.next_byte();
+    }
+
     /// Returns a slice of the raw, unprocessed input data from a specific range.
     /// Used for zero-copy extraction of content that contains no escape sequences.
     ///
@@ -0,0 +1,395 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A structural pre-scan for [`SliceParser`](crate::SliceParser)'s `simd`
+//! feature, following simd-json's two-stage design: scan the whole input
+//! in fixed-width words to find `{ } [ ] : ,` bytes and quote/backslash
+//! positions, then let the tokenizer skip straight between them instead of
+//! inspecting every byte.
+//!
+//! This ships stage one -- [`Scanner`] below -- fully tested against a
+//! byte-by-byte scalar walk of the same input. `core::simd` is still
+//! nightly-only (`#![feature(portable_simd)]`) and this crate targets
+//! stable, so the "lane" width here is a native `usize` word and the
+//! per-lane comparisons use the classic SWAR "does any byte in this word
+//! equal X" trick (Alan Mycroft's null-byte test, generalized by XOR-ing
+//! the needle in first) instead of real SIMD instructions. Swapping in
+//! target-specific intrinsics later is possible without changing
+//! [`Scanner`]'s API.
+//!
+//! Stage two -- `parser_core` actually jumping between the offsets this
+//! produces, instead of driving its existing byte-at-a-time state machine
+//! -- isn't wired up by this module. That needs a second tokenizer code
+//! path parallel to the current one, which is a much larger and riskier
+//! change than a pre-scan utility; [`Scanner`] is the foundation for that
+//! follow-up, not the follow-up itself.
+//!
+//! [`skip_whitespace`] is a second, independent pre-scan built the same
+//! way, for the one piece of stage one that doesn't need [`Scanner`]'s
+//! in-string/escaping state at all: skipping the run of whitespace before
+//! the next token. It deliberately stops at separate quote/backslash/
+//! structural bitmasks with escaped-quote resolution by backslash-run
+//! parity, real per-architecture SIMD intrinsics, and runtime CPU feature
+//! detection -- the fuller simdjson-stage-1 design this module's doc
+//! comment gestures at as a possible future -- for the same reasons
+//! [`Scanner`] itself stops at single-word SWAR: this crate is `no_std`
+//! with no manifest in this tree to add a CPU-detection dependency to,
+//! targets stable (`core::simd` is nightly-only), and runtime dispatch
+//! like `std::is_x86_feature_detected!` isn't available without `std`.
+//! A from-scratch per-architecture backend on top of raw CPUID/`mrs` reads
+//! would be a large, `unsafe`-heavy addition in its own right, not a
+//! small increment on top of this module's existing word-at-a-time
+//! approach. Escaped-quote resolution by backslash-run parity is also
+//! redundant here: [`Scanner::step`] already resolves it exactly, one
+//! byte at a time, for every word that isn't all boring content, and
+//! `\u` escapes never need special-casing because they only ever appear
+//! inside a string, where [`Scanner`] already treats the content
+//! opaquely until the closing quote.
+
+const WORD_BYTES: usize = core::mem::size_of::<usize>();
+
+/// `0x0101...01`: one `0x01` per byte lane, for any `usize` width.
+const LO: usize = usize::MAX / 255;
+/// `0x8080...80`: one `0x80` per byte lane.
+const HI: usize = LO << 7;
+
+/// `true` if any byte lane of `word` is `0x00`. The standard branchless
+/// null-byte test: `(v - LO) & !v & HI` is nonzero in a lane exactly when
+/// that lane underflowed from `0x00` and its top bit wasn't already set.
+fn has_zero_lane(word: usize) -> bool {
+    word.wrapping_sub(LO) & !word & HI != 0
+}
+
+/// `true` if any byte lane of `word` equals `needle`.
+fn has_byte(word: usize, needle: u8) -> bool {
+    // Broadcasting `needle` into every lane (`LO * needle`) and XOR-ing
+    // zeroes out exactly the lanes that matched, reducing this to the
+    // null-byte test above.
+    let broadcast = LO.wrapping_mul(needle as usize);
+    has_zero_lane(word ^ broadcast)
+}
+
+fn is_structural(byte: u8) -> bool {
+    matches!(byte, b'{' | b'}' | b'[' | b']' | b':' | b',')
+}
+
+/// `true` if `word` contains nothing [`Scanner`] needs to look at closely:
+/// no quote, no backslash, and no structural byte. Whole words like this
+/// are skipped in one step regardless of whether the scan is currently
+/// inside a string -- plain string content and whitespace between tokens
+/// both take this fast path.
+fn word_is_boring(word: usize) -> bool {
+    !(has_byte(word, b'"')
+        || has_byte(word, b'\\')
+        || has_byte(word, b'{')
+        || has_byte(word, b'}')
+        || has_byte(word, b'[')
+        || has_byte(word, b']')
+        || has_byte(word, b':')
+        || has_byte(word, b','))
+}
+
+fn word_at(input: &[u8], pos: usize) -> Option<usize> {
+    let bytes: [u8; WORD_BYTES] = input.get(pos..pos + WORD_BYTES)?.try_into().ok()?;
+    Some(usize::from_ne_bytes(bytes))
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\r' | b'\n')
+}
+
+/// Per-lane `0x80` mask: set in every lane of `word` that equals `needle`,
+/// `0` elsewhere. Unlike [`has_byte`], the mask itself is returned rather
+/// than reduced to a single `bool`, so callers can `|` several needles'
+/// masks together before testing them.
+fn lane_eq_mask(word: usize, needle: u8) -> usize {
+    let broadcast = LO.wrapping_mul(needle as usize);
+    let v = word ^ broadcast;
+    v.wrapping_sub(LO) & !v & HI
+}
+
+/// `true` if every byte lane of `word` is one of the four JSON whitespace
+/// bytes (space, tab, CR, LF). Checking the combined mask against `HI`
+/// outright -- rather than counting or locating individual set lanes --
+/// sidesteps the fact that `word_at`'s `usize::from_ne_bytes` makes lane
+/// order platform-endianness-dependent: this only ever asks "are all
+/// lanes whitespace", never "which lane", so byte order doesn't matter.
+fn word_is_all_whitespace(word: usize) -> bool {
+    let whitespace_lanes = lane_eq_mask(word, b' ')
+        | lane_eq_mask(word, b'\t')
+        | lane_eq_mask(word, b'\r')
+        | lane_eq_mask(word, b'\n');
+    whitespace_lanes == HI
+}
+
+/// Advances `pos` past a run of JSON whitespace (space, tab, CR, LF),
+/// word-at-a-time like [`Scanner`]'s own boring-word skip. Whole
+/// all-whitespace words are skipped in a single step via
+/// [`word_is_all_whitespace`]; the remaining run shorter than one word is
+/// walked byte-by-byte, since locating the exact first non-whitespace
+/// byte *within* a word would need a lane-order-dependent scan that
+/// `word_is_all_whitespace`'s order-independent equality check avoids.
+pub fn skip_whitespace(input: &[u8], mut pos: usize) -> usize {
+    while let Some(word) = word_at(input, pos) {
+        if !word_is_all_whitespace(word) {
+            break;
+        }
+        pos += WORD_BYTES;
+    }
+    while pos < input.len() && is_whitespace(input[pos]) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Scans a byte slice for `{ } [ ] : ,` bytes that are live JSON structure
+/// -- i.e. not inside a string -- yielding each one's offset and byte in
+/// source order. Quote-escaping (`\"`) and backslash-escaping (`\\`) are
+/// tracked the same way the tokenizer's own string scanning does, so this
+/// agrees with it on every offset.
+///
+/// Whole [`usize`]-wide words containing no quote, backslash, or
+/// structural byte are skipped in a single step via [`word_is_boring`];
+/// words that do contain one of those are walked byte-by-byte to resolve
+/// escaping and in-string state exactly. A trailing run shorter than one
+/// word is always walked byte-by-byte.
+pub struct Scanner<'a> {
+    input: &'a [u8],
+    pos: usize,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl<'a> Scanner<'a> {
+    /// Creates a scanner over `input`, starting outside any string.
+    pub fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            pos: 0,
+            in_string: false,
+            escaped: false,
+        }
+    }
+
+    /// Advances past one byte, updating `in_string`/`escaped`, and returns
+    /// `Some((offset, byte))` if it's a live structural byte.
+    fn step(&mut self) -> Option<(usize, u8)> {
+        let offset = self.pos;
+        let byte = self.input[offset];
+        self.pos += 1;
+
+        if self.in_string {
+            if byte == b'"' && !self.escaped {
+                self.in_string = false;
+            }
+            self.escaped = byte == b'\\' && !self.escaped;
+            return None;
+        }
+
+        if byte == b'"' {
+            self.in_string = true;
+            self.escaped = false;
+            return None;
+        }
+
+        if is_structural(byte) {
+            return Some((offset, byte));
+        }
+        None
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = (usize, u8);
+
+    fn next(&mut self) -> Option<(usize, u8)> {
+        loop {
+            if self.pos >= self.input.len() {
+                return None;
+            }
+
+            // Only the word-skip fast path needs the in_string check: a
+            // boring word never contains a quote, so in_string can't
+            // change partway through it, and a structural byte inside a
+            // string doesn't count regardless of which word it's in --
+            // `step` already handles that correctly either way. The skip
+            // just avoids calling `step` per byte when nothing in the word
+            // is interesting to anyone.
+            //
+            // `escaped` also has to survive into the next word correctly:
+            // `word_is_boring` only looks *inside* the word, so it has no
+            // way to see that the byte immediately before it was an
+            // unescaped backslash -- skipping in that case would carry a
+            // stale `escaped = true` past an arbitrary number of boring
+            // bytes and misread the eventual closing quote as escaped.
+            // Requiring `!self.escaped` before taking the fast path avoids
+            // that: a pending escape always falls through to `step`, which
+            // resolves it one byte at a time the same as it always did.
+            if !self.escaped {
+                if let Some(word) = word_at(self.input, self.pos) {
+                    if word_is_boring(word) {
+                        self.pos += WORD_BYTES;
+                        continue;
+                    }
+                }
+            }
+
+            if let Some(hit) = self.step() {
+                return Some(hit);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A byte-by-byte scalar walk with the same escaping rules, used as
+    /// the ground truth [`Scanner`] is checked against.
+    fn scalar_scan(input: &[u8]) -> Vec<(usize, u8)> {
+        let mut out = Vec::new();
+        let mut in_string = false;
+        let mut escaped = false;
+        for (offset, &byte) in input.iter().enumerate() {
+            if in_string {
+                if byte == b'"' && !escaped {
+                    in_string = false;
+                }
+                escaped = byte == b'\\' && !escaped;
+                continue;
+            }
+            if byte == b'"' {
+                in_string = true;
+                escaped = false;
+                continue;
+            }
+            if is_structural(byte) {
+                out.push((offset, byte));
+            }
+        }
+        out
+    }
+
+    fn collect(scanner: Scanner<'_>) -> Vec<(usize, u8)> {
+        let mut out = Vec::new();
+        for hit in scanner {
+            out.push(hit);
+        }
+        out
+    }
+
+    fn assert_matches_scalar(input: &[u8]) {
+        assert_eq!(collect(Scanner::new(input)), scalar_scan(input));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_matches_scalar(b"");
+    }
+
+    #[test]
+    fn test_flat_object() {
+        assert_matches_scalar(br#"{"a":1,"b":2}"#);
+    }
+
+    #[test]
+    fn test_structural_bytes_inside_strings_are_suppressed() {
+        assert_matches_scalar(br#"{"key":"a{b}[c]:d,e"}"#);
+    }
+
+    #[test]
+    fn test_escaped_quote_does_not_end_string() {
+        assert_matches_scalar(br#"{"a":"x\"}y","b":1}"#);
+    }
+
+    #[test]
+    fn test_escaped_backslash_before_quote_does_end_string() {
+        // The string is `x\` (one escaped backslash), so the following `"`
+        // is the real closing quote, not an escaped one.
+        assert_matches_scalar(br#"{"a":"x\\","b":1}"#);
+    }
+
+    #[test]
+    fn test_escaped_backslash_directly_before_word_skip_does_not_leak_into_closing_quote() {
+        // A literal backslash, immediately followed by a full word's worth
+        // of boring bytes before the closing quote. If `escaped` survives
+        // the word-skip stale, the closing quote below is misread as
+        // escaped and the string (and the rest of the document) is never
+        // seen as closed.
+        let mut input = Vec::new();
+        input.extend_from_slice(br#"{"a":""#);
+        input.push(b'\\');
+        input.extend(core::iter::repeat(b'a').take(WORD_BYTES));
+        input.extend_from_slice(br#"","b":1}"#);
+        assert_matches_scalar(&input);
+    }
+
+    #[test]
+    fn test_long_run_of_boring_bytes_spans_many_words() {
+        let mut input = Vec::new();
+        input.extend_from_slice(b"{\"a\":\"");
+        input.extend(core::iter::repeat(b'x').take(200));
+        input.extend_from_slice(b"\"}");
+        assert_matches_scalar(&input);
+    }
+
+    #[test]
+    fn test_trailing_bytes_shorter_than_one_word() {
+        assert_matches_scalar(b"{}");
+        assert_matches_scalar(b"[1,2,3]");
+    }
+
+    /// A byte-by-byte scalar walk, used as the ground truth
+    /// [`skip_whitespace`] is checked against.
+    fn scalar_skip_whitespace(input: &[u8], mut pos: usize) -> usize {
+        while pos < input.len() && is_whitespace(input[pos]) {
+            pos += 1;
+        }
+        pos
+    }
+
+    fn assert_skip_matches_scalar(input: &[u8], pos: usize) {
+        assert_eq!(
+            skip_whitespace(input, pos),
+            scalar_skip_whitespace(input, pos)
+        );
+    }
+
+    #[test]
+    fn test_skip_whitespace_empty_input() {
+        assert_skip_matches_scalar(b"", 0);
+    }
+
+    #[test]
+    fn test_skip_whitespace_no_leading_whitespace() {
+        assert_skip_matches_scalar(b"{}", 0);
+    }
+
+    #[test]
+    fn test_skip_whitespace_short_run() {
+        assert_skip_matches_scalar(b"  \t\r\n{}", 0);
+    }
+
+    #[test]
+    fn test_skip_whitespace_run_spans_many_words() {
+        let mut input = Vec::new();
+        input.extend(core::iter::repeat(b' ').take(200));
+        input.extend_from_slice(b"{}");
+        assert_skip_matches_scalar(&input, 0);
+    }
+
+    #[test]
+    fn test_skip_whitespace_all_whitespace_input() {
+        let input = b"   \t\t  ";
+        assert_skip_matches_scalar(input, 0);
+    }
+
+    #[test]
+    fn test_skip_whitespace_from_nonzero_offset() {
+        let mut input = Vec::new();
+        input.extend_from_slice(b"{\"a\":1}");
+        input.extend(core::iter::repeat(b' ').take(200));
+        input.extend_from_slice(b",");
+        assert_skip_matches_scalar(&input, 7);
+    }
+}
@@ -64,6 +64,23 @@ impl ContentExtractor for SliceContentBuilder<'_, '_> {
         self.copy_on_escape.begin_string(pos);
     }
 
+    fn consume_plain_content_run(&mut self) -> Result<Option<&[u8]>, ParseError> {
+        let start = self.buffer.current_pos();
+        let remaining = self.buffer.slice(start, self.buffer.data_len())?;
+        let run_len = remaining
+            .iter()
+            .take_while(|&&b| b != b'"' && b != b'\\' && b >= 0x20)
+            .count();
+        if run_len == 0 {
+            return Ok(None);
+        }
+        self.buffer.set_position(start + run_len);
+        // The whole input is resident, so this is just a re-slice of it --
+        // CopyOnEscape doesn't need to know about plain runs, it only cares
+        // about the positions where an escape starts/ends.
+        Ok(Some(&remaining[..run_len]))
+    }
+
     fn unicode_escape_collector_mut(&mut self) -> &mut UnicodeEscapeCollector {
         &mut self.unicode_escape_collector
     }
@@ -111,6 +128,12 @@ impl ContentExtractor for SliceContentBuilder<'_, '_> {
         Ok(Event::Number(json_number))
     }
 
+    fn extract_raw(&mut self, start_pos: usize, end_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+        let bytes = self.get_borrowed_slice(start_pos, end_pos)?;
+        let text = crate::shared::from_utf8(bytes)?;
+        Ok(Event::RawValue(crate::String::Borrowed(text)))
+    }
+
     fn begin_unicode_escape(&mut self) -> Result<(), ParseError> {
         Ok(())
     }
@@ -174,6 +197,19 @@ impl ContentExtractor for SliceContentBuilder<'_, '_> {
 /// This implementation provides access to both borrowed content from the original
 /// input slice and unescaped content from the CopyOnEscape scratch buffer.
 impl<'a, 'b> DataSource<'a, 'b> for SliceContentBuilder<'a, 'b> {
+    fn next_byte(&mut self) -> Result<Option<u8>, ParseError> {
+        match self.buffer_mut().consume_byte() {
+            Ok(byte) => Ok(Some(byte)),
+            Err(crate::slice_input_buffer::Error::ReachedEnd) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn peek_byte(&mut self) -> Result<Option<u8>, ParseError> {
+        let pos = self.buffer.current_pos();
+        Ok(self.buffer.slice(pos, pos + 1).ok().and_then(|s| s.first().copied()))
+    }
+
     fn get_borrowed_slice(&'a self, start: usize, end: usize) -> Result<&'a [u8], ParseError> {
         self.buffer.slice(start, end).map_err(Into::into)
     }
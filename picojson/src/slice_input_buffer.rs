@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use core::marker::PhantomData;
+
 use crate::ParseError;
 
 /// Error type for SliceInputBuffer operations.
@@ -12,52 +14,186 @@ pub enum Error {
 }
 
 /// A buffer that manages input data and current parsing position.
-/// This encapsulates the data slice and position that are always used together.
+///
+/// Borrows httparse's `Bytes` technique: instead of a `(slice, pos)` pair
+/// bounds-checked through `slice::get` on every access, position is a raw
+/// `cursor` pointer compared directly against `end`, removing the
+/// index-into-slice bounds check from the hot byte-at-a-time tokenizer
+/// loop. `start` anchors [`Self::current_pos`] (`cursor - start`) and lets
+/// the original slice be reconstructed on demand for the handful of
+/// operations -- `slice`, `position_for_offset` -- that need more than a
+/// single byte. `PhantomData<&'a [u8]>` ties the buffer back to the
+/// lifetime of the slice the pointers were derived from, the same as if
+/// the slice itself were still a field.
 #[derive(Debug)]
 pub struct SliceInputBuffer<'a> {
-    data: &'a [u8],
-    pos: usize,
+    start: *const u8,
+    end: *const u8,
+    cursor: *const u8,
+    _data: PhantomData<&'a [u8]>,
 }
 
 pub trait InputBuffer {
     fn is_past_end(&self) -> bool;
     fn consume_byte(&mut self) -> Result<u8, Error>;
+
+    /// Looks at the next byte without consuming it, or `None` at the end of
+    /// input. Mirrors [`DataSource::peek_byte`](crate::shared::DataSource::peek_byte)'s
+    /// shape one layer down, at the raw-cursor level rather than the
+    /// content-extraction one -- see [`Self::discard`] for why the two
+    /// don't currently share a single delimiter-lookahead call site.
+    fn peek(&self) -> Option<u8>;
+
+    /// Advances past a byte already inspected with [`Self::peek`], without
+    /// re-reading it.
+    ///
+    /// Note for anyone wiring this up to replace a `saturating_sub(1)`
+    /// delimiter back-calculation (e.g. [`ContentRange::end_position_excluding_delimiter`](crate::shared::ContentRange::end_position_excluding_delimiter)/
+    /// [`ContentRange::number_end_position`](crate::shared::ContentRange::number_end_position)):
+    /// those back-calculations undo consumption the *tokenizer's* own FSM
+    /// already did internally, not this buffer's. The tokenizer is a
+    /// consume-then-classify scanner -- it has to read the byte after a
+    /// token to know the token ended, and only reports that back as an
+    /// event once it already has -- so swapping its instances of this
+    /// pattern for a peek-before-consume one would mean restructuring the
+    /// FSM's core loop in `ujson::Tokenizer`, not just this trait. This
+    /// method exists for callers sitting above the tokenizer that want
+    /// their own lookahead without consuming the tokenizer's input (the
+    /// same role [`DataSource::peek_byte`]/[`DataSource::discard`] already
+    /// play one layer up, currently unused by any call site for the same
+    /// reason).
+    fn discard(&mut self);
 }
 
 impl InputBuffer for SliceInputBuffer<'_> {
     fn is_past_end(&self) -> bool {
-        self.pos > self.data.len()
+        (self.cursor as usize) > (self.end as usize)
     }
     fn consume_byte(&mut self) -> Result<u8, Error> {
-        match self.data.get(self.pos) {
-            Some(&byte) => {
-                self.pos = self.pos.checked_add(1).ok_or(Error::InvalidSliceBounds)?;
-                Ok(byte)
-            }
-            None => {
-                self.pos = self.pos.checked_add(1).ok_or(Error::InvalidSliceBounds)?;
-                Err(Error::ReachedEnd)
-            }
+        if (self.cursor as usize) < (self.end as usize) {
+            // SAFETY: just checked `cursor` is strictly before `end`, and
+            // `[start, end)` spans exactly the bytes of the `'a` slice
+            // `Self::new` was built from, so `cursor` points at a valid,
+            // initialized byte within it.
+            let byte = unsafe { *self.cursor };
+            self.cursor = self.cursor.wrapping_add(1);
+            Ok(byte)
+        } else {
+            // One-past-`end` is a valid, never-dereferenced position (see
+            // the `pos == data.len()` case in `test_buffer_boundary_behavior`);
+            // `wrapping_add` rather than `add` keeps bumping it further past
+            // that on repeated calls past end pointer-arithmetic-safe too,
+            // since this branch never reads through `cursor`.
+            self.cursor = self.cursor.wrapping_add(1);
+            Err(Error::ReachedEnd)
         }
     }
+    fn peek(&self) -> Option<u8> {
+        SliceInputBuffer::peek(self)
+    }
+    fn discard(&mut self) {
+        // Same `wrapping_add` past `end` as `consume_byte`'s error branch:
+        // advancing here is only ever called right after `peek` reported a
+        // byte, so there's nothing to re-validate.
+        self.cursor = self.cursor.wrapping_add(1);
+    }
 }
 impl<'a> SliceInputBuffer<'a> {
     pub fn current_pos(&self) -> usize {
-        self.pos
+        (self.cursor as usize).wrapping_sub(self.start as usize)
+    }
+
+    /// Rewinds (or fast-forwards) to a previously observed [`Self::current_pos`],
+    /// for [`crate::SliceParser::restore`]. The backing slice is always fully
+    /// available, so this is just a position reset -- no data is re-fetched.
+    pub fn set_position(&mut self, pos: usize) {
+        self.cursor = self.start.wrapping_add(pos);
+    }
+
+    /// Reconstructs the original `'a` slice from `start`/`end`.
+    ///
+    /// SAFETY: `start` and `end` are always derived together from the same
+    /// `'a [u8]` slice (in [`Self::new`]) and never separately mutated, so
+    /// `[start, end)` is exactly that slice's bytes for as long as `'a` is
+    /// live.
+    fn full_data(&self) -> &'a [u8] {
+        let len = (self.end as usize).wrapping_sub(self.start as usize);
+        unsafe { core::slice::from_raw_parts(self.start, len) }
+    }
+
+    /// Computes the line/column location of an arbitrary byte offset --
+    /// such as one carried by an already-produced [`Event`](crate::Event)'s
+    /// [`Span`](crate::Span). The whole input is always available here, so
+    /// this scans `data[0..offset]` once counting newlines; only possible
+    /// here because the whole input is always available -- a
+    /// [`StreamParser`](crate::StreamParser) can't offer this, since earlier
+    /// bytes may already have been compacted out of its buffer by the time
+    /// the caller asks.
+    pub fn position_for_offset(&self, offset: usize) -> crate::Position {
+        let data = self.full_data();
+        let scanned = data.get(..offset).unwrap_or(data);
+        let mut line = 1;
+        let mut column = 1;
+        for &byte in scanned {
+            if byte == b'\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        crate::Position {
+            byte_offset: offset,
+            line,
+            column,
+        }
     }
     /// Creates a new SliceInputBuffer with the given data.
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data, pos: 0 }
+        let start = data.as_ptr();
+        Self {
+            start,
+            end: start.wrapping_add(data.len()),
+            cursor: start,
+            _data: PhantomData,
+        }
+    }
+
+    /// Reads the byte at the cursor without consuming it, or `None` at the
+    /// end of data.
+    pub fn peek(&self) -> Option<u8> {
+        if (self.cursor as usize) < (self.end as usize) {
+            // SAFETY: see `consume_byte`.
+            Some(unsafe { *self.cursor })
+        } else {
+            None
+        }
+    }
+
+    /// Reads the `N` bytes starting at the cursor without consuming them,
+    /// if that many remain, in a single bounds check instead of `N`
+    /// individual ones -- e.g. matching all 4 hex digits of a `\uXXXX`
+    /// escape, or the literal `true`/`null`, at once.
+    pub fn peek_n<const N: usize>(&self) -> Option<[u8; N]> {
+        if (self.end as usize).wrapping_sub(self.cursor as usize) < N {
+            return None;
+        }
+        // SAFETY: the check above guarantees `N` readable bytes starting at
+        // `cursor`, all within `[start, end)`; `[u8; N]` has no alignment
+        // requirement beyond 1, so an unaligned read is fine.
+        Some(unsafe { core::ptr::read(self.cursor as *const [u8; N]) })
     }
 
     /// Gets a slice of the data from start to end positions, with bounds checking.
     pub fn slice(&self, start: usize, end: usize) -> Result<&'a [u8], Error> {
-        self.data.get(start..end).ok_or(Error::InvalidSliceBounds)
+        self.full_data()
+            .get(start..end)
+            .ok_or(Error::InvalidSliceBounds)
     }
 
     /// Gets the length of the underlying data for bounds checking.
     pub fn data_len(&self) -> usize {
-        self.data.len()
+        (self.end as usize).wrapping_sub(self.start as usize)
     }
 }
 
@@ -70,11 +206,11 @@ impl crate::number_parser::NumberExtractor for SliceInputBuffer<'_> {
     fn current_position(&self) -> usize {
         // Return the actual current position (AFTER any delimiter)
         // Delimiter handling is now centralized in parse_number_event()
-        self.pos
+        self.current_pos()
     }
 
     fn is_empty(&self) -> bool {
-        self.pos >= self.data.len()
+        self.current_pos() >= self.data_len()
     }
 }
 
@@ -132,4 +268,24 @@ mod tests {
             "consume_byte() should fail when pos > data.len()"
         );
     }
+
+    #[test]
+    fn test_trait_peek_and_discard_match_consume_byte() {
+        let data = b"ab";
+        let mut buffer = SliceInputBuffer::new(data);
+
+        // `peek` never advances, and agrees with the inherent method of the
+        // same name.
+        assert_eq!(InputBuffer::peek(&buffer), Some(b'a'));
+        assert_eq!(InputBuffer::peek(&buffer), buffer.peek());
+        assert_eq!(buffer.current_pos(), 0);
+
+        // `discard` advances past a peeked byte without re-reading it.
+        InputBuffer::discard(&mut buffer);
+        assert_eq!(buffer.current_pos(), 1);
+        assert_eq!(InputBuffer::peek(&buffer), Some(b'b'));
+
+        assert_eq!(buffer.consume_byte(), Ok(b'b'));
+        assert_eq!(InputBuffer::peek(&buffer), None);
+    }
 }
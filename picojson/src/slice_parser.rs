@@ -1,8 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::event_processor::{EscapeTiming, ParserCore};
+use crate::event_processor::{ContentExtractor, EscapeTiming, ParserCore};
 use crate::parse_error::ParseError;
-use crate::shared::{Event, PullParser};
+use crate::shared::{Event, PullParser, State};
 use crate::slice_content_builder::SliceContentBuilder;
 use crate::slice_input_buffer::InputBuffer;
 use crate::ujson;
@@ -12,6 +12,19 @@ use ujson::{BitStackConfig, DefaultConfig};
 /// A pull parser that parses JSON from a slice.
 ///
 /// Generic over BitStack storage type for configurable nesting depth.
+///
+/// Every constructor here assumes `input` is already UTF-8 (RFC 8259 also
+/// allows UTF-16/UTF-32, with or without a BOM). There's no
+/// `with_encoding_detection` counterpart that sniffs and transcodes a
+/// non-UTF-8 slice in place: doing that would mean copying the transcoded
+/// bytes into a scratch buffer before the tokenizer ever sees them, which
+/// gives up exactly the zero-copy borrow from `input` this type exists for
+/// -- at that point the caller isn't really using `SliceParser` for its
+/// slice, just its tokenizer. [`crate::TranscodingReader`] already covers
+/// this for the `Reader`-based front ends (wrap the byte source in one and
+/// hand it to [`crate::StreamParser`]), sniffing a leading BOM
+/// (UTF-8/UTF-16LE/UTF-16BE/UTF-32LE/UTF-32BE) and transcoding to UTF-8 on
+/// the fly into a buffer the caller already owns either way.
 // Lifetime 'a is the input buffer lifetime
 // lifetime 'b is the scratch/copy buffer lifetime
 pub struct SliceParser<'a, 'b, C: BitStackConfig = DefaultConfig> {
@@ -19,6 +32,16 @@ pub struct SliceParser<'a, 'b, C: BitStackConfig = DefaultConfig> {
     parser_core: ParserCore<C::Bucket, C::Counter>,
     /// The content builder that handles SliceParser-specific content extraction
     content_builder: SliceContentBuilder<'a, 'b>,
+    /// Whether this parser accepts a sequence of whitespace-separated
+    /// top-level values (NDJSON-style) instead of exactly one.
+    streaming: bool,
+    /// In streaming mode, whether the `Event::EndDocument` boundary for the
+    /// value the tokenizer just finished still needs to be returned.
+    boundary_pending: bool,
+    /// Set by [`Self::peek_event`] to the state just before the peeked
+    /// event, so a subsequent [`Self::peek_event`] call can replay it
+    /// instead of advancing further. Cleared on every `next_event()`.
+    peek_checkpoint: Option<Checkpoint<C::Bucket, C::Counter>>,
 }
 
 /// Methods for the pull parser.
@@ -56,6 +79,35 @@ impl<'a> SliceParser<'a, '_, DefaultConfig> {
     pub fn new_from_slice(input: &'a [u8]) -> Self {
         Self::with_config_from_slice(input)
     }
+
+    /// Creates a parser over a sequence of whitespace-separated top-level
+    /// JSON values (NDJSON-style), instead of exactly one.
+    ///
+    /// Each value still ends with a single [`Event::EndDocument`], but once
+    /// that's been returned, [`next_event`](PullParser::next_event) resumes
+    /// with the next value's events instead of repeating `EndDocument`
+    /// forever -- so a record stream can be consumed as one continuous
+    /// series of events without building a new parser per line. Assumes no
+    /// string escapes; for escapes, use [`with_buffer_ndjson`](Self::with_buffer_ndjson).
+    ///
+    /// Unlike the push-style `FeedParser`/`PollParser`/`AsyncFeedParser`
+    /// NDJSON mode (which brackets each record with its own
+    /// `FeedEvent::StartDocument`/`EndDocument` pair, since those front ends
+    /// have nowhere else to signal "a new record just started"), this pull
+    /// interface does not emit a `StartDocument` between records: the first
+    /// event of the next document is simply whatever that document's first
+    /// real event is (e.g. `StartObject`). Callers only ever see one
+    /// `StartDocument`, at the very start of the stream.
+    ///
+    /// # Example
+    /// ```
+    /// use picojson::{Event, PullParser, SliceParser};
+    /// let mut parser = SliceParser::new_ndjson("{\"a\": 1}\n{\"a\": 2}\n");
+    /// assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    /// ```
+    pub fn new_ndjson(input: &'a str) -> Self {
+        Self::with_config_and_buffer_from_slice_streaming(input.as_bytes(), &mut [])
+    }
 }
 
 /// Constructor with scratch buffer for SliceParser using DefaultConfig
@@ -94,6 +146,13 @@ impl<'a, 'b> SliceParser<'a, 'b, DefaultConfig> {
     pub fn with_buffer_from_slice(input: &'a [u8], scratch_buffer: &'b mut [u8]) -> Self {
         Self::with_config_and_buffer_from_slice(input, scratch_buffer)
     }
+
+    /// Like [`new_ndjson`](Self::new_ndjson), but with an external scratch
+    /// buffer for inputs that contain string escapes.
+    pub fn with_buffer_ndjson(input: &'a str, scratch_buffer: &'b mut [u8]) -> Self {
+        Self::with_config_and_buffer_from_slice_streaming(input.as_bytes(), scratch_buffer)
+    }
+
 }
 
 /// Generic constructor for SliceParser with custom configurations
@@ -139,6 +198,21 @@ impl<'a, 'b, C: BitStackConfig> SliceParser<'a, 'b, C> {
         SliceParser {
             parser_core: ParserCore::new(),
             content_builder: SliceContentBuilder::new(input, scratch_buffer),
+            streaming: false,
+            boundary_pending: false,
+            peek_checkpoint: None,
+        }
+    }
+
+    /// Like [`with_config_and_buffer_from_slice`](Self::with_config_and_buffer_from_slice),
+    /// but in NDJSON streaming mode (see [`new_ndjson`](Self::new_ndjson)).
+    pub(crate) fn with_config_and_buffer_from_slice_streaming(
+        input: &'a [u8],
+        scratch_buffer: &'b mut [u8],
+    ) -> Self {
+        SliceParser {
+            streaming: true,
+            ..Self::with_config_and_buffer_from_slice(input, scratch_buffer)
         }
     }
 
@@ -153,17 +227,405 @@ impl<'a, 'b, C: BitStackConfig> SliceParser<'a, 'b, C> {
             |_, _| Ok(()),
         )
     }
-}
 
-impl<C: BitStackConfig> PullParser for SliceParser<'_, '_, C> {
-    fn next_event(&mut self) -> Result<Event<'_, '_>, ParseError> {
+    /// Returns the line/column location of the current parse position, for
+    /// reporting alongside a [`ParseError`] returned from [`next_event`](PullParser::next_event).
+    /// Tracked incrementally as bytes are consumed, so this is O(1) rather
+    /// than rescanning the input. After a failed `next_event` call --
+    /// including [`ParseError::EndOfData`] -- this points at the last byte
+    /// actually consumed, since that's as far as the line/column counters
+    /// ever advanced.
+    pub fn position(&self) -> crate::Position {
+        self.parser_core.current_position()
+    }
+
+    /// Translates an arbitrary byte offset -- such as a
+    /// [`Span`](crate::Span)'s `start`/`end` from an already-produced
+    /// [`Event`](crate::Event) -- into its line/column [`Position`](crate::Position),
+    /// the same way [`Self::position`] translates the current one. Unlike
+    /// [`Self::position`], which is pinned to wherever parsing currently
+    /// sits, this works for any offset into the input because the whole
+    /// slice stays available for the parser's lifetime.
+    pub fn position_for_offset(&self, offset: usize) -> crate::Position {
+        self.content_builder.buffer().position_for_offset(offset)
+    }
+
+    /// Like [`next_event`](PullParser::next_event), but on failure returns
+    /// the [`Position`](crate::Position) of the byte that triggered it
+    /// alongside the error, so callers don't need a separate call to
+    /// [`Self::position`] afterwards.
+    pub fn next_event_located(&mut self) -> Result<Event<'_, '_>, (ParseError, crate::Position)> {
+        match PullParser::next_event(self) {
+            Err(e) => Err((e, self.position())),
+            Ok(event) => Ok(event),
+        }
+    }
+
+    /// Like [`next_event`](PullParser::next_event), but also returns the
+    /// [`Position`](crate::Position) immediately after the event, so
+    /// successful events can be located the same way
+    /// [`Self::next_event_located`] locates an error.
+    pub fn next_event_with_position(
+        &mut self,
+    ) -> Result<(Event<'_, '_>, crate::Position), ParseError> {
+        if self.content_builder.buffer().is_past_end() {
+            return Ok((Event::EndDocument, self.position()));
+        }
+        let event = self.parser_core.next_event_impl(
+            &mut self.content_builder,
+            EscapeTiming::OnBegin,
+            |_, _| Ok(()),
+        )?;
+        Ok((event, self.position()))
+    }
+
+    /// Like [`next_event`](PullParser::next_event), but also returns the
+    /// [`Span`](crate::Span) of source bytes the event was produced from,
+    /// so callers don't need to track offsets themselves to report where a
+    /// token came from (e.g. highlighting it in the original source).
+    ///
+    /// For scalar events the span covers the full lexeme, including
+    /// surrounding quotes for strings/keys. Container and `Bool`/`Null`
+    /// events cover their single token. `StartDocument`/`EndDocument`
+    /// carry whatever span was last recorded, since they don't consume a
+    /// token of their own.
+    pub fn next_event_with_span(&mut self) -> Result<(Event<'_, '_>, crate::Span), ParseError> {
+        if self.content_builder.buffer().is_past_end() {
+            return Ok((Event::EndDocument, self.parser_core.last_span()));
+        }
+        let event = self.parser_core.next_event_impl(
+            &mut self.content_builder,
+            EscapeTiming::OnBegin,
+            |_, _| Ok(()),
+        )?;
+        Ok((event, self.parser_core.last_span()))
+    }
+
+    /// Like [`raw_value`](Self::raw_value), but captures any complete next
+    /// value — scalar, object, or array — as a single [`Event::RawValue`]
+    /// instead of its usual decoded event(s). Call this exactly where a
+    /// value is expected, the same way as [`raw_value`](Self::raw_value).
+    ///
+    /// Scalars are captured in a single step, since [`ParserCore::last_span`]
+    /// already covers their full lexeme (including quotes for strings/keys);
+    /// objects and arrays are captured by tracking nesting depth until it
+    /// returns to the level it started at.
+    pub fn next_raw_value(&mut self) -> Result<Event<'_, '_>, ParseError> {
+        let Some((start, end)) = self.next_raw_value_span()? else {
+            return Ok(Event::EndDocument);
+        };
+        self.content_builder.extract_raw(start, end)
+    }
+
+    /// Like [`Self::next_raw_value`], but also returns the
+    /// [`Span`](crate::Span) of source bytes the raw value spans -- the
+    /// `[start, end)` byte range into the original input, covering the
+    /// whole captured value (including, for an object/array, everything
+    /// nested inside it), not just whichever token happened to be last.
+    /// Unlike [`Self::next_event_with_span`], this span can't be read back
+    /// afterwards via [`ParserCore::last_span`], since that only ever
+    /// reflects the most recently processed token.
+    pub fn next_raw_value_with_span(
+        &mut self,
+    ) -> Result<(Event<'_, '_>, crate::Span), ParseError> {
+        let Some((start, end)) = self.next_raw_value_span()? else {
+            return Ok((Event::EndDocument, crate::Span { start: 0, end: 0 }));
+        };
+        let span = crate::Span { start, end };
+        Ok((self.content_builder.extract_raw(start, end)?, span))
+    }
+
+    /// Like [`skip_value`](PullParser::skip_value), but also returns the
+    /// [`Span`](crate::Span) of the value skipped over, so a caller can hand
+    /// the `[start, end)` byte range to something else (e.g. stash a config
+    /// subtree for later parsing) instead of just discarding it. Call this
+    /// in place of following up an uninteresting [`Event::Key`] -- the same
+    /// spot [`Self::next_raw_value_with_span`] is called from -- not after
+    /// already consuming a `StartObject`/`StartArray`. This is exactly
+    /// [`Self::next_raw_value_span`] without the extraction step
+    /// [`Self::next_raw_value_with_span`] pays for.
+    pub fn skip_value_with_span(&mut self) -> Result<crate::Span, ParseError> {
+        let (start, end) = self.next_raw_value_span()?.unwrap_or_default();
+        Ok(crate::Span { start, end })
+    }
+
+    /// Drives the tokenizer through the next complete value -- scalar,
+    /// object, or array -- the same way [`Self::next_raw_value`] does, but
+    /// stops short of extracting it, returning just the `[start, end)`
+    /// byte range it spans. `None` means `EndDocument` was reached instead.
+    fn next_raw_value_span(&mut self) -> Result<Option<(usize, usize)>, ParseError> {
+        let start = match self.next_event_impl()? {
+            Event::EndDocument => return Ok(None),
+            Event::StartObject | Event::StartArray => {
+                let start = self.parser_core.last_span().start;
+                let mut depth: usize = 1;
+                loop {
+                    match self.next_event_impl()? {
+                        Event::StartObject | Event::StartArray => depth += 1,
+                        Event::EndObject | Event::EndArray => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        Event::EndDocument => return Err(ParseError::EndOfData),
+                        _ => {}
+                    }
+                }
+                start
+            }
+            _ => self.parser_core.last_span().start,
+        };
+        let end = self.parser_core.last_span().end;
+        Ok(Some((start, end)))
+    }
+
+    /// Captures the exact source bytes of the next value without decoding it,
+    /// zero-copy, as a borrow of the original input (serde_json's `raw_value`
+    /// equivalent). Call this instead of [`next_event`](PullParser::next_event)
+    /// exactly where a value is expected (e.g. right after a `Key` event, or
+    /// before the first top-level value).
+    ///
+    /// Escape sequences inside strings are preserved verbatim, not decoded.
+    /// Nesting is tracked the same way normal parsing is, so the same
+    /// `BitStack` depth limit applies.
+    ///
+    /// Only object and array values are supported, since those are the
+    /// typical "opaque blob" use case (a config subtree, a signed payload);
+    /// read scalar values with the normal `next_event` API instead. For a
+    /// value that might also be a bare scalar, use [`Self::next_raw_value`]
+    /// instead, which covers all three shapes (at the cost of returning an
+    /// [`Event::RawValue`] wrapping the slice rather than the slice itself).
+    pub fn raw_value(&mut self) -> Result<&[u8], ParseError> {
+        let start = match self.next_event_impl()? {
+            Event::StartObject | Event::StartArray => {
+                self.content_builder.buffer().current_pos().saturating_sub(1)
+            }
+            _ => return Err(crate::shared::UnexpectedState::StateMismatch.into()),
+        };
+
+        let mut depth: usize = 1;
+        loop {
+            match self.next_event_impl()? {
+                Event::StartObject | Event::StartArray => depth += 1,
+                Event::EndObject | Event::EndArray => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                Event::EndDocument => return Err(ParseError::EndOfData),
+                _ => {}
+            }
+        }
+
+        let end = self.content_builder.buffer().current_pos();
+        self.content_builder
+            .buffer()
+            .slice(start, end)
+            .map_err(Into::into)
+    }
+
+    /// Captures a [`Checkpoint`] of the parser's current state, so a caller
+    /// can consume further events speculatively -- e.g. trying a value as
+    /// one of several shapes -- and later [`Self::restore`] back to this
+    /// point if none of them match, without maintaining a shadow state
+    /// machine of its own.
+    ///
+    /// Only valid between complete events: returns
+    /// [`UnexpectedState::StateMismatch`](crate::shared::UnexpectedState::StateMismatch)
+    /// if called while a string, key, or number is partway through being
+    /// tokenized.
+    pub fn checkpoint(&self) -> Result<Checkpoint<C::Bucket, C::Counter>, ParseError> {
+        if *self.content_builder.parser_state() != State::None {
+            return Err(crate::shared::UnexpectedState::StateMismatch.into());
+        }
+        Ok(Checkpoint {
+            parser_core: self.parser_core.clone(),
+            pos: self.content_builder.buffer().current_pos(),
+        })
+    }
+
+    /// Restores state previously captured with [`Self::checkpoint`],
+    /// rewinding the input position and tokenizer state so the next call to
+    /// [`next_event`](PullParser::next_event) replays from that point.
+    pub fn restore(&mut self, checkpoint: Checkpoint<C::Bucket, C::Counter>) {
+        self.parser_core = checkpoint.parser_core;
+        self.content_builder
+            .buffer_mut()
+            .set_position(checkpoint.pos);
+    }
+
+    /// Looks at the next event without consuming it: a following call to
+    /// [`next_event`](PullParser::next_event) returns the same event again.
+    /// Useful for one-token-lookahead grammars, e.g. distinguishing an empty
+    /// object/array from one with contents, or dispatching on a key before
+    /// deciding how to read its value.
+    ///
+    /// `Event::String`/`Event::Key`/`Event::Number` can borrow from the
+    /// scratch buffer, so unlike [`next_event`](PullParser::next_event) this
+    /// can't simply cache the borrowed `Event` across the two calls without
+    /// holding the parser mutably borrowed in between -- instead, this
+    /// takes a [`Checkpoint`] before parsing and, if called again before the
+    /// next real [`next_event`](PullParser::next_event), rewinds to it and
+    /// re-parses, which `CopyOnEscape`'s ever-advancing scratch cursor makes
+    /// safe to repeat.
+    ///
+    /// Not part of [`PullParser`] for the same reason [`Self::checkpoint`]
+    /// isn't: `StreamParser` compacts consumed bytes out of its buffer, so
+    /// it has nothing to rewind to and can't offer this.
+    ///
+    /// This returns an owned [`Event`], not a `&Event` into some stashed
+    /// slot: `ParserState.evts`, the tokenizer callback's own two-slot
+    /// buffer, is fully drained back into a single [`Event`] before
+    /// `next_event_impl` returns, so there's no stashed event left to hand
+    /// out a reference to once a call completes. Caching the event itself
+    /// would also need somewhere to put `Event::String`/`Key`/`Number`'s
+    /// borrow of the scratch buffer across two calls while still letting
+    /// `next_event` advance that same buffer in between -- exactly the
+    /// aliasing [`Self::checkpoint`]/[`Self::restore`] sidesteps by
+    /// re-parsing instead of caching.
+    pub fn peek_event(&mut self) -> Result<Event<'_, '_>, ParseError> {
+        let checkpoint = match self.peek_checkpoint.take() {
+            Some(checkpoint) => checkpoint,
+            None => self.checkpoint()?,
+        };
+        self.restore(checkpoint.clone());
+        self.peek_checkpoint = Some(checkpoint);
+        self.next_event_uncached()
+    }
+
+    /// Like [`Self::peek_event`], but returns `None` in place of
+    /// `Event::EndDocument`, mirroring how [`next`](PullParser::next) relates
+    /// to [`next_event`](PullParser::next_event).
+    pub fn peek(&mut self) -> Option<Result<Event<'_, '_>, ParseError>> {
+        match self.peek_event() {
+            Ok(Event::EndDocument) => None,
+            other => Some(other),
+        }
+    }
+
+    /// The body of [`next_event`](PullParser::next_event), shared with
+    /// [`Self::peek_event`] so peeking and normal advancement run through
+    /// the exact same streaming-boundary and parsing logic.
+    fn next_event_uncached(&mut self) -> Result<Event<'_, '_>, ParseError> {
         if self.content_builder.buffer().is_past_end() {
             return Ok(Event::EndDocument);
         }
+        if let Some(event) = self.handle_streaming_boundary()? {
+            return Ok(event);
+        }
         self.next_event_impl()
     }
 }
 
+/// A snapshot of a [`SliceParser`]'s tokenizer state and input position,
+/// captured by [`SliceParser::checkpoint`] and restored by
+/// [`SliceParser::restore`].
+///
+/// Cheap to take: the backing slice is always fully available and
+/// re-readable, so this only clones the tokenizer's small bit-stack/depth
+/// state and the pending-event buffer, not any of the input itself.
+#[derive(Clone)]
+pub struct Checkpoint<T: ujson::BitBucket, C: ujson::DepthCounter> {
+    parser_core: ParserCore<T, C>,
+    pos: usize,
+}
+
+impl<C: BitStackConfig> SliceParser<'_, '_, C> {
+    /// In streaming mode, once the tokenizer reports a completed top-level
+    /// value, returns the `Event::EndDocument` boundary for it on the first
+    /// call, then on the next call skips any whitespace separating it from
+    /// the following value and re-arms the tokenizer to parse it -- or, if
+    /// only whitespace remains, leaves the parser at its natural end.
+    ///
+    /// Returns `Some(event)` when a streaming boundary was handled (the
+    /// caller should return it as-is); `None` means there's nothing special
+    /// to do and the caller should fall through to its normal event loop.
+    fn handle_streaming_boundary(&mut self) -> Result<Option<Event<'_, '_>>, ParseError> {
+        if !self.streaming || !self.parser_core.tokenizer.is_finished() {
+            return Ok(None);
+        }
+        if !self.boundary_pending {
+            self.boundary_pending = true;
+            return Ok(Some(Event::EndDocument));
+        }
+        self.boundary_pending = false;
+        loop {
+            let pos = self.content_builder.buffer().current_pos();
+            match self.content_builder.buffer_mut().consume_byte() {
+                Ok(b' ' | b'\t' | b'\n' | b'\r') => continue,
+                Ok(_) => {
+                    self.content_builder.buffer_mut().set_position(pos);
+                    self.parser_core.tokenizer.reset_for_next_document();
+                    return Ok(None);
+                }
+                Err(_) => return Ok(Some(Event::EndDocument)),
+            }
+        }
+    }
+}
+
+impl<C: BitStackConfig> PullParser for SliceParser<'_, '_, C> {
+    fn next_event(&mut self) -> Result<Event<'_, '_>, ParseError> {
+        self.peek_checkpoint = None;
+        self.next_event_uncached()
+    }
+
+    fn set_max_depth(&mut self, max_depth: usize) {
+        self.parser_core.set_max_depth(max_depth);
+    }
+
+    fn depth(&self) -> usize {
+        self.parser_core.depth()
+    }
+
+    fn remaining_depth(&self) -> Option<usize> {
+        self.parser_core.remaining_depth()
+    }
+
+    fn in_object(&self) -> bool {
+        self.parser_core.in_object()
+    }
+
+    fn in_array(&self) -> bool {
+        self.parser_core.in_array()
+    }
+
+    fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.parser_core.set_reject_escaped_keys(reject);
+    }
+
+    fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.parser_core.set_reject_bidi_controls(reject);
+    }
+
+    fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.parser_core.set_surrogate_policy(policy);
+    }
+
+    fn set_whitespace_events(&mut self, enabled: bool) {
+        self.parser_core.set_whitespace_events(enabled);
+    }
+
+    fn set_recovery_mode(&mut self, enabled: bool) {
+        self.parser_core.set_recovery_mode(enabled);
+    }
+
+    fn set_max_recovery_errors(&mut self, max: usize) {
+        self.parser_core.set_max_recovery_errors(max);
+    }
+
+    fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.parser_core.set_lenient_syntax(enabled);
+    }
+
+    fn skip_value(&mut self) -> Result<(), ParseError> {
+        self.peek_checkpoint = None;
+        self.parser_core.skip_value_impl(&mut self.content_builder)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,10 +7,42 @@ pub enum StreamBufferError {
     BufferFull,
     /// Attempted to read beyond available data
     EndOfData,
+    /// A [`StreamBuffer::scan_until`]/[`StreamBuffer::scan_until_any`] scan
+    /// reached `data_end` without finding its needle -- the token being
+    /// scanned spans past everything currently filled, rather than the
+    /// document having genuinely ended. `needed` is a lower bound (always
+    /// at least 1) on how many more bytes must be filled before retrying,
+    /// since the needle's actual distance past `data_end` isn't known yet.
+    /// Unlike [`Self::EndOfData`], this is resumable: fill more data and
+    /// call the scan again. [`ParseError::NeedMoreInput`](crate::ParseError::NeedMoreInput)
+    /// carries this same signal up through [`StreamContentBuilder`](crate::stream_content_builder::StreamContentBuilder)
+    /// to [`Poll::NeedMoreInput`](crate::Poll::NeedMoreInput) -- a token that
+    /// spans more `Reader` fills than fit in the buffer at once (a string
+    /// longer than one read, say) already resumes from wherever
+    /// [`Self::scan_string_body`]/[`Self::scan_until_any`] left `tokenize_pos`,
+    /// coordinated with [`Self::compact_from`] reclaiming the bytes already
+    /// consumed before it.
+    NeedMoreInput {
+        /// Lower bound on additional bytes needed before retrying the scan.
+        needed: usize,
+    },
     /// An unexpected error occurred.
     Unexpected,
     /// Invalid slice bounds provided for string extraction
     InvalidSliceBounds,
+    /// An [`crate::assembler::Assembler`] ran out of room to track another
+    /// disjoint gap between received segments.
+    TooManyHoles,
+}
+
+/// Whether a [`StreamBuffer`] treats `tokenize_pos`/`data_end` as plain
+/// offsets into `buffer` (`Linear`, the default), or as logical positions
+/// that wrap around the end of `buffer` once consumed space can be reused
+/// (`Ring`), avoiding a `compact_from` memmove on every refill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BufferMode {
+    Linear,
+    Ring,
 }
 
 /// StreamBuffer manages a single buffer for both input and escape processing
@@ -20,6 +52,19 @@ pub enum StreamBufferError {
 /// - Unescaped content is copied to buffer start when needed
 /// - Zero-copy string extraction when no escapes are present
 /// - Guaranteed space for escape processing (unescaped â‰¤ escaped)
+///
+/// Bytes already consumed past `tokenize_pos` aren't stuck forever: a
+/// fixed-size buffer reclaims them either by rotating/copying them out via
+/// [`compact_from`](Self::compact_from) (`Linear` mode, the default,
+/// returning the shift so the caller can rebase its own saved positions --
+/// see [`StreamContentBuilder::update_positions_after_compaction`](crate::stream_content_builder::StreamContentBuilder)),
+/// or by reusing that space in place once `BufferMode::Ring` wraps around
+/// it, avoiding the memmove entirely. Either way, a token that can't yet be
+/// completed because it runs past currently-filled data -- not because the
+/// document ended -- comes back as [`StreamBufferError::NeedMoreInput`]
+/// from [`scan_until_any`](Self::scan_until_any)/[`scan_string_body`](Self::scan_string_body),
+/// which the caller resolves by filling more and calling again, rather than
+/// a dedicated "suspend this token" state.
 pub struct StreamBuffer<'a> {
     /// The entire buffer slice
     buffer: &'a mut [u8],
@@ -29,6 +74,23 @@ pub struct StreamBuffer<'a> {
     data_end: usize,
     /// Length of unescaped content at buffer start (0 if no unescaping active)
     unescaped_len: usize,
+    /// `Linear` (default, `compact_from`-based) or `Ring` (wrap-around) mode.
+    mode: BufferMode,
+    /// Bytes dropped from the front of the buffer by every `compact_from`
+    /// so far; `stream_offset + tokenize_pos` is the position's offset in
+    /// the original stream, stable across compactions. Used by `mark`/
+    /// `seek_to` to tell a still-valid `Mark` from one whose bytes are gone.
+    stream_offset: u64,
+}
+
+/// A position captured by [`StreamBuffer::mark`], restorable via
+/// [`StreamBuffer::seek_to`] as long as the bytes it points at haven't since
+/// been dropped by a `compact_from`. Modeled on `std::io::SeekFrom`/`Cursor`,
+/// but opaque rather than a raw offset since it's only meaningful relative
+/// to the buffer that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mark {
+    absolute: u64,
 }
 
 impl<'a> StreamBuffer<'a> {
@@ -74,6 +136,87 @@ impl<'a> StreamBuffer<'a> {
             tokenize_pos: 0,
             data_end: 0,
             unescaped_len: 0,
+            mode: BufferMode::Linear,
+            stream_offset: 0,
+        }
+    }
+
+    /// Creates a new `StreamBuffer` in ring mode: `tokenize_pos`/`data_end`
+    /// wrap around the end of `buffer` once bytes behind `tokenize_pos` have
+    /// been consumed, so refilling no longer requires `compact_from` to
+    /// memmove the unprocessed tail back to offset 0.
+    ///
+    /// The tokenizer still needs a contiguous slice to hand out a zero-copy
+    /// token, so a span that straddles the wrap point must first be passed
+    /// through [`linearize`](Self::linearize), which is the only case that
+    /// costs a copy.
+    pub fn new_ring(buffer: &'a mut [u8]) -> Self {
+        Self {
+            buffer,
+            tokenize_pos: 0,
+            data_end: 0,
+            unescaped_len: 0,
+            mode: BufferMode::Ring,
+            stream_offset: 0,
+        }
+    }
+
+    /// Captures the current position so it can later be restored via
+    /// [`seek_to`](Self::seek_to), enabling cheap bounded backtracking (e.g.
+    /// to peek ahead and validate a trailing structural char before
+    /// committing) without copying bytes out.
+    pub fn mark(&self) -> Mark {
+        Mark {
+            absolute: self.absolute_position(),
+        }
+    }
+
+    /// The current read position's offset in the *original* input stream,
+    /// stable across `compact_from` rewinds -- unlike `tokenize_pos`, which
+    /// resets to (roughly) zero at every buffer wall once the stream has
+    /// been compacted at least once. Lets parse errors and emitted token
+    /// spans carry true source offsets into multi-megabyte streamed
+    /// documents rather than offsets that reset at each buffer wall.
+    pub fn absolute_position(&self) -> u64 {
+        self.stream_offset + self.tokenize_pos as u64
+    }
+
+    /// Total bytes dropped from the front of the buffer by `compact_from`
+    /// so far. Unlike `absolute_position`, this doesn't move as the read
+    /// position advances between compactions -- it only changes when a
+    /// compaction actually rebases local offsets, so comparing two readings
+    /// of it is a cheap way to tell whether any offset computed between
+    /// them (e.g. a raw-value capture's start) may have been invalidated.
+    pub(crate) fn compacted_bytes(&self) -> u64 {
+        self.stream_offset
+    }
+
+    /// Restores `tokenize_pos` to a previously captured [`Mark`].
+    ///
+    /// Fails with `InvalidSliceBounds` if the mark's bytes have since been
+    /// dropped by a `compact_from` (its absolute position predates
+    /// `stream_offset`), or if it lies past `data_end` (not yet received).
+    pub fn seek_to(&mut self, mark: Mark) -> Result<(), StreamBufferError> {
+        let relative = mark
+            .absolute
+            .checked_sub(self.stream_offset)
+            .ok_or(StreamBufferError::InvalidSliceBounds)?;
+        let relative =
+            usize::try_from(relative).map_err(|_| StreamBufferError::InvalidSliceBounds)?;
+        if relative > self.data_end {
+            return Err(StreamBufferError::InvalidSliceBounds);
+        }
+        self.tokenize_pos = relative;
+        Ok(())
+    }
+
+    /// Maps a logical position to its offset in `buffer`, wrapping in `Ring`
+    /// mode and passing through unchanged in `Linear` mode.
+    fn physical_index(&self, logical: usize) -> usize {
+        match self.mode {
+            BufferMode::Linear => logical,
+            BufferMode::Ring if self.buffer.is_empty() => 0,
+            BufferMode::Ring => logical % self.buffer.len(),
         }
     }
 
@@ -82,10 +225,8 @@ impl<'a> StreamBuffer<'a> {
         if self.tokenize_pos >= self.data_end {
             return Err(StreamBufferError::EndOfData);
         }
-        self.buffer
-            .get(self.tokenize_pos)
-            .copied()
-            .ok_or(StreamBufferError::EndOfData)
+        let idx = self.physical_index(self.tokenize_pos);
+        self.buffer.get(idx).copied().ok_or(StreamBufferError::EndOfData)
     }
 
     /// Advance the tokenize position by one byte
@@ -102,13 +243,217 @@ impl<'a> StreamBuffer<'a> {
         self.data_end.saturating_sub(self.tokenize_pos)
     }
 
+    /// Total capacity of the underlying buffer, for callers building
+    /// diagnostics around "this token doesn't fit" errors.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Advances `tokenize_pos` to the first occurrence of `needle` in
+    /// `buffer[tokenize_pos..data_end]`, returning the number of bytes
+    /// skipped over. Returns `NeedMoreInput` (with `tokenize_pos` left at
+    /// `data_end`) if `needle` isn't found, mirroring `BufRead::skip_until`:
+    /// the caller fills more data and calls again to resume -- this is a
+    /// buffer-boundary condition, not a claim that the document ended.
+    pub fn scan_until(&mut self, needle: u8) -> Result<usize, StreamBufferError> {
+        self.scan_until_any(&[needle])
+    }
+
+    /// Like [`scan_until`](Self::scan_until), but stops at the first byte
+    /// that matches any entry in `needles` (e.g. `"` and `\` while scanning
+    /// a string body).
+    ///
+    /// In `Linear` mode, a single needle is searched a machine word at a
+    /// time via the classic SWAR "find a byte" trick: XOR the word against
+    /// `needle` repeated in every lane, then
+    /// `(x.wrapping_sub(ones)) & !x & high_bits` is nonzero iff some lane
+    /// matched, and its lowest set bit gives the matching byte's offset.
+    /// Multiple needles, and `Ring` mode (which would otherwise have to
+    /// special-case the wrap point mid-word), fall back to a scalar loop.
+    pub fn scan_until_any(&mut self, needles: &[u8]) -> Result<usize, StreamBufferError> {
+        let start = self.tokenize_pos;
+        if self.mode == BufferMode::Ring {
+            return self.scan_scalar(needles, start);
+        }
+        let Some(haystack) = self.buffer.get(start..self.data_end) else {
+            return Err(StreamBufferError::InvalidSliceBounds);
+        };
+        let found = match needles {
+            [needle] => Self::swar_find(haystack, *needle),
+            _ => haystack.iter().position(|b| needles.contains(b)),
+        };
+        match found {
+            Some(offset) => {
+                self.tokenize_pos = start.wrapping_add(offset);
+                Ok(offset)
+            }
+            None => {
+                self.tokenize_pos = self.data_end;
+                Err(StreamBufferError::NeedMoreInput { needed: 1 })
+            }
+        }
+    }
+
+    /// `true` for a byte that ends a plain string-body run -- `"`, `\`, or a
+    /// control character (`0x00..=0x1F`) -- used by [`scan_string_body`](Self::scan_string_body)
+    /// in place of a three-way per-byte comparison. Mirrors the `ESCAPE`
+    /// lookup table `serde_json`'s `parse_str_bytes` uses for the same
+    /// forward scan.
+    const STRING_BODY_STOP: [bool; 256] = {
+        let mut table = [false; 256];
+        table[b'"' as usize] = true;
+        table[b'\\' as usize] = true;
+        let mut b = 0u8;
+        while b < 0x20 {
+            table[b as usize] = true;
+            b += 1;
+        }
+        table
+    };
+
+    /// Advances `tokenize_pos` over a run of plain JSON string-body bytes --
+    /// anything except `"`, `\`, or a control character (`0x00..=0x1F`) --
+    /// returning how many were skipped. Unlike [`scan_until_any`](Self::scan_until_any),
+    /// running out of currently-buffered data without hitting a stopping
+    /// byte isn't an error: it just means every byte available right now
+    /// is plain, so the whole `[tokenize_pos, data_end)` run is skipped and
+    /// the caller can fill and resume, the same as [`scan_until_any`]'s
+    /// `EndOfData` case but without forcing an `Err` for what's actually a
+    /// normal, common outcome.
+    ///
+    /// In `Linear` mode this walks `buffer[tokenize_pos..data_end]` directly
+    /// via [`Self::STRING_BODY_STOP`] rather than going through
+    /// [`current_byte`](Self::current_byte)/[`advance`](Self::advance) per
+    /// byte, the same table-lookup-plus-bulk-copy split `scan_until_any`
+    /// already uses. `Ring` mode keeps the scalar per-byte walk, since a
+    /// wrapped span isn't one contiguous slice to scan.
+    pub fn scan_string_body(&mut self) -> Result<usize, StreamBufferError> {
+        let start = self.tokenize_pos;
+        if self.mode == BufferMode::Ring {
+            while self.tokenize_pos < self.data_end {
+                if Self::STRING_BODY_STOP[self.current_byte()? as usize] {
+                    break;
+                }
+                self.advance()?;
+            }
+            return Ok(self.tokenize_pos - start);
+        }
+        let Some(haystack) = self.buffer.get(start..self.data_end) else {
+            return Err(StreamBufferError::InvalidSliceBounds);
+        };
+        let skipped = haystack
+            .iter()
+            .position(|&b| Self::STRING_BODY_STOP[b as usize])
+            .unwrap_or(haystack.len());
+        self.tokenize_pos = start.wrapping_add(skipped);
+        Ok(skipped)
+    }
+
+    /// Byte-at-a-time fallback for `scan_until_any`, used in `Ring` mode.
+    fn scan_scalar(&mut self, needles: &[u8], start: usize) -> Result<usize, StreamBufferError> {
+        while self.tokenize_pos < self.data_end {
+            if needles.contains(&self.current_byte()?) {
+                return Ok(self.tokenize_pos - start);
+            }
+            self.advance()?;
+        }
+        Err(StreamBufferError::NeedMoreInput { needed: 1 })
+    }
+
+    /// Word-at-a-time SWAR search for `needle` in `haystack`.
+    fn swar_find(haystack: &[u8], needle: u8) -> Option<usize> {
+        const WORD: usize = core::mem::size_of::<usize>();
+        let ones = usize::from_ne_bytes([1u8; WORD]);
+        let high_bits = ones.wrapping_mul(0x80);
+        let needle_word = ones.wrapping_mul(needle as usize);
+
+        let mut i = 0;
+        while i + WORD <= haystack.len() {
+            let Ok(chunk) = <[u8; WORD]>::try_from(&haystack[i..i + WORD]) else {
+                break;
+            };
+            let word = usize::from_ne_bytes(chunk);
+            let x = word ^ needle_word;
+            let has_match = x.wrapping_sub(ones) & !x & high_bits;
+            if has_match != 0 {
+                let byte_offset = if cfg!(target_endian = "little") {
+                    (has_match.trailing_zeros() / 8) as usize
+                } else {
+                    (has_match.leading_zeros() / 8) as usize
+                };
+                return Some(i + byte_offset);
+            }
+            i += WORD;
+        }
+        haystack[i..].iter().position(|&b| b == needle).map(|p| i + p)
+    }
+
     /// Get slice for Reader to fill with new data
+    ///
+    /// In `Ring` mode this returns only the contiguous free span up to the
+    /// wrap point; once the Reader fills it and calls `mark_filled`, call
+    /// this again to get the remaining free span (if any) at the front of
+    /// the buffer.
+    ///
     /// Returns None if no space available
     pub fn get_fill_slice(&mut self) -> Option<&mut [u8]> {
-        if self.data_end >= self.buffer.len() {
-            return None;
+        match self.mode {
+            BufferMode::Linear => {
+                if self.data_end >= self.buffer.len() {
+                    return None;
+                }
+                self.buffer.get_mut(self.data_end..)
+            }
+            BufferMode::Ring => {
+                let capacity = self.buffer.len();
+                let used = self.data_end.saturating_sub(self.tokenize_pos);
+                let free = capacity.saturating_sub(used);
+                if free == 0 {
+                    return None;
+                }
+                let start = self.physical_index(self.data_end);
+                let span = free.min(capacity - start);
+                self.buffer.get_mut(start..start.wrapping_add(span))
+            }
+        }
+    }
+
+    /// Rotates ring-mode storage so the logical span `[start, end)` becomes
+    /// a contiguous slice in `buffer`, returning its new bounds for use with
+    /// [`get_string_slice`](Self::get_string_slice). A no-op in `Linear`
+    /// mode, and in `Ring` mode whenever the span doesn't straddle the wrap
+    /// point -- rotating the whole buffer is the cost paid only for the rare
+    /// wrapped-token case.
+    ///
+    /// `end` must not exceed `tokenize_pos`: the rotation rebases
+    /// `tokenize_pos`/`data_end`/`stream_offset` by `start` the same way
+    /// [`compact_from`](Self::compact_from) does when it discards everything
+    /// before `start`, which is only sound for a span of already-scanned
+    /// data. A span reaching into not-yet-tokenized bytes (`end >
+    /// tokenize_pos`) would desync those fields from the buffer's actual
+    /// rotated layout, so it's rejected with `InvalidSliceBounds` instead.
+    pub fn linearize(&mut self, start: usize, end: usize) -> Result<(usize, usize), StreamBufferError> {
+        if start > end || end > self.data_end || end > self.tokenize_pos {
+            return Err(StreamBufferError::InvalidSliceBounds);
         }
-        self.buffer.get_mut(self.data_end..)
+        if self.mode == BufferMode::Linear {
+            return Ok((start, end));
+        }
+        let capacity = self.buffer.len();
+        if capacity == 0 {
+            return Err(StreamBufferError::InvalidSliceBounds);
+        }
+        let len = end - start;
+        let phys_start = self.physical_index(start);
+        if phys_start.saturating_add(len) <= capacity {
+            // Already contiguous; nothing to rotate.
+            return Ok((phys_start, phys_start + len));
+        }
+        self.buffer.rotate_left(phys_start);
+        self.tokenize_pos = self.tokenize_pos.saturating_sub(start);
+        self.data_end -= start;
+        self.stream_offset = self.stream_offset.wrapping_add(start as u64);
+        Ok((0, len))
     }
 
     /// Compact buffer by moving unprocessed data from a given start offset to the beginning.
@@ -117,6 +462,14 @@ impl<'a> StreamBuffer<'a> {
     /// * `start_offset` - The position from which to preserve data.
     ///
     /// Returns the offset by which data was moved.
+    ///
+    /// `start_offset` is always in the same coordinate space as `tokenize_pos`/
+    /// `data_end`, never the separate `[0..unescaped_len]` staging region
+    /// [`start_unescaping_with_copy`](Self::start_unescaping_with_copy)
+    /// copies content into -- callers (currently just
+    /// [`StreamContentBuilder`](crate::stream_content_builder::StreamContentBuilder)'s
+    /// fill-on-demand path) pass the token's original start position, same
+    /// as when [`Self::has_unescaped_content`] is `false`.
     pub fn compact_from(&mut self, start_offset: usize) -> Result<usize, StreamBufferError> {
         if start_offset == 0 {
             // Already at start, no compaction possible
@@ -129,6 +482,7 @@ impl<'a> StreamBuffer<'a> {
             // All data has been processed, reset to start
             self.tokenize_pos = 0;
             self.data_end = 0;
+            self.stream_offset = self.stream_offset.wrapping_add(offset as u64);
             return Ok(offset);
         }
 
@@ -161,6 +515,7 @@ impl<'a> StreamBuffer<'a> {
         // Update positions
         self.tokenize_pos = self.tokenize_pos.saturating_sub(offset);
         self.data_end = remaining_data;
+        self.stream_offset = self.stream_offset.wrapping_add(offset as u64);
 
         Ok(offset)
     }
@@ -168,13 +523,73 @@ impl<'a> StreamBuffer<'a> {
     /// Mark that Reader filled `bytes_read` bytes
     pub fn mark_filled(&mut self, bytes_read: usize) -> Result<(), StreamBufferError> {
         let new_data_end = self.data_end.wrapping_add(bytes_read);
-        if new_data_end > self.buffer.len() {
+        let used = match self.mode {
+            BufferMode::Linear => new_data_end,
+            BufferMode::Ring => new_data_end.saturating_sub(self.tokenize_pos),
+        };
+        if used > self.buffer.len() {
             return Err(StreamBufferError::Unexpected);
         }
         self.data_end = new_data_end;
         Ok(())
     }
 
+    /// Pulls bytes from `src` into the fill slice until either `src` is
+    /// exhausted or the buffer has no more room, copying across as many of
+    /// `src`'s fragments as needed. Returns the number of bytes pulled.
+    ///
+    /// This is the bridge from a [`crate::byte_source::ByteSource`] (scattered
+    /// DMA ring slots, the two halves of a split ring buffer) into the plain
+    /// contiguous fill slice `get_fill_slice` already exposes -- the caller
+    /// doesn't concatenate fragments themselves first.
+    pub fn fill_from_source(
+        &mut self,
+        src: &mut impl crate::byte_source::ByteSource,
+    ) -> Result<usize, StreamBufferError> {
+        let mut total = 0;
+        loop {
+            let Some(fill_slice) = self.get_fill_slice() else {
+                break;
+            };
+            if fill_slice.is_empty() {
+                break;
+            }
+            let chunk = src.chunk();
+            if chunk.is_empty() {
+                break;
+            }
+            let n = chunk.len().min(fill_slice.len());
+            fill_slice[..n].copy_from_slice(&chunk[..n]);
+            src.advance(n);
+            self.mark_filled(n)?;
+            total += n;
+        }
+        Ok(total)
+    }
+
+    /// Pulls bytes from `src` into the current fill slice and marks them
+    /// filled. Returns `Ok(0)` (rather than an error) both when the buffer
+    /// has no free space and when `src` made no progress this call -- in
+    /// either case the caller compacts/refills its upstream and calls again,
+    /// the same fill-until-stuck protocol [`fill_from_source`](Self::fill_from_source)
+    /// uses for [`crate::byte_source::ByteSource`].
+    pub fn fill_from_fill_source<S: crate::fill_source::FillSource>(
+        &mut self,
+        src: &mut S,
+    ) -> Result<usize, crate::fill_source::FillSourceError<S::Error>> {
+        let Some(dst) = self.get_fill_slice() else {
+            return Ok(0);
+        };
+        let written = src
+            .fill(dst)
+            .map_err(crate::fill_source::FillSourceError::Source)?;
+        if written > 0 {
+            self.mark_filled(written)
+                .map_err(crate::fill_source::FillSourceError::Buffer)?;
+        }
+        Ok(written)
+    }
+
     /// Start unescaping and copy existing content from a range in the buffer
     /// This handles the common case of starting escape processing partway through a string
     pub fn start_unescaping_with_copy(
@@ -229,6 +644,20 @@ impl<'a> StreamBuffer<'a> {
         self.unescaped_len = 0;
     }
 
+    /// Prepares the buffer to start parsing the next value in a stream of
+    /// concatenated/NDJSON-style documents, without disturbing `tokenize_pos`
+    /// or `data_end`.
+    ///
+    /// Unlike [`compact_from`](Self::compact_from), `recycle` never moves or
+    /// discards buffered bytes: whatever of the next document has already
+    /// been read past the one that just finished stays exactly where it is,
+    /// ready to be tokenized. It only clears the scratch state that belongs
+    /// to the document that just completed (e.g. unescaped string content),
+    /// so callers get a clean slate without losing read-ahead.
+    pub fn recycle(&mut self) {
+        self.clear_unescaped();
+    }
+
     /// Get current tokenize position (for string start tracking)
     pub fn current_position(&self) -> usize {
         self.tokenize_pos
@@ -255,6 +684,46 @@ impl<'a> StreamBuffer<'a> {
         }
     }
 
+    /// Append a run of bytes to the unescaped content in one copy, instead of
+    /// one `append_unescaped_byte` call per byte.
+    pub fn append_unescaped_slice(&mut self, bytes: &[u8]) -> Result<(), StreamBufferError> {
+        let end = self
+            .unescaped_len
+            .checked_add(bytes.len())
+            .ok_or(StreamBufferError::BufferFull)?;
+        let dest = self
+            .buffer
+            .get_mut(self.unescaped_len..end)
+            .ok_or(StreamBufferError::BufferFull)?;
+        dest.copy_from_slice(bytes);
+        self.unescaped_len = end;
+        Ok(())
+    }
+
+    /// Append a `[start, end)` range already sitting in the main buffer (e.g.
+    /// a plain run just returned by [`scan_string_body`](Self::scan_string_body))
+    /// to the unescaped content. Works like [`append_unescaped_slice`](Self::append_unescaped_slice)
+    /// but copies within `buffer` directly instead of going through an
+    /// external `&[u8]`, which would otherwise alias the same buffer this
+    /// method needs `&mut` access to.
+    pub fn append_unescaped_range(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> Result<(), StreamBufferError> {
+        let len = end.saturating_sub(start);
+        let dest_end = self
+            .unescaped_len
+            .checked_add(len)
+            .ok_or(StreamBufferError::BufferFull)?;
+        if dest_end > self.buffer.len() || end > self.buffer.len() {
+            return Err(StreamBufferError::BufferFull);
+        }
+        self.safe_copy_within(start, end, self.unescaped_len);
+        self.unescaped_len = dest_end;
+        Ok(())
+    }
+
     /// Truncate unescaped content by removing the specified number of bytes from the end
     pub fn truncate_unescaped_by(&mut self, count: usize) {
         self.unescaped_len = self.unescaped_len.saturating_sub(count);
@@ -492,6 +961,318 @@ mod tests {
         assert_eq!(result.unwrap_err(), StreamBufferError::BufferFull);
     }
 
+    #[test]
+    fn test_scan_until_finds_needle_across_word_boundaries() {
+        let mut buffer = [0u8; 32];
+        let mut db = StreamBuffer::new(&mut buffer);
+
+        // Longer than a machine word on any target, so the SWAR loop runs
+        // at least once before falling into the scalar tail.
+        let data = b"abcdefghijklmnopqrstuvwxyz\"rest";
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice[..data.len()].copy_from_slice(data);
+        }
+        db.mark_filled(data.len()).unwrap();
+
+        let skipped = db.scan_until(b'"').unwrap();
+        assert_eq!(skipped, 26);
+        assert_eq!(db.current_byte().unwrap(), b'"');
+    }
+
+    #[test]
+    fn test_scan_until_any_matches_first_of_several_needles() {
+        let mut buffer = [0u8; 16];
+        let mut db = StreamBuffer::new(&mut buffer);
+        let data = b"no specials \\yet";
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice[..data.len()].copy_from_slice(data);
+        }
+        db.mark_filled(data.len()).unwrap();
+
+        let skipped = db.scan_until_any(&[b'"', b'\\']).unwrap();
+        assert_eq!(skipped, 13);
+        assert_eq!(db.current_byte().unwrap(), b'\\');
+    }
+
+    #[test]
+    fn test_scan_until_not_found_advances_to_data_end() {
+        let mut buffer = [0u8; 8];
+        let mut db = StreamBuffer::new(&mut buffer);
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"no quote");
+        }
+        db.mark_filled(8).unwrap();
+
+        let err = db.scan_until(b'"').unwrap_err();
+        assert_eq!(err, StreamBufferError::NeedMoreInput { needed: 1 });
+        assert!(db.is_empty());
+    }
+
+    #[test]
+    fn test_scan_until_ring_mode_uses_scalar_fallback() {
+        let mut buffer = [0u8; 8];
+        let mut db = StreamBuffer::new_ring(&mut buffer);
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"abc\"defg");
+        }
+        db.mark_filled(8).unwrap();
+
+        let skipped = db.scan_until(b'"').unwrap();
+        assert_eq!(skipped, 3);
+        assert_eq!(db.current_byte().unwrap(), b'"');
+    }
+
+    #[test]
+    fn test_fill_from_source_crosses_fragment_boundary() {
+        use crate::byte_source::ChainedSource;
+
+        let mut buffer = [0u8; 16];
+        let mut db = StreamBuffer::new(&mut buffer);
+        let mut src = ChainedSource::new(&b"hello, "[..], &b"world"[..]);
+
+        let pulled = db.fill_from_source(&mut src).unwrap();
+        assert_eq!(pulled, 12);
+        assert_eq!(&db.buffer[..12], b"hello, world");
+        assert_eq!(db.remaining_bytes(), 12);
+    }
+
+    #[test]
+    fn test_fill_from_source_stops_when_buffer_is_full() {
+        let mut buffer = [0u8; 4];
+        let mut db = StreamBuffer::new(&mut buffer);
+        let mut src: &[u8] = b"hello";
+
+        let pulled = db.fill_from_source(&mut src).unwrap();
+        assert_eq!(pulled, 4);
+        assert_eq!(&db.buffer[..4], b"hell");
+        assert_eq!(src, &b"o"[..]);
+    }
+
+    #[test]
+    fn test_ring_get_fill_slice_wraps_after_consumption() {
+        let mut buffer = [0u8; 4];
+        let mut db = StreamBuffer::new_ring(&mut buffer);
+
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            assert_eq!(fill_slice.len(), 4);
+            fill_slice.copy_from_slice(b"abcd");
+        }
+        db.mark_filled(4).unwrap();
+        assert!(db.get_fill_slice().is_none());
+
+        // Consuming two bytes frees space at the front of the ring, not the end.
+        db.advance().unwrap();
+        db.advance().unwrap();
+        assert_eq!(db.remaining_bytes(), 2);
+
+        let fill_slice = db.get_fill_slice().unwrap();
+        assert_eq!(fill_slice.len(), 2);
+        fill_slice.copy_from_slice(b"ef");
+        db.mark_filled(2).unwrap();
+
+        assert_eq!(db.remaining_bytes(), 4);
+        for expected in [b'c', b'd', b'e', b'f'] {
+            assert_eq!(db.current_byte().unwrap(), expected);
+            db.advance().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_ring_linearize_rotates_only_when_span_wraps() {
+        let mut buffer = [0u8; 4];
+        let mut db = StreamBuffer::new_ring(&mut buffer);
+
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"abcd");
+        }
+        db.mark_filled(4).unwrap();
+        db.advance().unwrap();
+        db.advance().unwrap();
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"ef");
+        }
+        db.mark_filled(2).unwrap();
+        // Physical layout is now "efcd" (logical positions 2..6 = "cdef").
+        db.advance().unwrap();
+        db.advance().unwrap();
+        db.advance().unwrap();
+        // tokenize_pos is now 5; positions [2, 5) = "cde" are behind it.
+
+        // [2, 4) = "cd" is already contiguous -- no rotation needed.
+        let (s, e) = db.linearize(2, 4).unwrap();
+        assert_eq!(&db.buffer[s..e], b"cd");
+
+        // [3, 5) = "de" straddles the wrap point, forcing a rotation.
+        let (s, e) = db.linearize(3, 5).unwrap();
+        assert_eq!(&db.buffer[s..e], b"de");
+    }
+
+    #[test]
+    fn test_absolute_position_survives_ring_linearize_rotation() {
+        let mut buffer = [0u8; 4];
+        let mut db = StreamBuffer::new_ring(&mut buffer);
+
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"abcd");
+        }
+        db.mark_filled(4).unwrap();
+        db.advance().unwrap();
+        db.advance().unwrap();
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"ef");
+        }
+        db.mark_filled(2).unwrap();
+        // Physical layout is now "efcd" (logical positions 2..6 = "cdef").
+        db.advance().unwrap();
+        db.advance().unwrap();
+        db.advance().unwrap();
+        // tokenize_pos is now 5; absolute_position reflects the true offset
+        // into the original stream, same as after compact_from.
+        assert_eq!(db.absolute_position(), 5);
+
+        // [3, 5) = "de" straddles the wrap point, forcing the rotate path.
+        db.linearize(3, 5).unwrap();
+        assert_eq!(
+            db.absolute_position(),
+            5,
+            "linearize's rotation must rebase stream_offset the same way compact_from does"
+        );
+
+        db.advance().unwrap();
+        assert_eq!(db.absolute_position(), 6);
+    }
+
+    #[test]
+    fn test_linearize_rejects_span_reaching_past_tokenize_pos() {
+        let mut buffer = [0u8; 4];
+        let mut db = StreamBuffer::new_ring(&mut buffer);
+
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"abcd");
+        }
+        db.mark_filled(4).unwrap();
+        db.advance().unwrap();
+        db.advance().unwrap();
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"ef");
+        }
+        db.mark_filled(2).unwrap();
+        // tokenize_pos is 2 here; [2, 5) reaches one byte past it into
+        // not-yet-tokenized data, which rotating would desync tokenize_pos/
+        // data_end/stream_offset from the buffer's actual rotated layout.
+        assert_eq!(
+            db.linearize(2, 5),
+            Err(StreamBufferError::InvalidSliceBounds)
+        );
+
+        // [2, 4) stays within already-scanned data (end == tokenize_pos
+        // isn't reached yet, but this is still <= it) -- still allowed.
+        db.advance().unwrap();
+        db.advance().unwrap();
+        // tokenize_pos is now 4; [2, 4) no longer exceeds it.
+        assert!(db.linearize(2, 4).is_ok());
+    }
+
+    #[test]
+    fn test_absolute_position_survives_compaction() {
+        let mut buffer = [0u8; 10];
+        let mut db = StreamBuffer::new(&mut buffer);
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"0123456789");
+        }
+        db.mark_filled(10).unwrap();
+
+        for _ in 0..6 {
+            db.advance().unwrap();
+        }
+        assert_eq!(db.absolute_position(), 6);
+
+        db.compact_from(6).unwrap();
+        // tokenize_pos reset to 0 by compaction, but absolute_position
+        // still reflects the true offset into the original stream.
+        assert_eq!(db.absolute_position(), 6);
+
+        db.advance().unwrap();
+        assert_eq!(db.absolute_position(), 7);
+    }
+
+    #[test]
+    fn test_mark_and_seek_to_restores_position() {
+        let mut buffer = [0u8; 10];
+        let mut db = StreamBuffer::new(&mut buffer);
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"0123456789");
+        }
+        db.mark_filled(10).unwrap();
+
+        db.advance().unwrap();
+        db.advance().unwrap();
+        let mark = db.mark();
+        assert_eq!(db.current_byte().unwrap(), b'2');
+
+        // Look ahead, then backtrack to the mark.
+        db.advance().unwrap();
+        db.advance().unwrap();
+        assert_eq!(db.current_byte().unwrap(), b'4');
+
+        db.seek_to(mark).unwrap();
+        assert_eq!(db.current_byte().unwrap(), b'2');
+    }
+
+    #[test]
+    fn test_seek_to_fails_once_mark_is_compacted_away() {
+        let mut buffer = [0u8; 10];
+        let mut db = StreamBuffer::new(&mut buffer);
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice.copy_from_slice(b"0123456789");
+        }
+        db.mark_filled(10).unwrap();
+
+        db.advance().unwrap();
+        let mark = db.mark(); // points at '1'
+
+        for _ in 0..4 {
+            db.advance().unwrap();
+        }
+        db.compact_from(5).unwrap(); // drops everything the mark pointed at
+
+        assert_eq!(
+            db.seek_to(mark).unwrap_err(),
+            StreamBufferError::InvalidSliceBounds
+        );
+    }
+
+    #[test]
+    fn test_seek_to_fails_past_data_end() {
+        let mut buffer = [0u8; 10];
+        let mut db = StreamBuffer::new(&mut buffer);
+        {
+            let fill_slice = db.get_fill_slice().unwrap();
+            fill_slice[0..5].copy_from_slice(b"hello");
+        }
+        db.mark_filled(5).unwrap();
+
+        let mark = Mark { absolute: 8 };
+        assert_eq!(
+            db.seek_to(mark).unwrap_err(),
+            StreamBufferError::InvalidSliceBounds
+        );
+    }
+
     #[test]
     fn test_compact_basic() {
         let mut buffer = [0u8; 10];
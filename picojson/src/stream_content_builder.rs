@@ -2,7 +2,7 @@
 
 //! ContentBuilder implementation for StreamParser using StreamBuffer.
 
-use crate::escape_processor::UnicodeEscapeCollector;
+use crate::escape_processor::{PendingSurrogate, UnicodeEscapeCollector, Utf8Validator};
 use crate::event_processor::ContentExtractor;
 use crate::shared::{ContentRange, DataSource, State};
 use crate::stream_buffer::StreamBuffer;
@@ -19,6 +19,30 @@ pub struct StreamContentBuilder<'b, R: Reader> {
     parser_state: State,
     /// Unicode escape collector for \uXXXX sequences
     unicode_escape_collector: UnicodeEscapeCollector,
+    /// Validates the raw (unescaped) bytes of the string/key currently being
+    /// scanned, one [`ContentExtractor::consume_plain_content_run`] at a
+    /// time -- its DFA state carries across buffer refills, so a multibyte
+    /// character split by a refill boundary is still validated correctly.
+    /// Reset at [`begin_string_content`](Self::begin_string_content), and
+    /// checked for completion when the string/key closes (see
+    /// [`extract_string_content`](Self::extract_string_content)/
+    /// [`extract_key_content`](Self::extract_key_content)). Catches
+    /// malformed raw UTF-8 as soon as the closing quote is reached, rather
+    /// than only once the fully-assembled content reaches the
+    /// `core::str::from_utf8` check in [`crate::shared::get_content_piece`].
+    raw_utf8_validator: Utf8Validator,
+    /// The local buffer position a pending high surrogate's own `\uXXXX`
+    /// escape started at, set alongside
+    /// [`UnicodeEscapeCollector::take_pending`]/`restore_pending` whenever
+    /// [`process_unicode_escape_with_collector`](Self::process_unicode_escape_with_collector)
+    /// leaves a high surrogate waiting for its low surrogate. Rebased by
+    /// [`update_positions_after_compaction`](Self::update_positions_after_compaction)
+    /// like the `State::String`/`Key`/`Number` start positions, so a
+    /// `compact_from` landing between the two escapes of a pair doesn't
+    /// leave it pointing at the wrong place -- unlike the fixed 6-byte
+    /// back-offset `process_unicode_escape_sequence` used to compute this
+    /// with, which had no way to survive that.
+    pending_high_surrogate_pos: Option<usize>,
     /// Flag to reset unescaped content on next operation
     unescaped_reset_queued: bool,
     /// Flag to track when the input stream has been finished (for number parsing)
@@ -33,11 +57,21 @@ impl<'b, R: Reader> StreamContentBuilder<'b, R> {
             reader,
             parser_state: State::None,
             unicode_escape_collector: UnicodeEscapeCollector::new(),
+            raw_utf8_validator: Utf8Validator::new(),
+            pending_high_surrogate_pos: None,
             unescaped_reset_queued: false,
             finished: false,
         }
     }
 
+    /// See [`StreamBuffer::compacted_bytes`]. Used by
+    /// [`StreamParser::next_raw_value`](crate::StreamParser::next_raw_value)
+    /// to detect whether a capture spanning multiple buffer fills outlived a
+    /// compaction.
+    pub(crate) fn compacted_bytes(&self) -> u64 {
+        self.stream_buffer.compacted_bytes()
+    }
+
     /// Fill the buffer from the reader
     fn fill_buffer_from_reader(&mut self) -> Result<(), ParseError> {
         // If buffer is full, try to compact it first (original compaction logic)
@@ -56,7 +90,18 @@ impl<'b, R: Reader> StreamContentBuilder<'b, R> {
                 .map_err(ParseError::from)?;
 
             if compaction_offset == 0 {
-                // Buffer too small for current token - this is an input buffer size issue
+                // Buffer too small for current token, even after compacting
+                // away everything before it. A chunked-delivery mode for
+                // `State::String`/`State::Key` (flush the accumulated
+                // content here as `Event::StringChunk`/`Event::KeyChunk`,
+                // reset the buffer, and keep reading) would turn this into
+                // a resumable hand-off instead of a hard failure -- but
+                // that requires `next_byte`'s caller, the tokenizer's
+                // byte-pull loop in `ujson`, to have a way to yield control
+                // back up to `ParserCore::next_event_impl_with_flags`
+                // without having consumed a byte yet, which it doesn't
+                // today. Left as a follow-up, same as `assembler`/
+                // `reassembler`/`fill_source` in lib.rs.
                 return Err(ParseError::InputBufferFull);
             }
 
@@ -65,6 +110,12 @@ impl<'b, R: Reader> StreamContentBuilder<'b, R> {
         }
 
         if let Some(fill_slice) = self.stream_buffer.get_fill_slice() {
+            // `Reader::read` has no "nothing available yet, not EOF"
+            // outcome to preserve here -- a source that needs one isn't a
+            // `Reader` at all; see the design note on that trait. Use
+            // `PollReader`/`PollFeedParser` instead for a source (DMA
+            // buffer, non-blocking socket) that can't block until bytes
+            // arrive.
             let bytes_read = self
                 .reader
                 .read(fill_slice)
@@ -91,12 +142,40 @@ impl<'b, R: Reader> StreamContentBuilder<'b, R> {
                 if *pos >= compaction_offset {
                     *pos = pos.checked_sub(compaction_offset).unwrap_or(0);
                 } else {
-                    return Err(ParseError::Unexpected(
-                        crate::shared::UnexpectedState::InvalidSliceBounds,
-                    ));
+                    // The token starting at `pos` is older than everything
+                    // compaction just preserved, i.e. it's longer than the
+                    // buffer can hold -- report that plainly instead of
+                    // leaving the caller to guess from a generic error.
+                    return Err(ParseError::TokenTooLarge {
+                        offset: *pos,
+                        token_len: compaction_offset - *pos,
+                        buffer_len: self.stream_buffer.capacity(),
+                    });
                 }
             }
         }
+
+        // Query the collector's own pending state rather than trusting
+        // `pending_high_surrogate_pos` alone: the position is only
+        // meaningful while a high surrogate is actually still pending,
+        // and this keeps the two in sync by construction. Restored
+        // unchanged -- this is a read, not a consumption.
+        let pending = self.unicode_escape_collector.take_pending();
+        if matches!(pending, PendingSurrogate::PendingHigh(_)) {
+            if let Some(pos) = self.pending_high_surrogate_pos {
+                // Always inside the token `compact_start_pos` preserved, same
+                // invariant as the `State::Key`/`String`/`Number` positions above.
+                self.pending_high_surrogate_pos = Some(pos.checked_sub(compaction_offset).ok_or(
+                    ParseError::TokenTooLarge {
+                        offset: pos,
+                        token_len: compaction_offset - pos,
+                        buffer_len: self.stream_buffer.capacity(),
+                    },
+                )?);
+            }
+        }
+        self.unicode_escape_collector.restore_pending(pending);
+
         Ok(())
     }
 
@@ -105,6 +184,23 @@ impl<'b, R: Reader> StreamContentBuilder<'b, R> {
         self.finished
     }
 
+    /// Resets state for parsing the next value in a stream of concatenated
+    /// JSON documents (NDJSON and similar), after the caller has observed
+    /// that the previous top-level value is complete.
+    ///
+    /// Returns the absolute byte offset (see [`StreamBuffer::absolute_position`])
+    /// at which the next document begins. Bytes already buffered past that
+    /// offset -- the start of the next record, read ahead while filling for
+    /// the one that just finished -- are left exactly where they are: this
+    /// never calls `compact_from`, so it can't destroy read-ahead that
+    /// belongs to the next value.
+    pub fn recycle(&mut self) -> u64 {
+        self.parser_state = State::None;
+        self.finished = false;
+        self.stream_buffer.recycle();
+        self.stream_buffer.absolute_position()
+    }
+
     /// Apply queued unescaped content reset if flag is set
     pub fn apply_unescaped_reset_if_queued(&mut self) {
         if self.unescaped_reset_queued {
@@ -183,13 +279,52 @@ impl<R: Reader> ContentExtractor for StreamContentBuilder<'_, R> {
         &mut self.parser_state
     }
 
+    /// A position *within the current buffer window*, used internally for
+    /// slicing content out of `stream_buffer` -- it gets rewritten by
+    /// `update_positions_after_compaction` and is meaningless once that's
+    /// happened. Real, compaction-proof document location (absolute byte
+    /// offset plus 1-based line/column) is tracked independently of the
+    /// buffer in [`ParserCore::current_position`](crate::event_processor::ParserCore::current_position),
+    /// incremented once per consumed byte in `advance_position`, and is what
+    /// [`StreamParser::position`](crate::StreamParser::position) exposes.
     fn current_position(&self) -> usize {
         self.stream_buffer.current_position()
     }
 
+    fn consume_plain_content_run(&mut self) -> Result<Option<&[u8]>, ParseError> {
+        let start = self.stream_buffer.current_position();
+        let skipped = self
+            .stream_buffer
+            .scan_string_body()
+            .map_err(ParseError::from)?;
+        if skipped == 0 {
+            return Ok(None);
+        }
+        let end = start + skipped;
+
+        if self.stream_buffer.has_unescaped_content() {
+            self.stream_buffer
+                .append_unescaped_range(start, end)
+                .map_err(ParseError::from)?;
+        }
+
+        let run = self
+            .stream_buffer
+            .get_string_slice(start, end)
+            .map_err(ParseError::from)?;
+        for &byte in run {
+            self.raw_utf8_validator.feed(byte)?;
+        }
+
+        Ok(Some(run))
+    }
+
     fn begin_string_content(&mut self, _pos: usize) {
         // StreamParser doesn't need explicit string begin processing
-        // as it handles content accumulation automatically
+        // as it handles content accumulation automatically, but the raw
+        // UTF-8 validator tracks one string/key at a time and must start
+        // fresh for this one.
+        self.raw_utf8_validator = Utf8Validator::new();
     }
 
     fn unicode_escape_collector_mut(&mut self) -> &mut UnicodeEscapeCollector {
@@ -197,6 +332,7 @@ impl<R: Reader> ContentExtractor for StreamContentBuilder<'_, R> {
     }
 
     fn extract_string_content(&mut self, start_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+        self.raw_utf8_validator.finish()?;
         // StreamParser-specific: Queue reset to prevent content contamination
         if self.has_unescaped_content() {
             self.queue_unescaped_reset();
@@ -207,6 +343,7 @@ impl<R: Reader> ContentExtractor for StreamContentBuilder<'_, R> {
     }
 
     fn extract_key_content(&mut self, start_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+        self.raw_utf8_validator.finish()?;
         // StreamParser-specific: Queue reset to prevent content contamination
         if self.has_unescaped_content() {
             self.queue_unescaped_reset();
@@ -238,6 +375,15 @@ impl<R: Reader> ContentExtractor for StreamContentBuilder<'_, R> {
         Ok(Event::Number(json_number))
     }
 
+    fn extract_raw(&mut self, start_pos: usize, end_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+        let bytes = self
+            .stream_buffer
+            .get_string_slice(start_pos, end_pos)
+            .map_err(ParseError::from)?;
+        let text = crate::shared::from_utf8(bytes)?;
+        Ok(Event::RawValue(crate::String::Borrowed(text)))
+    }
+
     fn validate_and_extract_number(
         &mut self,
         from_container_end: bool,
@@ -260,6 +406,24 @@ impl<R: Reader> ContentExtractor for StreamContentBuilder<'_, R> {
         Ok(())
     }
 
+    fn finish_pending_unicode_escape(&mut self) -> Result<(), ParseError> {
+        // 3 bytes covers either recovery this can produce: U+FFFD's UTF-8
+        // encoding, or the pending surrogate's own WTF-8 encoding.
+        let mut utf8_buf = [0u8; 3];
+        // `finish_string` clears the collector's pending surrogate
+        // regardless of outcome (including the error path), so our own
+        // tracked position needs to follow suit.
+        self.pending_high_surrogate_pos = None;
+        if let Some(bytes) = self.unicode_escape_collector.finish_string(&mut utf8_buf)? {
+            for &byte in bytes {
+                self.stream_buffer
+                    .append_unescaped_byte(byte)
+                    .map_err(ParseError::from)?;
+            }
+        }
+        Ok(())
+    }
+
     fn process_unicode_escape_with_collector(&mut self) -> Result<(), ParseError> {
         // Define the provider for getting hex digits from the stream buffer
         let hex_slice_provider = |start, end| {
@@ -269,11 +433,23 @@ impl<R: Reader> ContentExtractor for StreamContentBuilder<'_, R> {
         };
 
         // Call the shared processor, which now returns the result by value
-        let (utf8_bytes_result, _) = crate::escape_processor::process_unicode_escape_sequence(
-            self.stream_buffer.current_position(),
-            &mut self.unicode_escape_collector,
-            hex_slice_provider,
-        )?;
+        let (utf8_bytes_result, escape_start_pos) =
+            crate::escape_processor::process_unicode_escape_sequence(
+                self.stream_buffer.current_position(),
+                &mut self.unicode_escape_collector,
+                hex_slice_provider,
+            )?;
+
+        // Whatever's pending *after* this call started at this escape's own
+        // position: either it just became pending (nothing was pending
+        // before), or it replaced an older pending high surrogate that this
+        // call flushed (two consecutive high surrogates -- see
+        // `UnicodeEscapeCollector::process_to_utf8`). Either way the old
+        // position, if any, is no longer the right one.
+        self.pending_high_surrogate_pos = self
+            .unicode_escape_collector
+            .has_pending_high_surrogate()
+            .then_some(escape_start_pos);
 
         // Handle the UTF-8 bytes if we have them
         if let Some((utf8_bytes, len)) = utf8_bytes_result {
@@ -327,6 +503,34 @@ impl<R: Reader> StreamContentBuilder<'_, R> {
 /// Note: StreamParser doesn't have a distinct 'input lifetime since it reads from a stream,
 /// so we use the buffer lifetime 'b for both borrowed and unescaped content.
 impl<'b, R: Reader> DataSource<'b, 'b> for StreamContentBuilder<'b, R> {
+    fn next_byte(&mut self) -> Result<Option<u8>, ParseError> {
+        if self.stream_buffer.is_empty() {
+            self.fill_buffer_from_reader()?;
+        }
+
+        if self.stream_buffer.is_empty() {
+            if !self.finished {
+                self.finished = true;
+            }
+            return Ok(None);
+        }
+
+        let byte = self.stream_buffer.current_byte()?;
+        self.stream_buffer.advance()?;
+
+        Ok(Some(byte))
+    }
+
+    fn peek_byte(&mut self) -> Result<Option<u8>, ParseError> {
+        if self.stream_buffer.is_empty() {
+            self.fill_buffer_from_reader()?;
+        }
+        if self.stream_buffer.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(self.stream_buffer.current_byte()?))
+    }
+
     fn get_borrowed_slice(&'b self, start: usize, end: usize) -> Result<&'b [u8], ParseError> {
         self.stream_buffer.get_string_slice(start, end).map_err(Into::into)
     }
@@ -339,3 +543,84 @@ impl<'b, R: Reader> DataSource<'b, 'b> for StreamContentBuilder<'b, R> {
         self.stream_buffer.has_unescaped_content()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::DataSource;
+
+    struct SliceReader<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    impl Reader for SliceReader<'_> {
+        type Error = ();
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let remaining = self.data.len() - self.pos;
+            let n = remaining.min(buf.len());
+            buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_peek_byte_is_idempotent_and_discard_advances_past_the_peeked_byte() {
+        let reader = SliceReader {
+            data: b"abc",
+            pos: 0,
+        };
+        let mut buffer = [0u8; 8];
+        let mut builder = StreamContentBuilder::new(&mut buffer, reader);
+
+        assert_eq!(DataSource::peek_byte(&mut builder).unwrap(), Some(b'a'));
+        // Peeking again without an intervening discard/next_byte must not
+        // advance -- the same byte comes back every time.
+        assert_eq!(DataSource::peek_byte(&mut builder).unwrap(), Some(b'a'));
+
+        DataSource::discard(&mut builder);
+        assert_eq!(DataSource::next_byte(&mut builder).unwrap(), Some(b'b'));
+        assert_eq!(DataSource::peek_byte(&mut builder).unwrap(), Some(b'c'));
+        assert_eq!(DataSource::next_byte(&mut builder).unwrap(), Some(b'c'));
+        assert_eq!(DataSource::peek_byte(&mut builder).unwrap(), None);
+    }
+
+    #[test]
+    fn test_pending_high_surrogate_position_rebases_across_compaction() {
+        // A `compact_from` landing between a high and low surrogate's
+        // escapes used to leave `process_unicode_escape_sequence`'s fixed
+        // 6-byte back-offset pointing at the wrong place; `StreamContentBuilder`
+        // now tracks the position itself and rebases it the same way it
+        // rebases `State::String`/`Key`/`Number`.
+        let reader = SliceReader { data: b"", pos: 0 };
+        let mut buffer = [0u8; 8];
+        let mut builder = StreamContentBuilder::new(&mut buffer, reader);
+
+        // Feed a real high surrogate (\uD801) into the collector so
+        // `has_pending_high_surrogate` is true, same as after a genuine
+        // `process_unicode_escape_with_collector` call for one.
+        let mut utf8_buf = [0u8; 4];
+        assert!(!builder.unicode_escape_collector.add_hex_digit(b'D').unwrap());
+        assert!(!builder.unicode_escape_collector.add_hex_digit(b'8').unwrap());
+        assert!(!builder.unicode_escape_collector.add_hex_digit(b'0').unwrap());
+        assert!(builder.unicode_escape_collector.add_hex_digit(b'1').unwrap());
+        let (bytes, _) = builder
+            .unicode_escape_collector
+            .process_to_utf8(&mut utf8_buf)
+            .unwrap();
+        assert!(bytes.is_none());
+        assert!(builder.unicode_escape_collector.has_pending_high_surrogate());
+
+        builder.parser_state = State::String(5);
+        builder.pending_high_surrogate_pos = Some(12);
+
+        builder.update_positions_after_compaction(10).unwrap();
+
+        assert_eq!(builder.parser_state, State::String(0));
+        assert_eq!(builder.pending_high_surrogate_pos, Some(2));
+        // Rebasing is a read, not a consumption -- the surrogate is still pending.
+        assert!(builder.unicode_escape_collector.has_pending_high_surrogate());
+    }
+}
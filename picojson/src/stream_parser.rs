@@ -20,9 +20,55 @@ pub trait Reader {
     /// - A return value of 0 **MUST** indicate true end of stream
     /// - Implementations **MUST NOT** return 0 unless no more data will ever be available
     /// - Returning 0 followed by non-zero reads in subsequent calls violates this contract
+    ///
+    /// A source that can't honor this -- one that would need to return 0
+    /// for "nothing available right now" rather than "stream is over" --
+    /// isn't a `Reader`; see the note below on why, and feed
+    /// [`PushParser`](crate::PushParser) instead.
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
 }
 
+// Note on incremental/"need more input" parsing over a `Reader`: this trait's
+// contract is deliberately strict -- 0 always means true end of stream --
+// which is what lets `StreamParser`'s per-byte loop in
+// `ParserCore::next_event_impl` call `read` straight through without a
+// recoverable "nothing available yet" outcome to thread through every
+// caller (`SliceParser` shares that same loop). Overloading `read`'s return
+// value with a second, recoverable meaning would be a breaking change to
+// every existing `Reader` impl (`SliceReader`, `ChunkReader`, `IoReader`,
+// `BufReader`, `TakeReader`, ...) for a capability this crate already has a
+// purpose-built, non-breaking answer to: feed bytes to a
+// [`PushParser`](crate::PushParser) (or its
+// [`FeedParser`](crate::FeedParser)/[`PollParser`](crate::PollParser)
+// wrappers) as they arrive and resume mid-token across calls, with
+// [`PushParser::needs_more_input`](crate::PushParser::needs_more_input) as
+// the "need more input" signal. Prefer that family for sources that can't
+// block waiting on more data; `Reader`/`StreamParser` stays the
+// blocking-source pull API it was designed as.
+//
+// This also covers the would-block/non-blocking-socket case some callers
+// ask for here: a `Reader::read` that would need to block is exactly a
+// source that "can't block waiting on more data" from `StreamParser`'s
+// point of view, so it belongs behind `PushParser::write`'s
+// `needs_more_input` signal, not a new recoverable variant threaded through
+// `Reader`/`ContentExtractor::next_byte`/`StreamContentBuilder`.
+//
+// That resumption -- including a `\uD83D`/`\uDE00` surrogate pair split
+// across a starvation boundary, and every split point of a representative
+// document -- is already exercised end-to-end in
+// `tests/feed_parser.rs` (`test_feed_parser_needs_more_input_mid_unicode_escape`,
+// `test_poll_parser_resumes_mid_unicode_escape_split_at_every_byte`,
+// `test_feed_parser_every_two_way_split_point_matches_single_chunk`); there's
+// no separate `StreamParser`-side gap left to fill here.
+//
+// This also covers "append more bytes and keep the in-progress token's start
+// offset" for a caller that can't block on a `Reader`: `PushParser::write`
+// already takes each new chunk as its own slice (no single growable buffer
+// to re-point), and `needs_more_input`/`PushContentBuilder`'s span tracking
+// already carry a partial token's start position across that boundary --
+// see `tests/feed_parser.rs`'s tests above for the Unicode-surrogate case and
+// `test_feed_parser_resumes_across_chunk_boundary` for plain keys/numbers.
+
 /// A pull parser that parses JSON from a stream.
 ///
 /// Generic over BitStackConfig for configurable nesting depth.
@@ -34,8 +80,32 @@ pub struct StreamParser<'b, R: Reader, C: BitStackConfig = DefaultConfig> {
     /// The unified provider that handles both content building and reader access
     /// This allows us to use the same unified pattern as SliceParser
     provider: StreamParserProvider<'b, R>,
+    /// Whether this parser accepts a sequence of whitespace-separated
+    /// top-level values (NDJSON-style) read one after another from `R`,
+    /// instead of exactly one -- set via [`StreamParser::new_ndjson`]/
+    /// [`with_buffer_ndjson`](StreamParser::with_buffer_ndjson). Resets the
+    /// tokenizer's container/depth state between records internally rather
+    /// than rebuilding the parser, so the same scratch buffer is reused for
+    /// every record in the stream.
+    streaming: bool,
+    /// In streaming mode, whether the `Event::EndDocument` boundary for the
+    /// value the tokenizer just finished still needs to be returned.
+    boundary_pending: bool,
 }
 
+/// Alias for [`Reader`] under the name used by some other `no_std` buffered-I/O
+/// crates. [`Reader`] already is the `no_std`, blocking-read, fixed-window
+/// trait this implies: `StreamParser` refills a fixed buffer from it and
+/// relocates any token that spans a refill boundary into the scratch buffer
+/// before discarding the consumed window. There is no separate `ReaderParser`
+/// type for this reason — see [`ReaderParser`].
+pub use Reader as ByteReader;
+
+/// Alias for [`StreamParser`] under a name that foregrounds what it's built
+/// on: any blocking [`ByteReader`] (a.k.a. [`Reader`]), refilled into a fixed
+/// window. See [`StreamParser`] for the full documentation.
+pub type ReaderParser<'b, R, C = DefaultConfig> = StreamParser<'b, R, C>;
+
 /// Methods for StreamParser using DefaultConfig
 impl<'b, R: Reader> StreamParser<'b, R, DefaultConfig> {
     /// Create a new StreamParser with default configuration
@@ -45,6 +115,41 @@ impl<'b, R: Reader> StreamParser<'b, R, DefaultConfig> {
     pub fn new(reader: R, buffer: &'b mut [u8]) -> Self {
         Self::with_config(reader, buffer)
     }
+
+    /// Creates a parser over a sequence of whitespace-separated top-level
+    /// JSON values (NDJSON-style) read one after another from `reader`,
+    /// instead of exactly one.
+    ///
+    /// Each value still ends with a single [`Event::EndDocument`], but once
+    /// that's been returned, [`next_event`](PullParser::next_event) resumes
+    /// with the next value's events instead of repeating `EndDocument`
+    /// forever -- so a caller can stream records (e.g. logs or telemetry)
+    /// off a byte [`Reader`] as one continuous series of events, without
+    /// allocating a buffer per record or building a new parser per record.
+    /// A blank line between records is skipped; a true end of stream (`read`
+    /// returning 0 with nothing left buffered) ends the parser the same way
+    /// it would for [`StreamParser::new`].
+    ///
+    /// To correlate a parse error with the record it broke on, call
+    /// [`Self::next_event_located`] instead of
+    /// [`next_event`](PullParser::next_event): `byte_offset`/`line`/`column`
+    /// advance continuously across the whole stream (never reset per
+    /// record), so the reported line already pinpoints which record failed
+    /// without a separate record counter to maintain.
+    ///
+    /// # Example
+    /// ```
+    /// use picojson::{ChunkReader, Event, PullParser, StreamParser};
+    ///
+    /// let json = b"{\"a\": 1}\n{\"a\": 2}\n";
+    /// let reader = ChunkReader::new(json, 4);
+    /// let mut buffer = [0u8; 64];
+    /// let mut parser = StreamParser::new_ndjson(reader, &mut buffer);
+    /// assert_eq!(parser.next_event().unwrap(), Event::StartObject);
+    /// ```
+    pub fn new_ndjson(reader: R, buffer: &'b mut [u8]) -> Self {
+        Self::with_config_streaming(reader, buffer)
+    }
 }
 
 /// Methods for StreamParser with custom BitStackConfig
@@ -70,6 +175,17 @@ impl<'b, R: Reader, C: BitStackConfig> StreamParser<'b, R, C> {
         Self {
             parser_core: ParserCore::new(),
             provider: StreamParserProvider::new(reader, buffer),
+            streaming: false,
+            boundary_pending: false,
+        }
+    }
+
+    /// Like [`with_config`](Self::with_config), but in NDJSON streaming mode
+    /// (see [`new_ndjson`](Self::new_ndjson)).
+    fn with_config_streaming(reader: R, buffer: &'b mut [u8]) -> Self {
+        Self {
+            streaming: true,
+            ..Self::with_config(reader, buffer)
         }
     }
 }
@@ -153,6 +269,10 @@ impl<R: Reader> ContentExtractor for StreamParserProvider<'_, R> {
         self.content_builder.begin_string_content(pos);
     }
 
+    fn consume_plain_content_run(&mut self) -> Result<Option<&[u8]>, ParseError> {
+        self.content_builder.consume_plain_content_run()
+    }
+
     fn unicode_escape_collector_mut(
         &mut self,
     ) -> &mut crate::escape_processor::UnicodeEscapeCollector {
@@ -177,6 +297,10 @@ impl<R: Reader> ContentExtractor for StreamParserProvider<'_, R> {
             .extract_number(start_pos, from_container_end, finished)
     }
 
+    fn extract_raw(&mut self, start_pos: usize, end_pos: usize) -> Result<Event<'_, '_>, ParseError> {
+        self.content_builder.extract_raw(start_pos, end_pos)
+    }
+
     /// Override the default validate_and_extract_number to use the finished state
     fn validate_and_extract_number(
         &mut self,
@@ -211,6 +335,230 @@ impl<R: Reader, C: BitStackConfig> StreamParser<'_, R, C> {
 
     // The compaction and helper methods are now handled by the provider
     // These methods can be removed since they're not needed with the new architecture
+
+    /// Returns the line/column location of the current parse position, for
+    /// reporting alongside a [`ParseError`] returned from [`next_event`](PullParser::next_event).
+    /// Tracked incrementally as bytes are consumed, so this is O(1) rather
+    /// than rescanning the input.
+    ///
+    /// The counters live on [`ParserCore`](crate::event_processor::ParserCore),
+    /// not on the scratch [`StreamBuffer`](crate::stream_buffer::StreamBuffer)
+    /// that `ChunkReader` refills and compacts -- so a buffer refill moves
+    /// where in the window a byte sits, but never touches `line`/`column`/
+    /// `byte_offset`, which only ever advance as bytes are consumed. A
+    /// syntax error many refills into a long stream still reports its true
+    /// position from the start of input, not from the start of whatever
+    /// chunk happened to contain it.
+    pub fn position(&self) -> crate::Position {
+        self.parser_core.current_position()
+    }
+
+    /// Like [`next_event`](PullParser::next_event), but on failure returns
+    /// the [`Position`](crate::Position) of the byte that triggered it
+    /// alongside the error, so callers don't need a separate call to
+    /// [`Self::position`] afterwards.
+    pub fn next_event_located(&mut self) -> Result<Event<'_, '_>, (ParseError, crate::Position)> {
+        match PullParser::next_event(self) {
+            Err(e) => Err((e, self.position())),
+            Ok(event) => Ok(event),
+        }
+    }
+
+    /// Like [`next_event`](PullParser::next_event), but also returns the
+    /// [`Position`](crate::Position) immediately after the event, so
+    /// successful events can be located the same way
+    /// [`Self::next_event_located`] locates an error.
+    pub fn next_event_with_position(
+        &mut self,
+    ) -> Result<(Event<'_, '_>, crate::Position), ParseError> {
+        if self.provider.finished {
+            return Ok((Event::EndDocument, self.position()));
+        }
+        if let Some(event) = self.handle_streaming_boundary()? {
+            return Ok((event, self.position()));
+        }
+
+        self.provider
+            .content_builder
+            .apply_unescaped_reset_if_queued();
+
+        let event = self.parser_core.next_event_impl(
+            &mut self.provider,
+            EscapeTiming::OnEnd,
+            |provider, byte| provider.content_builder.handle_byte_accumulation(byte),
+        )?;
+        Ok((event, self.position()))
+    }
+
+    /// Like [`next_event`](PullParser::next_event), but also returns the
+    /// [`Span`](crate::Span) of source bytes the event was produced from,
+    /// so callers don't need to track offsets themselves to report where a
+    /// token came from (e.g. highlighting it in the original source).
+    ///
+    /// For scalar events the span covers the full lexeme, including
+    /// surrounding quotes for strings/keys. Container and `Bool`/`Null`
+    /// events cover their single token. `StartDocument`/`EndDocument`
+    /// carry whatever span was last recorded, since they don't consume a
+    /// token of their own.
+    pub fn next_event_with_span(&mut self) -> Result<(Event<'_, '_>, crate::Span), ParseError> {
+        if self.provider.finished {
+            return Ok((Event::EndDocument, self.parser_core.last_span()));
+        }
+        if let Some(event) = self.handle_streaming_boundary()? {
+            return Ok((event, self.parser_core.last_span()));
+        }
+
+        self.provider
+            .content_builder
+            .apply_unescaped_reset_if_queued();
+
+        let event = self.parser_core.next_event_impl(
+            &mut self.provider,
+            EscapeTiming::OnEnd,
+            |provider, byte| provider.content_builder.handle_byte_accumulation(byte),
+        )?;
+        Ok((event, self.parser_core.last_span()))
+    }
+
+    /// Like [`SliceParser::raw_value`](crate::SliceParser::raw_value), but
+    /// captures any complete next value — scalar, object, or array — as a
+    /// single [`Event::RawValue`] instead of its usual decoded event(s).
+    /// Call this exactly where a value is expected.
+    ///
+    /// Scalars are captured in a single step, since [`ParserCore::last_span`]
+    /// already covers their full lexeme (including quotes for strings/keys);
+    /// objects and arrays are captured by tracking nesting depth until it
+    /// returns to the level it started at.
+    pub fn next_raw_value(&mut self) -> Result<Event<'_, '_>, ParseError> {
+        let Some((start, end)) = self.next_raw_value_span()? else {
+            return Ok(Event::EndDocument);
+        };
+        self.provider.extract_raw(start, end)
+    }
+
+    /// Like [`skip_value`](PullParser::skip_value), but also returns the
+    /// [`Span`](crate::Span) of the value skipped over, so a caller can hand
+    /// the `[start, end)` byte range to something else (e.g. stash a config
+    /// subtree for later parsing) instead of just discarding it. Call this
+    /// in place of following up an uninteresting [`Event::Key`] -- the same
+    /// spot [`Self::next_raw_value`] is called from -- not after already
+    /// consuming a `StartObject`/`StartArray`. This is exactly
+    /// [`Self::next_raw_value_span`] without the extraction step
+    /// [`Self::next_raw_value`] pays for.
+    pub fn skip_value_with_span(&mut self) -> Result<crate::Span, ParseError> {
+        let (start, end) = self.next_raw_value_span()?.unwrap_or_default();
+        Ok(crate::Span { start, end })
+    }
+
+    /// Drives the tokenizer through the next complete value -- scalar,
+    /// object, or array -- the same way [`Self::next_raw_value`] does, but
+    /// stops short of extracting it, returning just the `[start, end)`
+    /// byte range it spans. `None` means `EndDocument` was reached instead.
+    ///
+    /// An object or array capture can span enough buffer fills that
+    /// `StreamBuffer` compacts away the bytes `start` was recorded against
+    /// before `end` is reached, which would otherwise make `extract_raw`
+    /// read the wrong bytes out of the (since-rebased) buffer. This is
+    /// detected by comparing compaction counts before and after, rather
+    /// than up front, since there's no way to know how large the value is
+    /// until it's been fully walked.
+    fn next_raw_value_span(&mut self) -> Result<Option<(usize, usize)>, ParseError> {
+        let compacted_before = self.provider.content_builder.compacted_bytes();
+        let start = match self.next_event_impl()? {
+            Event::EndDocument => return Ok(None),
+            Event::StartObject | Event::StartArray => {
+                let start = self.parser_core.last_span().start;
+                let mut depth: usize = 1;
+                loop {
+                    match self.next_event_impl()? {
+                        Event::StartObject | Event::StartArray => depth += 1,
+                        Event::EndObject | Event::EndArray => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        Event::EndDocument => return Err(ParseError::EndOfData),
+                        _ => {}
+                    }
+                }
+                start
+            }
+            _ => self.parser_core.last_span().start,
+        };
+        let end = self.parser_core.last_span().end;
+        if self.provider.content_builder.compacted_bytes() != compacted_before {
+            return Err(ParseError::RawValueTooLarge);
+        }
+        Ok(Some((start, end)))
+    }
+
+    // Deliberately no `checkpoint`/`restore` here (see `SliceParser::checkpoint`):
+    // `StreamBuffer` compacts consumed bytes out of its backing buffer as parsing
+    // advances, so a byte position recorded earlier may no longer correspond to
+    // anything once compaction has run -- rewinding would read garbage or panic.
+    // A buffered `Reader` (e.g. wrapping input in `BufReader`) doesn't change this,
+    // since compaction happens in `StreamBuffer` itself, downstream of the reader.
+    // Speculative lookahead over a stream would need the buffer retained instead of
+    // compacted while a checkpoint is outstanding; not worth the complexity here.
+    //
+    // For the same reason there's no `peek_event`/`peek` here either (see
+    // `SliceParser::peek_event`): peeking is just a one-token checkpoint/restore
+    // under the hood, and this type has nothing to restore to.
+
+    /// In streaming mode, once the tokenizer reports a completed top-level
+    /// value, returns the `Event::EndDocument` boundary for it on the first
+    /// call, then on the next call skips any whitespace separating it from
+    /// the following record and re-arms the tokenizer to parse it -- or, if
+    /// the reader has nothing left to give (true EOF), leaves the parser at
+    /// its natural end instead. Mirrors `SliceParser`'s own streaming-boundary
+    /// handling, but refills from `R` instead of assuming the rest of the input is
+    /// already resident, since a record boundary can fall right at the edge
+    /// of what's currently buffered.
+    ///
+    /// Returns `Some(event)` when a streaming boundary was handled (the
+    /// caller should return it as-is); `None` means there's nothing special
+    /// to do and the caller should fall through to its normal event loop.
+    fn handle_streaming_boundary(&mut self) -> Result<Option<Event<'_, '_>>, ParseError> {
+        if !self.streaming || !self.parser_core.tokenizer.is_finished() {
+            return Ok(None);
+        }
+        if !self.boundary_pending {
+            self.boundary_pending = true;
+            return Ok(Some(Event::EndDocument));
+        }
+        self.boundary_pending = false;
+        loop {
+            if self.provider.content_builder.stream_buffer().is_empty() {
+                self.provider
+                    .content_builder
+                    .fill_buffer_from_reader(&mut self.provider.reader)?;
+                if self.provider.content_builder.stream_buffer().is_empty() {
+                    self.provider.finished = true;
+                    return Ok(Some(Event::EndDocument));
+                }
+            }
+            match self.provider.content_builder.stream_buffer().current_byte() {
+                Ok(b' ' | b'\t' | b'\n' | b'\r') => {
+                    self.provider
+                        .content_builder
+                        .stream_buffer_mut()
+                        .advance()
+                        .map_err(ParseError::from)?;
+                    continue;
+                }
+                Ok(_) => {
+                    self.provider.content_builder.recycle();
+                    self.parser_core.tokenizer.reset_for_next_document();
+                    return Ok(None);
+                }
+                Err(_) => {
+                    self.provider.finished = true;
+                    return Ok(Some(Event::EndDocument));
+                }
+            }
+        }
+    }
 }
 
 impl<R: Reader, C: BitStackConfig> PullParser for StreamParser<'_, R, C> {
@@ -219,6 +567,9 @@ impl<R: Reader, C: BitStackConfig> PullParser for StreamParser<'_, R, C> {
         if self.provider.finished {
             return Ok(Event::EndDocument);
         }
+        if let Some(event) = self.handle_streaming_boundary()? {
+            return Ok(event);
+        }
 
         self.provider
             .content_builder
@@ -226,6 +577,58 @@ impl<R: Reader, C: BitStackConfig> PullParser for StreamParser<'_, R, C> {
 
         self.next_event_impl()
     }
+
+    fn set_max_depth(&mut self, max_depth: usize) {
+        self.parser_core.set_max_depth(max_depth);
+    }
+
+    fn depth(&self) -> usize {
+        self.parser_core.depth()
+    }
+
+    fn remaining_depth(&self) -> Option<usize> {
+        self.parser_core.remaining_depth()
+    }
+
+    fn in_object(&self) -> bool {
+        self.parser_core.in_object()
+    }
+
+    fn in_array(&self) -> bool {
+        self.parser_core.in_array()
+    }
+
+    fn set_reject_escaped_keys(&mut self, reject: bool) {
+        self.parser_core.set_reject_escaped_keys(reject);
+    }
+
+    fn set_reject_bidi_controls(&mut self, reject: bool) {
+        self.parser_core.set_reject_bidi_controls(reject);
+    }
+
+    fn set_surrogate_policy(&mut self, policy: crate::escape_processor::SurrogatePolicy) {
+        self.parser_core.set_surrogate_policy(policy);
+    }
+
+    fn set_whitespace_events(&mut self, enabled: bool) {
+        self.parser_core.set_whitespace_events(enabled);
+    }
+
+    fn set_recovery_mode(&mut self, enabled: bool) {
+        self.parser_core.set_recovery_mode(enabled);
+    }
+
+    fn set_max_recovery_errors(&mut self, max: usize) {
+        self.parser_core.set_max_recovery_errors(max);
+    }
+
+    fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.parser_core.set_lenient_syntax(enabled);
+    }
+
+    fn skip_value(&mut self) -> Result<(), ParseError> {
+        self.parser_core.skip_value_impl(&mut self.provider)
+    }
 }
 
 #[cfg(test)]
@@ -414,6 +817,32 @@ mod tests {
         // The specific error behavior may vary
     }
 
+    #[test]
+    fn test_error_recovery_with_pending_state_reports_position() {
+        // Same malformed input as test_error_recovery_with_pending_state, which
+        // only checks that we hit *an* error without panicking or hanging; this
+        // pins down *where* next_event_located() says it happened, since the
+        // input has no newlines the error must land at the end of the buffer,
+        // one column past the last byte consumed.
+        let invalid_json = br#"{"key": 123,"#; // Missing closing brace
+        let reader = SliceReader::new(invalid_json);
+        let mut buffer = [0u8; 256];
+        let mut parser = TestStreamParser::new(reader, &mut buffer);
+
+        loop {
+            match parser.next_event_located() {
+                Ok(Event::EndDocument) => panic!("expected a parse error"),
+                Err((_err, pos)) => {
+                    assert_eq!(pos.line, 1);
+                    assert_eq!(pos.byte_offset, invalid_json.len());
+                    assert_eq!(pos.column, invalid_json.len() + 1);
+                    break;
+                }
+                Ok(_) => {}
+            }
+        }
+    }
+
     #[test]
     fn test_multiple_rapid_container_ends() {
         // Test deeply nested structures that end with numbers
@@ -876,10 +1305,18 @@ mod tests {
                     crate::NumberResult::FloatDisabled => {
                         // This is expected in no-float build
                     }
-                    #[cfg(feature = "float")]
+                    #[cfg(all(feature = "float", not(feature = "float32")))]
+                    crate::NumberResult::Float(f) => {
+                        // Exact, not approximate: parse_float is correctly-rounded
+                        // (see json_number.rs), so this is the same f64 `3.14`
+                        // parses to as a Rust literal.
+                        assert_eq!(*f, 3.14);
+                    }
+                    #[cfg(feature = "float32")]
                     crate::NumberResult::Float(f) => {
-                        // This is expected in float-enabled build
-                        assert!((f - 3.14).abs() < 0.01);
+                        // Correctly-rounded at f32 precision, which isn't the
+                        // same bit pattern as the f64 literal above.
+                        assert_eq!(*f, 3.14_f32);
                     }
                     #[cfg(feature = "float-skip")]
                     crate::NumberResult::FloatSkipped => {
@@ -927,10 +1364,14 @@ mod tests {
                         crate::NumberResult::FloatSkipped => {
                             // This is expected in float-skip build
                         }
-                        #[cfg(feature = "float")]
+                        #[cfg(all(feature = "float", not(feature = "float32")))]
                         crate::NumberResult::Float(f) => {
-                            // This is expected in float-enabled build
-                            assert!((f - 1000.0).abs() < f64::EPSILON);
+                            // Exact for the same reason as the "3.14" case above.
+                            assert_eq!(*f, 1000.0);
+                        }
+                        #[cfg(feature = "float32")]
+                        crate::NumberResult::Float(f) => {
+                            assert_eq!(*f, 1000.0_f32);
                         }
                         _ => panic!("Unexpected number parsing result for scientific notation"),
                     }
@@ -1263,4 +1704,105 @@ mod tests {
             ),
         }
     }
+
+    #[test]
+    fn test_skip_value_nested_container_then_resumes() {
+        let json = br#"{"skip": {"a": [1, 2, {"b": "}]ignored"}]}, "keep": true}"#;
+        let reader = SliceReader::new(json);
+        let mut buffer = [0u8; 256];
+        let mut parser = TestStreamParser::new(reader, &mut buffer);
+
+        assert_eq!(parser.next_event(), Ok(Event::StartObject));
+        assert_eq!(
+            parser.next_event(),
+            Ok(Event::Key(crate::String::Borrowed("skip")))
+        );
+        assert_eq!(parser.next_event(), Ok(Event::StartObject));
+        parser.skip_value().expect("skip the rest of the subtree");
+
+        assert_eq!(
+            parser.next_event(),
+            Ok(Event::Key(crate::String::Borrowed("keep")))
+        );
+        assert_eq!(parser.next_event(), Ok(Event::Bool(true)));
+        assert_eq!(parser.next_event(), Ok(Event::EndObject));
+        assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+    }
+
+    #[test]
+    fn test_skip_value_scalar_consumes_one_event() {
+        let json = br#"["a", 42, "b"]"#;
+        let reader = SliceReader::new(json);
+        let mut buffer = [0u8; 64];
+        let mut parser = TestStreamParser::new(reader, &mut buffer);
+
+        assert_eq!(parser.next_event(), Ok(Event::StartArray));
+        match parser.next_event() {
+            Ok(Event::String(_)) => {}
+            other => panic!("expected String, got {other:?}"),
+        }
+        parser.skip_value().expect("skip the number");
+        match parser.next_event() {
+            Ok(Event::String(_)) => {}
+            other => panic!("expected String, got {other:?}"),
+        }
+        assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    }
+
+    #[test]
+    fn test_max_depth_rejects_nesting_beyond_limit() {
+        let json = b"[[[1]]]"; // 3 levels deep
+        let reader = SliceReader::new(json);
+        let mut buffer = [0u8; 64];
+        let mut parser = TestStreamParser::new(reader, &mut buffer);
+        parser.set_max_depth(2);
+
+        assert_eq!(parser.next_event(), Ok(Event::StartArray));
+        assert_eq!(parser.next_event(), Ok(Event::StartArray));
+        assert_eq!(
+            parser.next_event(),
+            Err(ParseError::DepthLimitExceeded { depth: 3 })
+        );
+    }
+
+    #[test]
+    fn test_max_depth_allows_alternating_open_close_within_limit() {
+        let json = b"[[1], [2], [3]]"; // never nests past depth 2
+        let reader = SliceReader::new(json);
+        let mut buffer = [0u8; 64];
+        let mut parser = TestStreamParser::new(reader, &mut buffer);
+        parser.set_max_depth(2);
+
+        assert_eq!(parser.next_event(), Ok(Event::StartArray));
+        for _ in 0..3 {
+            assert_eq!(parser.next_event(), Ok(Event::StartArray));
+            match parser.next_event() {
+                Ok(Event::Number(_)) => {}
+                other => panic!("expected Number, got {other:?}"),
+            }
+            assert_eq!(parser.next_event(), Ok(Event::EndArray));
+        }
+        assert_eq!(parser.next_event(), Ok(Event::EndArray));
+        assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+    }
+
+    #[test]
+    fn test_default_max_depth_is_unbounded() {
+        let json = b"[[[[[[[[[[0]]]]]]]]]]"; // 10 levels deep, no set_max_depth call
+        let reader = SliceReader::new(json);
+        let mut buffer = [0u8; 128];
+        let mut parser = TestStreamParser::new(reader, &mut buffer);
+
+        for _ in 0..10 {
+            assert_eq!(parser.next_event(), Ok(Event::StartArray));
+        }
+        match parser.next_event() {
+            Ok(Event::Number(_)) => {}
+            other => panic!("expected Number, got {other:?}"),
+        }
+        for _ in 0..10 {
+            assert_eq!(parser.next_event(), Ok(Event::EndArray));
+        }
+        assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+    }
 }
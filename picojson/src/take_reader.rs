@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`TakeReader`], a [`Reader`] adapter that caps the total bytes delivered
+//! to the parser at a fixed limit -- the JSON-parsing equivalent of hyper's
+//! Content-Length `Length` body decoder. Useful for pulling exactly one
+//! length-prefixed JSON message out of a multiplexed stream and stopping
+//! cleanly, leaving the rest of the transport untouched for the next frame.
+
+use crate::Reader;
+
+/// Wraps an inner [`Reader`], delivering at most `limit` bytes total and
+/// then reporting end-of-stream (`Ok(0)`) regardless of how much more
+/// `inner` has buffered.
+pub struct TakeReader<R: Reader> {
+    inner: R,
+    limit: usize,
+    consumed: usize,
+}
+
+impl<R: Reader> TakeReader<R> {
+    /// Wraps `inner`, delivering at most `limit` bytes before reporting
+    /// end-of-stream.
+    pub fn new(inner: R, limit: usize) -> Self {
+        Self {
+            inner,
+            limit,
+            consumed: 0,
+        }
+    }
+
+    /// Recovers the underlying reader and how many bytes of the limit were
+    /// consumed, so the caller can keep reading subsequent frames from the
+    /// same transport.
+    pub fn into_inner(self) -> (R, usize) {
+        (self.inner, self.consumed)
+    }
+}
+
+impl<R: Reader> Reader for TakeReader<R> {
+    type Error = R::Error;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let remaining = self.limit - self.consumed;
+        if remaining == 0 {
+            return Ok(0);
+        }
+        let cap = buf.len().min(remaining);
+        let n = self.inner.read(&mut buf[..cap])?;
+        self.consumed += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_reader::ChunkReader;
+
+    #[test]
+    fn test_take_reader_stops_at_limit_even_with_more_inner_data() {
+        let data = b"hello world, more than the limit";
+        let inner = ChunkReader::full_slice(data);
+        let mut reader = TakeReader::new(inner, 5);
+
+        let mut buf = [0u8; 16];
+        let n = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"hello");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+        let (_inner, consumed) = reader.into_inner();
+        assert_eq!(consumed, 5);
+    }
+
+    #[test]
+    fn test_take_reader_caps_each_read_at_remaining_bytes() {
+        let data = b"0123456789";
+        let inner = ChunkReader::full_slice(data);
+        let mut reader = TakeReader::new(inner, 3);
+
+        let mut buf = [0u8; 16];
+        let n = reader.read(&mut buf).unwrap();
+        // Limit is smaller than both buf and what the inner reader offers.
+        assert_eq!(&buf[..n], b"012");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_take_reader_into_inner_recovers_remaining_transport() {
+        let data = b"ABCDE";
+        let inner = ChunkReader::new(data, 2);
+        let mut reader = TakeReader::new(inner, 3);
+
+        let mut buf = [0u8; 2];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2); // "AB"
+        assert_eq!(reader.read(&mut buf).unwrap(), 1); // "C" (limit reached)
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+        let (mut inner, consumed) = reader.into_inner();
+        assert_eq!(consumed, 3);
+        // "DE" is still sitting in the underlying transport for the next frame.
+        let n = inner.read(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"DE");
+    }
+}
@@ -0,0 +1,598 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`TranscodingReader`], a [`Reader`] adapter that sniffs a leading BOM
+//! (UTF-8, UTF-16LE/BE, or UTF-32LE/BE) and transcodes the stream to UTF-8
+//! on the fly, the way quick-xml's `encoding_rs_io` integration does for
+//! XML. JSON is defined as a stream of Unicode codepoints, so a
+//! `StreamParser` upstream of a UTF-16- or UTF-32-encoded source otherwise
+//! has no way to get at it without decoding the whole document into a
+//! buffer first.
+//!
+//! Detection is BOM-only: RFC 8259 also describes inferring the encoding
+//! from the zero-byte pattern of a BOM-less stream's first two bytes (e.g.
+//! a leading `00 XX` implies UTF-16BE, since ASCII JSON's first structural
+//! byte is never `0x00`). That heuristic is deliberately not implemented
+//! here -- guessing a whole-document encoding from content instead of an
+//! explicit marker is a fundamentally different kind of inference than BOM
+//! sniffing, with its own false-positive surface, and every source this
+//! reader actually needs to support (anything that round-trips through a
+//! `Reader`) can cheaply prepend a real BOM if it knows its own encoding.
+//!
+//! `no_std` and allocation-free like the other [`Reader`] adapters in this
+//! crate: raw bytes are pulled from `inner` into a caller-supplied window
+//! buffer, and decoding works one scalar value at a time so a surrogate
+//! pair (or a UTF-8 encoding that doesn't fit in the remainder of a small
+//! `read()` buffer) can be carried across calls without allocating.
+
+use crate::Reader;
+
+/// Errors transcoding a byte stream to UTF-8, in addition to whatever `R`'s
+/// own `read()` can fail with.
+#[derive(Debug, PartialEq)]
+pub enum TranscodingError<E> {
+    /// The inner reader failed.
+    Inner(E),
+    /// A UTF-16 or UTF-32 code unit was cut off by end-of-stream after only
+    /// part of its bytes.
+    TruncatedCodeUnit,
+    /// A `0xD800`-`0xDBFF` high surrogate was followed by something other
+    /// than a `0xDC00`-`0xDFFF` low surrogate to pair it with (including
+    /// end-of-stream).
+    UnpairedHighSurrogate,
+    /// A `0xDC00`-`0xDFFF` low surrogate appeared with no preceding high
+    /// surrogate for it to complete.
+    UnpairedLowSurrogate,
+    /// A UTF-32 code unit decoded to a value that isn't a valid Unicode
+    /// scalar value -- in the surrogate range (`0xD800`-`0xDFFF`, which
+    /// UTF-32 has no use for) or above `0x10FFFF`.
+    InvalidScalarValue {
+        /// The offending 32-bit code unit.
+        value: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Utf32Le,
+    Utf32Be,
+}
+
+/// A [`Reader`] wrapping an inner [`Reader`], transcoding a leading-BOM
+/// UTF-16LE/UTF-16BE/UTF-32LE/UTF-32BE stream to UTF-8 (or passing UTF-8
+/// input through unchanged, BOM included) so the bytes the tokenizer sees
+/// are always UTF-8 regardless of the source encoding.
+pub struct TranscodingReader<'b, R: Reader> {
+    inner: R,
+    window: &'b mut [u8],
+    window_pos: usize,
+    window_len: usize,
+    /// Bytes already pulled from `inner` while sniffing the BOM that turned
+    /// out not to be part of one, and so still need to be decoded/passed
+    /// through. Sized for the longest BOM candidate this can misread: a
+    /// 4-byte UTF-32BE BOM prefix (`00 00`) that turns out to just be two
+    /// leading NUL bytes of plain UTF-8 content followed by two more bytes
+    /// that don't complete it.
+    prebuffer: [u8; 4],
+    prebuffer_pos: usize,
+    prebuffer_len: usize,
+    encoding: Option<Encoding>,
+    pending_high_surrogate: Option<u16>,
+    /// UTF-8 bytes from a decoded scalar that didn't fit in a previous
+    /// `read()`'s buffer.
+    out_hold: [u8; 4],
+    out_hold_pos: usize,
+    out_hold_len: usize,
+}
+
+impl<'b, R: Reader> TranscodingReader<'b, R> {
+    /// Wraps `inner`, using `window` to pull raw bytes from it in chunks.
+    pub fn new(inner: R, window: &'b mut [u8]) -> Self {
+        Self {
+            inner,
+            window,
+            window_pos: 0,
+            window_len: 0,
+            prebuffer: [0; 4],
+            prebuffer_pos: 0,
+            prebuffer_len: 0,
+            encoding: None,
+            pending_high_surrogate: None,
+            out_hold: [0; 4],
+            out_hold_pos: 0,
+            out_hold_len: 0,
+        }
+    }
+
+    fn next_raw_byte(&mut self) -> Result<Option<u8>, TranscodingError<R::Error>> {
+        if self.prebuffer_pos < self.prebuffer_len {
+            let byte = self.prebuffer[self.prebuffer_pos];
+            self.prebuffer_pos += 1;
+            return Ok(Some(byte));
+        }
+        if self.window_pos >= self.window_len {
+            let n = self
+                .inner
+                .read(self.window)
+                .map_err(TranscodingError::Inner)?;
+            if n == 0 {
+                return Ok(None);
+            }
+            self.window_len = n;
+            self.window_pos = 0;
+        }
+        let byte = self.window[self.window_pos];
+        self.window_pos += 1;
+        Ok(Some(byte))
+    }
+
+    fn push_back(&mut self, bytes: &[u8]) {
+        self.prebuffer[..bytes.len()].copy_from_slice(bytes);
+        self.prebuffer_pos = 0;
+        self.prebuffer_len = bytes.len();
+    }
+
+    /// Determines the encoding from a leading BOM, consuming it. Falls back
+    /// to UTF-8 (with no bytes consumed, or whatever prefix turned out not
+    /// to be a BOM pushed back) if none is present.
+    ///
+    /// The `0xFF 0xFE` / `0x00 0x00` prefixes are shared between a UTF-16
+    /// BOM and the first two bytes of a UTF-32 one, so both arms peek two
+    /// bytes further before committing: a genuine UTF-16-encoded document
+    /// can't itself start with the codepoint `U+0000` (not a legal first
+    /// byte of JSON), so seeing one right after `0xFF 0xFE` is unambiguous
+    /// proof of a UTF-32LE BOM rather than UTF-16LE content that happens to
+    /// start that way.
+    fn sniff(&mut self) -> Result<(), TranscodingError<R::Error>> {
+        if self.encoding.is_some() {
+            return Ok(());
+        }
+        let Some(b0) = self.next_raw_byte()? else {
+            self.encoding = Some(Encoding::Utf8);
+            return Ok(());
+        };
+        let Some(b1) = self.next_raw_byte()? else {
+            self.push_back(&[b0]);
+            self.encoding = Some(Encoding::Utf8);
+            return Ok(());
+        };
+        self.encoding = Some(match (b0, b1) {
+            (0xFF, 0xFE) => match (self.next_raw_byte()?, self.next_raw_byte()?) {
+                (Some(0x00), Some(0x00)) => Encoding::Utf32Le,
+                (Some(b2), Some(b3)) => {
+                    self.push_back(&[b2, b3]);
+                    Encoding::Utf16Le
+                }
+                (Some(b2), None) => {
+                    self.push_back(&[b2]);
+                    Encoding::Utf16Le
+                }
+                (None, _) => Encoding::Utf16Le,
+            },
+            (0xFE, 0xFF) => Encoding::Utf16Be,
+            (0x00, 0x00) => match (self.next_raw_byte()?, self.next_raw_byte()?) {
+                (Some(0xFE), Some(0xFF)) => Encoding::Utf32Be,
+                (Some(b2), Some(b3)) => {
+                    self.push_back(&[b0, b1, b2, b3]);
+                    Encoding::Utf8
+                }
+                (Some(b2), None) => {
+                    self.push_back(&[b0, b1, b2]);
+                    Encoding::Utf8
+                }
+                (None, _) => {
+                    self.push_back(&[b0, b1]);
+                    Encoding::Utf8
+                }
+            },
+            (0xEF, 0xBB) => {
+                match self.next_raw_byte()? {
+                    Some(0xBF) => {}
+                    Some(b2) => self.push_back(&[b0, b1, b2]),
+                    None => self.push_back(&[b0, b1]),
+                }
+                Encoding::Utf8
+            }
+            _ => {
+                self.push_back(&[b0, b1]);
+                Encoding::Utf8
+            }
+        });
+        Ok(())
+    }
+
+    fn next_code_unit(
+        &mut self,
+        encoding: Encoding,
+    ) -> Result<Option<u16>, TranscodingError<R::Error>> {
+        let Some(b0) = self.next_raw_byte()? else {
+            return Ok(None);
+        };
+        let Some(b1) = self.next_raw_byte()? else {
+            return Err(TranscodingError::TruncatedCodeUnit);
+        };
+        Ok(Some(match encoding {
+            Encoding::Utf16Le => u16::from_le_bytes([b0, b1]),
+            Encoding::Utf16Be => u16::from_be_bytes([b0, b1]),
+            Encoding::Utf8 | Encoding::Utf32Le | Encoding::Utf32Be => {
+                unreachable!("next_code_unit is only called for UTF-16 encodings")
+            }
+        }))
+    }
+
+    /// Decodes the next 4-byte UTF-32 code unit, with no surrogate pairing
+    /// needed since UTF-32 already encodes one scalar value per unit.
+    fn next_utf32_scalar(
+        &mut self,
+        encoding: Encoding,
+    ) -> Result<Option<u32>, TranscodingError<R::Error>> {
+        let Some(b0) = self.next_raw_byte()? else {
+            return Ok(None);
+        };
+        let mut bytes = [b0, 0, 0, 0];
+        for slot in &mut bytes[1..] {
+            *slot = self
+                .next_raw_byte()?
+                .ok_or(TranscodingError::TruncatedCodeUnit)?;
+        }
+        let scalar = match encoding {
+            Encoding::Utf32Le => u32::from_le_bytes(bytes),
+            Encoding::Utf32Be => u32::from_be_bytes(bytes),
+            Encoding::Utf8 | Encoding::Utf16Le | Encoding::Utf16Be => {
+                unreachable!("next_utf32_scalar is only called for UTF-32 encodings")
+            }
+        };
+        if char::from_u32(scalar).is_none() {
+            return Err(TranscodingError::InvalidScalarValue { value: scalar });
+        }
+        Ok(Some(scalar))
+    }
+
+    /// Decodes the next Unicode scalar value: a UTF-32 code unit directly,
+    /// or a UTF-16 code unit combined with its surrogate pair partner
+    /// across as many `next_code_unit` calls as it takes.
+    fn next_scalar(&mut self, encoding: Encoding) -> Result<Option<u32>, TranscodingError<R::Error>> {
+        if let Encoding::Utf32Le | Encoding::Utf32Be = encoding {
+            return self.next_utf32_scalar(encoding);
+        }
+        loop {
+            let Some(unit) = self.next_code_unit(encoding)? else {
+                return if self.pending_high_surrogate.is_some() {
+                    Err(TranscodingError::UnpairedHighSurrogate)
+                } else {
+                    Ok(None)
+                };
+            };
+            if let Some(high) = self.pending_high_surrogate.take() {
+                return if (0xDC00..=0xDFFF).contains(&unit) {
+                    let combined =
+                        0x10000 + (((high as u32 - 0xD800) << 10) | (unit as u32 - 0xDC00));
+                    Ok(Some(combined))
+                } else {
+                    Err(TranscodingError::UnpairedHighSurrogate)
+                };
+            }
+            if (0xD800..=0xDBFF).contains(&unit) {
+                self.pending_high_surrogate = Some(unit);
+                continue;
+            }
+            if (0xDC00..=0xDFFF).contains(&unit) {
+                return Err(TranscodingError::UnpairedLowSurrogate);
+            }
+            return Ok(Some(unit as u32));
+        }
+    }
+}
+
+impl<'b, R: Reader> Reader for TranscodingReader<'b, R> {
+    type Error = TranscodingError<R::Error>;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.sniff()?;
+        let encoding = self.encoding.expect("sniff always sets an encoding");
+
+        if encoding == Encoding::Utf8 {
+            let mut written = 0;
+            while written < buf.len() {
+                match self.next_raw_byte()? {
+                    Some(byte) => {
+                        buf[written] = byte;
+                        written += 1;
+                    }
+                    None => break,
+                }
+            }
+            return Ok(written);
+        }
+
+        let mut written = 0;
+        while written < buf.len() && self.out_hold_pos < self.out_hold_len {
+            buf[written] = self.out_hold[self.out_hold_pos];
+            written += 1;
+            self.out_hold_pos += 1;
+        }
+        while written < buf.len() {
+            let Some(scalar) = self.next_scalar(encoding)? else {
+                break;
+            };
+            // The only scalars next_scalar can produce are a BMP code unit,
+            // a valid combined UTF-16 surrogate pair, or an
+            // already-validated UTF-32 code unit -- all always valid `char`s.
+            let ch = char::from_u32(scalar).expect("next_scalar only yields valid codepoints");
+            let mut encoded = [0u8; 4];
+            let encoded = ch.encode_utf8(&mut encoded).as_bytes();
+
+            let remaining = buf.len() - written;
+            if encoded.len() <= remaining {
+                buf[written..written + encoded.len()].copy_from_slice(encoded);
+                written += encoded.len();
+            } else {
+                buf[written..].copy_from_slice(&encoded[..remaining]);
+                written += remaining;
+                let leftover = encoded.len() - remaining;
+                self.out_hold[..leftover].copy_from_slice(&encoded[remaining..]);
+                self.out_hold_len = leftover;
+                self.out_hold_pos = 0;
+                break;
+            }
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_reader::ChunkReader;
+
+    fn decode_all(data: &[u8], inner_chunk_size: usize, window_size: usize) -> Vec<u8> {
+        let inner = ChunkReader::new(data, inner_chunk_size);
+        let mut window = vec![0u8; window_size];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+        let mut out = Vec::new();
+        let mut buf = vec![0u8; 3];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        out
+    }
+
+    fn utf16le(s: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        for unit in s.encode_utf16() {
+            out.extend_from_slice(&unit.to_le_bytes());
+        }
+        out
+    }
+
+    fn utf16be(s: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        for unit in s.encode_utf16() {
+            out.extend_from_slice(&unit.to_be_bytes());
+        }
+        out
+    }
+
+    fn utf32le(s: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        for ch in s.chars() {
+            out.extend_from_slice(&(ch as u32).to_le_bytes());
+        }
+        out
+    }
+
+    fn utf32be(s: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        for ch in s.chars() {
+            out.extend_from_slice(&(ch as u32).to_be_bytes());
+        }
+        out
+    }
+
+    #[test]
+    fn test_no_bom_passes_utf8_through_unchanged() {
+        assert_eq!(decode_all(b"hello world", usize::MAX, 8), b"hello world");
+    }
+
+    #[test]
+    fn test_utf8_bom_is_consumed_and_rest_passed_through() {
+        let mut data = vec![0xEF, 0xBB, 0xBF];
+        data.extend_from_slice(b"hello");
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"hello");
+    }
+
+    #[test]
+    fn test_two_bytes_that_almost_look_like_a_utf8_bom_are_not_consumed() {
+        // EF BB without the trailing BF isn't a BOM; all three bytes (plus
+        // whatever follows) must come through untouched.
+        let data = [0xEF, 0xBB, b'!'];
+        assert_eq!(decode_all(&data, usize::MAX, 8), data);
+    }
+
+    #[test]
+    fn test_utf16_le_bom_decodes_ascii() {
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&utf16le("hello"));
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"hello");
+    }
+
+    #[test]
+    fn test_utf16_be_bom_decodes_ascii() {
+        let mut data = vec![0xFE, 0xFF];
+        data.extend_from_slice(&utf16be("hello"));
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"hello");
+    }
+
+    #[test]
+    fn test_utf16_decodes_greek_letters_matching_escape_baseline() {
+        // Same codepoint (α, Greek alpha) the `\uXXXX` escape tests
+        // decode to, so the two decoding paths can be checked against the
+        // same expected UTF-8 bytes.
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&utf16le("α"));
+        assert_eq!(decode_all(&data, usize::MAX, 8), "α".as_bytes());
+    }
+
+    #[test]
+    fn test_utf16_surrogate_pair_split_across_tiny_windows_and_chunks() {
+        // U+1F600 (an emoji) is outside the BMP, so it's a surrogate pair in
+        // UTF-16: 4 bytes that, with a 1-byte inner chunk size and a 3-byte
+        // window, can only be reassembled correctly if the pending high
+        // surrogate survives across several `read()` calls.
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&utf16le("😀"));
+        assert_eq!(decode_all(&data, 1, 3), "😀".as_bytes());
+    }
+
+    #[test]
+    fn test_utf16_be_surrogate_pair_decodes_correctly() {
+        let mut data = vec![0xFE, 0xFF];
+        data.extend_from_slice(&utf16be("😀"));
+        assert_eq!(decode_all(&data, 1, 3), "😀".as_bytes());
+    }
+
+    #[test]
+    fn test_lone_high_surrogate_at_end_of_stream_is_an_error() {
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&0xD83Du16.to_le_bytes());
+        let inner = ChunkReader::new(&data, usize::MAX);
+        let mut window = [0u8; 8];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(TranscodingError::UnpairedHighSurrogate)
+        );
+    }
+
+    #[test]
+    fn test_high_surrogate_followed_by_non_surrogate_is_an_error() {
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&0xD83Du16.to_le_bytes());
+        data.extend_from_slice(&(b'!' as u16).to_le_bytes());
+        let inner = ChunkReader::new(&data, usize::MAX);
+        let mut window = [0u8; 8];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(TranscodingError::UnpairedHighSurrogate)
+        );
+    }
+
+    #[test]
+    fn test_lone_low_surrogate_is_an_error() {
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&0xDE00u16.to_le_bytes());
+        let inner = ChunkReader::new(&data, usize::MAX);
+        let mut window = [0u8; 8];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(TranscodingError::UnpairedLowSurrogate)
+        );
+    }
+
+    #[test]
+    fn test_truncated_code_unit_is_an_error() {
+        let data = [0xFF, 0xFE, 0x41]; // one lone trailing byte
+        let inner = ChunkReader::new(&data, usize::MAX);
+        let mut window = [0u8; 8];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(TranscodingError::TruncatedCodeUnit)
+        );
+    }
+
+    #[test]
+    fn test_utf32_le_bom_decodes_ascii() {
+        let mut data = vec![0xFF, 0xFE, 0x00, 0x00];
+        data.extend_from_slice(&utf32le("hello"));
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"hello");
+    }
+
+    #[test]
+    fn test_utf32_be_bom_decodes_ascii() {
+        let mut data = vec![0x00, 0x00, 0xFE, 0xFF];
+        data.extend_from_slice(&utf32be("hello"));
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"hello");
+    }
+
+    #[test]
+    fn test_utf32_decodes_a_codepoint_outside_the_bmp_without_surrogate_pairing() {
+        let mut data = vec![0xFF, 0xFE, 0x00, 0x00];
+        data.extend_from_slice(&utf32le("😀"));
+        assert_eq!(decode_all(&data, usize::MAX, 8), "😀".as_bytes());
+    }
+
+    #[test]
+    fn test_utf32_le_bom_is_not_confused_with_utf16_le_bom() {
+        // `FF FE` alone (no trailing `00 00`) is a UTF-16LE BOM; the first
+        // UTF-16LE code unit right after it must not be swallowed as part
+        // of a UTF-32 BOM.
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&utf16le("!"));
+        assert_eq!(decode_all(&data, usize::MAX, 8), b"!");
+    }
+
+    #[test]
+    fn test_truncated_utf32_code_unit_is_an_error() {
+        let data = [0xFF, 0xFE, 0x00, 0x00, 0x41, 0x00, 0x00]; // 3 of 4 bytes
+        let inner = ChunkReader::new(&data, usize::MAX);
+        let mut window = [0u8; 8];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(TranscodingError::TruncatedCodeUnit)
+        );
+    }
+
+    #[test]
+    fn test_utf32_surrogate_range_value_is_an_invalid_scalar() {
+        let mut data = vec![0xFF, 0xFE, 0x00, 0x00];
+        data.extend_from_slice(&0xD800u32.to_le_bytes());
+        let inner = ChunkReader::new(&data, usize::MAX);
+        let mut window = [0u8; 8];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+        let mut buf = [0u8; 8];
+        assert_eq!(
+            reader.read(&mut buf),
+            Err(TranscodingError::InvalidScalarValue { value: 0xD800 })
+        );
+    }
+
+    #[test]
+    fn test_encoded_codepoint_straddling_a_tiny_read_buffer_is_carried_over() {
+        // A 1-byte read buffer forces every multi-byte UTF-8 encoding (the
+        // 2-byte alpha, the 4-byte emoji) to be handed back across several
+        // `read()` calls via out_hold.
+        let mut data = vec![0xFF, 0xFE];
+        data.extend_from_slice(&utf16le("α😀"));
+        let inner = ChunkReader::new(&data, usize::MAX);
+        let mut window = [0u8; 16];
+        let mut reader = TranscodingReader::new(inner, &mut window);
+
+        let mut out = Vec::new();
+        let mut buf = [0u8; 1];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+        }
+        let mut expected = "α".as_bytes().to_vec();
+        expected.extend_from_slice("😀".as_bytes());
+        assert_eq!(out, expected);
+    }
+}
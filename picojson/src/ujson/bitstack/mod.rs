@@ -3,6 +3,11 @@
 use core::cmp::PartialEq;
 use core::ops::{BitAnd, BitOr, Shl, Shr};
 
+/// Returned by [`BitBucket::try_push`] when the bucket has no remaining
+/// capacity for another nesting level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitStackOverflow;
+
 /// Trait for bit buckets - provides bit storage for JSON parser state.
 /// This trait is implemented for both integer and [T; N] types.
 ///
@@ -10,11 +15,76 @@ use core::ops::{BitAnd, BitOr, Shl, Shr};
 /// This is the responsibility of the caller.
 pub trait BitBucket: Default {
     /// Pushes a bit (true for 1, false for 0) onto the stack.
+    ///
+    /// Stays infallible and silently drops the oldest bit once capacity is
+    /// exhausted (see [`ArrayBitBucket::push`]'s carry-discard comment) --
+    /// overflow detection lives one level up, in [`Self::try_push`], rather
+    /// than changing this method's signature. The tokenizer already tracks
+    /// its own depth counter separately from the bit stack (see
+    /// [`DepthCounter`]) and needs that count anyway for `pop`/`top` to mean
+    /// anything on a bucket that can't tell a stored `false` apart from an
+    /// unpushed bit, so checking it before the push was already free;
+    /// reporting the overflow as `push`'s return value would just move the
+    /// same check to every call site instead.
     fn push(&mut self, bit: bool);
     /// Pops the top bit off the stack, returning it if the stack isn’t empty.
     fn pop(&mut self) -> bool;
     /// Returns the top bit without removing it.
     fn top(&self) -> bool;
+
+    /// Total number of nesting levels this bucket can hold before `push`
+    /// would have to silently discard the oldest bit to make room.
+    const CAPACITY: usize;
+
+    /// Instance-level accessor for [`Self::CAPACITY`], for call sites that
+    /// only have a value (e.g. behind a generic `B: BitBucket` bound used
+    /// for error reporting) and would otherwise need `<B as
+    /// BitBucket>::CAPACITY` turbofish syntax to reach the associated const.
+    fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+
+    /// Nesting-level headroom as `Some(capacity)`, or `None` when this
+    /// bucket has no real ceiling of its own (bounded only by available
+    /// memory, as [`HeapBitBucket`](crate::HeapBitBucket) is) and
+    /// [`Self::CAPACITY`] is just a sentinel rather than a meaningful limit.
+    ///
+    /// Kept separate from [`Self::capacity`] rather than changing that
+    /// method's return type, since every existing caller of `capacity`
+    /// predates `HeapBitBucket` and already treats its `usize` as a real
+    /// limit -- this is the method for call sites (like
+    /// [`ParserCore::remaining_depth`](crate::event_processor::ParserCore::remaining_depth))
+    /// that need to tell "fixed ceiling" apart from "no real ceiling" instead
+    /// of silently treating `usize::MAX` as if it meant the latter.
+    fn capacity_bits(&self) -> Option<usize> {
+        Some(Self::CAPACITY)
+    }
+
+    /// Pushes a bit, failing instead of silently discarding the oldest one
+    /// once capacity is exhausted.
+    ///
+    /// `used` is the caller's own count of bits currently held -- per the
+    /// note on this trait, a bucket doesn't track its own depth, so it can't
+    /// check this from `self` alone. The tokenizer's parse context passes its
+    /// existing depth counter here.
+    ///
+    /// This is also why there's no `len`/`is_empty` on this trait and no
+    /// no-argument `try_push`: both would need the same self-tracking this
+    /// trait's doc comment already rules out (a stored `false` bit is
+    /// indistinguishable from no bit having been pushed there at all). The
+    /// tokenizer's parse context's own depth counter is that tracking, and
+    /// it already rejects overflow through
+    /// `ErrKind::MaxDepthReached`/`ParseError::TokenizerError` -- the same
+    /// path `try_push`'s failure here is routed through -- so a second,
+    /// separate "nesting too deep" error variant would just be a redundant
+    /// way to report the one condition this trait already catches.
+    fn try_push(&mut self, bit: bool, used: usize) -> Result<(), BitStackOverflow> {
+        if used >= Self::CAPACITY {
+            return Err(BitStackOverflow);
+        }
+        self.push(bit);
+        Ok(())
+    }
 }
 
 /// Automatic implementation for builtin-types ( u8, u32 etc ).
@@ -30,6 +100,8 @@ where
         + Default,
     T: From<u8>, // To create 0 and 1 constants
 {
+    const CAPACITY: usize = core::mem::size_of::<T>() * 8;
+
     fn push(&mut self, bit: bool) {
         *self = (self.clone() << 1u8) | T::from(bit as u8);
     }
@@ -61,6 +133,10 @@ pub trait DepthCounter: core::fmt::Debug + Copy {
 
     /// Check if depth is zero
     fn is_zero(self) -> bool;
+
+    /// The current depth as a plain `usize`, so it can be compared against a
+    /// [`BitBucket::CAPACITY`] regardless of this counter's own native width.
+    fn as_usize(self) -> usize;
 }
 
 macro_rules! impl_depth_counter {
@@ -78,6 +154,9 @@ macro_rules! impl_depth_counter {
 
                 #[inline]
                 fn is_zero(self) -> bool { self == 0 }
+
+                #[inline]
+                fn as_usize(self) -> usize { self as usize }
             }
         )*
     };
@@ -136,6 +215,70 @@ where
 /// This defines a 10-element array of [u32] for depth tracking bits, with a [u16] counter, allowing 320 levels of depth.
 pub type ArrayBitStack<const N: usize, T, D> = BitStackStruct<ArrayBitBucket<N, T>, D>;
 
+/// Shared big-endian shift/carry storage strategy for a `BitBucket` backed
+/// by several `T` elements in a row, whether that row is an owned `[T; N]`
+/// ([`ArrayBitBucket`]) or a borrowed `&mut [T]` ([`SliceBitBucket`]) --
+/// the algorithm only ever needs slice access, never the owning container's
+/// shape, so both impls just forward here instead of duplicating it.
+mod elements {
+    use core::ops::{BitAnd, BitOr, Shl, Shr};
+
+    pub(super) fn push<T>(elements: &mut [T], bit: bool)
+    where
+        T: Shl<u8, Output = T> + Shr<u8, Output = T> + BitAnd<T, Output = T> + BitOr<Output = T>,
+        T: From<u8> + Copy,
+    {
+        // Strategy: Use the row as big-endian storage, with leftmost element as most significant.
+        // Shift all elements left, carrying overflow from right to left.
+        let mut carry = T::from(bit as u8);
+        let element_bits = (core::mem::size_of::<T>() * 8) as u8;
+        let msb_shift = element_bits.saturating_sub(1);
+
+        // Start from the rightmost (least significant) element and work left.
+        for element in elements.iter_mut().rev() {
+            let old_msb = (*element >> msb_shift) & T::from(1); // Extract MSB that will be lost
+            *element = (*element << 1u8) | carry;
+            carry = old_msb;
+        }
+        // Note: carry from leftmost element is discarded (overflow).
+    }
+
+    pub(super) fn pop<T>(elements: &mut [T]) -> bool
+    where
+        T: Shl<u8, Output = T> + Shr<u8, Output = T> + BitAnd<T, Output = T> + BitOr<Output = T>,
+        T: PartialEq + From<u8> + Copy,
+    {
+        let Some(last_element) = elements.last() else {
+            return false;
+        };
+        let bit = (*last_element & T::from(1)) != T::from(0);
+
+        // Shift all elements right, carrying underflow from left to right.
+        let mut carry = T::from(0);
+        let element_bits = (core::mem::size_of::<T>() * 8) as u8;
+        let msb_shift = element_bits.saturating_sub(1);
+
+        // Start from the leftmost (most significant) element and work right.
+        for element in elements.iter_mut() {
+            let old_lsb = *element & T::from(1); // Extract LSB that will be lost
+            *element = (*element >> 1u8) | (carry << msb_shift);
+            carry = old_lsb;
+        }
+
+        bit
+    }
+
+    pub(super) fn top<T>(elements: &[T]) -> bool
+    where
+        T: BitAnd<T, Output = T> + PartialEq + From<u8> + Copy,
+    {
+        match elements.last() {
+            Some(last_element) => (*last_element & T::from(1)) != T::from(0),
+            None => false,
+        }
+    }
+}
+
 /// Array-based BitBucket implementation for large storage capacity.
 ///
 /// Provides large BitBucket storage using multiple elements.
@@ -163,67 +306,222 @@ where
         + Copy
         + Default,
 {
+    const CAPACITY: usize = N * core::mem::size_of::<T>() * 8;
+
     fn push(&mut self, bit: bool) {
-        // Strategy: Use array as big-endian storage, with leftmost element as most significant
-        // Shift all elements left, carrying overflow from right to left
-        let bit_val = T::from(bit as u8);
-        let mut carry = bit_val;
-        let element_bits = (core::mem::size_of::<T>() * 8) as u8;
-        let msb_shift = element_bits.saturating_sub(1);
+        elements::push(&mut self.0, bit);
+    }
 
-        // Start from the rightmost (least significant) element and work left
-        for i in (0..N).rev() {
-            let old_msb = if let Some(element) = self.0.get(i) {
-                (*element >> msb_shift) & T::from(1) // Extract MSB that will be lost
-            } else {
-                continue;
-            };
-            if let Some(element_mut) = self.0.get_mut(i) {
-                *element_mut = (*element_mut << 1u8) | carry;
-            }
-            carry = old_msb;
+    fn pop(&mut self) -> bool {
+        elements::pop(&mut self.0)
+    }
+
+    fn top(&self) -> bool {
+        elements::top(&self.0)
+    }
+}
+
+/// Slice-based [`BitBucket`] for sizing nesting depth at runtime: wraps a
+/// caller-supplied `&mut [T]` -- a window carved out of an existing scratch
+/// arena, say -- instead of forcing a compile-time `N` the way
+/// [`ArrayBitBucket`] does. One statically-linked parser binary can then
+/// handle both a shallow, latency-sensitive document and a deeply nested
+/// one by hitting the same code path with a bigger slice, rather than
+/// needing a distinct `ArrayBitStack<N, T>` monomorphization per depth.
+///
+/// Reuses the exact big-endian shift/carry strategy [`ArrayBitBucket`]
+/// uses (both forward to the shared [`elements`] helpers), just over a
+/// borrowed row instead of an owned one.
+///
+/// Unlike `ArrayBitBucket`/[`HeapBitBucket`](crate::HeapBitBucket), this
+/// type cannot currently serve as a [`BitStackConfig::Bucket`]: every
+/// front-end in this crate builds its tokenizer's bucket via a bare
+/// `Bucket::default()` (see `Tokenizer::new`/`ParseContext::new`), with no
+/// constructor parameter anywhere along that path to thread a borrowed
+/// slice (and thus a lifetime) through. `Default` is implementable here --
+/// `&mut [T]` already has a blanket `Default` impl yielding `&mut []` --
+/// but that can only ever produce a zero-capacity bucket, not the caller's
+/// real runtime buffer, so plugging this into `BitStackConfig` today would
+/// silently build a parser that overflows on the very first open
+/// container. Using it directly as a [`BitBucket`] (outside the
+/// `BitStackConfig`-driven front ends) or in a custom harness built around
+/// an explicitly-constructed `Tokenizer` is unaffected by this.
+#[derive(Debug, Default)]
+pub struct SliceBitBucket<'a, T>(pub &'a mut [T]);
+
+impl<'a, T: Default + Copy> SliceBitBucket<'a, T> {
+    /// Wraps `storage`, zeroing every element so the bucket starts out
+    /// empty (all-`false` bits) regardless of what `storage` held before.
+    pub fn new(storage: &'a mut [T]) -> Self {
+        storage.fill(T::default());
+        SliceBitBucket(storage)
+    }
+}
+
+impl<'a, T> BitBucket for SliceBitBucket<'a, T>
+where
+    T: Shl<u8, Output = T>
+        + Shr<u8, Output = T>
+        + BitAnd<T, Output = T>
+        + core::ops::BitOr<Output = T>
+        + PartialEq
+        + Clone
+        + From<u8>
+        + Copy
+        + Default,
+{
+    // No compile-time element count to multiply by, unlike `ArrayBitBucket`
+    // -- real capacity depends on the runtime length of the wrapped slice,
+    // which `capacity`/`capacity_bits` (overridden below) report instead.
+    // This sentinel only matters to `try_push`'s default body, which this
+    // impl also overrides, so it's never actually consulted.
+    const CAPACITY: usize = usize::MAX;
+
+    fn capacity(&self) -> usize {
+        self.0.len() * core::mem::size_of::<T>() * 8
+    }
+
+    fn capacity_bits(&self) -> Option<usize> {
+        Some(self.capacity())
+    }
+
+    fn try_push(&mut self, bit: bool, used: usize) -> Result<(), BitStackOverflow> {
+        if used >= self.capacity() {
+            return Err(BitStackOverflow);
         }
-        // Note: carry from leftmost element is discarded (overflow)
+        self.push(bit);
+        Ok(())
+    }
+
+    fn push(&mut self, bit: bool) {
+        elements::push(self.0, bit);
     }
 
     fn pop(&mut self) -> bool {
-        // Safely get the last element, returning false if N is 0.
-        let bit = if let Some(last_element) = self.0.get(N.saturating_sub(1)) {
-            (*last_element & T::from(1)) != T::from(0)
-        } else {
-            return false;
-        };
+        elements::pop(self.0)
+    }
 
-        // Shift all elements right, carrying underflow from left to right
-        let mut carry = T::from(0);
-        let element_bits = (core::mem::size_of::<T>() * 8) as u8;
-        let msb_shift = element_bits.saturating_sub(1);
+    fn top(&self) -> bool {
+        elements::top(self.0)
+    }
+}
 
-        // Start from the leftmost (most significant) element and work right
-        for i in 0..N {
-            let old_lsb = if let Some(element) = self.0.get(i) {
-                *element & T::from(1) // Extract LSB that will be lost
-            } else {
-                continue;
-            };
-            if let Some(element_mut) = self.0.get_mut(i) {
-                *element_mut = (*element_mut >> 1u8) | (carry << msb_shift);
+#[cfg(feature = "alloc")]
+mod heap_impl {
+    use super::{BitBucket, BitStackStruct};
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    const WORD_BITS: usize = usize::BITS as usize;
+
+    /// A growable [`BitBucket`] for `alloc` targets, backed by a `Vec<usize>`
+    /// of words plus a bit count, so nesting depth is limited only by
+    /// available memory rather than a compile-time `N` the way
+    /// [`super::ArrayBitBucket`] is.
+    ///
+    /// Unlike `ArrayBitBucket`'s fixed-width shift-register approach (forced
+    /// to discard the oldest bit once full, since there's nowhere else to
+    /// put it), a growable backing store can just append a new word on
+    /// demand, so `push`/`pop` here grow/shrink `words` by one element
+    /// exactly when `len` crosses a word boundary, rather than shifting
+    /// every existing bit on every call.
+    #[derive(Debug, Default)]
+    pub struct HeapBitBucket {
+        words: Vec<usize>,
+        len: usize,
+    }
+
+    impl BitBucket for HeapBitBucket {
+        // Bounded only by available memory, not a fixed bit width; `usize::MAX`
+        // is the "no real limit" value `try_push`'s default `used >= CAPACITY`
+        // check needs to effectively never reject a push on this impl.
+        const CAPACITY: usize = usize::MAX;
+
+        // Honest answer, unlike `CAPACITY`/`capacity()` above: this bucket
+        // has no real ceiling at all (short of exhausting memory), so
+        // callers asking specifically via `capacity_bits` -- rather than
+        // the legacy `capacity()` -- get `None` instead of the `usize::MAX`
+        // sentinel.
+        fn capacity_bits(&self) -> Option<usize> {
+            None
+        }
+
+        fn push(&mut self, bit: bool) {
+            let bit_index = self.len % WORD_BITS;
+            if bit_index == 0 {
+                self.words.push(0);
             }
-            carry = old_lsb;
+            if bit {
+                // `expect`: the `bit_index == 0` branch above just pushed a
+                // word if `words` didn't already have room for this bit.
+                let word = self.words.last_mut().expect("word just ensured");
+                *word |= 1usize << bit_index;
+            }
+            self.len += 1;
         }
 
-        bit
+        fn pop(&mut self) -> bool {
+            if self.len == 0 {
+                return false;
+            }
+            self.len -= 1;
+            let bit_index = self.len % WORD_BITS;
+            // `expect`: `len` only ever indexes into a word `push` already
+            // allocated for it.
+            let word = *self.words.last().expect("word for len");
+            let bit = (word >> bit_index) & 1 != 0;
+            if bit_index == 0 {
+                self.words.pop();
+            }
+            bit
+        }
+
+        fn top(&self) -> bool {
+            if self.len == 0 {
+                return false;
+            }
+            let bit_index = (self.len - 1) % WORD_BITS;
+            let word = *self.words.last().expect("word for len");
+            (word >> bit_index) & 1 != 0
+        }
     }
 
-    fn top(&self) -> bool {
-        // Safely get the last element, returning false if N is 0.
-        if let Some(last_element) = self.0.get(N.saturating_sub(1)) {
-            (*last_element & T::from(1)) != T::from(0)
-        } else {
-            false
+    impl HeapBitBucket {
+        /// Number of bits currently pushed.
+        ///
+        /// Not on [`BitBucket`] itself: that trait's doc comment already
+        /// rules out a general `len`, since a fixed-width bucket can't tell
+        /// a stored `false` apart from a never-pushed bit. `HeapBitBucket`
+        /// is the exception -- it grows `words` on demand, so it already has
+        /// to track real usage (`self.len`) to know where the next push/pop
+        /// belongs, unlike `ArrayBitBucket`'s always-fully-allocated array --
+        /// so exposing it here is just reading back state this bucket
+        /// already keeps, not adding new tracking the trait's design
+        /// deliberately leaves to the caller.
+        pub fn len_bits(&self) -> usize {
+            self.len
         }
     }
+
+    /// [`super::BitStackConfig`] pairing [`HeapBitBucket`] with a caller-chosen
+    /// [`super::DepthCounter`] `D`, the `alloc` counterpart to [`super::ArrayBitStack`].
+    ///
+    /// Example use:
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// # use picojson::{SliceParser, HeapBitStack};
+    /// let parser = SliceParser::<HeapBitStack<u32>>::with_config("{}");
+    /// # }
+    /// ```
+    ///
+    /// [`super::BitStackConfig`] itself comes for free: [`BitStackStruct`]
+    /// already has a blanket impl for any `B: BitBucket + Default` paired
+    /// with any `C: super::DepthCounter + Default`, which [`HeapBitBucket`]
+    /// satisfies like every other `BitBucket`.
+    pub type HeapBitStack<D> = BitStackStruct<HeapBitBucket, D>;
 }
+#[cfg(feature = "alloc")]
+pub use heap_impl::{HeapBitBucket, HeapBitStack};
 
 #[cfg(test)]
 mod tests {
@@ -306,6 +604,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_slice_bitstack_push_pop_matches_array_bitstack() {
+        // Same pattern as test_array_bitstack_large_capacity, but over a
+        // runtime-sized slice instead of a const-generic array.
+        let mut storage = [0u32; 10];
+        let mut bitstack = SliceBitBucket::new(&mut storage);
+
+        let pattern = [true, false, true, true, false, false, true, false];
+        for &bit in &pattern {
+            bitstack.push(bit);
+        }
+        assert!(bitstack.top());
+
+        for &expected in pattern.iter().rev() {
+            assert_eq!(bitstack.pop(), expected);
+        }
+    }
+
+    #[test]
+    fn test_slice_bitstack_new_zero_initializes_storage() {
+        // A slice handed in with leftover non-zero bits must still start
+        // out as an empty bucket (all-false), since callers are expected
+        // to carve this out of a reused scratch arena.
+        let mut storage = [0xFFu8; 2];
+        let mut bitstack = SliceBitBucket::new(&mut storage);
+        assert!(!bitstack.pop());
+        assert_eq!(storage, [0u8; 2]);
+    }
+
+    #[test]
+    fn test_slice_bitstack_capacity_tracks_slice_length() {
+        let mut storage = [0u8; 2];
+        let bitstack = SliceBitBucket::new(&mut storage);
+        assert_eq!(bitstack.capacity(), 16);
+        assert_eq!(bitstack.capacity_bits(), Some(16));
+    }
+
+    #[test]
+    fn test_slice_bitstack_try_push_rejects_once_capacity_reached() {
+        let mut storage = [0u8; 2];
+        let mut bitstack = SliceBitBucket::new(&mut storage);
+        for used in 0..16 {
+            assert_eq!(bitstack.try_push(true, used), Ok(()));
+        }
+        assert_eq!(bitstack.try_push(true, 16), Err(BitStackOverflow));
+    }
+
+    #[test]
+    fn test_try_push_rejects_once_capacity_reached() {
+        // u8 has 8 bits of capacity; try_push must refuse the 9th.
+        let mut bitstack: u8 = 0;
+        assert_eq!(<u8 as BitBucket>::CAPACITY, 8);
+        for used in 0..8 {
+            assert_eq!(bitstack.try_push(used % 2 == 0, used), Ok(()));
+        }
+        assert_eq!(bitstack.try_push(true, 8), Err(BitStackOverflow));
+
+        let mut array_bitstack: ArrayBitBucket<2, u8> = ArrayBitBucket::default();
+        assert_eq!(<ArrayBitBucket<2, u8> as BitBucket>::CAPACITY, 16);
+        for used in 0..16 {
+            assert_eq!(array_bitstack.try_push(true, used), Ok(()));
+        }
+        assert_eq!(array_bitstack.try_push(true, 16), Err(BitStackOverflow));
+    }
+
+    #[test]
+    fn test_capacity_instance_method_matches_associated_const() {
+        let bitstack: u8 = 0;
+        assert_eq!(bitstack.capacity(), <u8 as BitBucket>::CAPACITY);
+
+        let array_bitstack: ArrayBitBucket<2, u8> = ArrayBitBucket::default();
+        assert_eq!(
+            array_bitstack.capacity(),
+            <ArrayBitBucket<2, u8> as BitBucket>::CAPACITY
+        );
+    }
+
     #[test]
     fn test_array_bitstack_basic_moved() {
         // Test ArrayBitStack with 2 u8 elements (16-bit total capacity)
@@ -402,4 +777,72 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_heap_bitstack_lifo_order() {
+        let mut bitstack = HeapBitBucket::default();
+        let pattern = [true, false, true, true, false, false, true, false];
+        for &bit in &pattern {
+            bitstack.push(bit);
+        }
+        assert!(bitstack.top());
+        for &expected in pattern.iter().rev() {
+            assert_eq!(bitstack.pop(), expected);
+        }
+        assert!(!bitstack.pop(), "Empty stack returns false");
+        assert!(!bitstack.top(), "Empty stack top() returns false");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_heap_bitstack_grows_past_a_single_word() {
+        // usize::BITS bits fit in one word; push enough to force a second.
+        let mut bitstack = HeapBitBucket::default();
+        let total = usize::BITS as usize + 3;
+        for i in 0..total {
+            bitstack.push(i % 2 == 0);
+        }
+        for i in (0..total).rev() {
+            assert_eq!(bitstack.pop(), i % 2 == 0);
+        }
+        assert!(!bitstack.pop());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_heap_bitstack_try_push_never_rejects() {
+        let mut bitstack = HeapBitBucket::default();
+        for used in 0..(usize::BITS as usize * 4) {
+            assert_eq!(bitstack.try_push(true, used), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_capacity_bits_matches_capacity_for_fixed_buckets() {
+        let bitstack: u8 = 0;
+        assert_eq!(bitstack.capacity_bits(), Some(8));
+
+        let array_bitstack: ArrayBitBucket<2, u8> = ArrayBitBucket::default();
+        assert_eq!(array_bitstack.capacity_bits(), Some(16));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_heap_bitstack_capacity_bits_is_unbounded() {
+        let bitstack = HeapBitBucket::default();
+        assert_eq!(bitstack.capacity_bits(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_heap_bitstack_len_bits_tracks_pushes_and_pops() {
+        let mut bitstack = HeapBitBucket::default();
+        assert_eq!(bitstack.len_bits(), 0);
+        bitstack.push(true);
+        bitstack.push(false);
+        assert_eq!(bitstack.len_bits(), 2);
+        bitstack.pop();
+        assert_eq!(bitstack.len_bits(), 1);
+    }
 }
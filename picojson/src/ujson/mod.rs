@@ -11,7 +11,22 @@ pub use bitstack::BitStackConfig;
 pub use bitstack::BitStackStruct;
 pub use bitstack::DefaultConfig;
 pub use bitstack::DepthCounter;
+#[cfg(feature = "alloc")]
+pub use bitstack::{HeapBitBucket, HeapBitStack};
 
+// `Tokenizer`/`Event`/`EventToken` stay `pub(super)` -- internal plumbing
+// shared by `SliceParser`/`StreamParser`/`PushParser`'s common `ParserCore`,
+// not a second public API surface. `Event`/`EventToken` encode the raw
+// tokenizer's own state-machine vocabulary (e.g. `NumberAndArray`, a single
+// token standing in for "number immediately followed by `]`"), which is
+// shaped around what's convenient for `parse_chunk`'s internal resumption
+// logic rather than something worth committing to as a stable, documented
+// token stream for external consumers. A caller after token-level detail
+// -- raw spans, individual escape sequences, inter-token whitespace -- can
+// already get it from the existing `Event` (`crate::shared::Event`) API's
+// `ContentSpan`/`PartialContentSpanEnd`/`Whitespace` variants and
+// `next_event_with_span`, without this crate maintaining two differently-
+// shaped event vocabularies indefinitely.
 pub(super) use tokenizer::Tokenizer;
 
 pub use tokenizer::Error;
@@ -3,6 +3,78 @@
 use super::BitBucket;
 use super::DepthCounter;
 
+// Byte classification flags, ORed together per entry in `ENCODINGS` (as in
+// RON's parser) so the dozens of `b'0'..=b'9'`-style range matches below
+// collapse into table lookups instead of repeated range comparisons.
+const DIGIT: u8 = 1 << 0;
+const HEX: u8 = 1 << 1;
+const WHITESPACE: u8 = 1 << 2;
+const NUMBER_START: u8 = 1 << 3; // '1'..='9': a non-zero digit may start a number
+const EXP_MARKER: u8 = 1 << 4; // 'e' / 'E'
+
+const fn classify(b: u8) -> u8 {
+    match b {
+        b'0' => DIGIT,
+        b'1'..=b'9' => DIGIT | NUMBER_START,
+        b'a'..=b'd' | b'A'..=b'D' | b'f' | b'F' => HEX,
+        b'e' | b'E' => HEX | EXP_MARKER,
+        b' ' | b'\t' | b'\n' | b'\r' => WHITESPACE,
+        _ => 0,
+    }
+}
+
+const fn build_encodings() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    // `for` isn't available in const fn on our MSRV; index explicitly instead.
+    let mut b = 0usize;
+    while b < 256 {
+        table[b] = classify(b as u8);
+        b += 1;
+    }
+    table
+}
+
+/// Classification table: entry `b` ORs together the [`DIGIT`], [`HEX`],
+/// [`WHITESPACE`], [`NUMBER_START`], and [`EXP_MARKER`] flags that apply to
+/// byte `b`. Bytes `>= 0x80` always map to `0`, so multi-byte UTF-8 and
+/// invalid bytes fall through to the same rejection paths as any other
+/// unclassified byte.
+const ENCODINGS: [u8; 256] = build_encodings();
+
+#[inline]
+const fn is_digit(b: u8) -> bool {
+    ENCODINGS[b as usize] & DIGIT != 0
+}
+
+#[inline]
+const fn is_hex(b: u8) -> bool {
+    ENCODINGS[b as usize] & (DIGIT | HEX) != 0
+}
+
+#[inline]
+const fn is_whitespace(b: u8) -> bool {
+    ENCODINGS[b as usize] & WHITESPACE != 0
+}
+
+#[inline]
+const fn is_number_start(b: u8) -> bool {
+    ENCODINGS[b as usize] & NUMBER_START != 0
+}
+
+#[inline]
+const fn is_exp_marker(b: u8) -> bool {
+    ENCODINGS[b as usize] & EXP_MARKER != 0
+}
+
+/// How many nesting levels [`ParseContext`] remembers the opening byte
+/// offset for, independent of `T::CAPACITY`. Chosen to match
+/// [`DefaultConfig`](crate::DefaultConfig)'s `u32` bit bucket -- the common
+/// case never overflows this -- so a config built on a wider bucket (e.g.
+/// `ArrayBitBucket`) just stops growing its offset tracking past this point
+/// rather than needing an allocation to match its own, possibly much
+/// larger, depth limit.
+const OPEN_OFFSET_CAPACITY: usize = 32;
+
 #[derive(Debug, Clone)]
 struct ParseContext<T: BitBucket, D> {
     /// Keeps track of the depth of the object/array
@@ -11,6 +83,15 @@ struct ParseContext<T: BitBucket, D> {
     stack: T,
     /// Keeps track of the last comma and its position
     after_comma: Option<(u8, usize)>,
+    /// Byte offset of the `{`/`[` that opened each of the innermost
+    /// [`OPEN_OFFSET_CAPACITY`] still-open containers, so an "unterminated
+    /// container" error can point back at where it was opened rather than
+    /// just where input ran out. Parallel to `stack`/`depth` but capped at
+    /// a fixed size instead of generic over `T`, since it's a diagnostic
+    /// aid rather than something parsing correctness depends on.
+    open_offsets: [usize; OPEN_OFFSET_CAPACITY],
+    /// Number of valid entries at the start of `open_offsets`.
+    open_offsets_len: usize,
 }
 
 impl<T: BitBucket, D: DepthCounter> ParseContext<T, D> {
@@ -19,15 +100,38 @@ impl<T: BitBucket, D: DepthCounter> ParseContext<T, D> {
             depth: D::zero(),
             stack: T::default(),
             after_comma: None,
+            open_offsets: [0; OPEN_OFFSET_CAPACITY],
+            open_offsets_len: 0,
+        }
+    }
+    fn push_open_offset(&mut self, pos: usize) {
+        if self.open_offsets_len < OPEN_OFFSET_CAPACITY {
+            self.open_offsets[self.open_offsets_len] = pos;
+            self.open_offsets_len += 1;
+        }
+    }
+    fn pop_open_offset(&mut self) {
+        if self.open_offsets_len > 0 {
+            self.open_offsets_len -= 1;
+        }
+    }
+    /// Byte offset of the innermost still-open `{`/`[`, for reporting
+    /// alongside an unterminated-container error. `None` once nesting runs
+    /// deeper than [`OPEN_OFFSET_CAPACITY`] -- see its doc comment.
+    fn innermost_open_offset(&self) -> Option<usize> {
+        if self.open_offsets_len == 0 {
+            return None;
         }
+        Some(self.open_offsets[self.open_offsets_len - 1])
     }
     fn enter_object(&mut self, data: u8, pos: usize) -> Result<(), Error> {
+        let used = self.depth.as_usize();
         let (new_depth, overflow) = self.depth.increment();
-        if overflow {
+        if overflow || self.stack.try_push(true, used).is_err() {
             return Error::new(ErrKind::MaxDepthReached, data, pos);
         }
-        self.stack.push(true);
         self.depth = new_depth;
+        self.push_open_offset(pos);
         Ok(())
     }
     fn exit_object(&mut self, pos: usize) -> Result<(), Error> {
@@ -37,15 +141,17 @@ impl<T: BitBucket, D: DepthCounter> ParseContext<T, D> {
         self.stack.pop();
         let (new_depth, _underflow) = self.depth.decrement();
         self.depth = new_depth;
+        self.pop_open_offset();
         Ok(())
     }
     fn enter_array(&mut self, data: u8, pos: usize) -> Result<(), Error> {
+        let used = self.depth.as_usize();
         let (new_depth, overflow) = self.depth.increment();
-        if overflow {
+        if overflow || self.stack.try_push(false, used).is_err() {
             return Error::new(ErrKind::MaxDepthReached, data, pos);
         }
-        self.stack.push(false);
         self.depth = new_depth;
+        self.push_open_offset(pos);
         Ok(())
     }
     fn exit_array(&mut self, pos: usize) -> Result<(), Error> {
@@ -55,6 +161,7 @@ impl<T: BitBucket, D: DepthCounter> ParseContext<T, D> {
         self.stack.pop();
         let (new_depth, _underflow) = self.depth.decrement();
         self.depth = new_depth;
+        self.pop_open_offset();
         Ok(())
     }
     fn is_object(&self) -> bool {
@@ -80,6 +187,35 @@ enum State {
     Object { expect: Object },
     Array { expect: Array },
     Finished,
+    // Lenient-mode comment states (see `Tokenizer::set_lenient_syntax`). Each
+    // carries where to resume once the comment ends, since a comment can
+    // appear anywhere whitespace can. `start` remembers the position of the
+    // opening `/` so `Event::Begin(EventToken::Comment)` can be emitted at
+    // the right place once the second byte confirms it's really a comment.
+    MaybeComment { resume: CommentResume, start: usize },
+    LineComment { resume: CommentResume },
+    BlockComment { resume: CommentResume },
+    BlockCommentStar { resume: CommentResume },
+}
+
+/// Where to resume parsing once a lenient-mode comment ends. A plain `State`
+/// can't be stored here (it would make `State` infinitely recursive), so
+/// this captures just the handful of shapes a comment can interrupt.
+#[derive(Debug, Clone)]
+enum CommentResume {
+    Idle,
+    Finished,
+    Object(Object),
+    Array(Array),
+}
+
+fn resume_state(resume: CommentResume) -> State {
+    match resume {
+        CommentResume::Idle => State::Idle,
+        CommentResume::Finished => State::Finished,
+        CommentResume::Object(expect) => State::Object { expect },
+        CommentResume::Array(expect) => State::Array { expect },
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -102,6 +238,24 @@ enum Num {
     Exponent,
     ExponentSign,
     AfterExponent,
+    /// Lenient-mode-only: `0x`/`0X` seen, no hex digit yet.
+    HexPrefix,
+    /// Lenient-mode-only: at least one hex digit consumed.
+    Hex,
+    /// Lenient-mode-only: a `-` was followed by `I`, so `Infinity` is being
+    /// matched the same way [`process_token_char`] matches `true`/`false`/
+    /// `null`, just nested inside the `Number` span the `-` already opened.
+    NegInfinity(TokenProgress),
+    /// Lenient-mode-only: `-Infinity` fully matched, waiting for the byte
+    /// that ends the number (same role as [`Num::AfterExponent`] etc.).
+    AfterNegInfinity,
+    /// Lenient-mode-only: a digit-group `_` just seen in the integer part
+    /// (e.g. `1_`), a digit must follow or the number is invalid.
+    BeforeDecimalPointUnderscore,
+    /// Lenient-mode-only: a digit-group `_` just seen in the fraction part.
+    AfterDecimalPointUnderscore,
+    /// Lenient-mode-only: a digit-group `_` just seen in the exponent.
+    AfterExponentUnderscore,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -109,6 +263,10 @@ enum TokenType {
     True,
     False,
     Null,
+    /// Lenient-mode-only: see [`Tokenizer::set_lenient_syntax`].
+    Infinity,
+    /// Lenient-mode-only: see [`Tokenizer::set_lenient_syntax`].
+    NaN,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -122,6 +280,8 @@ enum Token {
     True(TokenProgress),
     False(TokenProgress),
     Null(TokenProgress),
+    Infinity(TokenProgress),
+    NaN(TokenProgress),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -143,11 +303,26 @@ pub enum EventToken {
     True,
     False,
     Null,
+    // Lenient-mode-only: see `Tokenizer::set_lenient_syntax`. `Infinity` also
+    // appears nested inside a `Number` span for the signed `-Infinity` form,
+    // the same way `NumberInteger` et al. do.
+    Infinity,
+    NaN,
     String,
     Key,
     Number,
     NumberAndArray,  // used for closing arrays after numbers
     NumberAndObject, // used for closing objects after numbers
+    // Granular number components, emitted only when
+    // [`Tokenizer::set_number_component_events`] is enabled -- see the note
+    // there for why these exist alongside the coarse `Number` above.
+    NumberSign,
+    NumberInteger,
+    NumberFraction,
+    NumberExponent,
+    // Spans a whole `//` or `/* */` comment, emitted only in lenient mode --
+    // see `Tokenizer::set_lenient_syntax`.
+    Comment,
     UnicodeEscape,
     EscapeSequence, // emitted when \ is encountered (start of any escape)
     // Simple escape sequences
@@ -161,9 +336,6 @@ pub enum EventToken {
     EscapeTab,            // \t
 }
 
-// todo: expose number events: sign, decimal, fraction, exponent
-// update when a part of number has finished tokenizing ?
-
 #[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     Begin(EventToken),
@@ -176,19 +348,70 @@ pub enum Event {
     Uninitialized,
 }
 
+#[derive(Clone)]
 pub struct Tokenizer<T: BitBucket = u32, D = u8> {
     state: State,
     total_consumed: usize,
     context: ParseContext<T, D>,
+    /// Set via [`Self::set_number_component_events`]. Not yet threaded
+    /// through to the crate's public `Event` type (see `shared::Event`) --
+    /// same "internal plumbing for now" situation as the
+    /// `assembler`/`reassembler` modules noted in `lib.rs`.
+    number_component_events: bool,
+    /// Set via [`Self::set_lenient_syntax`]. Enables the JSON5-like
+    /// relaxations: comments, a trailing comma before `]`/`}`, and
+    /// single-quoted strings.
+    lenient_syntax: bool,
+    /// The quote byte (`"` or, in lenient mode, `'`) that opened the string
+    /// currently being parsed, so the closing quote can be matched against
+    /// it. Strings never nest, so one field is enough to track this.
+    string_quote: u8,
+    /// 1-based line number of the byte about to be processed. Advances on
+    /// every `\n` consumed by [`Self::parse_chunk`], and is carried across
+    /// chunks as a `Tokenizer` field (rather than a `parse_chunk_inner`
+    /// local) so it stays correct for fragments split mid-line.
+    line: usize,
+    /// 0-based column of the byte about to be processed, following
+    /// proc-macro2's `LineColumn` convention. Resets to `0` whenever `line`
+    /// advances.
+    column: usize,
 }
 
-#[derive(PartialEq)]
 pub struct Error {
     kind: ErrKind,
     character: u8,
     position: usize,
+    line: usize,
+    column: usize,
+    open_container_offset: Option<usize>,
+}
+
+impl PartialEq for Error {
+    // `line`/`column`/`open_container_offset` are all filled in after the
+    // fact (see `with_line_col`/`with_open_container_offset`) rather than
+    // known at the `Error::new` call site, so equality ignores them --
+    // tests compare against bare `Error::new(...)` values that don't set
+    // any of the three, same as it ignored `line`/`column` before this
+    // field existed.
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.character == other.character
+            && self.position == other.position
+    }
 }
 
+/// What went wrong during tokenizing.
+///
+/// `ExpectedObjectKey`/`ExpectedObjectValue`/`ExpectedColon`/
+/// `ExpectedArrayItem` are this crate's answer to "structured expected-token
+/// errors": each already names the one token class the grammar was in a
+/// position to accept, which is the same information a generic
+/// `found`/`expected: bitflags` pair would carry, just spelled as distinct
+/// enum variants instead of a reusable bitflag type. A new variant here is
+/// one match arm away from a description and a caller matching on
+/// `self.kind`, same as every other error, so there's no separate type to
+/// keep in sync. Pair with [`Error::open_container_offset`] for where the
+/// enclosing `{`/`[` (if any) was opened.
 #[derive(PartialEq, Debug)]
 pub enum ErrKind {
     EmptyStream,
@@ -208,6 +431,8 @@ pub enum ErrKind {
     ExpectedObjectValue,
     ExpectedColon,
     ExpectedArrayItem,
+    InvalidComment,
+    UnterminatedComment,
 }
 
 impl Error {
@@ -216,20 +441,127 @@ impl Error {
             kind,
             character,
             position,
+            line: 0,
+            column: 0,
+            open_container_offset: None,
         })
     }
+
+    /// Fills in the line/column the error was raised at. Called once, at the
+    /// point where an error returned by `parse_chunk_inner` is about to
+    /// leave the tokenizer, using the `line`/`column` counters tracked on
+    /// `Tokenizer` -- `Error::new` itself has no access to them.
+    fn with_line_col(mut self, line: usize, column: usize) -> Self {
+        self.line = line;
+        self.column = column;
+        self
+    }
+
+    /// Fills in the byte offset of the innermost `{`/`[` that was still
+    /// open when this error was raised, if any was tracked -- see
+    /// [`ParseContext::innermost_open_offset`]. Same after-the-fact-builder
+    /// shape as `with_line_col`, since `Error::new` is called from deep
+    /// inside `parse_chunk_inner` without a `ParseContext` to hand.
+    fn with_open_container_offset(mut self, offset: Option<usize>) -> Self {
+        self.open_container_offset = offset;
+        self
+    }
+
+    /// Byte offset of the innermost `{`/`[` that was still open when this
+    /// error was raised, for pointing a diagnostic back at where an
+    /// unterminated container began. `None` when no container was open, or
+    /// when nesting ran deeper than this error's source tracks offsets for.
+    pub fn open_container_offset(&self) -> Option<usize> {
+        self.open_container_offset
+    }
+
+    /// The byte offset the error was raised at, counted from the start of
+    /// the document across all `parse_chunk` calls.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The 1-based line and 0-based column the error was raised at.
+    pub fn line_col(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    /// Whether this error means the document ran out of input while a
+    /// token or container was still open, rather than the input actually
+    /// being malformed -- i.e. it only ever comes from [`Self::finish`]
+    /// observing leftover open state, never from [`Self::parse_chunk`]
+    /// itself (which, per its own doc comment, never errors on running out
+    /// of bytes mid-token). Feeding more bytes and re-parsing from scratch
+    /// could turn this into a successful parse; every other `ErrKind` is a
+    /// genuine syntax error no amount of additional input would fix.
+    pub fn is_incomplete(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrKind::UnfinishedStream | ErrKind::UnterminatedComment
+        )
+    }
 }
 
 impl core::fmt::Debug for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "{:?}({}) at {}",
-            self.kind, self.character as char, self.position
-        )
+            "{:?}({}) at {} (line {}, column {})",
+            self.kind, self.character as char, self.position, self.line, self.column
+        )?;
+        if let Some(offset) = self.open_container_offset {
+            write!(f, ", container opened at {offset}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ErrKind {
+    const fn description(&self) -> &'static str {
+        match self {
+            ErrKind::EmptyStream => "input was empty",
+            ErrKind::UnfinishedStream => "input ended before the document was complete",
+            ErrKind::InvalidRoot => "document did not start with a valid JSON value",
+            ErrKind::InvalidToken => "unrecognized token",
+            ErrKind::UnescapedControlCharacter => "unescaped control character in a string",
+            ErrKind::TrailingComma => "trailing comma before a closing bracket",
+            ErrKind::ContentEnded => "unexpected byte after the document's value ended",
+            ErrKind::UnopenedArray => "array-closing `]` with no matching `[`",
+            ErrKind::UnopenedObject => "object-closing `}` with no matching `{`",
+            ErrKind::MaxDepthReached => "container nesting exceeded the tokenizer's depth limit",
+            ErrKind::InvalidNumber => "malformed number literal",
+            ErrKind::InvalidUnicodeEscape => "malformed `\\uXXXX` escape",
+            ErrKind::InvalidStringEscape => "unrecognized `\\` escape character",
+            ErrKind::ExpectedObjectKey => "expected an object key",
+            ErrKind::ExpectedObjectValue => "expected a value after `:`",
+            ErrKind::ExpectedColon => "expected `:` after an object key",
+            ErrKind::ExpectedArrayItem => "expected an array item after `,`",
+            ErrKind::InvalidComment => "'/' not followed by '/' or '*' to start a comment",
+            ErrKind::UnterminatedComment => "block comment opened with `/*` was never closed with `*/`",
+        }
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} ({:?} at line {}, column {}, byte {})",
+            self.kind.description(),
+            self.character as char,
+            self.line,
+            self.column,
+            self.position
+        )?;
+        if let Some(offset) = self.open_container_offset {
+            write!(f, ", container opened at byte {offset}")?;
+        }
+        Ok(())
     }
 }
 
+impl core::error::Error for Error {}
+
 impl Default for Tokenizer {
     fn default() -> Self {
         Self::new()
@@ -242,6 +574,8 @@ impl TokenType {
             TokenType::True => b"true",
             TokenType::False => b"false",
             TokenType::Null => b"null",
+            TokenType::Infinity => b"Infinity",
+            TokenType::NaN => b"NaN",
         }
     }
 
@@ -250,6 +584,8 @@ impl TokenType {
             TokenType::True => EventToken::True,
             TokenType::False => EventToken::False,
             TokenType::Null => EventToken::Null,
+            TokenType::Infinity => EventToken::Infinity,
+            TokenType::NaN => EventToken::NaN,
         }
     }
 }
@@ -288,19 +624,47 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
             state: State::Idle,
             total_consumed: 0,
             context: ParseContext::new(),
+            number_component_events: false,
+            lenient_syntax: false,
+            string_quote: b'"',
+            line: 1,
+            column: 0,
         }
     }
 
+    /// Enables (or disables) granular `Begin`/`End` events for a number's
+    /// sign, integer, fraction, and exponent parts (the `Number*` variants
+    /// of [`EventToken`]), alongside the existing `Begin(Number)`/
+    /// `End(Number)` pair. Off by default, so callers who only need the
+    /// coarse span pay nothing extra.
+    pub fn set_number_component_events(&mut self, enabled: bool) {
+        self.number_component_events = enabled;
+    }
+
+    /// Enables (or disables) a JSON5-like relaxed syntax, similar to the
+    /// extension system in RON and the comment handling in TOML/winnow
+    /// parsers: `//` line comments and `/* */` block comments are allowed
+    /// anywhere whitespace is legal (spanned by `Event::Begin`/
+    /// `End(EventToken::Comment)`), a trailing comma before `]`/`}` no
+    /// longer raises [`ErrKind::TrailingComma`], strings/keys may be
+    /// delimited with `'` as well as `"`, and numbers may additionally use
+    /// `_` digit-group separators (`1_000`), a `0x`/`0X` hexadecimal integer
+    /// form (`0x1A`), and the literals `Infinity`/`-Infinity`/`NaN`. Off by
+    /// default, so strict mode parses exactly as today.
+    pub fn set_lenient_syntax(&mut self, enabled: bool) {
+        self.lenient_syntax = enabled;
+    }
+
     fn check_trailing_comma(&mut self, data: u8) -> Result<(), Error> {
         // Check for trailing comma if we're at a closing bracket/brace
         if let Some((c, pos)) = self.context.after_comma {
-            if data == b']' || data == b'}' {
+            if (data == b']' || data == b'}') && !self.lenient_syntax {
                 return Error::new(ErrKind::TrailingComma, c, pos);
             }
         }
 
         // Only reset after_comma for non-whitespace characters
-        if !matches!(data, b' ' | b'\t' | b'\n' | b'\r') {
+        if !is_whitespace(data) {
             self.context.after_comma = None;
         }
         Ok(())
@@ -316,39 +680,111 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
         self.finish(callback)
     }
 
-    pub fn finish<F>(&mut self, callback: &mut F) -> Result<usize, Error>
+    /// Returns true once a complete top-level value has been parsed and the
+    /// tokenizer is idle at zero nesting depth, waiting for either `finish()`
+    /// or a reset to start a new document.
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, State::Finished)
+    }
+
+    /// Resets the tokenizer to parse another top-level value, for
+    /// multi-document (NDJSON-style) streaming. Only valid to call when
+    /// [`Self::is_finished`] is true; nesting depth is already zero at that
+    /// point, so no other state needs resetting.
+    ///
+    /// This is the primitive every parser front-end's own NDJSON mode is
+    /// already built on -- `SliceParser::new_ndjson`/`StreamParser::new_ndjson`
+    /// (and the `with_buffer`/chunked-reader equivalents on both, plus
+    /// `PushParser`, `FeedParser`, `PollParser`, and `AsyncStreamParser`'s
+    /// own streaming constructors) each poll [`Self::is_finished`] right
+    /// after a value completes and call this to resume at `State::Idle`
+    /// without allocating a new `Tokenizer`, emitting a fresh
+    /// `Event::StartDocument`/`Event::EndDocument` pair around the next
+    /// value rather than a dedicated boundary variant -- the existing
+    /// events already say "one value ended, another began" without growing
+    /// the public `Event` enum.
+    pub fn reset_for_next_document(&mut self) {
+        self.state = State::Idle;
+    }
+
+    /// The 1-based line and 0-based column of the next byte [`Self::parse_chunk`]
+    /// will process, tracked across chunks the same way [`Self::is_finished`]'s
+    /// `total_consumed` is. Column is 0-based (proc-macro2's `LineColumn`
+    /// convention) rather than 1-based -- callers wanting a 1-based column
+    /// for display just add one.
+    pub fn line_col(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    pub fn finish<F>(&mut self, mut callback: &mut F) -> Result<usize, Error>
     where
         F: FnMut(Event, usize) + ?Sized,
     {
         // we check that parser was idle, at zero nesting depth
         if !self.context.depth.is_zero() {
-            return Error::new(ErrKind::UnfinishedStream, b' ', self.total_consumed);
+            return Error::new(ErrKind::UnfinishedStream, b' ', self.total_consumed).map_err(|e| {
+                e.with_line_col(self.line, self.column)
+                    .with_open_container_offset(self.context.innermost_open_offset())
+            });
         }
         if self.total_consumed == 0 {
-            return Error::new(ErrKind::EmptyStream, b' ', self.total_consumed);
+            return Error::new(ErrKind::EmptyStream, b' ', self.total_consumed)
+                .map_err(|e| e.with_line_col(self.line, self.column));
         }
 
-        match &self.state {
+        let result = match &self.state {
             State::Finished => Ok(self.total_consumed),
             State::Number {
-                state: Num::LeadingZero,
-            }
-            | State::Number {
-                state: Num::BeforeDecimalPoint,
-            }
-            | State::Number {
-                state: Num::AfterDecimalPoint,
-            }
-            | State::Number {
-                state: Num::AfterExponent,
+                state:
+                    num_state @ (Num::LeadingZero
+                    | Num::BeforeDecimalPoint
+                    | Num::AfterDecimalPoint
+                    | Num::AfterExponent
+                    | Num::Hex
+                    | Num::AfterNegInfinity),
             } => {
+                self.end_open_number_component(num_state, self.total_consumed, &mut callback);
                 callback(Event::End(EventToken::Number), self.total_consumed);
                 Ok(self.total_consumed)
             }
+            // A trailing `//` comment after the top-level value is allowed
+            // to run to EOF without a newline, same as a real line ending.
+            State::LineComment {
+                resume: CommentResume::Finished,
+            } => {
+                callback(Event::End(EventToken::Comment), self.total_consumed);
+                Ok(self.total_consumed)
+            }
+            State::BlockComment { resume: _ } | State::BlockCommentStar { resume: _ } => {
+                Error::new(ErrKind::UnterminatedComment, b' ', self.total_consumed)
+            }
             _ => Error::new(ErrKind::UnfinishedStream, b' ', self.total_consumed),
-        }
-    }
-
+        };
+        result.map_err(|e| e.with_line_col(self.line, self.column))
+    }
+
+    /// Feeds another chunk of input, emitting whatever complete events it
+    /// produces, and holds all in-progress state -- the `BitStack`, the
+    /// current token's `State`, partial number/escape progress -- across
+    /// the call rather than requiring the whole document up front. This is
+    /// already the streaming/complete split nom's `bytes::streaming` vs
+    /// `bytes::complete` draws, just without a separate method or status
+    /// enum for it: a chunk ending mid-token (a `\uXXXX` escape stopped
+    /// after two hex digits, a surrogate pair split between its two `\u`
+    /// halves, a number like the `2` in `[2]` whose `End` is only decided
+    /// by the `]` that hasn't arrived yet) simply leaves `self.state`
+    /// parked there and returns `Ok` with no event for that token, instead
+    /// of erring -- [`Self::parse_chunk`] only ever returns `Err` for a
+    /// byte that's actually invalid, never for running out of bytes mid-token.
+    /// Every chunked front-end (`PushParser`/`FeedParser`/`PollParser`/
+    /// `AsyncStreamParser`, and `StreamParser` reading through
+    /// [`crate::stream_buffer::StreamBuffer`]) already drives this same
+    /// entry point one buffer at a time; [`Self::is_finished`] and
+    /// [`Self::finish`] are the only additional primitives needed to tell
+    /// "done, no more chunks coming" apart from "pause here, more may
+    /// follow". Position offsets (byte, line, column) keep counting across
+    /// calls via [`Self::line_col`] regardless of where a chunk boundary
+    /// falls.
     pub fn parse_chunk<F>(&mut self, data: &[u8], callback: &mut F) -> Result<usize, Error>
     where
         F: FnMut(Event, usize) + ?Sized,
@@ -367,7 +803,9 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
     where
         F: FnMut(Event, usize) + ?Sized,
     {
-        let consumed = self.parse_chunk_inner(data, callback)?;
+        let consumed = self
+            .parse_chunk_inner(data, callback)
+            .map_err(|e| e.with_line_col(self.line, self.column))?;
         self.total_consumed = self.total_consumed.wrapping_add(consumed);
         Ok(consumed)
     }
@@ -421,6 +859,14 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                 callback(Event::Begin(EventToken::Null), pos);
                 TokenType::Null
             }
+            b'I' => {
+                callback(Event::Begin(EventToken::Infinity), pos);
+                TokenType::Infinity
+            }
+            b'N' => {
+                callback(Event::Begin(EventToken::NaN), pos);
+                TokenType::NaN
+            }
             _ => return Error::new(ErrKind::InvalidToken, token, pos),
         };
 
@@ -433,11 +879,73 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
             TokenType::True => Token::True(progress),
             TokenType::False => Token::False(progress),
             TokenType::Null => Token::Null(progress),
+            TokenType::Infinity => Token::Infinity(progress),
+            TokenType::NaN => Token::NaN(progress),
         };
 
         Ok(State::Token { token })
     }
 
+    /// Emits `Begin(token)` for a number component, if
+    /// [`Self::set_number_component_events`] is enabled.
+    fn begin_number_component(
+        &self,
+        token: EventToken,
+        pos: usize,
+        callback: &mut dyn FnMut(Event, usize),
+    ) {
+        if self.number_component_events {
+            callback(Event::Begin(token), pos);
+        }
+    }
+
+    /// Emits `End(token)` for a number component, if
+    /// [`Self::set_number_component_events`] is enabled.
+    fn end_number_component(
+        &self,
+        token: EventToken,
+        pos: usize,
+        callback: &mut dyn FnMut(Event, usize),
+    ) {
+        if self.number_component_events {
+            callback(Event::End(token), pos);
+        }
+    }
+
+    /// Closes whichever number component is still open when a number ends
+    /// -- exactly one of integer, fraction, or exponent, since `Num`'s states
+    /// are mutually exclusive. No-op if [`Self::set_number_component_events`]
+    /// is disabled.
+    fn end_open_number_component(
+        &self,
+        num_state: &Num,
+        pos: usize,
+        callback: &mut dyn FnMut(Event, usize),
+    ) {
+        let token = match num_state {
+            Num::LeadingZero | Num::BeforeDecimalPoint | Num::Hex => EventToken::NumberInteger,
+            Num::AfterDecimalPoint => EventToken::NumberFraction,
+            Num::AfterExponent => EventToken::NumberExponent,
+            // `Infinity`'s component already closed the moment its last
+            // character matched, in the `Num::NegInfinity` arm itself.
+            Num::AfterNegInfinity => return,
+            // Sign/Decimal/Exponent/ExponentSign/HexPrefix/NegInfinity/the
+            // underscore-pending states can't reach a number-ending byte
+            // directly -- their own arms above either advance to one of the
+            // states above or return an error first.
+            Num::Sign
+            | Num::Decimal
+            | Num::Exponent
+            | Num::ExponentSign
+            | Num::HexPrefix
+            | Num::NegInfinity(_)
+            | Num::BeforeDecimalPointUnderscore
+            | Num::AfterDecimalPointUnderscore
+            | Num::AfterExponentUnderscore => return,
+        };
+        self.end_number_component(token, pos, callback);
+    }
+
     fn parse_chunk_inner<F>(&mut self, data: &[u8], mut callback: &mut F) -> Result<usize, Error>
     where
         F: FnMut(Event, usize) + ?Sized,
@@ -453,12 +961,27 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
             }
 
             self.state = match (&self.state, current_byte) {
-                (State::Number { state: Num::Sign }, b'0') => State::Number {
-                    state: Num::LeadingZero,
-                },
-                (State::Number { state: Num::Sign }, b'1'..=b'9') => State::Number {
-                    state: Num::BeforeDecimalPoint,
-                },
+                (State::Number { state: Num::Sign }, b'0') => {
+                    self.begin_number_component(EventToken::NumberInteger, pos, &mut callback);
+                    State::Number {
+                        state: Num::LeadingZero,
+                    }
+                }
+                (State::Number { state: Num::Sign }, b) if is_number_start(b) => {
+                    self.begin_number_component(EventToken::NumberInteger, pos, &mut callback);
+                    State::Number {
+                        state: Num::BeforeDecimalPoint,
+                    }
+                }
+                (State::Number { state: Num::Sign }, b'I') if self.lenient_syntax => {
+                    self.begin_number_component(EventToken::Infinity, pos, &mut callback);
+                    State::Number {
+                        state: Num::NegInfinity(TokenProgress {
+                            token_type: TokenType::Infinity,
+                            position: 1,
+                        }),
+                    }
+                }
                 (State::Number { state: Num::Sign }, _) => {
                     return Error::new(ErrKind::InvalidNumber, current_byte, pos);
                 }
@@ -466,48 +989,113 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     State::Number {
                         state: Num::LeadingZero,
                     },
-                    b'e' | b'E',
-                ) => State::Number {
-                    state: Num::Exponent,
+                    b'x' | b'X',
+                ) if self.lenient_syntax => State::Number {
+                    state: Num::HexPrefix,
                 },
+                (
+                    State::Number {
+                        state: Num::LeadingZero,
+                    },
+                    b,
+                ) if is_exp_marker(b) => {
+                    self.end_number_component(EventToken::NumberInteger, pos, &mut callback);
+                    self.begin_number_component(EventToken::NumberExponent, pos, &mut callback);
+                    State::Number {
+                        state: Num::Exponent,
+                    }
+                }
                 (
                     State::Number {
                         state: Num::LeadingZero,
                     },
                     b'.',
-                ) => State::Number {
-                    state: Num::Decimal,
+                ) => {
+                    self.end_number_component(EventToken::NumberInteger, pos, &mut callback);
+                    self.begin_number_component(EventToken::NumberFraction, pos, &mut callback);
+                    State::Number {
+                        state: Num::Decimal,
+                    }
+                }
+                (
+                    State::Number {
+                        state: Num::HexPrefix,
+                    },
+                    b,
+                ) if is_hex(b) => State::Number { state: Num::Hex },
+                (
+                    State::Number {
+                        state: Num::HexPrefix,
+                    },
+                    _,
+                ) => {
+                    return Error::new(ErrKind::InvalidNumber, current_byte, pos);
+                }
+                (State::Number { state: Num::Hex }, b) if is_hex(b) => {
+                    State::Number { state: Num::Hex }
+                }
+                (
+                    State::Number {
+                        state: Num::BeforeDecimalPoint,
+                    },
+                    b,
+                ) if is_digit(b) => State::Number {
+                    state: Num::BeforeDecimalPoint,
                 },
                 (
                     State::Number {
                         state: Num::BeforeDecimalPoint,
                     },
-                    b'0'..=b'9',
-                ) => State::Number {
+                    b'_',
+                ) if self.lenient_syntax => State::Number {
+                    state: Num::BeforeDecimalPointUnderscore,
+                },
+                (
+                    State::Number {
+                        state: Num::BeforeDecimalPointUnderscore,
+                    },
+                    b,
+                ) if is_digit(b) => State::Number {
                     state: Num::BeforeDecimalPoint,
                 },
+                (
+                    State::Number {
+                        state: Num::BeforeDecimalPointUnderscore,
+                    },
+                    _,
+                ) => {
+                    return Error::new(ErrKind::InvalidNumber, current_byte, pos);
+                }
                 (
                     State::Number {
                         state: Num::BeforeDecimalPoint,
                     },
                     b'.',
-                ) => State::Number {
-                    state: Num::Decimal,
-                },
+                ) => {
+                    self.end_number_component(EventToken::NumberInteger, pos, &mut callback);
+                    self.begin_number_component(EventToken::NumberFraction, pos, &mut callback);
+                    State::Number {
+                        state: Num::Decimal,
+                    }
+                }
                 (
                     State::Number {
                         state: Num::BeforeDecimalPoint,
                     },
-                    b'e' | b'E',
-                ) => State::Number {
-                    state: Num::Exponent,
-                },
+                    b,
+                ) if is_exp_marker(b) => {
+                    self.end_number_component(EventToken::NumberInteger, pos, &mut callback);
+                    self.begin_number_component(EventToken::NumberExponent, pos, &mut callback);
+                    State::Number {
+                        state: Num::Exponent,
+                    }
+                }
                 (
                     State::Number {
                         state: Num::Decimal,
                     },
-                    b'0'..=b'9',
-                ) => State::Number {
+                    b,
+                ) if is_digit(b) => State::Number {
                     state: Num::AfterDecimalPoint,
                 },
                 (
@@ -522,24 +1110,52 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     State::Number {
                         state: Num::AfterDecimalPoint,
                     },
-                    b'0'..=b'9',
-                ) => State::Number {
+                    b,
+                ) if is_digit(b) => State::Number {
                     state: Num::AfterDecimalPoint,
                 },
                 (
                     State::Number {
                         state: Num::AfterDecimalPoint,
                     },
-                    b'e' | b'E',
-                ) => State::Number {
-                    state: Num::Exponent,
+                    b'_',
+                ) if self.lenient_syntax => State::Number {
+                    state: Num::AfterDecimalPointUnderscore,
+                },
+                (
+                    State::Number {
+                        state: Num::AfterDecimalPointUnderscore,
+                    },
+                    b,
+                ) if is_digit(b) => State::Number {
+                    state: Num::AfterDecimalPoint,
                 },
+                (
+                    State::Number {
+                        state: Num::AfterDecimalPointUnderscore,
+                    },
+                    _,
+                ) => {
+                    return Error::new(ErrKind::InvalidNumber, current_byte, pos);
+                }
+                (
+                    State::Number {
+                        state: Num::AfterDecimalPoint,
+                    },
+                    b,
+                ) if is_exp_marker(b) => {
+                    self.end_number_component(EventToken::NumberFraction, pos, &mut callback);
+                    self.begin_number_component(EventToken::NumberExponent, pos, &mut callback);
+                    State::Number {
+                        state: Num::Exponent,
+                    }
+                }
                 (
                     State::Number {
                         state: Num::Exponent,
                     },
-                    b'0'..=b'9',
-                ) => State::Number {
+                    b,
+                ) if is_digit(b) => State::Number {
                     state: Num::AfterExponent,
                 },
                 (
@@ -562,8 +1178,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     State::Number {
                         state: Num::ExponentSign,
                     },
-                    b'0'..=b'9',
-                ) => State::Number {
+                    b,
+                ) if is_digit(b) => State::Number {
                     state: Num::AfterExponent,
                 },
                 (
@@ -578,26 +1194,70 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     State::Number {
                         state: Num::AfterExponent,
                     },
-                    b'0'..=b'9',
-                ) => State::Number {
+                    b,
+                ) if is_digit(b) => State::Number {
+                    state: Num::AfterExponent,
+                },
+                (
+                    State::Number {
+                        state: Num::AfterExponent,
+                    },
+                    b'_',
+                ) if self.lenient_syntax => State::Number {
+                    state: Num::AfterExponentUnderscore,
+                },
+                (
+                    State::Number {
+                        state: Num::AfterExponentUnderscore,
+                    },
+                    b,
+                ) if is_digit(b) => State::Number {
                     state: Num::AfterExponent,
                 },
-                (State::Number { state: _ }, b',') => {
+                (
+                    State::Number {
+                        state: Num::AfterExponentUnderscore,
+                    },
+                    _,
+                ) => {
+                    return Error::new(ErrKind::InvalidNumber, current_byte, pos);
+                }
+                (State::Number { state: Num::NegInfinity(progress) }, current_byte) => {
+                    match process_token_char(progress, current_byte) {
+                        Ok(Some(new_progress)) => State::Number {
+                            state: Num::NegInfinity(new_progress),
+                        },
+                        Ok(None) => {
+                            return Error::new(ErrKind::InvalidNumber, current_byte, pos);
+                        }
+                        Err(event_token) => {
+                            self.end_number_component(event_token, pos, &mut callback);
+                            State::Number {
+                                state: Num::AfterNegInfinity,
+                            }
+                        }
+                    }
+                }
+                (State::Number { state: num_state }, b',') => {
+                    self.end_open_number_component(num_state, pos, &mut callback);
                     callback(Event::End(EventToken::Number), pos);
                     self.context.after_comma = Some((current_byte, pos));
                     self.saw_a_comma_now_what()
                 }
-                (State::Number { state: _ }, b' ' | b'\t' | b'\n' | b'\r') => {
+                (State::Number { state: num_state }, b) if is_whitespace(b) => {
+                    self.end_open_number_component(num_state, pos, &mut callback);
                     callback(Event::End(EventToken::Number), pos);
                     self.maybe_exit_level()
                 }
-                (State::Number { state: _ }, b']') => {
+                (State::Number { state: num_state }, b']') => {
+                    self.end_open_number_component(num_state, pos, &mut callback);
                     callback(Event::End(EventToken::NumberAndArray), pos);
                     callback(Event::ArrayEnd, pos);
                     self.context.exit_array(pos)?;
                     self.maybe_exit_level()
                 }
-                (State::Number { state: _ }, b'}') => {
+                (State::Number { state: num_state }, b'}') => {
+                    self.end_open_number_component(num_state, pos, &mut callback);
                     callback(Event::End(EventToken::NumberAndObject), pos);
                     callback(Event::ObjectEnd, pos);
                     self.context.exit_object(pos)?;
@@ -611,8 +1271,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                         state: String::Normal,
                         key,
                     },
-                    b'"',
-                ) => {
+                    _,
+                ) if current_byte == self.string_quote => {
                     if *key {
                         callback(Event::End(EventToken::Key), pos);
                         State::Object {
@@ -694,8 +1354,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                         state: String::Unicode0,
                         key,
                     },
-                    b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F',
-                ) => {
+                    b,
+                ) if is_hex(b) => {
                     callback(Event::Begin(EventToken::UnicodeEscape), pos);
                     State::String {
                         state: String::Unicode1,
@@ -707,8 +1367,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                         state: String::Unicode1,
                         key,
                     },
-                    b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F',
-                ) => State::String {
+                    b,
+                ) if is_hex(b) => State::String {
                     state: String::Unicode2,
                     key: *key,
                 },
@@ -717,8 +1377,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                         state: String::Unicode2,
                         key,
                     },
-                    b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F',
-                ) => State::String {
+                    b,
+                ) if is_hex(b) => State::String {
                     state: String::Unicode3,
                     key: *key,
                 },
@@ -727,8 +1387,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                         state: String::Unicode3,
                         key,
                     },
-                    b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F',
-                ) => {
+                    b,
+                ) if is_hex(b) => {
                     callback(Event::End(EventToken::UnicodeEscape), pos);
                     State::String {
                         state: String::Normal,
@@ -761,8 +1421,64 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     | State::Object { expect: _ }
                     | State::Array { expect: _ }
                     | State::Finished,
-                    b' ' | b'\t' | b'\n' | b'\r',
-                ) => self.state.clone(),
+                    b,
+                ) if is_whitespace(b) => self.state.clone(),
+                // Lenient-mode comments: a `/` anywhere whitespace is legal
+                // starts one, resuming the interrupted state once it ends.
+                (State::Idle, b'/') if self.lenient_syntax => State::MaybeComment {
+                    resume: CommentResume::Idle,
+                    start: pos,
+                },
+                (State::Finished, b'/') if self.lenient_syntax => State::MaybeComment {
+                    resume: CommentResume::Finished,
+                    start: pos,
+                },
+                (State::Object { expect }, b'/') if self.lenient_syntax => State::MaybeComment {
+                    resume: CommentResume::Object(expect.clone()),
+                    start: pos,
+                },
+                (State::Array { expect }, b'/') if self.lenient_syntax => State::MaybeComment {
+                    resume: CommentResume::Array(expect.clone()),
+                    start: pos,
+                },
+                (State::MaybeComment { resume, start }, b'/') => {
+                    callback(Event::Begin(EventToken::Comment), *start);
+                    State::LineComment {
+                        resume: resume.clone(),
+                    }
+                }
+                (State::MaybeComment { resume, start }, b'*') => {
+                    callback(Event::Begin(EventToken::Comment), *start);
+                    State::BlockComment {
+                        resume: resume.clone(),
+                    }
+                }
+                (State::MaybeComment { resume: _, start: _ }, _) => {
+                    return Error::new(ErrKind::InvalidComment, current_byte, pos);
+                }
+                (State::LineComment { resume }, b'\n') => {
+                    callback(Event::End(EventToken::Comment), pos);
+                    resume_state(resume.clone())
+                }
+                (State::LineComment { resume }, _) => State::LineComment {
+                    resume: resume.clone(),
+                },
+                (State::BlockComment { resume }, b'*') => State::BlockCommentStar {
+                    resume: resume.clone(),
+                },
+                (State::BlockComment { resume }, _) => State::BlockComment {
+                    resume: resume.clone(),
+                },
+                (State::BlockCommentStar { resume }, b'/') => {
+                    callback(Event::End(EventToken::Comment), pos);
+                    resume_state(resume.clone())
+                }
+                (State::BlockCommentStar { resume }, b'*') => State::BlockCommentStar {
+                    resume: resume.clone(),
+                },
+                (State::BlockCommentStar { resume }, _) => State::BlockComment {
+                    resume: resume.clone(),
+                },
                 (
                     State::Idle
                     | State::Object {
@@ -805,6 +1521,7 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     },
                     b'"',
                 ) => {
+                    self.string_quote = b'"';
                     callback(Event::Begin(EventToken::String), pos);
                     State::String {
                         state: String::Normal,
@@ -819,8 +1536,15 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     | State::Array {
                         expect: Array::ItemOrEnd,
                     },
-                    b't' | b'f' | b'n',
-                ) => self.start_token(current_byte, pos, &mut callback)?,
+                    b'\'',
+                ) if self.lenient_syntax => {
+                    self.string_quote = b'\'';
+                    callback(Event::Begin(EventToken::String), pos);
+                    State::String {
+                        state: String::Normal,
+                        key: false,
+                    }
+                }
                 (
                     State::Idle
                     | State::Object {
@@ -829,11 +1553,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     | State::Array {
                         expect: Array::ItemOrEnd,
                     },
-                    b'-', /*| b'+' */
-                ) => {
-                    callback(Event::Begin(EventToken::Number), pos);
-                    State::Number { state: Num::Sign }
-                }
+                    b't' | b'f' | b'n',
+                ) => self.start_token(current_byte, pos, &mut callback)?,
                 (
                     State::Idle
                     | State::Object {
@@ -842,13 +1563,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     | State::Array {
                         expect: Array::ItemOrEnd,
                     },
-                    b'0',
-                ) => {
-                    callback(Event::Begin(EventToken::Number), pos);
-                    State::Number {
-                        state: Num::LeadingZero,
-                    }
-                }
+                    b'I' | b'N',
+                ) if self.lenient_syntax => self.start_token(current_byte, pos, &mut callback)?,
                 (
                     State::Idle
                     | State::Object {
@@ -857,9 +1573,62 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     | State::Array {
                         expect: Array::ItemOrEnd,
                     },
-                    b'1'..=b'9',
+                    b'+',
+                ) if self.lenient_syntax => {
+                    // A leading `+` is a JSON5 extension (strict RFC 8259
+                    // only allows `-`), so it's gated the same way as the
+                    // other `lenient_syntax` arms above, but otherwise
+                    // behaves exactly like `-` below.
+                    callback(Event::Begin(EventToken::Number), pos);
+                    self.begin_number_component(EventToken::NumberSign, pos, &mut callback);
+                    self.end_number_component(EventToken::NumberSign, pos, &mut callback);
+                    State::Number { state: Num::Sign }
+                }
+                (
+                    State::Idle
+                    | State::Object {
+                        expect: Object::Value,
+                    }
+                    | State::Array {
+                        expect: Array::ItemOrEnd,
+                    },
+                    b'-',
+                ) => {
+                    callback(Event::Begin(EventToken::Number), pos);
+                    // The sign is a single byte, so its component span opens
+                    // and closes together, like the simple escape sequences above.
+                    self.begin_number_component(EventToken::NumberSign, pos, &mut callback);
+                    self.end_number_component(EventToken::NumberSign, pos, &mut callback);
+                    State::Number { state: Num::Sign }
+                }
+                (
+                    State::Idle
+                    | State::Object {
+                        expect: Object::Value,
+                    }
+                    | State::Array {
+                        expect: Array::ItemOrEnd,
+                    },
+                    b'0',
                 ) => {
                     callback(Event::Begin(EventToken::Number), pos);
+                    self.begin_number_component(EventToken::NumberInteger, pos, &mut callback);
+                    State::Number {
+                        state: Num::LeadingZero,
+                    }
+                }
+                (
+                    State::Idle
+                    | State::Object {
+                        expect: Object::Value,
+                    }
+                    | State::Array {
+                        expect: Array::ItemOrEnd,
+                    },
+                    b,
+                ) if is_number_start(b) => {
+                    callback(Event::Begin(EventToken::Number), pos);
+                    self.begin_number_component(EventToken::NumberInteger, pos, &mut callback);
                     State::Number {
                         state: Num::BeforeDecimalPoint,
                     }
@@ -886,6 +1655,20 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     },
                     b'"',
                 ) => {
+                    self.string_quote = b'"';
+                    callback(Event::Begin(EventToken::Key), pos);
+                    State::String {
+                        state: String::Normal,
+                        key: true,
+                    }
+                }
+                (
+                    State::Object {
+                        expect: Object::Key,
+                    },
+                    b'\'',
+                ) if self.lenient_syntax => {
+                    self.string_quote = b'\'';
                     callback(Event::Begin(EventToken::Key), pos);
                     State::String {
                         state: String::Normal,
@@ -899,7 +1682,9 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     b'}',
                 ) => {
                     if let Some((comma_char, _)) = self.context.after_comma {
-                        return Error::new(ErrKind::TrailingComma, comma_char, pos);
+                        if !self.lenient_syntax {
+                            return Error::new(ErrKind::TrailingComma, comma_char, pos);
+                        }
                     }
                     self.context.exit_object(pos)?;
                     callback(Event::ObjectEnd, pos);
@@ -958,6 +1743,7 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                 (State::Token { token }, current_byte) => {
                     let progress = match token {
                         Token::True(p) | Token::False(p) | Token::Null(p) => p,
+                        Token::Infinity(p) | Token::NaN(p) => p,
                     };
                     match process_token_char(progress, current_byte) {
                         Ok(Some(new_progress)) => {
@@ -966,6 +1752,8 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                                 TokenType::True => Token::True(new_progress),
                                 TokenType::False => Token::False(new_progress),
                                 TokenType::Null => Token::Null(new_progress),
+                                TokenType::Infinity => Token::Infinity(new_progress),
+                                TokenType::NaN => Token::NaN(new_progress),
                             };
                             State::Token { token: new_token }
                         }
@@ -1023,6 +1811,12 @@ impl<T: BitBucket, D: DepthCounter> Tokenizer<T, D> {
                     return Error::new(ErrKind::ContentEnded, current_byte, pos)
                 }
             };
+            if current_byte == b'\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                self.column += 1;
+            }
             pos = pos.saturating_add(1);
         }
         Ok(pos)
@@ -1242,6 +2036,26 @@ mod tests {
         assert_eq!(r, Error::new(ErrKind::ExpectedColon, b't', 6));
     }
 
+    #[test]
+    fn test_object_missing_colon_reports_line_and_column() {
+        let mut parser = Tokenizer::new();
+        let err = parser
+            .p(b"{\n  \"key\"true\n}", &mut |_, _| {})
+            .unwrap_err();
+        assert_eq!(err.line_col(), (2, 7));
+    }
+
+    #[test]
+    fn test_crlf_line_endings_count_as_one_line_increment_each() {
+        // `\r` must not bump the line on its own, or a `\r\n` document would
+        // be reported as twice as many lines as it actually has.
+        let mut parser = Tokenizer::new();
+        let err = parser
+            .p(b"{\r\n  \"key\"true\r\n}", &mut |_, _| {})
+            .unwrap_err();
+        assert_eq!(err.line_col(), (2, 7));
+    }
+
     #[test]
     fn test_object_missing_value() {
         let mut m: [Event; 3] = core::array::from_fn(|_| Event::Uninitialized);
@@ -1520,8 +2334,534 @@ mod tests {
         assert!(events.len() > 8); // Multiple ArrayStart/End + Number events
     }
 
-    // TODO: Array BitStack support needs custom implementation
-    // Arrays don't implement the required bit operations for BitStack trait
+    #[test]
+    fn test_array_bitstack_exceeds_integer_bitstack_depth() {
+        // `ArrayBitStack<N, T, D>` backs the bit stack with an `[T; N]`
+        // array instead of a single integer, so capacity scales with N
+        // rather than being capped at an integer's bit width -- here 10
+        // u32 elements give 320 levels, ten times deeper than even a u64
+        // bit stack could hold.
+        let mut parser: Tokenizer<super::super::ArrayBitBucket<10, u32>, u16> = Tokenizer::new();
+        let mut json = Vec::new();
+        json.extend(core::iter::repeat(b'[').take(100));
+        json.push(b'1');
+        json.extend(core::iter::repeat(b']').take(100));
+
+        let mut events = Vec::new();
+        let result = parser.parse_full(&json, &mut |event, _pos| {
+            events.push(event);
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(
+            events.iter().filter(|e| **e == Event::ArrayStart).count(),
+            100
+        );
+        assert_eq!(
+            events.iter().filter(|e| **e == Event::ArrayEnd).count(),
+            100
+        );
+    }
+
+    #[test]
+    fn test_number_component_events_off_by_default() {
+        let mut parser = Tokenizer::new();
+        let mut events = Vec::new();
+        parser
+            .p(b"-12.5e+3", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert!(
+            events
+                .iter()
+                .all(|e| !matches!(
+                    e,
+                    Event::Begin(
+                        EventToken::NumberSign
+                            | EventToken::NumberInteger
+                            | EventToken::NumberFraction
+                            | EventToken::NumberExponent
+                    ) | Event::End(
+                        EventToken::NumberSign
+                            | EventToken::NumberInteger
+                            | EventToken::NumberFraction
+                            | EventToken::NumberExponent
+                    )
+                )),
+            "no component events expected when the flag is off, got {events:?}"
+        );
+    }
+
+    #[test]
+    fn test_number_component_events_cover_sign_integer_fraction_and_exponent() {
+        let mut parser = Tokenizer::new();
+        parser.set_number_component_events(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"-12.5e+3", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::Begin(EventToken::Number),
+                Event::Begin(EventToken::NumberSign),
+                Event::End(EventToken::NumberSign),
+                Event::Begin(EventToken::NumberInteger),
+                Event::End(EventToken::NumberInteger),
+                Event::Begin(EventToken::NumberFraction),
+                Event::End(EventToken::NumberFraction),
+                Event::Begin(EventToken::NumberExponent),
+                Event::End(EventToken::NumberExponent),
+                Event::End(EventToken::Number),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_number_component_events_close_open_component_at_eof() {
+        // No terminating byte at all -- finish() must close both the open
+        // integer component and the coarse Number span.
+        let mut parser = Tokenizer::new();
+        parser.set_number_component_events(true);
+        let mut events = Vec::new();
+        parser.p(b"42", &mut |event, _pos| events.push(event)).unwrap();
+        parser.finish(&mut |event, _pos| events.push(event)).unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::Begin(EventToken::Number),
+                Event::Begin(EventToken::NumberInteger),
+                Event::End(EventToken::NumberInteger),
+                Event::End(EventToken::Number),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_number_component_events_close_open_component_on_merged_array_terminator() {
+        // `]` right after a digit is reported as a combined
+        // End(NumberAndArray)/ArrayEnd pair rather than two separate bytes --
+        // that merged terminator still has to close whatever number
+        // component (here, the fraction) was open.
+        let mut parser = Tokenizer::new();
+        parser.set_number_component_events(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"[1.5]", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::ArrayStart,
+                Event::Begin(EventToken::Number),
+                Event::Begin(EventToken::NumberInteger),
+                Event::End(EventToken::NumberInteger),
+                Event::Begin(EventToken::NumberFraction),
+                Event::End(EventToken::NumberFraction),
+                Event::End(EventToken::NumberAndArray),
+                Event::ArrayEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_syntax_off_by_default_rejects_comment() {
+        let r = Tokenizer::<u32, u8>::new().t(b"[1 // comment\n]");
+        assert_eq!(r, Error::new(ErrKind::ExpectedArrayItem, b'/', 3));
+    }
+
+    #[test]
+    fn test_lenient_line_comment() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"[1, // trailing\n2]", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::ArrayStart,
+                Event::Begin(EventToken::Number),
+                Event::End(EventToken::Number),
+                Event::Begin(EventToken::Comment),
+                Event::End(EventToken::Comment),
+                Event::Begin(EventToken::Number),
+                Event::End(EventToken::NumberAndArray),
+                Event::ArrayEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_comment_between_colon_and_value() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(br#"{"a": /* c */ 1}"#, &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::ObjectStart,
+                Event::Begin(EventToken::Key),
+                Event::End(EventToken::Key),
+                Event::Begin(EventToken::Comment),
+                Event::End(EventToken::Comment),
+                Event::Begin(EventToken::Number),
+                Event::End(EventToken::NumberAndObject),
+                Event::ObjectEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_block_comment() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"[/* a * b */1]", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::ArrayStart,
+                Event::Begin(EventToken::Comment),
+                Event::End(EventToken::Comment),
+                Event::Begin(EventToken::Number),
+                Event::End(EventToken::NumberAndArray),
+                Event::ArrayEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_unterminated_block_comment_is_an_error() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"1 /* oops", &mut |event, _pos| events.push(event))
+            .unwrap();
+        let r = parser.finish(&mut |event, _pos| events.push(event));
+        assert_eq!(r, Error::new(ErrKind::UnterminatedComment, b' ', 9));
+    }
+
+    #[test]
+    fn test_unfinished_stream_reports_innermost_open_container_offset() {
+        let mut parser = Tokenizer::<u32, u8>::new();
+        let mut events = Vec::new();
+        parser
+            .p(br#"{"a":["incomplete"#, &mut |event, _pos| events.push(event))
+            .unwrap();
+        let r = parser.finish(&mut |event, _pos| events.push(event));
+        let err = r.unwrap_err();
+        assert_eq!(err.kind, ErrKind::UnfinishedStream);
+        // the `[` at byte 5 is the innermost still-open container, not the
+        // `{` at byte 0 that encloses it.
+        assert_eq!(err.open_container_offset(), Some(5));
+    }
+
+    #[test]
+    fn test_unfinished_stream_reports_none_when_nothing_was_open() {
+        let mut parser = Tokenizer::<u32, u8>::new();
+        let mut events = Vec::new();
+        // An unterminated root-level string: depth stays zero, so there's
+        // no container to report the offset of.
+        parser
+            .p(br#""incomplete"#, &mut |event, _pos| events.push(event))
+            .unwrap();
+        let r = parser.finish(&mut |event, _pos| events.push(event));
+        let err = r.unwrap_err();
+        assert_eq!(err.kind, ErrKind::UnfinishedStream);
+        assert_eq!(err.open_container_offset(), None);
+    }
+
+    #[test]
+    fn test_lenient_trailing_comma_is_allowed() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"[1,2,]"), Ok(6));
+
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"{\"a\":1,}"), Ok(8));
+    }
+
+    #[test]
+    fn test_lenient_single_quoted_strings() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"{'a':'hello'}", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::ObjectStart,
+                Event::Begin(EventToken::Key),
+                Event::End(EventToken::Key),
+                Event::Begin(EventToken::String),
+                Event::End(EventToken::String),
+                Event::ObjectEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_single_quoted_string_may_contain_double_quote() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"['a \" b']"), Ok(9));
+    }
+
+    #[test]
+    fn test_lenient_underscore_digit_separators() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"1_000"), Ok(5));
+
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"1_000.25_5e1_0"), Ok(14));
+
+        let mut parser = Tokenizer::new();
+        assert!(parser.t(b"1_000").is_err());
+    }
+
+    #[test]
+    fn test_lenient_underscore_rejects_doubled_or_trailing() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert!(parser.t(b"1__2").is_err());
+
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert!(parser.t(b"1_,").is_err());
+    }
+
+    #[test]
+    fn test_lenient_hex_integer() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"0x1A"), Ok(4));
+
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert!(parser.t(b"0x,").is_err());
+
+        let mut parser = Tokenizer::new();
+        assert!(parser.t(b"0x1A").is_err());
+    }
+
+    #[test]
+    fn test_lenient_infinity_and_nan() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"Infinity", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::Begin(EventToken::Infinity),
+                Event::End(EventToken::Infinity),
+            ]
+        );
+
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"NaN"), Ok(3));
+
+        let mut parser = Tokenizer::new();
+        assert!(parser.t(b"Infinity").is_err());
+    }
+
+    #[test]
+    fn test_lenient_negative_infinity_is_a_number() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"-Infinity ", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::Begin(EventToken::Number),
+                Event::End(EventToken::Number),
+            ]
+        );
+
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert!(parser.t(b"-Infinitx").is_err());
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_leading_plus_sign() {
+        let mut parser = Tokenizer::new();
+        assert!(parser.t(b"+5").is_err());
+    }
+
+    #[test]
+    fn test_lenient_leading_plus_sign_is_a_number() {
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut events = Vec::new();
+        parser
+            .p(b"+5", &mut |event, _pos| events.push(event))
+            .unwrap();
+        assert_eq!(
+            events,
+            [
+                Event::Begin(EventToken::Number),
+                Event::End(EventToken::Number),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_leading_plus_infinity_is_a_number() {
+        // `Num::Sign` doesn't distinguish which byte put it there, so the
+        // `+Infinity` chain works the same way `-Infinity` does.
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        assert_eq!(parser.t(b"+Infinity"), Ok(9));
+    }
+
+    #[test]
+    fn test_line_col_advances_past_newlines() {
+        let mut parser = Tokenizer::new();
+        parser
+            .parse_full(b"[\n  1,\n  2\n]", &mut |_, _| {})
+            .unwrap();
+        assert_eq!(parser.line_col(), (4, 1));
+    }
+
+    #[test]
+    fn test_error_reports_line_and_column() {
+        let mut parser = Tokenizer::new();
+        let err = parser.p(b"1\n$", &mut |_, _| {}).unwrap_err();
+        assert_eq!(err.line_col(), (2, 0));
+    }
+
+    #[test]
+    fn test_line_col_carries_across_chunk_boundary() {
+        let mut parser = Tokenizer::new();
+        parser.p(b"1\n", &mut |_, _| {}).unwrap();
+        assert_eq!(parser.line_col(), (2, 0));
+
+        let err = parser.p(b"$", &mut |_, _| {}).unwrap_err();
+        assert_eq!(err.line_col(), (2, 0));
+    }
+
+    #[test]
+    fn test_reset_for_next_document_accepts_concatenated_top_level_values() {
+        // Same family of input as the `conformance` module's
+        // test_conformance_double_array (`false false`, which a single p()
+        // call over the whole buffer still rejects with ContentEnded, by
+        // design -- that's the strict, default, single-document behavior).
+        // Polling is_finished() after each value and calling
+        // reset_for_next_document() before the next one is the multi-document
+        // mode every streaming front-end's `new_ndjson` constructor already
+        // layers on top of these two primitives.
+        let mut parser = Tokenizer::new();
+        let mut seen = Vec::new();
+        parser
+            .p(b"false", &mut |ev, _pos| seen.push(ev))
+            .unwrap();
+        assert!(parser.is_finished());
+        assert_eq!(
+            seen,
+            [Event::Begin(EventToken::False), Event::End(EventToken::False)]
+        );
+
+        parser.reset_for_next_document();
+        seen.clear();
+        parser
+            .p(b" false", &mut |ev, _pos| seen.push(ev))
+            .unwrap();
+        assert!(parser.is_finished());
+        assert_eq!(
+            seen,
+            [Event::Begin(EventToken::False), Event::End(EventToken::False)]
+        );
+    }
+
+    #[test]
+    fn test_unicode_escape_split_across_chunk_boundary() {
+        // The `\u00` / `41"` split lands mid-escape -- two hex digits in,
+        // two still to come -- with no byte yet that could be called
+        // invalid, so this must hold State::Unicode2 rather than erring.
+        let mut parser = Tokenizer::new();
+        let mut seen = Vec::new();
+        parser
+            .p(b"\"\\u00", &mut |ev, _pos| seen.push(ev))
+            .unwrap();
+        assert_eq!(
+            seen,
+            [
+                Event::Begin(EventToken::String),
+                Event::Begin(EventToken::EscapeSequence),
+                Event::Begin(EventToken::UnicodeEscape),
+            ]
+        );
+
+        seen.clear();
+        parser.p(b"41\"", &mut |ev, _pos| seen.push(ev)).unwrap();
+        assert_eq!(
+            seen,
+            [
+                Event::End(EventToken::UnicodeEscape),
+                Event::End(EventToken::String),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_number_held_open_across_chunk_boundary() {
+        // `2` in `[2]` can't emit its `End` until the `]` is seen, so the
+        // chunk boundary right after the digit must hold the number open
+        // rather than closing it early.
+        let mut parser = Tokenizer::new();
+        let mut seen = Vec::new();
+        parser.p(b"[2", &mut |ev, _pos| seen.push(ev)).unwrap();
+        assert_eq!(
+            seen,
+            [Event::ArrayStart, Event::Begin(EventToken::Number)]
+        );
+
+        seen.clear();
+        parser.p(b"]", &mut |ev, _pos| seen.push(ev)).unwrap();
+        assert_eq!(
+            seen,
+            [Event::End(EventToken::NumberAndArray), Event::ArrayEnd]
+        );
+    }
+
+    #[test]
+    fn test_lenient_infinity_split_across_chunk_boundary() {
+        // `Infinity` goes through the same `TokenProgress`-tracked
+        // `Token::Infinity` state `true`/`false`/`null` already use, so a
+        // split mid-literal must hold state rather than erring, the same
+        // way `test_unicode_escape_split_across_chunk_boundary` proves for
+        // the unicode-escape machinery above.
+        let mut parser = Tokenizer::new();
+        parser.set_lenient_syntax(true);
+        let mut seen = Vec::new();
+        parser
+            .p(b"Infi", &mut |ev, _pos| seen.push(ev))
+            .unwrap();
+        assert_eq!(seen, [Event::Begin(EventToken::Infinity)]);
+
+        seen.clear();
+        parser.p(b"nity", &mut |ev, _pos| seen.push(ev)).unwrap();
+        assert_eq!(seen, [Event::End(EventToken::Infinity)]);
+    }
 }
 
 #[cfg(test)]
@@ -1916,11 +3256,15 @@ mod conformance {
 
     #[test]
     fn test_conformance_i_structure_500_nested_arrays() {
+        // Default config is a u32 bit stack (32 levels) with a u8 depth
+        // counter (255 levels); the bit stack's smaller capacity is now the
+        // one that's hit, catching the silent bit-stack corruption that used
+        // to let parsing run all the way to the depth counter's own overflow.
         let data = include_bytes!("testdata/i_structure_500_nested_arrays.json");
-        let starts: [(Event, usize); 255] = core::array::from_fn(|x: usize| (Event::ArrayStart, x));
+        let starts: [(Event, usize); 32] = core::array::from_fn(|x: usize| (Event::ArrayStart, x));
         check!(
             data,
-            Error::new(ErrKind::MaxDepthReached, b'[', 255),
+            Error::new(ErrKind::MaxDepthReached, b'[', 32),
             starts.as_slice()
         );
     }
@@ -2227,4 +3571,5 @@ mod conformance {
             ]
         );
     }
+
 }
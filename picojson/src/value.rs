@@ -0,0 +1,359 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! An owned DOM [`Value`] tree, built from any [`PullParser`]'s event
+//! stream, for callers who aren't memory-constrained but still want this
+//! crate's streaming core underneath instead of a separate DOM parser.
+//!
+//! Like [`FeedParser`](crate::FeedParser)/[`PushParser`](crate::PushParser),
+//! this unconditionally depends on `alloc` rather than gating it behind a
+//! feature -- there's no `no_std`-without-`alloc` build of this module to
+//! preserve, since a `Vec`-backed tree is the entire point.
+
+extern crate alloc;
+
+use alloc::string::String as OwnedString;
+use alloc::vec::Vec;
+
+use crate::shared::UnexpectedState;
+use crate::{Event, JsonNumber, ParseError, PullParser};
+
+/// A decoded JSON number, widened just enough to keep integer precision
+/// when the literal actually is an integer -- the same split
+/// [`JsonNumber::as_i64`]/[`as_u64`](JsonNumber::as_u64)/[`as_f64`](JsonNumber::as_f64)
+/// offer, collapsed into one value since [`Value::Number`] has nowhere to
+/// defer the choice to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    /// Fits in an `i64` (includes all non-negative values that also fit).
+    Int(i64),
+    /// A non-negative integer too large for an `i64` but not a `u64`.
+    UInt(u64),
+    /// Has a fraction or exponent, or overflowed both integer variants.
+    Float(f64),
+}
+
+impl Number {
+    fn from_json_number(n: &JsonNumber<'_, '_>) -> Self {
+        if let Ok(i) = n.as_i64() {
+            Number::Int(i)
+        } else if let Ok(u) = n.as_u64() {
+            Number::UInt(u)
+        } else {
+            Number::Float(n.as_f64().unwrap_or(0.0))
+        }
+    }
+}
+
+/// An owned JSON value tree, assembled from a parser's event stream by
+/// [`Value::from_parser`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A JSON `null`.
+    Null,
+    /// A JSON `true`/`false`.
+    Bool(bool),
+    /// A JSON number, decoded via [`Number::from_json_number`].
+    Number(Number),
+    /// A JSON string, with any escapes already decoded.
+    String(OwnedString),
+    /// A JSON array, in source order.
+    Array(Vec<Value>),
+    /// A JSON object, as key/value pairs in source order (JSON doesn't
+    /// require sorted or deduplicated keys, so a `Vec` preserves both the
+    /// order and any duplicates exactly as written, the same way
+    /// [`PathStack`](crate::PathStack) tracks keys by source order rather
+    /// than through a map).
+    Object(Vec<(OwnedString, Value)>),
+}
+
+/// One container currently being assembled, kept on an explicit stack so
+/// [`Value::from_parser`] never recurses -- the same no-recursion invariant
+/// the tokenizer's [`BitStackConfig`](crate::BitStackConfig)-bounded depth
+/// tracking preserves, now extended to the tree this module builds on top
+/// of it.
+enum Frame {
+    Array(Vec<Value>),
+    Object(Vec<(OwnedString, Value)>, Option<OwnedString>),
+}
+
+/// Folds one event into `stack`/`root`, shared by [`Value::from_parser`]'s
+/// pull loop and [`TreeBuilder`]'s push-driven `handle_event`. Callers
+/// handle `Event::StartDocument`/`Event::EndDocument` themselves before
+/// reaching here -- this only ever sees events that open, close, or fill a
+/// value.
+fn push_event(
+    stack: &mut Vec<Frame>,
+    root: &mut Option<Value>,
+    event: Event<'_, '_>,
+) -> Result<(), ParseError> {
+    let value = match event {
+        Event::StartArray => {
+            stack.push(Frame::Array(Vec::new()));
+            return Ok(());
+        }
+        Event::StartObject => {
+            stack.push(Frame::Object(Vec::new(), None));
+            return Ok(());
+        }
+        Event::EndArray => match stack.pop() {
+            Some(Frame::Array(items)) => Value::Array(items),
+            _ => return Err(ParseError::Unexpected(UnexpectedState::StateMismatch)),
+        },
+        Event::EndObject => match stack.pop() {
+            Some(Frame::Object(entries, _)) => Value::Object(entries),
+            _ => return Err(ParseError::Unexpected(UnexpectedState::StateMismatch)),
+        },
+        Event::Key(key) => {
+            match stack.last_mut() {
+                Some(Frame::Object(_, pending)) => {
+                    *pending = Some(OwnedString::from(key.as_str()));
+                }
+                _ => return Err(ParseError::Unexpected(UnexpectedState::StateMismatch)),
+            }
+            return Ok(());
+        }
+        Event::String(s) => Value::String(OwnedString::from(s.as_str())),
+        Event::Number(n) => Value::Number(Number::from_json_number(&n)),
+        Event::Bool(b) => Value::Bool(b),
+        Event::Null => Value::Null,
+        _ => return Err(ParseError::Unexpected(UnexpectedState::StateMismatch)),
+    };
+
+    match stack.last_mut() {
+        Some(Frame::Array(items)) => items.push(value),
+        Some(Frame::Object(entries, pending)) => {
+            let key = pending
+                .take()
+                .ok_or(ParseError::Unexpected(UnexpectedState::StateMismatch))?;
+            entries.push((key, value));
+        }
+        None => *root = Some(value),
+    }
+    Ok(())
+}
+
+/// Builds a [`Value`] tree from a [`PushParser`](crate::PushParser)'s event
+/// stream -- the [`PushParserHandler`](crate::PushParserHandler)
+/// counterpart to [`Value::from_parser`], for callers feeding input as it
+/// arrives rather than pulling from a buffer already fully in hand.
+///
+/// ```
+/// use picojson::{PushParser, ParseError, TreeBuilder};
+///
+/// let mut scratch = [0u8; 128];
+/// let mut parser = PushParser::new(TreeBuilder::new(), &mut scratch);
+/// parser.write::<ParseError>(br#"{"a": 1}"#).unwrap();
+/// let value = parser.finish::<ParseError>().unwrap().into_value().unwrap();
+/// assert_eq!(value.get("a").unwrap().as_i64(), Some(1));
+/// ```
+#[derive(Default)]
+pub struct TreeBuilder {
+    stack: Vec<Frame>,
+    root: Option<Value>,
+}
+
+impl TreeBuilder {
+    /// A builder with nothing parsed yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The finished tree, once every event through `Event::EndDocument` has
+    /// been handled via [`PushParser::write`](crate::PushParser::write)/
+    /// [`finish`](crate::PushParser::finish). `None` if the document hasn't
+    /// produced a root value yet (including while it's still in progress).
+    pub fn into_value(self) -> Option<Value> {
+        self.root
+    }
+}
+
+impl<'input, 'scratch> crate::PushParserHandler<'input, 'scratch, ParseError> for TreeBuilder {
+    fn handle_event(&mut self, event: Event<'input, 'scratch>) -> Result<(), ParseError> {
+        match event {
+            Event::StartDocument | Event::EndDocument => Ok(()),
+            other => push_event(&mut self.stack, &mut self.root, other),
+        }
+    }
+}
+
+impl Value {
+    /// Drives `parser` to completion and assembles its event stream into a
+    /// `Value` tree.
+    ///
+    /// Assumes `parser` is in its default configuration: whitespace events,
+    /// raw-value capture, and error-recovery mode are all parser opt-ins
+    /// that produce events this builder doesn't have a tree representation
+    /// for, and seeing one is reported as
+    /// [`ParseError::Unexpected`]`(`[`UnexpectedState::StateMismatch`]`)`.
+    pub fn from_parser<P: PullParser>(parser: &mut P) -> Result<Value, ParseError> {
+        let mut stack: Vec<Frame> = Vec::new();
+        let mut root: Option<Value> = None;
+
+        loop {
+            let event = parser.next_event()?;
+            if matches!(event, Event::EndDocument) {
+                break;
+            }
+            push_event(&mut stack, &mut root, event)?;
+        }
+
+        root.ok_or(ParseError::EndOfData)
+    }
+
+    /// The value of `key` in this object, or `None` if this isn't an
+    /// object or has no such key. Returns the first match if `key` is
+    /// duplicated.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// The element at `index` in this array, or `None` if this isn't an
+    /// array or `index` is out of bounds.
+    pub fn index(&self, index: usize) -> Option<&Value> {
+        match self {
+            Value::Array(items) => items.get(index),
+            _ => None,
+        }
+    }
+
+    /// This value's string, or `None` if it isn't a [`Value::String`].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// This value's bool, or `None` if it isn't a [`Value::Bool`].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// This value as an `i64`, widening from [`Number::UInt`] when it fits
+    /// and truncating from [`Number::Float`], or `None` if it isn't a
+    /// [`Value::Number`] or doesn't fit.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Number(Number::Int(i)) => Some(*i),
+            Value::Number(Number::UInt(u)) => i64::try_from(*u).ok(),
+            Value::Number(Number::Float(f)) => Some(*f as i64),
+            _ => None,
+        }
+    }
+
+    /// This value as an `f64`, or `None` if it isn't a [`Value::Number`].
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(Number::Int(i)) => Some(*i as f64),
+            Value::Number(Number::UInt(u)) => Some(*u as f64),
+            Value::Number(Number::Float(f)) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// This value's elements, or `None` if it isn't a [`Value::Array`].
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PushParser, SliceParser};
+
+    #[test]
+    fn test_builds_flat_object() {
+        let mut parser = SliceParser::new(r#"{"a":1,"b":true,"c":null}"#);
+        let value = Value::from_parser(&mut parser).unwrap();
+        assert_eq!(value.get("a").unwrap().as_i64(), Some(1));
+        assert_eq!(value.get("b").unwrap().as_bool(), Some(true));
+        assert_eq!(value.get("c").unwrap(), &Value::Null);
+    }
+
+    #[test]
+    fn test_builds_nested_array_and_object() {
+        let mut parser = SliceParser::new(r#"{"items":[1,2,{"x":"y"}]}"#);
+        let value = Value::from_parser(&mut parser).unwrap();
+        let items = value.get("items").unwrap().as_array().unwrap();
+        assert_eq!(items[0].as_i64(), Some(1));
+        assert_eq!(items[1].as_i64(), Some(2));
+        assert_eq!(items[2].get("x").unwrap().as_str(), Some("y"));
+    }
+
+    #[test]
+    fn test_builds_deeply_nested_array_without_recursion() {
+        // 20 levels, comfortably inside DefaultConfig's 32-bit bucket --
+        // just enough to confirm the builder's explicit Vec stack tracks
+        // nesting correctly, without needing a wider BitStackConfig.
+        const DEPTH: usize = 20;
+        let mut json = OwnedString::new();
+        for _ in 0..DEPTH {
+            json.push('[');
+        }
+        for _ in 0..DEPTH {
+            json.push(']');
+        }
+        let mut parser = SliceParser::new(&json);
+        let value = Value::from_parser(&mut parser).unwrap();
+        let mut depth = 0;
+        let mut current = &value;
+        loop {
+            match current.as_array() {
+                Some(items) if !items.is_empty() => {
+                    depth += 1;
+                    current = &items[0];
+                }
+                Some(_) => break,
+                None => panic!("expected nested arrays"),
+            }
+        }
+        assert_eq!(depth, DEPTH);
+    }
+
+    #[test]
+    fn test_preserves_duplicate_keys_in_source_order() {
+        let mut parser = SliceParser::new(r#"{"a":1,"a":2}"#);
+        let value = Value::from_parser(&mut parser).unwrap();
+        match value {
+            Value::Object(entries) => {
+                assert_eq!(entries, alloc::vec![
+                    (OwnedString::from("a"), Value::Number(Number::Int(1))),
+                    (OwnedString::from("a"), Value::Number(Number::Int(2))),
+                ]);
+            }
+            _ => panic!("expected object"),
+        }
+    }
+
+    #[test]
+    fn test_tree_builder_matches_from_parser_for_the_same_input() {
+        // TreeBuilder is push_event driven through PushParserHandler rather
+        // than PullParser's next_event loop, but it shares the same
+        // push_event folding logic as Value::from_parser -- so it should
+        // build an identical tree from the same bytes.
+        let json = r#"{"items":[1,2,{"x":"y"}],"n":null}"#;
+
+        let mut scratch = [0u8; 32];
+        let mut parser = PushParser::new(TreeBuilder::new(), &mut scratch);
+        parser.write::<ParseError>(json.as_bytes()).unwrap();
+        let from_push = parser.finish::<ParseError>().unwrap().into_value().unwrap();
+
+        let mut pull_parser = SliceParser::new(json);
+        let from_pull = Value::from_parser(&mut pull_parser).unwrap();
+
+        assert_eq!(from_push, from_pull);
+    }
+}
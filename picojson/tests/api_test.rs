@@ -1,6 +1,6 @@
 // Test the new API entry points
 
-use picojson::{Event, ParseError, PullParser, String};
+use picojson::{Event, JsonNumber, ParseError, PullParser, SliceParser, String, SurrogatePolicy};
 
 #[test]
 fn test_new_no_escapes() {
@@ -133,3 +133,1131 @@ fn test_mixed_string_types() {
     assert_eq!(parser.next_event(), Ok(Event::EndObject));
     assert_eq!(parser.next_event(), Ok(Event::EndDocument));
 }
+
+#[test]
+fn test_position_reports_line_and_column() {
+    // The stray comma on line 2 is where parsing should fail.
+    let json = "{\n  \"a\": ,\n}";
+    let mut parser = SliceParser::new(json);
+
+    loop {
+        match parser.next_event() {
+            Ok(Event::EndDocument) => panic!("expected a parse error"),
+            Err(_) => break,
+            Ok(_) => {}
+        }
+    }
+
+    let pos = parser.position();
+    assert_eq!(pos.line, 2);
+    assert_eq!(pos.column, 9);
+}
+
+#[test]
+fn test_raw_value_captures_object_verbatim() {
+    let json = r#"{"config": {"a": [1, 2, "x\n"]}, "rest": true}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("config")))
+    );
+
+    let raw = parser.raw_value().unwrap();
+    assert_eq!(raw, br#"{"a": [1, 2, "x\n"]}"#);
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("rest")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::Bool(true)));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_raw_value_rejects_scalar_start() {
+    let json = r#"{"a": 1}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("a")))
+    );
+
+    assert!(parser.raw_value().is_err());
+}
+
+#[test]
+fn test_next_event_located_attaches_position_to_error() {
+    let json = "{\n  \"a\": ,\n}";
+    let mut parser = SliceParser::new(json);
+
+    loop {
+        match parser.next_event_located() {
+            Ok(Event::EndDocument) => panic!("expected a parse error"),
+            Err((_err, pos)) => {
+                assert_eq!(pos.line, 2);
+                assert_eq!(pos.column, 9);
+                break;
+            }
+            Ok(_) => {}
+        }
+    }
+}
+
+#[test]
+fn test_next_event_with_position_locates_successful_events() {
+    let json = "{\n  \"a\": 1\n}";
+    let mut parser = SliceParser::new(json);
+
+    let (event, pos) = parser.next_event_with_position().unwrap();
+    assert_eq!(event, Event::StartObject);
+    assert_eq!(pos.line, 1);
+
+    let (event, pos) = parser.next_event_with_position().unwrap();
+    assert_eq!(event, Event::Key(String::Borrowed("a")));
+    assert_eq!(pos.line, 2);
+
+    let (event, pos) = parser.next_event_with_position().unwrap();
+    assert_eq!(event, Event::Number(String::Borrowed("1")));
+    assert_eq!(pos.line, 2);
+    assert_eq!(pos, parser.position());
+}
+
+#[test]
+fn test_position_after_escaped_string_counts_source_bytes_not_unescaped_output() {
+    // `\n` inside the string is 2 source bytes (`\` and `n`) but unescapes to
+    // a single byte; position tracking has to advance by the source length
+    // it actually consumed, not by how long the unescaped value turned out.
+    let json = r#"["ab\ncd", 99]"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = PullParser::with_buffer(json, &mut scratch);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+
+    let (event, pos) = parser.next_event_with_position().unwrap();
+    match event {
+        Event::String(s) => assert_eq!(&*s, "ab\ncd"),
+        other => panic!("expected a string, got {other:?}"),
+    }
+    // Byte 9 is the `,` right after the closing quote of `"ab\ncd"`.
+    assert_eq!(pos.byte_offset, 9);
+    assert_eq!(&json[pos.byte_offset..pos.byte_offset + 1], ",");
+
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("99"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_position_for_offset_locates_a_past_span() {
+    use picojson::Position;
+
+    let json = "{\n  \"a\": 1\n}";
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    let (_, span) = parser.next_event_with_span().unwrap();
+
+    // `span.end` lines up with the cursor position right after the event,
+    // the same one `next_event_with_position` would have reported for it.
+    assert_eq!(
+        parser.position_for_offset(span.end),
+        Position {
+            byte_offset: span.end,
+            line: 2,
+            column: 9
+        }
+    );
+
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+}
+
+#[test]
+fn test_skip_value_discards_nested_object() {
+    let json = r#"{"skip": {"a": [1, 2, {"deep": true}]}, "keep": 5}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("skip")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    parser.skip_value().unwrap();
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("keep")))
+    );
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_skip_value_never_validates_content_that_would_otherwise_error() {
+    // An unpaired high surrogate -- `extract_string_content` would reject
+    // this with `UnpairedHighSurrogate` if the string were ever decoded.
+    // `skip_value` must complete without touching extraction at all, so
+    // this invalid escape never actually gets validated.
+    let json = r#"{"skip": "\uD800bad", "keep": 5}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("skip")))
+    );
+    parser.skip_value().unwrap();
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("keep")))
+    );
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_skip_value_ignores_structural_looking_bytes_inside_strings() {
+    // `}`, `]`, and `"` inside string content must not be mistaken for
+    // real container delimiters while skipping -- the tokenizer's own
+    // string-state tracking is what skip_value relies on for this, not a
+    // separate scan.
+    let json = r#"{"skip": {"a": "}]\"still inside", "b": [1, "}]", 2]}, "keep": 5}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("skip")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    parser.skip_value().unwrap();
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("keep")))
+    );
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_skip_value_on_single_scalar_completes_immediately() {
+    let json = r#"{"a": "ignored", "b": 2}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("a")))
+    );
+    parser.skip_value().unwrap();
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("b")))
+    );
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+}
+
+#[test]
+fn test_skip_value_discards_nested_array() {
+    let json = r#"[[1, [2, 3]], 4]"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    parser.skip_value().unwrap();
+
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_next_event_with_span_covers_full_lexeme() {
+    use picojson::Span;
+
+    let json = r#"{"key": [1, true]}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(
+        parser.next_event_with_span().unwrap(),
+        (Event::StartObject, Span { start: 0, end: 1 })
+    );
+    assert_eq!(
+        parser.next_event_with_span().unwrap(),
+        (
+            Event::Key(String::Borrowed("key")),
+            Span { start: 1, end: 6 }
+        )
+    );
+    assert_eq!(
+        parser.next_event_with_span().unwrap(),
+        (Event::StartArray, Span { start: 8, end: 9 })
+    );
+
+    let (event, span) = parser.next_event_with_span().unwrap();
+    assert!(matches!(event, Event::Number(_)));
+    assert_eq!(span, Span { start: 9, end: 10 });
+    assert_eq!(&json[span.start..span.end], "1");
+
+    assert_eq!(
+        parser.next_event_with_span().unwrap(),
+        (Event::Bool(true), Span { start: 12, end: 16 })
+    );
+    assert_eq!(&json[12..16], "true");
+    assert_eq!(
+        parser.next_event_with_span().unwrap(),
+        (Event::EndArray, Span { start: 16, end: 17 })
+    );
+    assert_eq!(
+        parser.next_event_with_span().unwrap(),
+        (Event::EndObject, Span { start: 17, end: 18 })
+    );
+}
+
+#[test]
+fn test_number_as_raw_str_excludes_merged_closing_delimiter() {
+    // `3]` lexes as a single combined End(NumberAndArray) token, not a
+    // separate Number then ArrayEnd -- `as_raw_str()` must still report
+    // just the digits, not the trailing `]` that was merged into the same
+    // tokenizer event.
+    let json = r#"[1, 2, 3]"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    let mut last_number = std::string::String::new();
+    loop {
+        match parser.next_event().unwrap() {
+            Event::Number(n) => last_number = n.as_raw_str().into(),
+            Event::EndArray => break,
+            other => panic!("unexpected event {other:?}"),
+        }
+    }
+    assert_eq!(last_number, "3");
+}
+
+#[test]
+fn test_next_raw_value_captures_object_and_scalars() {
+    let json = r#"{"config": {"a": [1, "x\n"]}, "count": 7, "ok": true}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("config")))
+    );
+    assert_eq!(
+        parser.next_raw_value(),
+        Ok(Event::RawValue(String::Borrowed(r#"{"a": [1, "x\n"]}"#)))
+    );
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("count")))
+    );
+    assert_eq!(
+        parser.next_raw_value(),
+        Ok(Event::RawValue(String::Borrowed("7")))
+    );
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("ok")))
+    );
+    assert_eq!(
+        parser.next_raw_value(),
+        Ok(Event::RawValue(String::Borrowed("true")))
+    );
+
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_next_raw_value_with_span_covers_whole_subtree() {
+    use picojson::Span;
+
+    let json = r#"{"config": {"a": [1, 2]}}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("config")))
+    );
+
+    let raw = r#"{"a": [1, 2]}"#;
+    let start = json.find(raw).unwrap();
+    assert_eq!(
+        parser.next_raw_value_with_span(),
+        Ok((
+            Event::RawValue(String::Borrowed(raw)),
+            Span {
+                start,
+                end: start + raw.len(),
+            }
+        ))
+    );
+
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_skip_value_with_span_covers_whole_subtree_without_extracting() {
+    use picojson::Span;
+
+    let json = r#"{"config": {"a": [1, 2]}, "count": 7}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("config")))
+    );
+
+    let raw = r#"{"a": [1, 2]}"#;
+    let start = json.find(raw).unwrap();
+    assert_eq!(
+        parser.skip_value_with_span(),
+        Ok(Span {
+            start,
+            end: start + raw.len(),
+        })
+    );
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("count")))
+    );
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_next_raw_value_captures_a_bare_top_level_document() {
+    // `next_raw_value` works at the document root too, not just nested
+    // under a key -- a whole-document passthrough/hash use case, same as
+    // serde_json's `raw_value` applied to the top-level `Deserializer`.
+    let mut parser = SliceParser::new(r#"[1, 2, 3]"#);
+    assert_eq!(
+        parser.next_raw_value(),
+        Ok(Event::RawValue(String::Borrowed("[1, 2, 3]")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    let mut parser = SliceParser::new("42");
+    assert_eq!(
+        parser.next_raw_value(),
+        Ok(Event::RawValue(String::Borrowed("42")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_checkpoint_restore_allows_speculative_lookahead() {
+    let json = r#"{"value": 42}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("value")))
+    );
+
+    let checkpoint = parser.checkpoint().unwrap();
+
+    // Speculatively consume the number; restoring below should rewind past it.
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    parser.restore(checkpoint);
+
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Number(String::Borrowed("42")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_ndjson_streams_concatenated_top_level_values() {
+    let mut parser = SliceParser::new_ndjson("{\"a\": 1}\n{\"a\": 2}\n42\n");
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("1"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("2"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("42"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    // No more values: stays at EndDocument instead of erroring on trailing whitespace.
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_ndjson_mode_also_accepts_space_separated_values_not_just_newlines() {
+    // "NDJSON" in the constructor's name is the common case, not the only
+    // one this mode accepts -- any of the grammar's usual whitespace bytes
+    // (the same ones tolerated around a single top-level value) separate
+    // records just as well as a newline does.
+    let mut parser = SliceParser::new_ndjson("1 2\t3");
+
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("1"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("2"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("3"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_peek_event_does_not_consume() {
+    let json = r#"{"key": "value"}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+
+    // Peeking repeatedly returns the same event without advancing.
+    assert_eq!(
+        parser.peek_event(),
+        Ok(Event::Key(String::Borrowed("key")))
+    );
+    assert_eq!(
+        parser.peek_event(),
+        Ok(Event::Key(String::Borrowed("key")))
+    );
+
+    // next_event() returns the previously peeked event, then moves on.
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Key(String::Borrowed("key")))
+    );
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::String(String::Borrowed("value")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_peek_reports_none_at_end_of_document() {
+    let json = "true";
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::Bool(true)));
+    assert_eq!(parser.peek(), None);
+    assert_eq!(parser.peek(), None);
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_peek_event_dispatches_on_value_shape() {
+    // A value-dispatching reader branches on whether the next event is a
+    // container start or a scalar before deciding how to consume it --
+    // peek_event lets it look without losing the event if it guesses wrong.
+    let json = r#"[{"a": 1}, "scalar"]"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+
+    match parser.peek_event() {
+        Ok(Event::StartObject) => {}
+        other => panic!("expected to peek StartObject, got {other:?}"),
+    }
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("1"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+
+    match parser.peek_event() {
+        Ok(Event::String(_)) => {}
+        other => panic!("expected to peek String, got {other:?}"),
+    }
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::String(String::Borrowed("scalar")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_surrogate_pair_escape_decodes_to_single_codepoint() {
+    // U+1F600 (an emoji) is outside the Basic Multilingual Plane, so JSON can
+    // only spell it as a surrogate pair; the string should come out as the
+    // one combined codepoint, not two unpaired surrogates.
+    let json = r#"["\uD83D\uDE00"]"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = PullParser::with_buffer(json, &mut scratch);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    match parser.next_event() {
+        Ok(Event::String(s)) => assert_eq!(&*s, "\u{1F600}"),
+        other => panic!("expected the decoded emoji, got {other:?}"),
+    }
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_lone_high_surrogate_escape_is_rejected() {
+    // A high surrogate with no low surrogate to pair with can't be decoded
+    // into a real codepoint by default (lossy WTF-8 mode is opt-in).
+    let json = r#"["\uD83D"]"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = PullParser::with_buffer(json, &mut scratch);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(
+        parser.next_event(),
+        Err(ParseError::UnpairedHighSurrogate)
+    );
+}
+
+#[test]
+fn test_lone_low_surrogate_escape_is_rejected() {
+    // A low surrogate with no preceding high surrogate is equally invalid.
+    let json = r#"["\uDE00"]"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = PullParser::with_buffer(json, &mut scratch);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.next_event(), Err(ParseError::UnpairedLowSurrogate));
+}
+
+#[test]
+fn test_wtf8_surrogate_policy_does_not_yet_rescue_a_lone_high_surrogate_at_string_end() {
+    // `set_surrogate_policy(Wtf8)` primes the `UnicodeEscapeCollector` for
+    // WTF-8 output, but `Event::String` wraps a `&str` and a lone surrogate
+    // is never valid UTF-8 either way -- so a high surrogate still pending
+    // at the closing quote is unaffected by the policy and still errors
+    // exactly as it does by default. See `PullParser::set_surrogate_policy`'s
+    // doc comment for why.
+    let json = r#"["\uD83D"]"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = PullParser::with_buffer(json, &mut scratch);
+    parser.set_surrogate_policy(SurrogatePolicy::Wtf8);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.next_event(), Err(ParseError::UnpairedHighSurrogate));
+}
+
+#[test]
+fn test_set_max_depth_rejects_deeper_containers() {
+    let json = r#"[[1]]"#;
+    let mut parser = SliceParser::new(json);
+    parser.set_max_depth(1);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(
+        parser.next_event(),
+        Err(ParseError::DepthLimitExceeded { depth: 2 })
+    );
+}
+
+#[test]
+fn test_exceeding_native_bitstack_capacity_is_a_clean_error_without_set_max_depth() {
+    // `DefaultConfig`'s bucket is a u32, so it can track 32 levels of nesting
+    // before it runs out of room. Without ever calling `set_max_depth`,
+    // opening a 33rd level must still fail cleanly (the tokenizer checks
+    // capacity before pushing) rather than silently wrapping/corrupting the
+    // bit stack.
+    let mut json = std::string::String::new();
+    for _ in 0..33 {
+        json.push('[');
+    }
+    let mut parser = SliceParser::new(&json);
+
+    for _ in 0..32 {
+        assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    }
+    assert!(matches!(
+        parser.next_event(),
+        Err(ParseError::TokenizerError(_))
+    ));
+}
+
+#[test]
+fn test_set_max_depth_rejects_deeper_containers_at_the_opening_bracket_position() {
+    // `set_max_depth` itself reports only the depth that was exceeded (see
+    // `test_set_max_depth_rejects_deeper_containers`); pairing the error
+    // with `position()` (the same pattern `Position`'s own doc comment
+    // describes for every other `ParseError`) is what points at the
+    // specific opening bracket that went one level too deep.
+    let json = r#"[[1]]"#;
+    let mut parser = SliceParser::new(json);
+    parser.set_max_depth(1);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    match parser.next_event_located() {
+        Err((ParseError::DepthLimitExceeded { depth: 2 }, position)) => {
+            assert_eq!(position.byte_offset, 2); // just past the second `[`, at offset 1
+        }
+        other => panic!("expected a located DepthLimitExceeded, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_position_tracks_line_column_across_crlf_line_ending() {
+    // `\r\n` isn't tracked as a single unit (see `advance_position`'s doc
+    // comment): the `\r` just bumps the column like any other byte, and the
+    // `\n` right after it resets the column regardless -- so the position
+    // reported for whatever comes right after a CRLF pair already matches
+    // what treating it as one break would produce. Same json/setup as
+    // `test_set_max_depth_rejects_deeper_containers_at_the_opening_bracket_position`,
+    // with a CRLF inserted before the second `[`.
+    let json = "[\r\n[1]]";
+    let mut parser = SliceParser::new(json);
+    parser.set_max_depth(1);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    match parser.next_event_located() {
+        Err((ParseError::DepthLimitExceeded { depth: 2 }, position)) => {
+            assert_eq!(position.byte_offset, 4); // just past the second `[`, at offset 3
+            assert_eq!(position.line, 2);
+            assert_eq!(position.column, 2);
+        }
+        other => panic!("expected a located DepthLimitExceeded, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_set_max_depth_allows_containers_within_limit() {
+    let json = r#"[[1]]"#;
+    let mut parser = SliceParser::new(json);
+    parser.set_max_depth(2);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("1"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_depth_tracks_nesting_across_start_and_end_events() {
+    let json = r#"[[1], 2]"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.depth(), 0);
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.depth(), 1);
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.depth(), 2);
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("1"))));
+    assert_eq!(parser.depth(), 2);
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.depth(), 1);
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("2"))));
+    assert_eq!(parser.depth(), 1);
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.depth(), 0);
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+    assert_eq!(parser.depth(), 0);
+}
+
+#[test]
+fn test_remaining_depth_reflects_set_max_depth() {
+    let json = r#"[[1]]"#;
+    let mut parser = SliceParser::new(json);
+    parser.set_max_depth(2);
+
+    assert_eq!(parser.remaining_depth(), Some(2));
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.remaining_depth(), Some(1));
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.remaining_depth(), Some(0));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("1"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.remaining_depth(), Some(1));
+}
+
+#[test]
+fn test_remaining_depth_falls_back_to_bitbucket_capacity_without_set_max_depth() {
+    // `DefaultConfig`'s bucket is a u32 (see
+    // `test_exceeding_native_bitstack_capacity_is_a_clean_error_without_set_max_depth`),
+    // so with no `set_max_depth` call, 32 levels of headroom come from the
+    // bucket's own compile-time width instead.
+    let mut parser = SliceParser::new("[[1]]");
+    assert_eq!(parser.remaining_depth(), Some(32));
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.remaining_depth(), Some(31));
+}
+
+#[test]
+fn test_in_object_and_in_array_reflect_the_innermost_open_container() {
+    let json = r#"{"a": [1, {"b": 2}]}"#;
+    let mut parser = SliceParser::new(json);
+
+    // Document root: neither.
+    assert!(!parser.in_object());
+    assert!(!parser.in_array());
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(parser.in_object());
+    assert!(!parser.in_array());
+
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert!(!parser.in_object());
+    assert!(parser.in_array());
+
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("1"))));
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    // The innermost container is the nested object, not the array holding it.
+    assert!(parser.in_object());
+    assert!(!parser.in_array());
+
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("b"))));
+    assert_eq!(parser.next_event(), Ok(Event::Number(String::Borrowed("2"))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    // Back out to the array.
+    assert!(!parser.in_object());
+    assert!(parser.in_array());
+
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert!(parser.in_object());
+    assert!(!parser.in_array());
+
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert!(!parser.in_object());
+    assert!(!parser.in_array());
+}
+
+#[test]
+fn test_reject_escaped_keys_allows_plain_keys() {
+    let json = r#"{"plain": 1}"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = SliceParser::with_buffer(json, &mut scratch);
+    parser.set_reject_escaped_keys(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    match parser.next_event() {
+        Ok(Event::Key(key)) => {
+            assert_eq!(&*key, "plain");
+            assert!(!key.was_escaped());
+        }
+        other => panic!("expected a plain key, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_reject_escaped_keys_rejects_an_escaped_key() {
+    let json = r#"{"a\nb": 1}"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = SliceParser::with_buffer(json, &mut scratch);
+    parser.set_reject_escaped_keys(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Err(ParseError::EscapedKeyRejected));
+}
+
+#[test]
+fn test_reject_escaped_keys_does_not_affect_string_values() {
+    // The strict mode is about keys specifically; an escaped string value
+    // is unaffected.
+    let json = r#"{"key": "a\nb"}"#;
+    let mut scratch = [0u8; 64];
+    let mut parser = SliceParser::with_buffer(json, &mut scratch);
+    parser.set_reject_escaped_keys(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    match parser.next_event() {
+        Ok(Event::String(s)) => {
+            assert_eq!(&*s, "a\nb");
+            assert!(s.was_escaped());
+        }
+        other => panic!("expected the escaped string value, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_reject_bidi_controls_off_by_default_allows_them() {
+    let json = "\"a\u{202E}b\"";
+    let mut parser = SliceParser::new(json);
+
+    match parser.next_event() {
+        Ok(Event::String(s)) => assert_eq!(&*s, "a\u{202E}b"),
+        other => panic!("expected the string to parse unchanged, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_reject_bidi_controls_rejects_a_bidi_override_in_a_string() {
+    let json = "\"a\u{202E}b\"";
+    let mut parser = SliceParser::new(json);
+    parser.set_reject_bidi_controls(true);
+
+    assert_eq!(parser.next_event(), Err(ParseError::BidiControlInString));
+}
+
+#[test]
+fn test_reject_bidi_controls_rejects_a_bidi_isolate_in_a_key() {
+    let json = "{\"a\u{2066}b\": 1}";
+    let mut scratch = [0u8; 64];
+    let mut parser = SliceParser::with_buffer(json, &mut scratch);
+    parser.set_reject_bidi_controls(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Err(ParseError::BidiControlInString));
+}
+
+#[test]
+fn test_reject_bidi_controls_allows_ordinary_text() {
+    let json = r#""plain text""#;
+    let mut parser = SliceParser::new(json);
+    parser.set_reject_bidi_controls(true);
+
+    match parser.next_event() {
+        Ok(Event::String(s)) => assert_eq!(&*s, "plain text"),
+        other => panic!("expected plain text to pass through, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_whitespace_events_off_by_default_matches_prior_behavior() {
+    // Off by default: whitespace is skipped exactly as before, and
+    // `Event::Whitespace` never appears.
+    let json = "{ \"a\" : 1 }";
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Number(String::Borrowed("1")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_whitespace_events_surfaces_indentation_and_separators() {
+    let json = "{ \"a\" : 1 }";
+    let mut parser = SliceParser::new(json);
+    parser.set_whitespace_events(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Whitespace(String::Borrowed(" ")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Whitespace(String::Borrowed(" ")))
+    );
+    // No event at all for the `:` itself -- it's structural punctuation,
+    // not whitespace, and reproducible from context alone.
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Whitespace(String::Borrowed(" ")))
+    );
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Number(String::Borrowed("1")))
+    );
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Whitespace(String::Borrowed(" ")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_whitespace_events_splits_run_terminating_a_bare_number() {
+    // The space after `1` both ends the bare number and starts the next
+    // whitespace run; each must surface as its own event rather than being
+    // merged or dropped.
+    let json = "[1 , 2]";
+    let mut parser = SliceParser::new(json);
+    parser.set_whitespace_events(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Number(String::Borrowed("1")))
+    );
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Whitespace(String::Borrowed(" ")))
+    );
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Whitespace(String::Borrowed(" ")))
+    );
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Number(String::Borrowed("2")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_whitespace_events_flushes_trailing_whitespace_before_eof() {
+    let json = "[1]  \t\n";
+    let mut parser = SliceParser::new(json);
+    parser.set_whitespace_events(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Number(String::Borrowed("1")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::Whitespace(String::Borrowed("  \t\n")))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_recovery_mode_off_by_default_aborts_on_first_error() {
+    // Off by default: a malformed token still aborts the parse exactly as
+    // before, matching every prior release's behavior.
+    let json = r#"{"a":,"b":true}"#;
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert!(matches!(
+        parser.next_event(),
+        Err(ParseError::TokenizerError(_))
+    ));
+}
+
+#[test]
+fn test_recovery_mode_emits_error_event_and_recovers() {
+    // `,` where a value was expected after `"a":` -- recovery resynchronizes
+    // on the next delimiter it can find without mistaking the rest of the
+    // object for structure, which here is the object's own closing `}`
+    // (resynchronization scans raw bytes rather than re-running the
+    // tokenizer, so it can't recover the still-valid `"b":true` pair that
+    // `}` happens to follow -- see `ParserCore::set_recovery_mode`'s doc
+    // comment on why this is necessarily best-effort).
+    let json = r#"{"a":,"b":true}"#;
+    let mut parser = SliceParser::new(json);
+    parser.set_recovery_mode(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    match parser.next_event() {
+        Ok(Event::Error { position, kind }) => {
+            assert_eq!(position, 6);
+            assert!(matches!(kind, ParseError::TokenizerError(_)));
+        }
+        other => panic!("expected a recovered Event::Error, got {other:?}"),
+    }
+    // The object is still properly closed -- balanced even though its
+    // content was partly discarded during recovery.
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_recovery_mode_closes_open_containers_when_input_ends_after_error() {
+    // The malformed value is also the last byte of the document: resync
+    // never finds a `,`/`}`/`]` to re-anchor on, so the container left open
+    // by the error must still be closed once input truly ends instead of
+    // leaving an unbalanced stream.
+    let json = r#"{"a":@"#;
+    let mut parser = SliceParser::new(json);
+    parser.set_recovery_mode(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert!(matches!(parser.next_event(), Ok(Event::Error { .. })));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_recovery_mode_max_recovery_errors_aborts_instead_of_resynchronizing_again() {
+    // Two independent bad array elements: with no cap, recovery mode would
+    // emit an `Event::Error` for each and keep going. Capped at one, the
+    // second error aborts the parse as `Err` instead of resynchronizing
+    // past it, the same as if recovery mode were off for that error.
+    let json = r#"[@,@]"#;
+    let mut parser = SliceParser::new(json);
+    parser.set_recovery_mode(true);
+    parser.set_max_recovery_errors(1);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert!(matches!(parser.next_event(), Ok(Event::Error { .. })));
+    assert!(matches!(
+        parser.next_event(),
+        Err(ParseError::TokenizerError(_))
+    ));
+}
+
+#[test]
+fn test_lenient_syntax_off_by_default_rejects_trailing_comma() {
+    let mut parser = SliceParser::new(r#"[1,2,]"#);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.next_event(), Ok(Event::Number(JsonNumber::from_slice(b"1").unwrap())));
+    assert_eq!(parser.next_event(), Ok(Event::Number(JsonNumber::from_slice(b"2").unwrap())));
+    assert!(matches!(
+        parser.next_event(),
+        Err(ParseError::TokenizerError(_))
+    ));
+}
+
+#[test]
+fn test_lenient_syntax_allows_trailing_comma_once_enabled() {
+    let mut parser = SliceParser::new(r#"[1,2,]"#);
+    parser.set_lenient_syntax(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(parser.next_event(), Ok(Event::Number(JsonNumber::from_slice(b"1").unwrap())));
+    assert_eq!(parser.next_event(), Ok(Event::Number(JsonNumber::from_slice(b"2").unwrap())));
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_lenient_syntax_allows_leading_plus_sign_and_value_is_unaffected() {
+    let mut parser = SliceParser::new(r#"[+5]"#);
+    parser.set_lenient_syntax(true);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    match parser.next_event() {
+        Ok(Event::Number(n)) => {
+            assert_eq!(n.as_str(), "+5");
+            assert_eq!(n.as_int(), Some(5));
+        }
+        other => panic!("expected a Number event, got {other:?}"),
+    }
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
@@ -55,7 +55,7 @@ fn test_malformed_json_invalid_escape() {
 
     // Should fail on invalid escape sequence
     match parser.next_event() {
-        Err(ParseError::InvalidEscapeSequence) => {
+        Err(ParseError::UnknownEscapeChar { .. }) => {
             // Expected behavior
         }
         Err(ParseError::TokenizerError(_)) => {
@@ -79,7 +79,7 @@ fn test_malformed_json_invalid_unicode_escape() {
 
     // Should fail on invalid Unicode escape
     match parser.next_event() {
-        Err(ParseError::InvalidUnicodeHex) => {
+        Err(ParseError::InvalidUnicodeHexDigit { .. }) => {
             // Expected behavior
         }
         Err(ParseError::TokenizerError(_)) => {
@@ -188,6 +188,27 @@ fn test_malformed_json_unexpected_comma() {
     }
 }
 
+#[test]
+fn test_unterminated_array_error_reports_where_it_was_opened() {
+    let json = r#"{"a":[1,"#; // `[` at byte 5 never closed
+    let mut parser = SliceParser::new(json);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert_eq!(parser.next_event(), Ok(Event::Key(String::Borrowed("a"))));
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+
+    match parser.next_event() {
+        Err(ParseError::TokenizerError(e)) => {
+            assert_eq!(e.open_container_offset(), Some(5));
+        }
+        other => panic!(
+            "Expected TokenizerError pointing at the unterminated `[`, got: {:?}",
+            other
+        ),
+    }
+}
+
 #[test]
 fn test_malformed_json_invalid_number() {
     let json = r#"{"number": 123.456.789}"#; // Invalid number format
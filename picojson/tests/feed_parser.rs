@@ -0,0 +1,350 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Integration tests for the iterator-style FeedParser
+use picojson::{FeedEvent, FeedParser, ParseError, Poll, PollParser, Position};
+
+#[test]
+fn test_feed_parser_single_chunk() {
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new(&mut buffer);
+
+    let events: Vec<_> = parser.feed(br#"{"a": 1}"#).unwrap().collect();
+    assert_eq!(
+        events,
+        [
+            FeedEvent::StartDocument,
+            FeedEvent::StartObject,
+            FeedEvent::Key("a".into()),
+            FeedEvent::Number("1".into()),
+            FeedEvent::EndObject,
+        ]
+    );
+
+    let tail: Vec<_> = parser.finish().unwrap().collect();
+    assert_eq!(tail, [FeedEvent::EndDocument]);
+}
+
+#[test]
+fn test_feed_parser_resumes_across_chunk_boundary() {
+    // Split mid-key and mid-number so the parser must carry state between calls.
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new(&mut buffer);
+
+    let mut events = Vec::new();
+    events.extend(parser.feed(br#"{"na"#).unwrap());
+    events.extend(parser.feed(br#"me": 4"#).unwrap());
+    events.extend(parser.feed(br#"2}"#).unwrap());
+    events.extend(parser.finish().unwrap());
+
+    assert_eq!(
+        events,
+        [
+            FeedEvent::StartDocument,
+            FeedEvent::StartObject,
+            FeedEvent::Key("name".into()),
+            FeedEvent::Number("42".into()),
+            FeedEvent::EndObject,
+            FeedEvent::EndDocument,
+        ]
+    );
+}
+
+#[test]
+fn test_feed_parser_ndjson_streams_concatenated_values_across_chunks() {
+    // Split the second record across a chunk boundary, and leave trailing
+    // whitespace after the last one to confirm it's tolerated as clean EOF.
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new_ndjson(&mut buffer);
+
+    let mut events = Vec::new();
+    events.extend(parser.feed(b"{\"a\": 1}\n{\"a\":").unwrap());
+    events.extend(parser.feed(b" 2}\n  \n").unwrap());
+    events.extend(parser.finish().unwrap());
+
+    assert_eq!(
+        events,
+        [
+            FeedEvent::StartDocument,
+            FeedEvent::StartObject,
+            FeedEvent::Key("a".into()),
+            FeedEvent::Number("1".into()),
+            FeedEvent::EndObject,
+            FeedEvent::EndDocument,
+            FeedEvent::StartDocument,
+            FeedEvent::StartObject,
+            FeedEvent::Key("a".into()),
+            FeedEvent::Number("2".into()),
+            FeedEvent::EndObject,
+            FeedEvent::EndDocument,
+        ]
+    );
+}
+
+#[test]
+fn test_poll_parser_ndjson_streams_concatenated_values() {
+    let mut buffer = [0u8; 128];
+    let mut parser = PollParser::new_ndjson(&mut buffer);
+
+    parser.feed(b"1\n2\n").unwrap();
+    let mut events = Vec::new();
+    loop {
+        match parser.poll_event() {
+            Poll::Event(event) => events.push(event),
+            Poll::NeedMoreInput => break,
+        }
+    }
+    parser.finish().unwrap();
+    while let Poll::Event(event) = parser.poll_event() {
+        events.push(event);
+    }
+
+    assert_eq!(
+        events,
+        [
+            FeedEvent::StartDocument,
+            FeedEvent::Number("1".into()),
+            FeedEvent::EndDocument,
+            FeedEvent::StartDocument,
+            FeedEvent::Number("2".into()),
+            FeedEvent::EndDocument,
+        ]
+    );
+}
+
+#[test]
+fn test_feed_parser_needs_more_input_reflects_mid_token_state() {
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new(&mut buffer);
+
+    // Cut off mid-key: the parser is sitting inside a token.
+    let _ = parser.feed(br#"{"na"#).unwrap();
+    assert!(parser.needs_more_input());
+
+    // Key closed, value not started yet: nothing in progress.
+    let _ = parser.feed(br#"me": "#).unwrap();
+    assert!(!parser.needs_more_input());
+
+    // A number has no closing delimiter of its own, so ending a chunk right
+    // after its digits is still ambiguous -- more digits could follow.
+    let _ = parser.feed(b"42").unwrap();
+    assert!(parser.needs_more_input());
+
+    // The `}` disambiguates the number and closes the object.
+    let _ = parser.feed(b"}").unwrap();
+    assert!(!parser.needs_more_input());
+}
+
+#[test]
+fn test_feed_parser_needs_more_input_mid_unicode_escape() {
+    // U+1F600 is a surrogate pair (an emoji); splitting right after
+    // the high surrogate exercises the unicode escape collector's state
+    // surviving a chunk boundary, not just a plain string/key/number cutoff.
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new(&mut buffer);
+
+    let _ = parser.feed(br#"["\uD83D"#).unwrap();
+    assert!(parser.needs_more_input());
+
+    let events: Vec<_> = parser.feed(br#"\uDE00"]"#).unwrap().collect();
+    assert_eq!(
+        events,
+        [
+            FeedEvent::StartDocument,
+            FeedEvent::StartArray,
+            FeedEvent::String("\u{1F600}".into()),
+            FeedEvent::EndArray,
+        ]
+    );
+    assert!(!parser.needs_more_input());
+
+    let tail: Vec<_> = parser.finish().unwrap().collect();
+    assert_eq!(tail, [FeedEvent::EndDocument]);
+}
+
+#[test]
+fn test_feed_parser_position_advances_across_lines_and_chunks() {
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new(&mut buffer);
+
+    let _ = parser.feed(b"{\n  \"a\": ").unwrap();
+    assert_eq!(
+        parser.position(),
+        Position {
+            byte_offset: 9,
+            line: 2,
+            column: 8,
+        }
+    );
+
+    let _ = parser.feed(b"1}").unwrap();
+    assert_eq!(
+        parser.position(),
+        Position {
+            byte_offset: 11,
+            line: 2,
+            column: 10,
+        }
+    );
+}
+
+#[test]
+fn test_feed_parser_position_carries_newline_flag_across_chunk_split_at_newline() {
+    // The split lands *immediately* after the `\n`, so the "was the previous
+    // byte a newline" bookkeeping has to survive the chunk boundary itself,
+    // not just a later byte within the same chunk.
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new(&mut buffer);
+
+    let _ = parser.feed(b"{\n").unwrap();
+    assert_eq!(
+        parser.position(),
+        Position {
+            byte_offset: 2,
+            line: 2,
+            column: 1,
+        }
+    );
+
+    let _ = parser.feed(b"  1}").unwrap();
+    assert_eq!(
+        parser.position(),
+        Position {
+            byte_offset: 6,
+            line: 2,
+            column: 5,
+        }
+    );
+}
+
+#[test]
+fn test_feed_parser_set_max_depth_rejects_deeper_containers() {
+    let mut buffer = [0u8; 128];
+    let mut parser = FeedParser::new(&mut buffer);
+    parser.set_max_depth(1);
+
+    match parser.feed(b"[[1]]") {
+        Err(ParseError::DepthLimitExceeded { depth: 2 }) => {}
+        Err(other) => panic!("expected DepthLimitExceeded{{ depth: 2 }}, got {other:?}"),
+        Ok(_) => panic!("expected a depth-limit error"),
+    }
+}
+
+#[test]
+fn test_feed_parser_byte_at_a_time_matches_single_chunk() {
+    // A resumed parse (fed one byte at a time, forcing every possible cut
+    // point through a number, a plain string, and an escape) must produce
+    // exactly the events a single all-at-once feed would, since that's the
+    // whole point of being resumable rather than just chunk-aware.
+    let input = br#"{"name": "line1\nline2", "count": 42, "ok": true}"#;
+
+    let mut whole_buffer = [0u8; 128];
+    let mut whole_parser = FeedParser::new(&mut whole_buffer);
+    let mut expected: Vec<_> = whole_parser.feed(input).unwrap().collect();
+    expected.extend(whole_parser.finish().unwrap());
+
+    let mut byte_buffer = [0u8; 128];
+    let mut byte_parser = FeedParser::new(&mut byte_buffer);
+    let mut actual = Vec::new();
+    for byte in input {
+        actual.extend(byte_parser.feed(core::slice::from_ref(byte)).unwrap());
+    }
+    actual.extend(byte_parser.finish().unwrap());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_feed_parser_every_two_way_split_point_matches_single_chunk() {
+    // `write()` never surfaces a spurious `ParseError::EndOfData` for a
+    // chunk that ends mid-token (string, key, number, or `\uXXXX` escape) --
+    // it's caught internally and reported only via `needs_more_input()` --
+    // so splitting the same input into exactly two pieces at *any* byte
+    // offset must still resume correctly and produce identical events.
+    let input = br#"{"name": "li1ne1", "count": 42, "ok": true}"#;
+
+    let mut whole_buffer = [0u8; 128];
+    let mut whole_parser = FeedParser::new(&mut whole_buffer);
+    let mut expected: Vec<_> = whole_parser.feed(input).unwrap().collect();
+    expected.extend(whole_parser.finish().unwrap());
+
+    for split in 0..=input.len() {
+        let mut buffer = [0u8; 128];
+        let mut parser = FeedParser::new(&mut buffer);
+        let mut actual: Vec<_> = parser.feed(&input[..split]).unwrap().collect();
+        actual.extend(parser.feed(&input[split..]).unwrap());
+        actual.extend(parser.finish().unwrap());
+
+        assert_eq!(actual, expected, "split at offset {split} produced different events");
+    }
+}
+
+#[test]
+fn test_poll_parser_reports_need_more_input_mid_chunk() {
+    // Split mid-key and mid-number, polling to exhaustion between feeds.
+    let mut buffer = [0u8; 128];
+    let mut parser = PollParser::new(&mut buffer);
+
+    let mut events = Vec::new();
+    for chunk in [&br#"{"na"#[..], br#"me": 4"#, br#"2}"#] {
+        parser.feed(chunk).unwrap();
+        loop {
+            match parser.poll_event() {
+                Poll::Event(event) => events.push(event),
+                Poll::NeedMoreInput => break,
+            }
+        }
+    }
+
+    parser.finish().unwrap();
+    while let Poll::Event(event) = parser.poll_event() {
+        events.push(event);
+    }
+
+    assert_eq!(
+        events,
+        [
+            FeedEvent::StartDocument,
+            FeedEvent::StartObject,
+            FeedEvent::Key("name".into()),
+            FeedEvent::Number("42".into()),
+            FeedEvent::EndObject,
+            FeedEvent::EndDocument,
+        ]
+    );
+}
+
+#[test]
+fn test_poll_parser_resumes_mid_unicode_escape_split_at_every_byte() {
+    // Feeding one byte at a time forces every possible cut point inside the
+    // escape -- between `\` and `u`, inside the 4 hex digits, and right at
+    // the surrogate boundary -- to resume correctly via poll_event() alone.
+    let input = br#"["\uD83D\uDE00"]"#;
+    let mut buffer = [0u8; 128];
+    let mut parser = PollParser::new(&mut buffer);
+
+    let mut events = Vec::new();
+    for byte in input {
+        parser.feed(core::slice::from_ref(byte)).unwrap();
+        loop {
+            match parser.poll_event() {
+                Poll::Event(event) => events.push(event),
+                Poll::NeedMoreInput => break,
+            }
+        }
+    }
+    parser.finish().unwrap();
+    while let Poll::Event(event) = parser.poll_event() {
+        events.push(event);
+    }
+
+    assert_eq!(
+        events,
+        [
+            FeedEvent::StartDocument,
+            FeedEvent::StartArray,
+            FeedEvent::String("\u{1F600}".into()),
+            FeedEvent::EndArray,
+            FeedEvent::EndDocument,
+        ]
+    );
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Golden event-transcript tests.
+//!
+//! `json_checker_tests.rs`'s `run_parser_test` only counts events, so a
+//! parser that emits the *wrong* events for an accepted file still passes.
+//! Here, each fixture's full `Event` stream is serialized to a canonical
+//! line-oriented text form and compared against a checked-in `.expected`
+//! file under `tests/data/golden_transcripts/`. Set `UPDATE_GOLDEN=1` to
+//! (re)write the `.expected` files from the current parser output instead
+//! of comparing against them.
+
+use picojson::{Event, ParseError, PullParser, SliceParser};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Renders one `Event` as a single canonical transcript line. Strings,
+/// keys, and number raw text are the already-unescaped/raw source text, so
+/// they're included verbatim rather than re-quoted.
+fn transcript_line(event: &Event<'_, '_>) -> String {
+    match event {
+        Event::StartDocument => "StartDocument".to_string(),
+        Event::EndDocument => "EndDocument".to_string(),
+        Event::StartObject => "StartObject".to_string(),
+        Event::EndObject => "EndObject".to_string(),
+        Event::StartArray => "StartArray".to_string(),
+        Event::EndArray => "EndArray".to_string(),
+        Event::Key(k) => format!("Key {}", k.as_str()),
+        Event::String(s) => format!("String {}", s.as_str()),
+        Event::Number(n) => format!("Number {}", n.as_raw_str()),
+        Event::Bool(b) => format!("Bool {b}"),
+        Event::Null => "Null".to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+fn transcript(json: &str) -> Result<String, ParseError> {
+    let mut buffer = [0u8; 2048];
+    let mut parser = SliceParser::with_buffer(json, &mut buffer);
+    let mut lines = Vec::new();
+    loop {
+        let event = parser.next_event()?;
+        let is_end = matches!(event, Event::EndDocument);
+        lines.push(transcript_line(&event));
+        if is_end {
+            break;
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+/// A Myers shortest-edit-script diff between two line sequences, returned
+/// as `-`/`+`/` `-prefixed lines.
+///
+/// Implements the greedy D-path algorithm from Myers' "An O(ND) Difference
+/// Algorithm": for each edit distance `d` from 0 upward, tracks the
+/// furthest-reaching x on every diagonal `k` in `[-d, d]` reachable in
+/// exactly `d` edits, stopping as soon as one reaches the bottom-right
+/// corner of the edit graph, then backtracks the recorded trace to emit
+/// the script.
+fn myers_diff(expected: &[&str], actual: &[&str]) -> Vec<String> {
+    let n = expected.len() as isize;
+    let m = actual.len() as isize;
+    let max_d = (n + m).max(1);
+    let offset = max_d;
+    let size = (2 * max_d + 1) as usize;
+    let mut v = vec![0isize; size];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    let reached_end = 'search: loop {
+        let d = trace.len() as isize;
+        trace.push(v.clone());
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let down = k == -d
+                || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize]);
+            let mut x = if down {
+                v[(k + 1 + offset) as usize]
+            } else {
+                v[(k - 1 + offset) as usize] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && expected[x as usize] == actual[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'search true;
+            }
+            k += 2;
+        }
+        if d >= max_d {
+            break false;
+        }
+    };
+    debug_assert!(reached_end, "myers_diff: no path found within max_d edits");
+
+    let mut result = Vec::new();
+    let mut x = n;
+    let mut y = m;
+    for d in (0..trace.len() as isize).rev() {
+        let v = &trace[d as usize];
+        let k = x - y;
+        let down =
+            k == -d || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize]);
+        let prev_k = if down { k + 1 } else { k - 1 };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            result.push(format!("  {}", expected[(x - 1) as usize]));
+            x -= 1;
+            y -= 1;
+        }
+
+        if d > 0 {
+            if x == prev_x {
+                result.push(format!("+ {}", actual[(y - 1) as usize]));
+            } else {
+                result.push(format!("- {}", expected[(x - 1) as usize]));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+
+    result.reverse();
+    result
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    Path::new(&manifest_dir)
+        .join("tests/data/golden_transcripts")
+        .join(format!("{name}.expected"))
+}
+
+fn assert_matches_golden(name: &str, json: &str) {
+    let actual =
+        transcript(json).unwrap_or_else(|e| panic!("{name}: failed to parse {json:?}: {e:?}"));
+    let path = golden_path(name);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("missing golden file {path:?} -- run with UPDATE_GOLDEN=1 to create it")
+    });
+
+    if actual != expected {
+        let expected_lines: Vec<&str> = expected.lines().collect();
+        let actual_lines: Vec<&str> = actual.lines().collect();
+        let diff = myers_diff(&expected_lines, &actual_lines).join("\n");
+        panic!("{name}: transcript doesn't match {path:?}\n{diff}");
+    }
+}
+
+#[test]
+fn test_transcript_matches_golden_for_flat_object() {
+    assert_matches_golden("flat_object", r#"{"a":1,"b":true,"c":null}"#);
+}
+
+#[test]
+fn test_transcript_matches_golden_for_nested_array_and_object() {
+    assert_matches_golden("nested_array_and_object", r#"{"items":[1,2,{"x":"y"}]}"#);
+}
+
+#[test]
+fn test_myers_diff_reports_a_single_changed_line() {
+    let expected = ["a", "b", "c"];
+    let actual = ["a", "x", "c"];
+    let diff = myers_diff(&expected, &actual);
+    assert_eq!(diff, vec!["  a", "- b", "+ x", "  c"]);
+}
+
+#[test]
+fn test_myers_diff_reports_identical_sequences_as_all_context() {
+    // No `-`/`+` lines when nothing changed -- every line is emitted as
+    // context, same as the changed-line case above does around its edit.
+    let lines = ["a", "b", "c"];
+    let diff = myers_diff(&lines, &lines);
+    assert!(diff.iter().all(|line| line.starts_with("  ")));
+    assert_eq!(diff.len(), lines.len());
+}
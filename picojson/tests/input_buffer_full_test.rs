@@ -134,3 +134,41 @@ fn test_input_buffer_full_with_extremely_long_token() {
         }
     }
 }
+
+#[test]
+fn test_long_string_value_resumes_across_many_small_reader_fills() {
+    // The buffer above fails because the token genuinely doesn't fit in the
+    // buffer at all. Here the string fits in the buffer just fine, but the
+    // `Reader` only ever hands back a few bytes at a time, so the token
+    // still spans many `StreamBuffer` fills -- exactly the "need more data
+    // but can't fit it all in one read" case `StreamBuffer`'s
+    // `NeedMoreInput`/`compact_from` pairing (see their doc comments) is
+    // for. A small fixed buffer should still parse it correctly.
+    let long_value = "x".repeat(500);
+    let json = format!(r#"{{"key": "{long_value}"}}"#);
+
+    let mut buffer = [0u8; 64];
+    let reader = LargeDataReader::new(&json, 3);
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert!(matches!(
+        parser.next_event().unwrap(),
+        picojson::Event::StartObject
+    ));
+    assert!(matches!(
+        parser.next_event().unwrap(),
+        picojson::Event::Key(k) if k.as_ref() == "key"
+    ));
+    match parser.next_event().unwrap() {
+        picojson::Event::String(s) => assert_eq!(s.as_ref(), long_value),
+        other => panic!("expected a String event, got {other:?}"),
+    }
+    assert!(matches!(
+        parser.next_event().unwrap(),
+        picojson::Event::EndObject
+    ));
+    assert!(matches!(
+        parser.next_event().unwrap(),
+        picojson::Event::EndDocument
+    ));
+}
@@ -54,7 +54,7 @@ mod json_checker_tests {
         let mut parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
 
         let to_parse_error = |e: PushParseError<ParseError>| match e {
-            PushParseError::Parse(parse_err) => parse_err,
+            PushParseError::Parse { code, .. } => code,
             PushParseError::Handler(handler_err) => handler_err,
         };
 
@@ -331,6 +331,212 @@ mod json_checker_tests {
         }
     }
 
+    /// A parser front-end selectable by [`run_conformance_suite`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum ParserBackend {
+        Slice,
+        Push,
+        Stream,
+    }
+
+    impl ParserBackend {
+        fn run(self, json_content: &str) -> Result<usize, ParseError> {
+            match self {
+                ParserBackend::Slice => run_parser_test(json_content),
+                ParserBackend::Push => run_push_parser_test(json_content),
+                ParserBackend::Stream => run_stream_parser_test(json_content),
+            }
+        }
+    }
+
+    /// What a conformance corpus filename's prefix says about the expected
+    /// outcome, following the json.org / JSONTestSuite `y_`/`n_`/`i_`
+    /// convention (see `JSON_checker_tests`'s own `pass`/`fail` naming above
+    /// for this crate's original, narrower suite).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum ExpectedOutcome {
+        /// `y_*` -- must parse successfully.
+        MustAccept,
+        /// `n_*` -- must fail to parse.
+        MustReject,
+        /// `i_*` -- implementation-defined; either outcome is valid, so only
+        /// the observed behavior gets recorded, never judged.
+        ImplementationDefined,
+    }
+
+    fn classify_by_filename(filename: &str) -> Option<ExpectedOutcome> {
+        if filename.starts_with("y_") {
+            Some(ExpectedOutcome::MustAccept)
+        } else if filename.starts_with("n_") {
+            Some(ExpectedOutcome::MustReject)
+        } else if filename.starts_with("i_") {
+            Some(ExpectedOutcome::ImplementationDefined)
+        } else {
+            None
+        }
+    }
+
+    /// A structured tally produced by [`run_conformance_suite`], covering
+    /// every `y_`/`n_`/`i_`-prefixed file in the walked directory
+    /// (anything else is skipped, same as this corpus convention does
+    /// upstream).
+    #[derive(Debug, Default)]
+    struct ConformanceReport {
+        accepted: usize,
+        rejected: usize,
+        /// A corpus file couldn't even be read (missing, not UTF-8, ...) --
+        /// distinct from a well-formed `n_` file being correctly rejected.
+        crashed: usize,
+        /// Always 0 today: nothing here runs under a wall-clock deadline,
+        /// so there's no way to observe a hang as distinct from a slow
+        /// pass. Kept as a field so a future timeout wrapper around
+        /// `ParserBackend::run` has somewhere to report into without
+        /// changing this struct's shape.
+        timed_out: usize,
+        /// `i_*` files, paired with whether this backend happened to
+        /// accept them.
+        deviations: Vec<(String, bool)>,
+        /// `y_`/`n_` files whose outcome didn't match their prefix and
+        /// weren't named in `allowed_deviations`.
+        disagreements: Vec<String>,
+    }
+
+
+    /// Walks `dir`, classifies each `y_`/`n_`/`i_`-prefixed file by name,
+    /// runs it through `backend`, and tallies the result into a
+    /// [`ConformanceReport`].
+    ///
+    /// `allowed_deviations` names files (e.g. `"n_single_space.json"`) that
+    /// are known and accepted to disagree with their prefix -- this crate's
+    /// documented deviations (scalar root values per RFC 7159, unbounded
+    /// nesting depth; see `known_deviations` above) live here as data rather
+    /// than as indices baked into the runner, so this same function works
+    /// against any categorized corpus by adjusting the allow-list to match
+    /// what that corpus expects of it.
+    fn run_conformance_suite(
+        dir: &Path,
+        backend: ParserBackend,
+        allowed_deviations: &[&str],
+    ) -> ConformanceReport {
+        let mut report = ConformanceReport::default();
+
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return report,
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(filename) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let Some(expected) = classify_by_filename(filename) else {
+                continue;
+            };
+            let filename = filename.to_string();
+
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(_) => {
+                    report.crashed += 1;
+                    continue;
+                }
+            };
+
+            let accepted = backend.run(&content).is_ok();
+            if accepted {
+                report.accepted += 1;
+            } else {
+                report.rejected += 1;
+            }
+
+            match expected {
+                ExpectedOutcome::MustAccept if !accepted => {
+                    if !allowed_deviations.contains(&filename.as_str()) {
+                        report.disagreements.push(filename);
+                    }
+                }
+                ExpectedOutcome::MustReject if accepted => {
+                    if !allowed_deviations.contains(&filename.as_str()) {
+                        report.disagreements.push(filename);
+                    }
+                }
+                ExpectedOutcome::ImplementationDefined => {
+                    report.deviations.push((filename, accepted));
+                }
+                _ => {}
+            }
+        }
+
+        report
+    }
+
+    mod conformance_runner {
+        use super::*;
+
+        fn sample_dir() -> std::path::PathBuf {
+            let manifest_dir =
+                std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+            Path::new(&manifest_dir).join("tests/data/conformance_sample")
+        }
+
+        #[test]
+        fn test_conformance_suite_tallies_accept_reject_and_deviation_cases() {
+            let report = run_conformance_suite(&sample_dir(), ParserBackend::Slice, &[]);
+
+            // y_array_empty.json accepted, n_object_trailing_comma.json
+            // rejected -- both agree with their prefix, so neither shows up
+            // as a disagreement.
+            assert_eq!(report.accepted, 2); // y_array_empty.json, i_number_huge_exp.json
+            assert_eq!(report.rejected, 1); // n_object_trailing_comma.json
+            assert_eq!(report.crashed, 0);
+            assert!(report.disagreements.is_empty());
+
+            // i_number_huge_exp.json ([1e400]) is implementation-defined:
+            // this crate's NumberResult::IntegerOverflow path means it's
+            // accepted, recorded rather than treated as a failure.
+            assert_eq!(
+                report.deviations,
+                vec![("i_number_huge_exp.json".to_string(), true)]
+            );
+        }
+
+        #[test]
+        fn test_conformance_suite_reports_disagreement_outside_the_allow_list() {
+            // A corpus author who expects this backend to also reject
+            // trailing commas gets a disagreement unless they allow-list it
+            // by name -- same file, opposite-of-actual expectation.
+            let content = fs::read_to_string(
+                sample_dir().join("n_object_trailing_comma.json"),
+            )
+            .unwrap();
+            assert!(ParserBackend::Slice.run(&content).is_err());
+
+            // Flip n_object_trailing_comma.json's own expectation by
+            // reclassifying it as a would-be `y_` file via a throwaway
+            // temp copy, so the runner sees a MustAccept case that actually
+            // gets rejected.
+            let temp_dir = std::env::temp_dir().join("picojson_conformance_disagreement_test");
+            fs::create_dir_all(&temp_dir).unwrap();
+            fs::write(temp_dir.join("y_should_be_rejected.json"), &content).unwrap();
+
+            let report = run_conformance_suite(&temp_dir, ParserBackend::Slice, &[]);
+            assert_eq!(
+                report.disagreements,
+                vec!["y_should_be_rejected.json".to_string()]
+            );
+
+            let report_allowed = run_conformance_suite(
+                &temp_dir,
+                ParserBackend::Slice,
+                &["y_should_be_rejected.json"],
+            );
+            assert!(report_allowed.disagreements.is_empty());
+
+            fs::remove_dir_all(&temp_dir).ok();
+        }
+    }
+
     #[test]
     fn test_comprehensive_suite() {
         let mut pass_count = 0;
@@ -668,6 +668,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_number_event_typed_accessors() {
+        // Confirms `Event::Number`'s typed accessors -- as_i64/as_u64/as_f64/
+        // is_integer -- already give a handler everything NumberTestHandler
+        // above had to fall back to as_str() for.
+        struct NumberTestHandler {
+            seen_int: Option<i64>,
+            seen_float_is_integer: Option<bool>,
+        }
+
+        impl<'a, 'b> PushParserHandler<'a, 'b, ()> for NumberTestHandler {
+            fn handle_event(&mut self, event: Event<'a, 'b>) -> Result<(), ()> {
+                if let Event::Number(n) = event {
+                    if n.is_integer() {
+                        self.seen_int = Some(n.as_i64().unwrap());
+                    } else {
+                        self.seen_float_is_integer = Some(n.is_integer());
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        let mut buffer = [0u8; 64];
+        let handler = NumberTestHandler {
+            seen_int: None,
+            seen_float_is_integer: None,
+        };
+        let mut parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+        parser.write(br#"[42, 3.14]"#).unwrap();
+        parser.finish::<()>().unwrap();
+        let handler = parser.destroy();
+
+        assert_eq!(handler.seen_int, Some(42));
+        assert_eq!(handler.seen_float_is_integer, Some(false));
+    }
+
     #[test]
     fn test_single_slash_escape() {
         use picojson::{DefaultConfig, Event, PushParser, PushParserHandler};
@@ -853,4 +890,139 @@ mod tests {
             "Invalid escape sequence in key should fail"
         );
     }
+
+    #[test]
+    fn test_raw_value_capture_for_object() {
+        use picojson::RawCapture;
+
+        struct RawCapturingHandler {
+            raw_values: std::vec::Vec<std::string::String>,
+            other_events: usize,
+        }
+
+        impl<'a, 'b> PushParserHandler<'a, 'b, ()> for RawCapturingHandler {
+            fn handle_event(&mut self, event: Event<'a, 'b>) -> Result<(), ()> {
+                match event {
+                    Event::RawValue(s) => self.raw_values.push(s.as_str().to_string()),
+                    Event::EndDocument => {}
+                    _ => self.other_events += 1,
+                }
+                Ok(())
+            }
+
+            fn on_value_start(&mut self) -> RawCapture {
+                RawCapture::CaptureRaw
+            }
+        }
+
+        let mut buffer = [0u8; 64];
+        let handler = RawCapturingHandler {
+            raw_values: std::vec::Vec::new(),
+            other_events: 0,
+        };
+        let mut parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+
+        parser.write(br#"{"a": [1, 2, "x"]}"#).unwrap();
+        let handler = parser.finish::<()>().unwrap();
+
+        assert_eq!(handler.raw_values, vec![r#"{"a": [1, 2, "x"]}"#]);
+        assert_eq!(handler.other_events, 0);
+    }
+
+    #[test]
+    fn test_raw_value_capture_for_number_spanning_chunks() {
+        use picojson::RawCapture;
+
+        struct RawCapturingHandler {
+            raw_values: std::vec::Vec<std::string::String>,
+            other_events: usize,
+        }
+
+        impl<'a, 'b> PushParserHandler<'a, 'b, ()> for RawCapturingHandler {
+            fn handle_event(&mut self, event: Event<'a, 'b>) -> Result<(), ()> {
+                match event {
+                    Event::RawValue(s) => self.raw_values.push(s.as_str().to_string()),
+                    Event::EndDocument => {}
+                    _ => self.other_events += 1,
+                }
+                Ok(())
+            }
+
+            fn on_value_start(&mut self) -> RawCapture {
+                RawCapture::CaptureRaw
+            }
+        }
+
+        let mut buffer = [0u8; 64];
+        let handler = RawCapturingHandler {
+            raw_values: std::vec::Vec::new(),
+            other_events: 0,
+        };
+        let mut parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+
+        // Split a top-level number right in the middle of its digits, so it's
+        // delivered via PartialContentSpanStart/End rather than the
+        // single-chunk ContentSpan fast path exercised above.
+        parser.write(b"123").unwrap();
+        parser.write(b"456").unwrap();
+        let handler = parser.finish::<()>().unwrap();
+
+        assert_eq!(handler.raw_values, vec!["123456"]);
+        assert_eq!(handler.other_events, 0);
+    }
+
+    #[test]
+    fn test_streaming_parser_emits_start_and_end_document_per_value() {
+        struct RecordingHandler {
+            events: std::vec::Vec<std::string::String>,
+        }
+
+        impl<'a, 'b> PushParserHandler<'a, 'b, ()> for RecordingHandler {
+            fn handle_event(&mut self, event: Event<'a, 'b>) -> Result<(), ()> {
+                let label = match event {
+                    Event::StartDocument => "start",
+                    Event::EndDocument => "end",
+                    Event::Number(_) => "number",
+                    _ => "other",
+                };
+                self.events.push(label.to_string());
+                Ok(())
+            }
+        }
+
+        let mut buffer = [0u8; 64];
+        let handler = RecordingHandler {
+            events: std::vec::Vec::new(),
+        };
+        let mut parser = PushParser::<_, DefaultConfig>::new_streaming(handler, &mut buffer);
+
+        parser.write(b"1 2 3").unwrap();
+        let handler = parser.finish::<()>().unwrap();
+
+        assert_eq!(
+            handler.events,
+            vec![
+                "start", "number", "end", "start", "number", "end", "start", "number", "end",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error_reports_line_and_column() {
+        use picojson::PushParseError;
+
+        let mut buffer = [0u8; 64];
+        let handler = SimpleHandler;
+        let mut parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+
+        // Second line, third column is where the stray comma is detected.
+        let result = parser.write(b"{\n  ,}");
+        match result {
+            Err(PushParseError::Parse { at, .. }) => {
+                assert_eq!(at.line, 2);
+                assert_eq!(at.column, 3);
+            }
+            other => panic!("Expected a Parse error with a position, got {:?}", other),
+        }
+    }
 }
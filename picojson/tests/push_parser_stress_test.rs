@@ -6,8 +6,8 @@
 //! robustness under different memory and data delivery constraints.
 
 use picojson::{
-    DefaultConfig, Event, JsonNumber, NumberResult, ParseError, PushParseError, PushParser,
-    PushParserHandler,
+    DefaultConfig, Event, Flow, JsonNumber, NumberResult, ParseError, Position, PushParseError,
+    PushParser, PushParserHandler, RawCapture,
 };
 
 /// Owned event representation for comparison
@@ -328,7 +328,7 @@ fn test_push_parsing_with_config(
             Ok(())
         }
         Err(e) => match e {
-            PushParseError::Parse(parse_err) => Err(parse_err),
+            PushParseError::Parse { code, .. } => Err(code),
             PushParseError::Handler(handler_err) => Err(handler_err),
         },
     }
@@ -624,8 +624,12 @@ fn test_push_parser_stress_document_validation() {
         println!("--- Testing Invalid: {} ---", name);
 
         let buffer_size = 50; // Adequate buffer
-        let chunk_patterns: &[&[usize]] = &[&[1], &[3], &[10]];
+        // Includes the byte-by-byte pattern and a single-write (effectively
+        // whole-buffer) pattern, so the error position reported for one
+        // matches the other exactly across the chunk boundaries they imply.
+        let chunk_patterns: &[&[usize]] = &[&[1], &[3], &[10], &[50]];
 
+        let mut positions = Vec::new();
         for &pattern in chunk_patterns {
             let mut buffer = vec![0u8; buffer_size];
             // For invalid JSON tests, use a permissive handler that doesn't validate events
@@ -633,16 +637,279 @@ fn test_push_parser_stress_document_validation() {
             let parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
             let mut writer = ChunkedWriter::new(json, pattern);
 
-            let result = writer.run(parser);
+            let result: Result<_, PushParseError<ParseError>> = writer.run(parser);
 
-            if result.is_ok() {
-                panic!(
+            match result {
+                Ok(_) => panic!(
                     "❌ [P={:?}] Expected FAILURE for '{}', but got SUCCESS",
                     pattern, name
-                );
-            } else {
-                println!("✅ [P={:?}] Correctly FAILED for '{}'", pattern, name);
+                ),
+                Err(PushParseError::Parse { at, .. }) => {
+                    println!(
+                        "✅ [P={:?}] Correctly FAILED for '{}' at {:?}",
+                        pattern, name, at
+                    );
+                    assert_ne!(
+                        at,
+                        Position::default(),
+                        "[P={:?}] '{}' reported a default (untracked) error position",
+                        pattern,
+                        name
+                    );
+                    positions.push((pattern, at));
+                }
+                Err(PushParseError::Handler(_)) => {
+                    panic!("❌ [P={:?}] Unexpected handler error for '{}'", pattern, name)
+                }
             }
         }
+
+        let (reference_pattern, reference_pos) = positions[0];
+        for &(pattern, pos) in &positions[1..] {
+            assert_eq!(
+                pos, reference_pos,
+                "'{}' reported different positions for chunk patterns {:?} ({:?}) vs {:?} ({:?})",
+                name, reference_pattern, reference_pos, pattern, pos
+            );
+        }
+    }
+}
+
+/// Handler that captures every top-level value's verbatim source text via
+/// [`RawCapture::CaptureRaw`] instead of its decoded events.
+struct RawCapturingHandler {
+    raw_values: Vec<String>,
+}
+
+impl<'input, 'scratch> PushParserHandler<'input, 'scratch, ParseError> for RawCapturingHandler {
+    fn handle_event(&mut self, event: Event<'input, 'scratch>) -> Result<(), ParseError> {
+        if let Event::RawValue(s) = event {
+            self.raw_values.push(s.as_str().to_string());
+        }
+        Ok(())
+    }
+
+    fn on_value_start(&mut self) -> RawCapture {
+        RawCapture::CaptureRaw
+    }
+}
+
+#[test]
+fn test_raw_value_capture_splices_identically_across_chunk_boundaries() {
+    // A nested container whose raw text is long enough to cross a 1- and
+    // 3-byte chunk boundary more than once.
+    let json: &[u8] = br#"{"a": [1, 2, {"nested": true}, "nested escape \n here"]}"#;
+
+    let chunk_patterns: &[&[usize]] = &[&[1], &[3], &[7], &[json.len()]];
+    let mut captures = Vec::new();
+
+    for &pattern in chunk_patterns {
+        let mut buffer = vec![0u8; 128];
+        let handler = RawCapturingHandler {
+            raw_values: Vec::new(),
+        };
+        let parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+        let mut writer = ChunkedWriter::new(json, pattern);
+
+        let result: Result<_, PushParseError<ParseError>> = writer.run(parser);
+        let handler =
+            result.unwrap_or_else(|e| panic!("[P={:?}] unexpected parse failure: {:?}", pattern, e));
+
+        captures.push((pattern, handler.raw_values));
+    }
+
+    let (reference_pattern, reference_values) = &captures[0];
+    for (pattern, values) in &captures[1..] {
+        assert_eq!(
+            values, reference_values,
+            "chunk pattern {:?} captured different raw text than {:?}",
+            pattern, reference_pattern
+        );
+    }
+    assert_eq!(
+        reference_values,
+        &vec![core::str::from_utf8(json).unwrap().to_string()]
+    );
+}
+
+/// Handler that skips the value of one particular object key via
+/// [`Flow::SkipContainer`], recording every other event it's delivered.
+struct SkipValueHandler<'target> {
+    skip_key: &'target str,
+    next_value_is_skip_target: bool,
+    received: Vec<OwnedEvent>,
+}
+
+impl<'target> SkipValueHandler<'target> {
+    fn new(skip_key: &'target str) -> Self {
+        Self {
+            skip_key,
+            next_value_is_skip_target: false,
+            received: Vec::new(),
+        }
+    }
+}
+
+impl<'input, 'scratch> PushParserHandler<'input, 'scratch, ParseError> for SkipValueHandler<'_> {
+    fn handle_event(&mut self, event: Event<'input, 'scratch>) -> Result<(), ParseError> {
+        self.received.push(OwnedEvent::from_event(&event));
+        Ok(())
+    }
+
+    fn handle_event_flow(&mut self, event: Event<'input, 'scratch>) -> Result<Flow, ParseError> {
+        let is_skip_target = self.next_value_is_skip_target
+            && matches!(event, Event::StartObject | Event::StartArray);
+        self.next_value_is_skip_target = false;
+        if let Event::Key(k) = &event {
+            self.next_value_is_skip_target = k.as_ref() == self.skip_key;
+        }
+
+        self.received.push(OwnedEvent::from_event(&event));
+        Ok(if is_skip_target {
+            Flow::SkipContainer
+        } else {
+            Flow::Continue
+        })
+    }
+}
+
+#[test]
+fn test_skip_container_omits_nested_events_but_keeps_siblings() {
+    let json: &[u8] = br#"{"a": 1, "skip": {"nested": [1, 2, "deep"], "more": true}, "b": 2}"#;
+    let chunk_patterns: &[&[usize]] = &[&[1], &[5], &[json.len()]];
+
+    for &pattern in chunk_patterns {
+        let mut buffer = vec![0u8; 128];
+        let handler = SkipValueHandler::new("skip");
+        let parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+        let mut writer = ChunkedWriter::new(json, pattern);
+
+        let result: Result<_, PushParseError<ParseError>> = writer.run(parser);
+        let handler = result
+            .unwrap_or_else(|e| panic!("[P={:?}] unexpected parse failure: {:?}", pattern, e));
+
+        assert!(
+            !handler
+                .received
+                .contains(&OwnedEvent::String("deep".to_string())),
+            "[P={:?}] skipped subtree's string content leaked through",
+            pattern
+        );
+        assert!(
+            !handler.received.contains(&OwnedEvent::Bool(true)),
+            "[P={:?}] skipped subtree's bool content leaked through",
+            pattern
+        );
+        assert!(
+            handler.received.contains(&OwnedEvent::Key("b".to_string())),
+            "[P={:?}] sibling content after the skipped container was lost",
+            pattern
+        );
+        assert!(
+            handler
+                .received
+                .contains(&OwnedEvent::Number("2".to_string())),
+            "[P={:?}] sibling content after the skipped container was lost",
+            pattern
+        );
+
+        // Two StartObject events (the document root, and the skipped "skip"
+        // value), but only one EndObject (the root's) -- the skipped value's
+        // own close is never delivered.
+        let start_objects = handler
+            .received
+            .iter()
+            .filter(|e| **e == OwnedEvent::StartObject)
+            .count();
+        let end_objects = handler
+            .received
+            .iter()
+            .filter(|e| **e == OwnedEvent::EndObject)
+            .count();
+        assert_eq!(start_objects, 2, "[P={:?}] unexpected StartObject count", pattern);
+        assert_eq!(end_objects, 1, "[P={:?}] unexpected EndObject count", pattern);
+    }
+}
+
+#[test]
+fn test_skip_container_still_validates_malformed_contents() {
+    // The "skip" value's array is opened with `[` but closed with `}` -- a
+    // structural error the underlying tokenizer must still catch even
+    // though the handler never sees the skipped subtree's events.
+    let json: &[u8] = br#"{"skip": {"a": [1, 2}, "more": true}}"#;
+    let chunk_patterns: &[&[usize]] = &[&[1], &[json.len()]];
+
+    for &pattern in chunk_patterns {
+        let mut buffer = vec![0u8; 64];
+        let handler = SkipValueHandler::new("skip");
+        let parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+        let mut writer = ChunkedWriter::new(json, pattern);
+
+        let result: Result<_, PushParseError<ParseError>> = writer.run(parser);
+        match result {
+            Ok(_) => panic!(
+                "[P={:?}] SkipContainer must not bypass structural validation of its own contents",
+                pattern
+            ),
+            Err(PushParseError::Parse { .. }) => {}
+            Err(PushParseError::Handler(e)) => {
+                panic!("[P={:?}] unexpected handler error: {:?}", pattern, e)
+            }
+        }
+    }
+}
+
+/// Handler that stops parsing after a fixed number of events via
+/// [`Flow::Stop`], recording everything it saw up to (and including) the one
+/// that triggered the stop.
+struct StopAfterNHandler {
+    remaining: usize,
+    received: Vec<OwnedEvent>,
+}
+
+impl<'input, 'scratch> PushParserHandler<'input, 'scratch, ParseError> for StopAfterNHandler {
+    fn handle_event(&mut self, event: Event<'input, 'scratch>) -> Result<(), ParseError> {
+        self.received.push(OwnedEvent::from_event(&event));
+        Ok(())
+    }
+
+    fn handle_event_flow(&mut self, event: Event<'input, 'scratch>) -> Result<Flow, ParseError> {
+        self.received.push(OwnedEvent::from_event(&event));
+        if self.remaining == 0 {
+            return Ok(Flow::Stop);
+        }
+        self.remaining -= 1;
+        Ok(Flow::Continue)
+    }
+}
+
+#[test]
+fn test_stop_ends_parsing_early_without_error() {
+    let json: &[u8] = br#"{"a": 1, "b": 2, "c": 3}"#;
+    let chunk_patterns: &[&[usize]] = &[&[1], &[json.len()]];
+
+    for &pattern in chunk_patterns {
+        let mut buffer = vec![0u8; 64];
+        let handler = StopAfterNHandler {
+            remaining: 2,
+            received: Vec::new(),
+        };
+        let parser = PushParser::<_, DefaultConfig>::new(handler, &mut buffer);
+        let mut writer = ChunkedWriter::new(json, pattern);
+
+        let result: Result<_, PushParseError<ParseError>> = writer.run(parser);
+        let handler = result
+            .unwrap_or_else(|e| panic!("[P={:?}] Flow::Stop must not surface as an error: {:?}", pattern, e));
+
+        assert_eq!(
+            handler.received,
+            vec![
+                OwnedEvent::StartObject,
+                OwnedEvent::Key("a".to_string()),
+                OwnedEvent::Number("1".to_string()),
+            ],
+            "[P={:?}] expected parsing to stop right after the 3rd event",
+            pattern
+        );
     }
 }
@@ -1,5 +1,248 @@
 // Integration test for StreamParser configurability
-use picojson::{ArrayBitStack, BitStackStruct, ChunkReader, Event, PullParser, StreamParser};
+use picojson::{
+    ArrayBitStack, BitStackStruct, ByteReader, ChunkReader, Event, ParseError, PullParser,
+    ReaderParser, StreamParser,
+};
+
+#[test]
+fn test_stream_parser_position_survives_buffer_compaction() {
+    // A small buffer fed a few bytes at a time forces StreamBuffer to
+    // compact away everything before the stray comma long before parsing
+    // reaches it, so line/column here can only come from the running
+    // counters in StreamContentBuilder, not from re-scanning the input.
+    let json = b"{\n  \"a\": ,\n}";
+    let reader = ChunkReader::new(json, 3);
+    let mut buffer = [0u8; 16];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    loop {
+        match parser.next_event_located() {
+            Ok(Event::EndDocument) => panic!("expected a parse error"),
+            Err((_err, pos)) => {
+                assert_eq!(pos.line, 2);
+                assert_eq!(pos.column, 9);
+                break;
+            }
+            Ok(_) => {}
+        }
+    }
+}
+
+#[test]
+fn test_next_raw_value_rejects_capture_that_outlives_compaction() {
+    // The nested object is long enough relative to the tiny buffer that
+    // StreamBuffer has to compact mid-capture, moving the start of the
+    // subtree out from under the span next_raw_value recorded at its
+    // StartObject. That must surface as RawValueTooLarge, not a silently
+    // wrong (or panicking) extraction.
+    let json = br#"{"a": {"x": 1, "y": 2, "z": 3}}"#;
+    let reader = ChunkReader::new(json, 3);
+    let mut buffer = [0u8; 12];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+
+    assert_eq!(parser.next_raw_value(), Err(ParseError::RawValueTooLarge));
+}
+
+#[test]
+fn test_long_plain_string_run_across_small_reader_chunks() {
+    // Long plain (escape-free) runs are now fed to the tokenizer in a single
+    // batch instead of one byte at a time, but the reader still only hands
+    // over a few bytes per `read` and the buffer is small enough to force
+    // mid-string compaction, so this exercises the batching path alongside
+    // the ordinary refill/compaction machinery.
+    let json = br#"{"key": "abcdefghijklmnopqrstuvwxyz0123456789", "after": true}"#;
+    let reader = ChunkReader::new(json, 3);
+    let mut buffer = [0u8; 16];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::String("abcdefghijklmnopqrstuvwxyz0123456789".into()))
+    );
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert_eq!(parser.next_event(), Ok(Event::Bool(true)));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+}
+
+#[test]
+fn test_plain_run_batching_preserves_escapes() {
+    // A plain run both before and after an escape makes sure the batched
+    // path and the escape path hand off to each other correctly, appending
+    // into the same unescaped content rather than clobbering it.
+    let json = br#"{"key": "before\nafter-some-more-plain-text"}"#;
+    let reader = ChunkReader::new(json, 4);
+    let mut buffer = [0u8; 16];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert_eq!(
+        parser.next_event(),
+        Ok(Event::String("before\nafter-some-more-plain-text".into()))
+    );
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+}
+
+#[test]
+fn test_skip_value_discards_nested_subtree_without_reader_running_dry() {
+    // The nested array/object closes with a number immediately followed by
+    // the delimiter (`3]`, then `}`), which the tokenizer reports as a
+    // combined End(NumberAndArray)/ArrayEnd (and End(NumberAndObject)/
+    // ObjectEnd) pair rather than two separate bytes -- make sure depth
+    // tracking handles that merged pair, not just a plain `]`/`}` on its own.
+    let json = br#"{"skip": {"a": [1, 2, 3]}, "keep": 5}"#;
+    let reader = ChunkReader::new(json, 3);
+    let mut buffer = [0u8; 16];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    parser.skip_value().unwrap();
+
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_ndjson_streams_concatenated_top_level_values_across_reader_chunks() {
+    // Records are separated by a newline and read a few bytes at a time, so
+    // this exercises the boundary-detection skip (and the refill it may
+    // need) alongside the ordinary small-chunk refill machinery.
+    let json = b"{\"a\": 1}\n{\"a\": 2}\n42\n";
+    let reader = ChunkReader::new(json, 3);
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::new_ndjson(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    // No more records: true EOF keeps returning EndDocument.
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_ndjson_skips_blank_line_between_records() {
+    // A blank line (just the newline that ends the previous record's line,
+    // with nothing but whitespace before the next) must be skipped rather
+    // than rejected as a second top-level value or leading to a spurious
+    // empty document.
+    let json = b"{\"a\": 1}\n\n{\"a\": 2}\n";
+    let reader = ChunkReader::new(json, 3);
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::new_ndjson(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    assert_eq!(parser.next_event(), Ok(Event::StartObject));
+    assert!(matches!(parser.next_event(), Ok(Event::Key(_))));
+    assert!(matches!(parser.next_event(), Ok(Event::Number(_))));
+    assert_eq!(parser.next_event(), Ok(Event::EndObject));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+
+    // No more records: true EOF keeps returning EndDocument.
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_stream_parser_decodes_surrogate_pair_split_across_reader_chunks() {
+    // The high/low surrogate escape is split across reader reads, so the
+    // combined codepoint can only come from state the content builder carries
+    // between refills, not from seeing both halves in one buffered read.
+    let json = br#"["\uD83D\uDE00"]"#;
+    let reader = ChunkReader::new(json, 5);
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    match parser.next_event() {
+        Ok(Event::String(s)) => assert_eq!(&*s, "\u{1F600}"),
+        other => panic!("expected the decoded emoji, got {other:?}"),
+    }
+    assert_eq!(parser.next_event(), Ok(Event::EndArray));
+    assert_eq!(parser.next_event(), Ok(Event::EndDocument));
+}
+
+#[test]
+fn test_stream_parser_rejects_lone_high_surrogate() {
+    let json = br#"["\uD83D"]"#;
+    let reader = ChunkReader::new(json, 5);
+    let mut buffer = [0u8; 32];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert_eq!(parser.next_event(), Ok(Event::StartArray));
+    assert_eq!(
+        parser.next_event(),
+        Err(ParseError::UnpairedHighSurrogate)
+    );
+}
+
+#[test]
+fn test_stream_parser_next_event_with_span_covers_full_lexeme() {
+    // Small buffer and small reader chunks so the span has to come from the
+    // running counters in StreamContentBuilder, the same way position() does
+    // in test_stream_parser_position_survives_buffer_compaction, rather than
+    // from re-scanning input that's still resident.
+    let json = br#"{"key": 42}"#;
+    let reader = ChunkReader::new(json, 3);
+    let mut buffer = [0u8; 16];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    let (event, span) = parser.next_event_with_span().unwrap();
+    assert_eq!(event, Event::StartObject);
+    assert_eq!(span.start, 0);
+    assert_eq!(span.end, 1);
+
+    let (event, span) = parser.next_event_with_span().unwrap();
+    assert!(matches!(event, Event::Key(_)));
+    assert_eq!(&json[span.start..span.end], br#""key""#);
+
+    let (event, span) = parser.next_event_with_span().unwrap();
+    assert!(matches!(event, Event::Number(_)));
+    assert_eq!(&json[span.start..span.end], b"42");
+}
+
+#[test]
+fn test_reader_parser_alias_is_stream_parser() {
+    // ReaderParser/ByteReader are aliases for StreamParser/Reader; exercise
+    // them under those names to make sure the alias actually resolves.
+    fn assert_is_byte_reader<R: ByteReader>() {}
+    assert_is_byte_reader::<ChunkReader<'_>>();
+
+    let json = b"{\"name\": \"test\"}";
+    let reader = ChunkReader::new(json, 4);
+    let mut buffer = [0u8; 128];
+    let mut parser: ReaderParser<_> = ReaderParser::new(reader, &mut buffer);
+
+    assert_eq!(parser.next_event().unwrap(), Event::StartObject);
+    assert!(matches!(parser.next_event().unwrap(), Event::Key(_)));
+    assert!(matches!(parser.next_event().unwrap(), Event::String(_)));
+    assert_eq!(parser.next_event().unwrap(), Event::EndObject);
+    assert_eq!(parser.next_event().unwrap(), Event::EndDocument);
+}
 
 #[test]
 fn test_stream_parser_default_config() {
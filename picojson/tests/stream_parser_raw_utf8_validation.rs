@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! `StreamParser` validates a string's raw (unescaped) bytes incrementally,
+//! via `Utf8Validator`, rather than only discovering malformed UTF-8 once
+//! the fully assembled content reaches `core::str::from_utf8`. These tests
+//! cover the resulting `ParseError::InvalidUtf8Sequence`, including across
+//! a chunk boundary that splits a multibyte character.
+
+use picojson::{ChunkReader, Event, ParseError, PullParser, StreamParser};
+
+#[test]
+fn test_stray_continuation_byte_in_raw_string_is_rejected() {
+    // 0x80 on its own is a continuation byte with no lead byte -- not valid
+    // UTF-8, and never escaped, so it's scanned as plain string content.
+    let json = [b'"', b'a', 0x80, b'b', b'"'];
+    let reader = ChunkReader::full_slice(&json);
+    let mut buffer = [0u8; 64];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    match parser.next_event() {
+        Err(ParseError::InvalidUtf8Sequence) => {}
+        other => panic!("Expected InvalidUtf8Sequence, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_overlong_encoding_in_raw_string_is_rejected() {
+    // 0xC0 0x80 is an overlong encoding of NUL; only 0xC2..=0xDF may start a
+    // 2-byte sequence.
+    let json = [b'"', 0xC0, 0x80, b'"'];
+    let reader = ChunkReader::full_slice(&json);
+    let mut buffer = [0u8; 64];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    match parser.next_event() {
+        Err(ParseError::InvalidUtf8Sequence) => {}
+        other => panic!("Expected InvalidUtf8Sequence, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_truncated_multibyte_sequence_at_closing_quote_is_rejected() {
+    // 0xE4 0xB8 starts a 3-byte sequence that never gets its third byte --
+    // the closing quote arrives instead.
+    let json = [b'"', 0xE4, 0xB8, b'"'];
+    let reader = ChunkReader::full_slice(&json);
+    let mut buffer = [0u8; 64];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    match parser.next_event() {
+        Err(ParseError::InvalidUtf8Sequence) => {}
+        other => panic!("Expected InvalidUtf8Sequence, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_well_formed_multibyte_sequence_split_across_chunks_still_parses() {
+    // "中" is 3 well-formed UTF-8 bytes (0xE4 0xB8 0xAD); a 2-byte chunk
+    // reader splits it mid-character, exercising the validator's DFA state
+    // carrying across separate `consume_plain_content_run` calls.
+    let json = "\"\u{4E2D}\"".into_bytes();
+    let reader = ChunkReader::new(&json, 2);
+    let mut buffer = [0u8; 64];
+    let mut parser = StreamParser::new(reader, &mut buffer);
+
+    assert_eq!(
+        parser.next_event().unwrap(),
+        Event::String("\u{4E2D}".into())
+    );
+}
@@ -6,7 +6,8 @@
 //! handle UTF-16 surrogate pairs identically across different configurations.
 
 use picojson::{
-    ChunkReader, DefaultConfig, Event, PullParser, SliceParser, StreamParser, String as JsonString,
+    ChunkReader, DefaultConfig, Event, ParseError, PullParser, SliceParser, StreamParser,
+    String as JsonString, SurrogatePolicy,
 };
 
 /// Test fixture that runs a JSON input against any PullParser implementation
@@ -412,6 +413,127 @@ fn test_pathological_cases() {
     );
 }
 
+#[test]
+fn test_replace_policy_rescues_dangling_high_surrogate_at_string_end() {
+    // Same pathological input as `test_pathological_cases`, but with
+    // `SurrogatePolicy::Replace` set: `UnicodeEscapeCollector::finish_string`
+    // now substitutes U+FFFD instead of erroring, so the parse succeeds.
+    let input = r#"["\uD801"]"#;
+    let expected = [
+        Event::StartArray,
+        Event::String(JsonString::Unescaped("\u{FFFD}")),
+        Event::EndArray,
+        Event::EndDocument,
+    ];
+
+    let mut buffer = [0u8; 1024];
+    let mut parser = create_stream_parser_full(input, &mut buffer);
+    parser.set_surrogate_policy(SurrogatePolicy::Replace);
+    test_fixture(parser, &expected);
+
+    let mut buffer = [0u8; 1024];
+    let mut parser = create_stream_parser_chunked(input, 3, &mut buffer);
+    parser.set_surrogate_policy(SurrogatePolicy::Replace);
+    test_fixture(parser, &expected);
+}
+
+/// Runs `input` with [`SurrogatePolicy::Replace`] set against `SliceParser`,
+/// a full-chunk `StreamParser`, and a `StreamParser` chunked at
+/// `stream_chunk_size`, checking all three produce `expected_events`.
+fn test_replace_policy_fixture(input: &str, stream_chunk_size: usize, expected_events: &[Event]) {
+    let mut scratch = [0u8; 1024];
+    let mut parser = create_slice_parser(input, &mut scratch);
+    parser.set_surrogate_policy(SurrogatePolicy::Replace);
+    test_fixture(parser, expected_events);
+
+    let mut buffer = [0u8; 1024];
+    let mut parser = create_stream_parser_full(input, &mut buffer);
+    parser.set_surrogate_policy(SurrogatePolicy::Replace);
+    test_fixture(parser, expected_events);
+
+    let mut buffer = [0u8; 1024];
+    let mut parser = create_stream_parser_chunked(input, stream_chunk_size, &mut buffer);
+    parser.set_surrogate_policy(SurrogatePolicy::Replace);
+    test_fixture(parser, expected_events);
+}
+
+#[test]
+fn test_replace_policy_substitutes_lone_low_surrogate() {
+    // Same input as `test_lone_low_surrogate_error`, but with
+    // `SurrogatePolicy::Replace`: a low surrogate with no preceding high
+    // surrogate becomes one U+FFFD instead of `ParseError::UnpairedLowSurrogate`.
+    test_replace_policy_fixture(
+        r#"["\uDC37"]"#,
+        5,
+        &[
+            Event::StartArray,
+            Event::String(JsonString::Unescaped("\u{FFFD}")),
+            Event::EndArray,
+            Event::EndDocument,
+        ],
+    );
+}
+
+#[test]
+fn test_replace_policy_substitutes_high_surrogate_followed_by_non_low_surrogate() {
+    // `\uD801` is immediately followed by a second `\u` escape for a
+    // non-surrogate codepoint, flushing the dangling high surrogate as one
+    // U+FFFD in the very same call that decodes `A` -- both come out
+    // of `EscapeProcessor::process_unicode_escape`'s "pending high
+    // surrogate, not paired" branch together, in that order.
+    test_replace_policy_fixture(
+        r#"["\uD801\u0041"]"#,
+        6,
+        &[
+            Event::StartArray,
+            Event::String(JsonString::Unescaped("\u{FFFD}A")),
+            Event::EndArray,
+            Event::EndDocument,
+        ],
+    );
+}
+
+#[test]
+fn test_replace_policy_substitutes_double_high_surrogate() {
+    // Same input as `test_double_high_surrogate_error`. The first high
+    // surrogate is flushed as U+FFFD when the second one arrives instead of
+    // pairing with it; the second then itself dangles to the end of the
+    // string, so `finish_string` flushes it as a second U+FFFD.
+    test_replace_policy_fixture(
+        r#"["\uD801\uD802"]"#,
+        8,
+        &[
+            Event::StartArray,
+            Event::String(JsonString::Unescaped("\u{FFFD}\u{FFFD}")),
+            Event::EndArray,
+            Event::EndDocument,
+        ],
+    );
+}
+
+#[test]
+fn test_wtf8_policy_still_fails_on_dangling_high_surrogate_but_with_a_different_error() {
+    // With `SurrogatePolicy::Wtf8`, `finish_string` succeeds in emitting the
+    // surrogate's 3-byte WTF-8 encoding into the scratch buffer, but that
+    // encoding isn't valid UTF-8 on its own, so the final `from_utf8` check
+    // over the assembled string still rejects it. The parse still fails
+    // overall, just via `ParseError::Utf8` instead of
+    // `ParseError::UnpairedHighSurrogate`.
+    let input = r#"["\uD801"]"#;
+
+    let mut buffer = [0u8; 1024];
+    let mut parser = create_stream_parser_full(input, &mut buffer);
+    parser.set_surrogate_policy(SurrogatePolicy::Wtf8);
+    loop {
+        match parser.next_event() {
+            Ok(Event::EndDocument) => panic!("Expected error but parsing completed successfully"),
+            Ok(_) => continue,
+            Err(ParseError::Utf8(_)) => break,
+            Err(other) => panic!("Expected ParseError::Utf8, got {:?}", other),
+        }
+    }
+}
+
 #[test]
 fn test_complex_nested_structures() {
     let input = r#"{"users": [{"name": "\uD801\uDC37", "emoji": "\uD834\uDD1E"}]}"#;